@@ -0,0 +1,65 @@
+//! Generates the ARN to CloudFormation resource type table from `data/cloudformation_types.csv`.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+const CLOUDFORMATION_TYPES: &str = "data/cloudformation_types.csv";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", CLOUDFORMATION_TYPES);
+
+    let csv = fs::read_to_string(CLOUDFORMATION_TYPES)
+        .unwrap_or_else(|err| panic!("reading {}: {}", CLOUDFORMATION_TYPES, err));
+
+    // Sorted by service and resource type, so the table can be binary searched
+    let mut types = BTreeMap::new();
+    for (idx, line) in csv.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(',').collect();
+        let (service, resource_type, type_name) = match fields[..] {
+            [service, resource_type, type_name] => (service, resource_type, type_name),
+            _ => panic!("{}:{}: expected 3 fields", CLOUDFORMATION_TYPES, idx + 1),
+        };
+
+        let parts: Vec<&str> = type_name.split("::").collect();
+        if service.is_empty() || parts.len() != 3 || parts.iter().any(|part| part.is_empty()) {
+            panic!("{}:{}: invalid entry", CLOUDFORMATION_TYPES, idx + 1);
+        }
+
+        if types.insert((service, resource_type), type_name).is_some() {
+            panic!(
+                "{}:{}: duplicate entry for {}/{}",
+                CLOUDFORMATION_TYPES,
+                idx + 1,
+                service,
+                resource_type
+            );
+        }
+    }
+
+    let mut table = String::from(
+        "/// `(service, resource type, CloudFormation type)`, sorted by service and resource type so it can be binary\n\
+         /// searched.\n\
+         ///\n\
+         /// An empty resource type matches resources that have no type prefix at all (an S3 bucket, an SQS queue).\n\
+         const RESOURCE_TYPES: &[(&str, &str, &str)] = &[\n",
+    );
+    for ((service, resource_type), type_name) in types {
+        writeln!(
+            table,
+            "    ({:?}, {:?}, {:?}),",
+            service, resource_type, type_name
+        )
+        .unwrap();
+    }
+    table.push_str("];\n");
+
+    let out = Path::new(&env::var_os("OUT_DIR").unwrap()).join("cloudformation_types.rs");
+    fs::write(out, table).unwrap();
+}
@@ -0,0 +1,19 @@
+//! Parsing, validating and formatting ARNs without an allocator.
+//!
+//! Build with `cargo build --example no_std --no-default-features`.
+
+#![no_std]
+
+use arn::naive::NaiveArn;
+use arn::validate::{ValidationIssue, ValidationLevel};
+
+/// Parse `input`, check it at [`ValidationLevel::Standard`] and write the ARN into `buf`.
+pub fn check<'b>(input: &str, buf: &'b mut [u8]) -> Result<&'b str, Option<ValidationIssue>> {
+    let arn = NaiveArn::parse(input).map_err(|_| None)?;
+
+    if let Some(issue) = arn.first_issue(ValidationLevel::Standard) {
+        return Err(Some(issue));
+    }
+
+    arn.format_into(buf).ok_or(None)
+}
@@ -0,0 +1,48 @@
+/* C interface to the arn crate, built with the `ffi` feature. See src/ffi.rs for details. */
+
+#ifndef ARN_H
+#define ARN_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define ARN_OK 0
+#define ARN_ERR_NULL (-1)
+#define ARN_ERR_UTF8 (-2)
+#define ARN_ERR_BUFFER_TOO_SMALL (-3)
+#define ARN_ERR_INVALID_LEVEL (-4)
+
+#define ARN_LEVEL_SYNTAX 0
+#define ARN_LEVEL_STANDARD 1
+#define ARN_LEVEL_STRICT 2
+
+/* A string borrowed from the input, which isn't NUL-terminated. `ptr` is NULL for a missing region or account ID. */
+typedef struct ArnStr {
+    const char *ptr;
+    size_t len;
+} ArnStr;
+
+typedef struct ArnParts {
+    ArnStr partition;
+    ArnStr service;
+    ArnStr region;
+    ArnStr account_id;
+    ArnStr resource;
+} ArnParts;
+
+/* Each returns ARN_OK, the number of an ARNxxx error code (7 for ARN007), or a negative ARN_ERR_* code. */
+
+int arn_parse(const char *input, ArnParts *out, size_t *error_position);
+
+int arn_validate(const char *input, int level, size_t *error_position);
+
+int arn_format(const ArnParts *arn, char *buf, size_t len, size_t *written);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ARN_H */
@@ -0,0 +1,140 @@
+//! AWS account IDs
+
+use core::fmt;
+
+use crate::naive::NaiveArn;
+
+/// A 12-digit AWS account ID.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::account::AccountId;
+///
+/// let account_id = AccountId::parse("123456789012").unwrap();
+/// assert_eq!(account_id.root_arn("aws").to_string(), "arn:aws:iam::123456789012:root");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountId<'a>(&'a str);
+
+impl<'a> AccountId<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseAccountIdError> {
+        if s.len() != 12 {
+            return Err(ParseAccountIdError::InvalidLength);
+        }
+
+        if !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseAccountIdError::InvalidCharacter);
+        }
+
+        Ok(AccountId(s))
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// The `arn:partition:iam::account-id:root` ARN that identifies the account as a principal.
+    pub fn root_arn(&self, partition: &'a str) -> NaiveArn<'a> {
+        NaiveArn {
+            partition,
+            service: "iam",
+            region: None,
+            account_id: Some(self.0),
+            resource: "root",
+        }
+    }
+}
+
+impl<'a> NaiveArn<'a> {
+    /// Whether this is an account root principal, `arn:partition:iam::account-id:root`.
+    pub fn is_account_root(&self) -> bool {
+        self.account_root().is_some()
+    }
+
+    /// The account this ARN is the root principal of, if it is an `arn:partition:iam::account-id:root` ARN.
+    pub fn account_root(&self) -> Option<AccountId<'a>> {
+        if self.service != "iam" || self.region.is_some() || self.resource != "root" {
+            return None;
+        }
+
+        AccountId::parse(self.account_id?).ok()
+    }
+}
+
+impl<'a> fmt::Display for AccountId<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseAccountIdError {
+    InvalidLength,
+    InvalidCharacter,
+}
+
+impl fmt::Display for ParseAccountIdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseAccountIdError::InvalidLength => write!(f, "Account ID must be 12 digits long"),
+            ParseAccountIdError::InvalidCharacter => {
+                write!(f, "Account ID must only contain digits")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseAccountIdError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountId, ParseAccountIdError};
+    use crate::naive::NaiveArn;
+
+    #[test]
+    fn root_arn() {
+        let account_id = AccountId::parse("123456789012").unwrap();
+        let arn = account_id.root_arn("aws-cn");
+
+        assert_eq!(arn.to_string(), "arn:aws-cn:iam::123456789012:root");
+        assert!(arn.is_account_root());
+        assert_eq!(arn.account_root(), Some(account_id));
+    }
+
+    #[test]
+    fn parsed_root_arn() {
+        let arn = NaiveArn::parse("arn:aws:iam::123456789012:root").unwrap();
+
+        assert!(arn.is_account_root());
+        assert_eq!(arn.account_root().unwrap().as_str(), "123456789012");
+    }
+
+    #[test]
+    fn not_root() {
+        for arn_str in &[
+            "arn:aws:iam::123456789012:role/root",
+            "arn:aws:iam::123456789012:user/root",
+            "arn:aws:iam:::root",
+            "arn:aws:iam::1234:root",
+            "arn:aws:sts::123456789012:root",
+        ] {
+            let arn = NaiveArn::parse(arn_str).unwrap();
+
+            assert!(!arn.is_account_root(), "{}", arn_str);
+        }
+    }
+
+    #[test]
+    fn malformed_account_ids() {
+        assert_eq!(
+            AccountId::parse("1234-5678-9012"),
+            Err(ParseAccountIdError::InvalidLength)
+        );
+        assert_eq!(
+            AccountId::parse("12345678901a"),
+            Err(ParseAccountIdError::InvalidCharacter)
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! Account aliases, for showing accounts by a memorable name in human-facing output
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, string::String};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::BuildHasher;
+
+/// Looks up the alias of an account, such as `prod-payments` for `123456789012`.
+///
+/// `HashMap`s and `BTreeMap`s from account IDs to aliases are resolvers.
+pub trait AccountAliasResolver {
+    /// The alias of `account_id`, if it has one.
+    fn alias(&self, account_id: &str) -> Option<&str>;
+}
+
+impl<R: AccountAliasResolver + ?Sized> AccountAliasResolver for &R {
+    fn alias(&self, account_id: &str) -> Option<&str> {
+        (**self).alias(account_id)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: BuildHasher> AccountAliasResolver for HashMap<String, String, S> {
+    fn alias(&self, account_id: &str) -> Option<&str> {
+        self.get(account_id).map(String::as_str)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AccountAliasResolver for BTreeMap<String, String> {
+    fn alias(&self, account_id: &str) -> Option<&str> {
+        self.get(account_id).map(String::as_str)
+    }
+}
+
+/// An account ID with its alias, if it has one.
+///
+/// Displays as `123456789012 (prod-payments)`, or just the account ID without an alias.
+///
+/// # Example
+///
+/// ~~~~
+/// # #[cfg(feature = "std")]
+/// # {
+/// use std::collections::HashMap;
+///
+/// use arn::alias::AccountLabel;
+///
+/// let aliases: HashMap<_, _> = [("123456789012".to_owned(), "prod-payments".to_owned())].into();
+///
+/// assert_eq!(AccountLabel::resolve("123456789012", &aliases).to_string(), "123456789012 (prod-payments)");
+/// assert_eq!(AccountLabel::resolve("210987654321", &aliases).to_string(), "210987654321");
+/// # }
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountLabel<'a> {
+    pub account_id: &'a str,
+    pub alias: Option<&'a str>,
+}
+
+impl<'a> AccountLabel<'a> {
+    /// Label `account_id` with its alias from `resolver`.
+    pub fn resolve<R: AccountAliasResolver + ?Sized>(account_id: &'a str, resolver: &'a R) -> Self {
+        AccountLabel {
+            account_id,
+            alias: resolver.alias(account_id),
+        }
+    }
+}
+
+impl<'a> fmt::Display for AccountLabel<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.account_id)?;
+        if let Some(alias) = self.alias {
+            write!(f, " ({})", alias)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    #[cfg(feature = "std")]
+    use super::AccountAliasResolver;
+    use super::AccountLabel;
+    use std::collections::BTreeMap;
+    #[cfg(feature = "std")]
+    use std::collections::HashMap;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn maps() {
+        let hash_map: HashMap<String, String> =
+            [("111111111111".to_owned(), "prod".to_owned())].into();
+        let btree_map: BTreeMap<String, String> = hash_map.clone().into_iter().collect();
+
+        for resolver in [&hash_map as &dyn AccountAliasResolver, &btree_map] {
+            assert_eq!(resolver.alias("111111111111"), Some("prod"));
+            assert_eq!(resolver.alias("222222222222"), None);
+        }
+    }
+
+    #[test]
+    fn label() {
+        let aliases: BTreeMap<String, String> =
+            [("111111111111".to_owned(), "prod".to_owned())].into();
+
+        assert_eq!(
+            AccountLabel::resolve("111111111111", &aliases),
+            AccountLabel {
+                account_id: "111111111111",
+                alias: Some("prod")
+            }
+        );
+        assert_eq!(
+            AccountLabel::resolve("222222222222", &&aliases).to_string(),
+            "222222222222"
+        );
+    }
+}
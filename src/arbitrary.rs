@@ -0,0 +1,127 @@
+//! `quickcheck` support, for property testing code that handles ARNs
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::buf::ArnBuf;
+use crate::partition::Partition;
+
+const SERVICES: &[&str] = &[
+    "dynamodb", "ec2", "iam", "kms", "lambda", "s3", "sns", "sqs", "states",
+];
+
+const RESOURCE_CHARS: &[char] = &[
+    'a', 'b', 'c', 'x', 'y', 'z', 'A', 'Z', '0', '1', '9', '-', '_', '.', '/', ':', '*', '@', '+',
+    '=',
+];
+
+/// Arbitrary ARNs that [`ArnBuf::parse`] accepts: a known partition, one of its regions, a 12-digit account ID and a
+/// resource of up to [`Gen::size`] characters. The region and account ID are each left out a quarter of the time.
+///
+/// Shrinking removes the region and account ID, moves to the `aws` partition, and then shortens the resource, so a
+/// failing ARN shrinks towards one like `arn:aws:s3:::a`.
+impl Arbitrary for ArnBuf {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let partition = *g.choose(Partition::ALL).unwrap();
+
+        let region = if u8::arbitrary(g) % 4 == 0 {
+            None
+        } else {
+            g.choose(partition.regions())
+                .map(|&region| region.to_owned())
+        };
+        let account_id = if u8::arbitrary(g) % 4 == 0 {
+            None
+        } else {
+            Some(format!("{:012}", u64::arbitrary(g) % 1_000_000_000_000))
+        };
+
+        let len = 1 + usize::arbitrary(g) % g.size().max(1);
+        let resource = (0..len)
+            .map(|_| *g.choose(RESOURCE_CHARS).unwrap())
+            .collect();
+
+        ArnBuf {
+            partition: partition.id().to_owned(),
+            service: (*g.choose(SERVICES).unwrap()).to_owned(),
+            region,
+            account_id,
+            resource,
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let mut candidates = Vec::new();
+
+        if self.region.is_some() {
+            candidates.push(ArnBuf {
+                region: None,
+                ..self.clone()
+            });
+        }
+        if self.account_id.is_some() {
+            candidates.push(ArnBuf {
+                account_id: None,
+                ..self.clone()
+            });
+        }
+        if self.partition != "aws" {
+            candidates.push(ArnBuf {
+                partition: "aws".to_owned(),
+                region: None,
+                ..self.clone()
+            });
+        }
+
+        let arn = self.clone();
+        let resources = self.resource.shrink().map(move |resource| ArnBuf {
+            resource,
+            ..arn.clone()
+        });
+
+        // Shrinking the resource can leave it empty or with characters that can't appear in an ARN.
+        Box::new(
+            candidates
+                .into_iter()
+                .chain(resources)
+                .filter(|arn| ArnBuf::parse(&arn.to_string()).as_ref() == Ok(arn)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{Arbitrary, Gen, QuickCheck};
+
+    use crate::buf::ArnBuf;
+
+    #[test]
+    fn round_trips() {
+        fn prop(arn: ArnBuf) -> bool {
+            ArnBuf::parse(&arn.to_string()).as_ref() == Ok(&arn)
+        }
+
+        QuickCheck::new().quickcheck(prop as fn(ArnBuf) -> bool);
+    }
+
+    #[test]
+    fn shrinks_to_minimal() {
+        let mut g = Gen::new(20);
+        let arn = loop {
+            let arn = ArnBuf::arbitrary(&mut g);
+            if arn.region.is_some() && arn.account_id.is_some() && arn.resource.len() > 1 {
+                break arn;
+            }
+        };
+
+        // Shrink greedily, the way quickcheck does for a property that always fails.
+        let mut smallest = arn;
+        while let Some(smaller) = smallest.shrink().next() {
+            smallest = smaller;
+        }
+
+        assert_eq!(smallest.partition, "aws");
+        assert_eq!(smallest.region, None);
+        assert_eq!(smallest.account_id, None);
+        assert_eq!(smallest.resource.chars().count(), 1);
+    }
+}
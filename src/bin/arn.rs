@@ -0,0 +1,391 @@
+//! `arn` command-line tool
+
+use std::collections::HashSet;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, IsTerminal, Write};
+use std::path::PathBuf;
+use std::process;
+
+use arn::find::ArnFinder;
+use arn::naive::NaiveArn;
+use arn::pattern::ArnPattern;
+use arn::validate::ValidationLevel;
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::json;
+
+#[derive(Parser)]
+#[command(name = "arn", version, about = "Parse, validate and explain AWS ARNs")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the components of an ARN
+    Parse {
+        arn: String,
+        #[arg(long, value_enum, default_value_t = Format::Json)]
+        format: Format,
+    },
+    /// Check ARNs, printing a diagnostic for each problem and exiting non-zero if any are invalid
+    Validate {
+        #[arg(required = true)]
+        arns: Vec<String>,
+        /// syntax, standard or strict
+        #[arg(long, default_value_t = ValidationLevel::Standard)]
+        level: ValidationLevel,
+    },
+    /// Describe each component of an ARN in plain English
+    Explain { arn: String },
+    /// Print the ARNs read from stdin, one per line, that match any of the IAM-style patterns
+    Match {
+        #[arg(required = true)]
+        patterns: Vec<String>,
+        /// Print the lines that don't match instead
+        #[arg(short = 'v', long)]
+        invert: bool,
+    },
+    /// Print every distinct valid ARN found in the files (or stdin), in the order they first appear
+    Extract {
+        files: Vec<PathBuf>,
+        /// syntax, standard or strict
+        #[arg(long, default_value_t = ValidationLevel::Standard)]
+        level: ValidationLevel,
+        /// Prefix each ARN with the file and line number it was first found on
+        #[arg(short = 'n', long)]
+        line_numbers: bool,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Json,
+    Table,
+}
+
+fn main() {
+    let code = match Cli::parse().command {
+        Command::Parse { arn, format } => parse(&arn, format),
+        Command::Validate { arns, level } => validate(&arns, level),
+        Command::Explain { arn } => explain(&arn),
+        Command::Match { patterns, invert } => match_stdin(&patterns, invert),
+        Command::Extract {
+            files,
+            level,
+            line_numbers,
+        } => extract(&files, level, line_numbers),
+    };
+
+    process::exit(code);
+}
+
+/// Parse `s`, printing an error and returning `None` if it isn't an ARN.
+///
+/// The error is shown beneath `s`, with the problem highlighted, when stderr is a terminal and `NO_COLOR` isn't set.
+fn parse_or_report(s: &str) -> Option<NaiveArn<'_>> {
+    match NaiveArn::parse(s) {
+        Ok(arn) => Some(arn),
+        Err(err) if use_color() => {
+            eprintln!("{}", err.highlight(s));
+            None
+        }
+        Err(err) => {
+            eprintln!("{}: {}", s, err);
+            None
+        }
+    }
+}
+
+fn use_color() -> bool {
+    env::var_os("NO_COLOR").is_none() && io::stderr().is_terminal()
+}
+
+fn parse(s: &str, format: Format) -> i32 {
+    let arn = match parse_or_report(s) {
+        Some(arn) => arn,
+        None => return 1,
+    };
+
+    match format {
+        Format::Json => println!("{}", to_json(&arn)),
+        Format::Table => print!("{}", to_table(&arn)),
+    }
+
+    0
+}
+
+fn validate(arns: &[String], level: ValidationLevel) -> i32 {
+    let mut code = 0;
+
+    for s in arns {
+        let arn = match parse_or_report(s) {
+            Some(arn) => arn,
+            None => {
+                code = 1;
+                continue;
+            }
+        };
+
+        if let Err(issues) = arn.validate(level) {
+            for issue in issues {
+                eprintln!("{}: {}", s, issue);
+            }
+            code = 1;
+        }
+    }
+
+    code
+}
+
+fn explain(s: &str) -> i32 {
+    match parse_or_report(s) {
+        Some(arn) => {
+            print!("{}", explanation(&arn));
+            0
+        }
+        None => 1,
+    }
+}
+
+/// Like `grep`, exits with 0 if any lines were printed, 1 if none were and 2 on errors.
+fn match_stdin(patterns: &[String], invert: bool) -> i32 {
+    let patterns = match patterns
+        .iter()
+        .map(|pattern| ArnPattern::parse(pattern).map_err(|err| (pattern, err)))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(patterns) => patterns,
+        Err((pattern, err)) => {
+            eprintln!("{}: {}", pattern, err);
+            return 2;
+        }
+    };
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    let mut code = 1;
+
+    for line in io::stdin().lock().lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => {
+                eprintln!("{}", err);
+                return 2;
+            }
+        };
+
+        if matches_any(&patterns, line.trim()) != invert {
+            if writeln!(stdout, "{}", line).is_err() {
+                return 2;
+            }
+            code = 0;
+        }
+    }
+
+    code
+}
+
+/// Whether `line` is an ARN matching any of `patterns`.
+fn matches_any(patterns: &[ArnPattern], line: &str) -> bool {
+    match NaiveArn::parse(line) {
+        Ok(arn) => patterns.iter().any(|pattern| pattern.matches(&arn)),
+        Err(_) => false,
+    }
+}
+
+fn extract(files: &[PathBuf], level: ValidationLevel, line_numbers: bool) -> i32 {
+    let mut inputs: Vec<(String, Box<dyn BufRead>)> = Vec::new();
+
+    if files.is_empty() {
+        inputs.push(("-".to_owned(), Box::new(BufReader::new(io::stdin()))));
+    }
+
+    for path in files {
+        match File::open(path) {
+            Ok(file) => inputs.push((path.display().to_string(), Box::new(BufReader::new(file)))),
+            Err(err) => {
+                eprintln!("{}: {}", path.display(), err);
+                return 2;
+            }
+        }
+    }
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    let mut seen = HashSet::new();
+
+    for (name, input) in inputs {
+        for (idx, line) in input.lines().enumerate() {
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => {
+                    eprintln!("{}: {}", name, err);
+                    return 2;
+                }
+            };
+
+            for arn in find_arns(&line, level) {
+                if !seen.insert(arn.to_owned()) {
+                    continue;
+                }
+
+                let written = if line_numbers {
+                    writeln!(stdout, "{}:{}:{}", name, idx + 1, arn)
+                } else {
+                    writeln!(stdout, "{}", arn)
+                };
+                if written.is_err() {
+                    return 2;
+                }
+            }
+        }
+    }
+
+    0
+}
+
+/// The ARNs in `text` that are valid at `level`.
+fn find_arns(text: &str, level: ValidationLevel) -> Vec<&str> {
+    ArnFinder::default()
+        .find_iter(text)
+        .filter(|(_, arn)| matches!(arn, Ok(arn) if arn.validate(level).is_ok()))
+        .map(|(range, _)| &text[range])
+        .collect()
+}
+
+fn to_json(arn: &NaiveArn) -> serde_json::Value {
+    json!({
+        "partition": arn.partition,
+        "service": arn.service,
+        "region": arn.region,
+        "account_id": arn.account_id,
+        "resource": arn.resource,
+    })
+}
+
+fn to_table(arn: &NaiveArn) -> String {
+    let rows = [
+        ("partition", arn.partition),
+        ("service", arn.service),
+        ("region", arn.region.unwrap_or_default()),
+        ("account_id", arn.account_id.unwrap_or_default()),
+        ("resource", arn.resource),
+    ];
+
+    rows.iter()
+        .map(|(name, value)| format!("{:<10}  {}\n", name, value))
+        .collect()
+}
+
+fn explanation(arn: &NaiveArn) -> String {
+    let explanation = arn.explain();
+
+    let partition = match explanation.partition_name {
+        Some(name) => format!("{} ({})", explanation.partition, name),
+        None => format!("{} (not a known partition)", explanation.partition),
+    };
+
+    let service = match explanation.service_name {
+        Some(name) => format!("{} ({})", explanation.service, name),
+        None => explanation.service.to_owned(),
+    };
+
+    let region = match explanation.region {
+        Some(region) => region.to_owned(),
+        None => "none, the resource is global".to_owned(),
+    };
+
+    let account_id = match explanation.account_id {
+        Some("aws") => "aws, the resource is managed by AWS".to_owned(),
+        Some(account_id) => account_id.to_owned(),
+        None => "none, the resource name is unique across accounts".to_owned(),
+    };
+
+    let resource = match explanation.resource_type {
+        Some(resource_type) => format!(
+            "{} (type {}, id {})",
+            arn.resource, resource_type, explanation.resource_id
+        ),
+        None => arn.resource.to_owned(),
+    };
+
+    format!(
+        "Partition:  {}\nService:    {}\nRegion:     {}\nAccount ID: {}\nResource:   {}\n",
+        partition, service, region, account_id, resource
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{explanation, find_arns, matches_any, to_json, to_table};
+    use arn::naive::NaiveArn;
+    use arn::pattern::ArnPattern;
+    use arn::validate::ValidationLevel;
+
+    #[test]
+    fn json() {
+        let arn = NaiveArn::parse("arn:aws:s3:::my_corporate_bucket").unwrap();
+
+        assert_eq!(
+            to_json(&arn).to_string(),
+            r#"{"account_id":null,"partition":"aws","region":null,"resource":"my_corporate_bucket","service":"s3"}"#
+        );
+    }
+
+    #[test]
+    fn table() {
+        let arn = NaiveArn::parse("arn:aws:sqs:us-east-1:123456789012:orders").unwrap();
+
+        assert_eq!(
+            to_table(&arn),
+            "partition   aws\nservice     sqs\nregion      us-east-1\naccount_id  123456789012\nresource    orders\n"
+        );
+    }
+
+    #[test]
+    fn explain() {
+        let arn = NaiveArn::parse("arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98").unwrap();
+
+        assert_eq!(
+            explanation(&arn),
+            "Partition:  aws (AWS Standard)\n\
+             Service:    ec2 (Amazon Elastic Compute Cloud)\n\
+             Region:     us-east-1\n\
+             Account ID: 123456789012\n\
+             Resource:   vpc/vpc-fd580e98 (type vpc, id vpc-fd580e98)\n"
+        );
+    }
+
+    #[test]
+    fn matching() {
+        let patterns = [
+            ArnPattern::parse("arn:aws:s3:::prod-*").unwrap(),
+            ArnPattern::parse("arn:aws:sqs:*:123456789012:*").unwrap(),
+        ];
+
+        assert!(matches_any(&patterns, "arn:aws:s3:::prod-logs"));
+        assert!(matches_any(
+            &patterns,
+            "arn:aws:sqs:eu-west-1:123456789012:orders"
+        ));
+        assert!(!matches_any(&patterns, "arn:aws:s3:::dev-logs"));
+        assert!(!matches_any(&patterns, "prod-logs"));
+    }
+
+    #[test]
+    fn extracting() {
+        let text = r#"{"Role": "arn:aws:iam::123456789012:role/Admin", "Queues": [arn:aws:sqs:us-east-1:123456789012:orders]} see arn:aws:s3:::logs. warn:aws:s3:::nope arn:aws:sqs:us-east:123456789012:bad"#;
+
+        assert_eq!(
+            find_arns(text, ValidationLevel::Standard),
+            [
+                "arn:aws:iam::123456789012:role/Admin",
+                "arn:aws:sqs:us-east-1:123456789012:orders",
+                "arn:aws:s3:::logs",
+            ]
+        );
+        assert_eq!(find_arns(text, ValidationLevel::Syntax).len(), 4);
+    }
+}
@@ -0,0 +1,122 @@
+//! Owned `arn:partition:service:region:account-id:resource` formatted ARN
+
+use alloc::{borrow::ToOwned, string::String};
+use core::fmt;
+use core::str::FromStr;
+
+use crate::naive::{NaiveArn, ParseNaiveArnError};
+
+/// Owned counterpart of [`NaiveArn`], for ARNs that are built or decoded rather than borrowed from an input string.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::buf::ArnBuf;
+///
+/// let arn: ArnBuf = "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98".parse().unwrap();
+/// assert_eq!(arn.as_naive().service, "ec2");
+/// ~~~~
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArnBuf {
+    /// See [`NaiveArn::partition`].
+    pub partition: String,
+
+    /// See [`NaiveArn::service`].
+    pub service: String,
+
+    /// See [`NaiveArn::region`].
+    pub region: Option<String>,
+
+    /// See [`NaiveArn::account_id`].
+    pub account_id: Option<String>,
+
+    /// See [`NaiveArn::resource`].
+    pub resource: String,
+}
+
+impl ArnBuf {
+    pub fn parse(s: &str) -> Result<Self, ParseNaiveArnError> {
+        NaiveArn::parse(s).map(ArnBuf::from)
+    }
+
+    /// Borrow this ARN as a [`NaiveArn`].
+    pub fn as_naive(&self) -> NaiveArn<'_> {
+        NaiveArn {
+            partition: &self.partition,
+            service: &self.service,
+            region: self.region.as_deref(),
+            account_id: self.account_id.as_deref(),
+            resource: &self.resource,
+        }
+    }
+}
+
+impl<'a> From<NaiveArn<'a>> for ArnBuf {
+    fn from(arn: NaiveArn<'a>) -> Self {
+        ArnBuf {
+            partition: arn.partition.to_owned(),
+            service: arn.service.to_owned(),
+            region: arn.region.map(str::to_owned),
+            account_id: arn.account_id.map(str::to_owned),
+            resource: arn.resource.to_owned(),
+        }
+    }
+}
+
+impl<'a> From<&'a ArnBuf> for NaiveArn<'a> {
+    fn from(arn: &'a ArnBuf) -> Self {
+        arn.as_naive()
+    }
+}
+
+impl FromStr for ArnBuf {
+    type Err = ParseNaiveArnError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ArnBuf::parse(s)
+    }
+}
+
+impl fmt::Display for ArnBuf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_naive().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArnBuf;
+    use crate::naive::{NaiveArn, ParseNaiveArnError};
+
+    #[test]
+    fn round_trip() {
+        let arn_str = "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98";
+        let arn: ArnBuf = arn_str.parse().unwrap();
+
+        assert_eq!(arn.partition, "aws");
+        assert_eq!(arn.service, "ec2");
+        assert_eq!(arn.region.as_deref(), Some("us-east-1"));
+        assert_eq!(arn.account_id.as_deref(), Some("123456789012"));
+        assert_eq!(arn.resource, "vpc/vpc-fd580e98");
+
+        assert_eq!(arn.to_string(), arn_str);
+        assert_eq!(arn.as_naive(), NaiveArn::parse(arn_str).unwrap());
+    }
+
+    #[test]
+    fn no_region_no_account_id() {
+        let arn = ArnBuf::parse("arn:aws:s3:::my_corporate_bucket").unwrap();
+
+        assert_eq!(arn.region, None);
+        assert_eq!(arn.account_id, None);
+        assert_eq!(arn.to_string(), "arn:aws:s3:::my_corporate_bucket");
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            ArnBuf::parse("arn:aws:ec2:us-east-1:123456789012:"),
+            Err(ParseNaiveArnError::MissingResource { position: 35 })
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! Validating every ARN in a file, with positions, for CI checks
+
+use std::fmt;
+use std::io::{self, BufRead};
+
+use crate::find::ArnFinder;
+use crate::validate::ValidationLevel;
+
+/// The result of [`validate`]ing a document.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    /// The number of ARNs found, valid or not.
+    pub checked: usize,
+    /// A finding for every problem, in the order they appear in the document.
+    pub findings: Vec<Finding>,
+}
+
+impl Report {
+    /// Whether every ARN found was valid.
+    pub fn is_ok(&self) -> bool {
+        self.findings.is_empty()
+    }
+}
+
+/// A problem with an ARN found by [`validate`].
+///
+/// Displays as `line:column: message: text`, the format most CI problem matchers accept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// The one-based line the ARN is on.
+    pub line: usize,
+    /// The one-based column, in characters, the ARN starts at.
+    pub column: usize,
+    /// The stable code of the problem, e.g. `ARN202`.
+    pub code: &'static str,
+    /// The description of the problem, starting with its code.
+    pub message: String,
+    /// The offending ARN.
+    pub text: String,
+}
+
+impl Finding {
+    /// The finding as a GitHub Actions workflow command, which annotates the line in `file`.
+    pub fn github_annotation(&self, file: &str) -> String {
+        format!(
+            "::error file={},line={},col={},title={}::{}: {}",
+            file, self.line, self.column, self.code, self.message, self.text
+        )
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: {}: {}",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+/// Find every ARN in `reader` (as [`ArnFinder`] does) and validate it at `level`.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::bulk::validate;
+/// use arn::validate::ValidationLevel;
+///
+/// let template = r#"
+/// Resources:
+///   Queue: arn:aws:sqs:us-east-1:123456789012:orders
+///   Bucket: "arn:aws:s3:us-east:123456789012:logs"
+/// "#;
+///
+/// let report = validate(template.as_bytes(), ValidationLevel::Standard).unwrap();
+/// assert_eq!(report.checked, 2);
+/// assert_eq!(
+///     report.findings[0].to_string(),
+///     "4:12: ARN202: Invalid region: arn:aws:s3:us-east:123456789012:logs"
+/// );
+/// ~~~~
+pub fn validate<R: io::Read>(reader: R, level: ValidationLevel) -> io::Result<Report> {
+    let finder = ArnFinder::default();
+    let mut report = Report::default();
+
+    for (idx, line) in io::BufReader::new(reader).lines().enumerate() {
+        let line = line?;
+
+        for (range, arn) in finder.find_iter(&line) {
+            report.checked += 1;
+
+            let problems = match arn {
+                Ok(arn) => match arn.validate(level) {
+                    Ok(()) => continue,
+                    Err(issues) => issues
+                        .iter()
+                        .map(|issue| (issue.code(), issue.to_string()))
+                        .collect(),
+                },
+                Err(err) => vec![(err.code(), err.to_string())],
+            };
+
+            let column = line[..range.start].chars().count() + 1;
+            for (code, message) in problems {
+                report.findings.push(Finding {
+                    line: idx + 1,
+                    column,
+                    code,
+                    message,
+                    text: line[range.clone()].to_owned(),
+                });
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate, Finding};
+    use crate::validate::ValidationLevel;
+
+    const DOCUMENT: &str = "\
+{
+  \"Principal\": \"arn:aws:iam::123456789012:role/Admin\",
+  \"Resource\": [\"arn:aws:s3:::bucket\", \"arn:aws\"],
+  \"Queue\": \"arn:aws:sqs:us-east-1:123456789012:orders/extra\"
+}
+";
+
+    #[test]
+    fn findings() {
+        let report = validate(DOCUMENT.as_bytes(), ValidationLevel::Strict).unwrap();
+
+        assert_eq!(report.checked, 4);
+        assert!(!report.is_ok());
+        assert_eq!(
+            report.findings,
+            [
+                Finding {
+                    line: 3,
+                    column: 40,
+                    code: "ARN001",
+                    message: "ARN001: Not enough elements".to_owned(),
+                    text: "arn:aws".to_owned(),
+                },
+                Finding {
+                    line: 4,
+                    column: 13,
+                    code: "ARN107",
+                    message: "ARN107: Invalid resource element".to_owned(),
+                    text: "arn:aws:sqs:us-east-1:123456789012:orders/extra".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn levels() {
+        let report = validate(DOCUMENT.as_bytes(), ValidationLevel::Syntax).unwrap();
+
+        assert_eq!(report.findings.len(), 1);
+    }
+
+    #[test]
+    fn github_annotation() {
+        let report = validate(
+            "  arn:aws:s3:::b, arn:aws\n".as_bytes(),
+            ValidationLevel::Strict,
+        )
+        .unwrap();
+
+        assert_eq!(
+            report.findings[0].github_annotation("policy.json"),
+            "::error file=policy.json,line=1,col=19,title=ARN001::ARN001: Not enough elements: arn:aws"
+        );
+    }
+}
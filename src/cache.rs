@@ -0,0 +1,133 @@
+//! Memoized parsing, for request paths that parse the same few ARNs over and over
+
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use lru::LruCache;
+
+use crate::buf::ArnBuf;
+use crate::naive::{ParseNaiveArnError, MAX_LEN};
+
+type Entries = LruCache<Box<str>, Result<Arc<ArnBuf>, ParseNaiveArnError>>;
+
+/// A bounded cache of parse results, keyed by the input string, that evicts the least recently used entry when full.
+///
+/// Successfully parsed ARNs are shared through an [`Arc`], so a hit doesn't copy the ARN. Failures are cached too, so
+/// a client repeating a bad ARN doesn't cost a parse each time. The cache can be shared between threads.
+///
+/// # Example
+///
+/// ~~~~
+/// use std::num::NonZeroUsize;
+/// use std::sync::Arc;
+///
+/// use arn::cache::ArnCache;
+///
+/// let cache = ArnCache::new(NonZeroUsize::new(1024).unwrap());
+///
+/// let first = cache.parse("arn:aws:iam::123456789012:role/Admin").unwrap();
+/// let second = cache.parse("arn:aws:iam::123456789012:role/Admin").unwrap();
+///
+/// assert!(Arc::ptr_eq(&first, &second));
+/// assert_eq!(cache.len(), 1);
+/// ~~~~
+#[derive(Debug)]
+pub struct ArnCache {
+    entries: Mutex<Entries>,
+}
+
+impl ArnCache {
+    /// An empty cache holding at most `capacity` entries.
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        ArnCache {
+            entries: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Parse `s`, or return the result of parsing it before.
+    ///
+    /// Inputs longer than [`MAX_LEN`] are rejected without being cached.
+    pub fn parse(&self, s: &str) -> Result<Arc<ArnBuf>, ParseNaiveArnError> {
+        if s.len() > MAX_LEN {
+            return Err(ParseNaiveArnError::InputTooLong { length: s.len() });
+        }
+
+        if let Some(result) = self.lock().get(s) {
+            return result.clone();
+        }
+
+        // Parse without holding the lock; another thread parsing the same input at once just does the work twice.
+        let result = ArnBuf::parse(s).map(Arc::new);
+        self.lock().put(s.into(), result.clone());
+        result
+    }
+
+    /// The number of cached inputs.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Remove every entry.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+    use std::sync::Arc;
+
+    use super::ArnCache;
+
+    fn cache(capacity: usize) -> ArnCache {
+        ArnCache::new(NonZeroUsize::new(capacity).unwrap())
+    }
+
+    #[test]
+    fn shares_results() {
+        let cache = cache(2);
+        let arn_str = "arn:aws:sqs:us-east-1:123456789012:orders";
+
+        let first = cache.parse(arn_str).unwrap();
+        assert_eq!(first.to_string(), arn_str);
+        assert!(Arc::ptr_eq(&first, &cache.parse(arn_str).unwrap()));
+    }
+
+    #[test]
+    fn caches_errors() {
+        let cache = cache(2);
+
+        assert_eq!(cache.parse("arn:aws:s3"), cache.parse("arn:aws:s3"));
+        assert!(cache.parse("arn:aws:s3").is_err());
+        assert_eq!(cache.len(), 1);
+
+        assert!(cache.parse(&"a".repeat(4096)).is_err());
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = cache(2);
+        let a = cache.parse("arn:aws:s3:::a").unwrap();
+        cache.parse("arn:aws:s3:::b").unwrap();
+
+        // Using `a` again makes `b` the least recently used.
+        cache.parse("arn:aws:s3:::a").unwrap();
+        cache.parse("arn:aws:s3:::c").unwrap();
+
+        assert_eq!(cache.len(), 2);
+        assert!(Arc::ptr_eq(&a, &cache.parse("arn:aws:s3:::a").unwrap()));
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+}
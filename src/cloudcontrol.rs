@@ -0,0 +1,592 @@
+//! Cloud Control API `TypeName` / `Identifier` pairs for ARNs
+
+use alloc::borrow::Cow;
+use alloc::{borrow::ToOwned, format, string::ToString};
+use core::fmt;
+
+use crate::buf::ArnBuf;
+use crate::context::Context;
+use crate::naive::NaiveArn;
+use crate::partition::{self, Partition};
+
+/// How a resource type's Cloud Control primary identifier relates to its ARN.
+#[derive(Clone, Copy)]
+enum Identifier {
+    /// The identifier is the ARN itself.
+    Arn,
+    /// The identifier is the resource, minus its type prefix.
+    Suffix,
+    /// Like `Suffix`, for IAM entities: the identifier is the name, without the entity's path.
+    IamName,
+    /// Like `Suffix`, minus a trailing `:qualifier` or `:*`.
+    Unqualified,
+    /// The SSM parameter name, which keeps its leading `/` when hierarchical.
+    SsmParameter,
+    /// The SQS queue URL.
+    QueueUrl,
+}
+
+/// Which of the region and account ID a resource type's ARNs carry.
+#[derive(Clone, Copy)]
+enum Scope {
+    /// Both a region and an account ID.
+    Regional,
+    /// An account ID but no region, like IAM entities.
+    Global,
+    /// Neither a region nor an account ID, like S3 buckets and Route 53 hosted zones.
+    Partition,
+}
+
+/// `(CloudFormation type, service, resource prefix, scope, identifier form)`
+const RESOURCE_TYPES: &[(&str, &str, &str, Scope, Identifier)] = &[
+    (
+        "AWS::CloudWatch::Alarm",
+        "cloudwatch",
+        "alarm:",
+        Scope::Regional,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::DynamoDB::Table",
+        "dynamodb",
+        "table/",
+        Scope::Regional,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::EC2::Instance",
+        "ec2",
+        "instance/",
+        Scope::Regional,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::EC2::SecurityGroup",
+        "ec2",
+        "security-group/",
+        Scope::Regional,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::EC2::Subnet",
+        "ec2",
+        "subnet/",
+        Scope::Regional,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::EC2::VPC",
+        "ec2",
+        "vpc/",
+        Scope::Regional,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::EC2::Volume",
+        "ec2",
+        "volume/",
+        Scope::Regional,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::ECR::Repository",
+        "ecr",
+        "repository/",
+        Scope::Regional,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::ECS::Cluster",
+        "ecs",
+        "cluster/",
+        Scope::Regional,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::EKS::Cluster",
+        "eks",
+        "cluster/",
+        Scope::Regional,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::ElasticLoadBalancingV2::LoadBalancer",
+        "elasticloadbalancing",
+        "loadbalancer/",
+        Scope::Regional,
+        Identifier::Arn,
+    ),
+    (
+        "AWS::ElasticLoadBalancingV2::TargetGroup",
+        "elasticloadbalancing",
+        "targetgroup/",
+        Scope::Regional,
+        Identifier::Arn,
+    ),
+    (
+        "AWS::IAM::Group",
+        "iam",
+        "group/",
+        Scope::Global,
+        Identifier::IamName,
+    ),
+    (
+        "AWS::IAM::InstanceProfile",
+        "iam",
+        "instance-profile/",
+        Scope::Global,
+        Identifier::IamName,
+    ),
+    (
+        "AWS::IAM::ManagedPolicy",
+        "iam",
+        "policy/",
+        Scope::Global,
+        Identifier::Arn,
+    ),
+    (
+        "AWS::IAM::Role",
+        "iam",
+        "role/",
+        Scope::Global,
+        Identifier::IamName,
+    ),
+    (
+        "AWS::IAM::User",
+        "iam",
+        "user/",
+        Scope::Global,
+        Identifier::IamName,
+    ),
+    (
+        "AWS::KMS::Alias",
+        "kms",
+        "",
+        Scope::Regional,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::KMS::Key",
+        "kms",
+        "key/",
+        Scope::Regional,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::Kinesis::Stream",
+        "kinesis",
+        "stream/",
+        Scope::Regional,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::Lambda::Function",
+        "lambda",
+        "function:",
+        Scope::Regional,
+        Identifier::Unqualified,
+    ),
+    (
+        "AWS::Logs::LogGroup",
+        "logs",
+        "log-group:",
+        Scope::Regional,
+        Identifier::Unqualified,
+    ),
+    (
+        "AWS::RDS::DBCluster",
+        "rds",
+        "cluster:",
+        Scope::Regional,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::RDS::DBInstance",
+        "rds",
+        "db:",
+        Scope::Regional,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::Route53::HostedZone",
+        "route53",
+        "hostedzone/",
+        Scope::Partition,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::S3::Bucket",
+        "s3",
+        "",
+        Scope::Partition,
+        Identifier::Suffix,
+    ),
+    (
+        "AWS::SNS::Topic",
+        "sns",
+        "",
+        Scope::Regional,
+        Identifier::Arn,
+    ),
+    (
+        "AWS::SQS::Queue",
+        "sqs",
+        "",
+        Scope::Regional,
+        Identifier::QueueUrl,
+    ),
+    (
+        "AWS::SSM::Parameter",
+        "ssm",
+        "parameter",
+        Scope::Regional,
+        Identifier::SsmParameter,
+    ),
+    (
+        "AWS::SecretsManager::Secret",
+        "secretsmanager",
+        "secret:",
+        Scope::Regional,
+        Identifier::Arn,
+    ),
+    (
+        "AWS::StepFunctions::StateMachine",
+        "states",
+        "stateMachine:",
+        Scope::Regional,
+        Identifier::Arn,
+    ),
+];
+
+/// The `TypeName` and `Identifier` that Cloud Control API operations (`GetResource`, `UpdateResource`, ...) use to
+/// address a resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloudControlIdentifier<'a> {
+    /// The CloudFormation resource type, e.g. `AWS::SQS::Queue`.
+    pub type_name: &'static str,
+
+    /// The resource's primary identifier, e.g. the queue URL for an SQS queue.
+    pub identifier: Cow<'a, str>,
+}
+
+impl<'a> NaiveArn<'a> {
+    /// The Cloud Control API `TypeName` and `Identifier` for this ARN, if the resource type is supported.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let arn = NaiveArn::parse("arn:aws:lambda:us-east-1:123456789012:function:my-function").unwrap();
+    /// let id = arn.cloud_control_identifier().unwrap();
+    ///
+    /// assert_eq!(id.type_name, "AWS::Lambda::Function");
+    /// assert_eq!(id.identifier, "my-function");
+    /// ~~~~
+    pub fn cloud_control_identifier(&self) -> Option<CloudControlIdentifier<'a>> {
+        let type_name = self.cloudformation_type()?;
+        let (_, _, prefix, _, form) = RESOURCE_TYPES.iter().find(|(ty, ..)| *ty == type_name)?;
+        let suffix = self.resource.strip_prefix(prefix)?;
+
+        let identifier = match form {
+            Identifier::Arn => Cow::Owned(self.to_string()),
+            Identifier::Suffix => Cow::Borrowed(suffix),
+            Identifier::IamName => Cow::Borrowed(suffix.rsplit('/').next()?),
+            Identifier::Unqualified => match suffix.strip_suffix(":*") {
+                Some(name) => Cow::Borrowed(name),
+                None => Cow::Borrowed(suffix.split(':').next()?),
+            },
+            Identifier::SsmParameter => match suffix.strip_prefix('/')? {
+                name if name.contains('/') => Cow::Borrowed(suffix),
+                name => Cow::Borrowed(name),
+            },
+            Identifier::QueueUrl => Cow::Owned(format!(
+                "https://sqs.{}.{}/{}/{}",
+                self.region?,
+                self.known_partition()?.dns_suffix(),
+                self.account_id?,
+                self.resource
+            )),
+        };
+
+        Some(CloudControlIdentifier {
+            type_name,
+            identifier,
+        })
+    }
+}
+
+impl ArnBuf {
+    /// Build the ARN of a resource from its Cloud Control API `TypeName` and `Identifier`.
+    ///
+    /// `context` supplies the partition, region and account that most identifiers don't carry. IAM identifiers are
+    /// bare names, so the resulting IAM ARNs assume the default `/` path.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::buf::ArnBuf;
+    /// use arn::context::Context;
+    ///
+    /// let context = Context::new("aws", "us-east-1", "123456789012");
+    /// let arn = ArnBuf::from_cloud_control("AWS::EC2::VPC", "vpc-fd580e98", &context).unwrap();
+    ///
+    /// assert_eq!(arn.to_string(), "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98");
+    /// ~~~~
+    pub fn from_cloud_control(
+        type_name: &str,
+        identifier: &str,
+        context: &Context,
+    ) -> Result<Self, CloudControlError> {
+        let (_, service, prefix, scope, form) = RESOURCE_TYPES
+            .iter()
+            .find(|(ty, ..)| *ty == type_name)
+            .ok_or(CloudControlError::UnsupportedType)?;
+
+        if identifier.is_empty() {
+            return Err(CloudControlError::InvalidIdentifier);
+        }
+
+        let resource = match form {
+            Identifier::Arn => {
+                return ArnBuf::parse(identifier).map_err(|_| CloudControlError::InvalidIdentifier)
+            }
+            Identifier::QueueUrl => return queue_url_to_arn(identifier),
+            Identifier::Suffix | Identifier::IamName | Identifier::Unqualified => {
+                format!("{}{}", prefix, identifier)
+            }
+            Identifier::SsmParameter if identifier.starts_with('/') => {
+                format!("{}{}", prefix, identifier)
+            }
+            Identifier::SsmParameter => format!("{}/{}", prefix, identifier),
+        };
+
+        Ok(ArnBuf {
+            partition: context.partition.to_owned(),
+            service: (*service).to_owned(),
+            region: match scope {
+                Scope::Regional => Some(context.region.to_owned()),
+                Scope::Global | Scope::Partition => None,
+            },
+            account_id: match scope {
+                Scope::Regional | Scope::Global => Some(context.account_id.to_owned()),
+                Scope::Partition => None,
+            },
+            resource,
+        })
+    }
+}
+
+/// `https://sqs.region.amazonaws.com/account-id/queue-name` to the queue's ARN.
+fn queue_url_to_arn(url: &str) -> Result<ArnBuf, CloudControlError> {
+    let rest = url
+        .strip_prefix("https://")
+        .ok_or(CloudControlError::InvalidIdentifier)?;
+
+    let mut parts = rest.splitn(3, '/');
+    let (host, account_id, name) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(host), Some(account_id), Some(name))
+            if !account_id.is_empty() && !name.is_empty() && !name.contains('/') =>
+        {
+            (host, account_id, name)
+        }
+        _ => return Err(CloudControlError::InvalidIdentifier),
+    };
+
+    let (prefix, dns_suffix) =
+        partition::split_host(host).ok_or(CloudControlError::InvalidIdentifier)?;
+    let region = prefix
+        .strip_prefix("sqs.")
+        .ok_or(CloudControlError::InvalidIdentifier)?;
+    let partition = Partition::from_dns_suffix(dns_suffix, Some(region))
+        .ok_or(CloudControlError::InvalidIdentifier)?;
+
+    Ok(ArnBuf {
+        partition: partition.id().to_owned(),
+        service: "sqs".to_owned(),
+        region: Some(region.to_owned()),
+        account_id: Some(account_id.to_owned()),
+        resource: name.to_owned(),
+    })
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CloudControlError {
+    UnsupportedType,
+    InvalidIdentifier,
+}
+
+impl fmt::Display for CloudControlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CloudControlError::UnsupportedType => write!(f, "Unsupported resource type"),
+            CloudControlError::InvalidIdentifier => {
+                write!(f, "Invalid identifier for resource type")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CloudControlError {}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+
+    use super::CloudControlError;
+    use crate::buf::ArnBuf;
+    use crate::context::Context;
+    use crate::naive::NaiveArn;
+    use crate::service::{route53::Route53Arn, s3::S3Arn};
+
+    const CONTEXT: Context<'static> = Context {
+        partition: "aws",
+        region: "us-east-1",
+        account_id: "123456789012",
+    };
+
+    fn round_trip(arn_str: &str, type_name: &str, identifier: &str) {
+        let arn = NaiveArn::parse(arn_str).unwrap();
+        let id = arn.cloud_control_identifier().unwrap();
+
+        assert_eq!(id.type_name, type_name);
+        assert_eq!(id.identifier, identifier);
+
+        let rebuilt = ArnBuf::from_cloud_control(type_name, identifier, &CONTEXT).unwrap();
+        assert_eq!(rebuilt.to_string(), arn_str);
+    }
+
+    #[test]
+    fn suffix_identifiers() {
+        round_trip(
+            "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98",
+            "AWS::EC2::VPC",
+            "vpc-fd580e98",
+        );
+        round_trip(
+            "arn:aws:s3:::my_corporate_bucket",
+            "AWS::S3::Bucket",
+            "my_corporate_bucket",
+        );
+        round_trip(
+            "arn:aws:kms:us-east-1:123456789012:alias/my-key",
+            "AWS::KMS::Alias",
+            "alias/my-key",
+        );
+        round_trip(
+            "arn:aws:iam::123456789012:role/my-role",
+            "AWS::IAM::Role",
+            "my-role",
+        );
+    }
+
+    #[test]
+    fn partition_scoped_identifiers() {
+        round_trip(
+            "arn:aws:route53:::hostedzone/Z1D633PJN98FT9",
+            "AWS::Route53::HostedZone",
+            "Z1D633PJN98FT9",
+        );
+
+        let zone =
+            ArnBuf::from_cloud_control("AWS::Route53::HostedZone", "Z1D633PJN98FT9", &CONTEXT)
+                .unwrap();
+        assert_eq!(
+            Route53Arn::try_from(zone.as_naive()).unwrap(),
+            Route53Arn::hosted_zone("Z1D633PJN98FT9")
+        );
+
+        let bucket =
+            ArnBuf::from_cloud_control("AWS::S3::Bucket", "my_corporate_bucket", &CONTEXT).unwrap();
+        assert_eq!(
+            S3Arn::try_from(bucket.as_naive()).unwrap().bucket,
+            "my_corporate_bucket"
+        );
+    }
+
+    #[test]
+    fn arn_identifiers() {
+        let arn_str = "arn:aws:sns:us-east-1:123456789012:my_corporate_topic";
+        round_trip(arn_str, "AWS::SNS::Topic", arn_str);
+    }
+
+    #[test]
+    fn queue_url_identifier() {
+        round_trip(
+            "arn:aws:sqs:us-east-1:123456789012:my-queue",
+            "AWS::SQS::Queue",
+            "https://sqs.us-east-1.amazonaws.com/123456789012/my-queue",
+        );
+
+        let arn = ArnBuf::from_cloud_control(
+            "AWS::SQS::Queue",
+            "https://sqs.cn-north-1.amazonaws.com.cn/123456789012/my-queue",
+            &CONTEXT,
+        )
+        .unwrap();
+        assert_eq!(
+            arn.to_string(),
+            "arn:aws-cn:sqs:cn-north-1:123456789012:my-queue"
+        );
+    }
+
+    #[test]
+    fn ssm_parameter_identifiers() {
+        round_trip(
+            "arn:aws:ssm:us-east-1:123456789012:parameter/app/db/password",
+            "AWS::SSM::Parameter",
+            "/app/db/password",
+        );
+        round_trip(
+            "arn:aws:ssm:us-east-1:123456789012:parameter/password",
+            "AWS::SSM::Parameter",
+            "password",
+        );
+    }
+
+    #[test]
+    fn qualified_identifiers() {
+        let arn =
+            NaiveArn::parse("arn:aws:lambda:us-east-1:123456789012:function:my-function:PROD")
+                .unwrap();
+        assert_eq!(
+            arn.cloud_control_identifier().unwrap().identifier,
+            "my-function"
+        );
+
+        let arn = NaiveArn::parse(
+            "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/my-function:*",
+        )
+        .unwrap();
+        assert_eq!(
+            arn.cloud_control_identifier().unwrap().identifier,
+            "/aws/lambda/my-function"
+        );
+
+        let arn = NaiveArn::parse("arn:aws:iam::123456789012:role/service-role/my-role").unwrap();
+        assert_eq!(
+            arn.cloud_control_identifier().unwrap().identifier,
+            "my-role"
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        let arn = NaiveArn::parse("arn:aws:s3:::my_corporate_bucket/exampleobject.png").unwrap();
+        assert_eq!(arn.cloud_control_identifier(), None);
+
+        assert_eq!(
+            ArnBuf::from_cloud_control("AWS::Foo::Bar", "baz", &CONTEXT),
+            Err(CloudControlError::UnsupportedType)
+        );
+        assert_eq!(
+            ArnBuf::from_cloud_control("AWS::SNS::Topic", "my_corporate_topic", &CONTEXT),
+            Err(CloudControlError::InvalidIdentifier)
+        );
+        assert_eq!(
+            ArnBuf::from_cloud_control("AWS::SQS::Queue", "my-queue", &CONTEXT),
+            Err(CloudControlError::InvalidIdentifier)
+        );
+    }
+}
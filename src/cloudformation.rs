@@ -0,0 +1,192 @@
+//! Mapping from ARNs to CloudFormation resource types
+//!
+//! The mapping is generated at build time from `data/cloudformation_types.csv`; add a row there to support another
+//! resource type.
+
+use crate::naive::{split_resource, NaiveArn};
+
+include!(concat!(env!("OUT_DIR"), "/cloudformation_types.rs"));
+
+impl<'a> NaiveArn<'a> {
+    /// The CloudFormation resource type (e.g. `AWS::SQS::Queue`) of the resource this ARN identifies, if known.
+    ///
+    /// Sub-resources without a CloudFormation type of their own, such as S3 objects or SNS subscriptions, return
+    /// `None`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let arn = NaiveArn::parse("arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98").unwrap();
+    /// assert_eq!(arn.cloudformation_type(), Some("AWS::EC2::VPC"));
+    /// ~~~~
+    pub fn cloudformation_type(&self) -> Option<&'static str> {
+        let resource_type = match split_resource(self.resource) {
+            (resource_type, Some(rest)) => {
+                // Classic load balancers are `loadbalancer/name`, v2 ones are `loadbalancer/{app,net,gwy}/name/id`
+                if self.service == "elasticloadbalancing"
+                    && resource_type == "loadbalancer"
+                    && rest.matches('/').count() < 2
+                {
+                    return Some("AWS::ElasticLoadBalancing::LoadBalancer");
+                }
+
+                // Indexes and streams are `table/name/index/index-name` and `table/name/stream/label`
+                if self.service == "dynamodb" && resource_type == "table" && rest.contains('/') {
+                    return None;
+                }
+
+                // Multi-Region Access Points are `accesspoint/alias.mrap`, with no region
+                if self.service == "s3"
+                    && resource_type == "accesspoint"
+                    && rest
+                        .split('/')
+                        .next()
+                        .is_some_and(|alias| alias.ends_with(".mrap"))
+                {
+                    return Some("AWS::S3::MultiRegionAccessPoint");
+                }
+
+                // Log streams are `log-group:group-name:log-stream:stream-name`
+                if self.service == "logs"
+                    && resource_type == "log-group"
+                    && rest.contains(":log-stream:")
+                {
+                    return Some("AWS::Logs::LogStream");
+                }
+
+                resource_type
+            }
+            (_, None) => "",
+        };
+
+        RESOURCE_TYPES
+            .binary_search_by(|(service, ty, _)| {
+                (*service, *ty).cmp(&(self.service, resource_type))
+            })
+            .ok()
+            .map(|idx| RESOURCE_TYPES[idx].2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RESOURCE_TYPES;
+    use crate::naive::NaiveArn;
+
+    fn cloudformation_type(arn_str: &str) -> Option<&'static str> {
+        NaiveArn::parse(arn_str).unwrap().cloudformation_type()
+    }
+
+    #[test]
+    fn table_is_sorted() {
+        for pair in RESOURCE_TYPES.windows(2) {
+            assert!(
+                (pair[0].0, pair[0].1) < (pair[1].0, pair[1].1),
+                "{:?}",
+                pair
+            );
+        }
+    }
+
+    #[test]
+    fn typed_resources() {
+        assert_eq!(
+            cloudformation_type("arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98"),
+            Some("AWS::EC2::VPC")
+        );
+        assert_eq!(
+            cloudformation_type("arn:aws:cloudwatch:us-east-1:123456789012:alarm:MyAlarmName"),
+            Some("AWS::CloudWatch::Alarm")
+        );
+        assert_eq!(
+            cloudformation_type("arn:aws:iam::123456789012:role/service-role/my-role"),
+            Some("AWS::IAM::Role")
+        );
+        assert_eq!(
+            cloudformation_type("arn:aws:dynamodb:us-east-1:123456789012:table/Books"),
+            Some("AWS::DynamoDB::Table")
+        );
+        assert_eq!(
+            cloudformation_type("arn:aws:logs:us-east-1:123456789012:log-group:my-log-group*:log-stream:my-log-stream*"),
+            Some("AWS::Logs::LogStream")
+        );
+        assert_eq!(
+            cloudformation_type("arn:aws:logs:us-east-1:123456789012:log-group:my-log-group:*"),
+            Some("AWS::Logs::LogGroup")
+        );
+    }
+
+    #[test]
+    fn untyped_resources() {
+        assert_eq!(
+            cloudformation_type("arn:aws:sqs:us-east-1:123456789012:my-queue"),
+            Some("AWS::SQS::Queue")
+        );
+        assert_eq!(
+            cloudformation_type("arn:aws:s3:::my_corporate_bucket"),
+            Some("AWS::S3::Bucket")
+        );
+        assert_eq!(
+            cloudformation_type("arn:aws:codecommit:us-east-1:123456789012:MyDemoRepo"),
+            Some("AWS::CodeCommit::Repository")
+        );
+    }
+
+    #[test]
+    fn access_points() {
+        assert_eq!(
+            cloudformation_type("arn:aws:s3:us-west-2:123456789012:accesspoint/my-access-point"),
+            Some("AWS::S3::AccessPoint")
+        );
+        assert_eq!(
+            cloudformation_type("arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap"),
+            Some("AWS::S3::MultiRegionAccessPoint")
+        );
+    }
+
+    #[test]
+    fn load_balancers() {
+        assert_eq!(
+            cloudformation_type("arn:aws:elasticloadbalancing:us-east-1:123456789012:loadbalancer/app/my-lb/50dc6c495c0c9188"),
+            Some("AWS::ElasticLoadBalancingV2::LoadBalancer")
+        );
+        assert_eq!(
+            cloudformation_type(
+                "arn:aws:elasticloadbalancing:us-east-1:123456789012:loadbalancer/my-classic-lb"
+            ),
+            Some("AWS::ElasticLoadBalancing::LoadBalancer")
+        );
+    }
+
+    #[test]
+    fn sub_resources() {
+        assert_eq!(
+            cloudformation_type("arn:aws:s3:::my_corporate_bucket/exampleobject.png"),
+            None
+        );
+        assert_eq!(
+            cloudformation_type("arn:aws:sns:us-east-1:123456789012:my_corporate_topic:02034b43-fefa-4e07-a5eb-3be56f8c54ce"),
+            None
+        );
+        assert_eq!(
+            cloudformation_type(
+                "arn:aws:dynamodb:us-east-1:123456789012:table/Books/index/Authors"
+            ),
+            None
+        );
+        assert_eq!(
+            cloudformation_type("arn:aws:dynamodb:us-east-1:123456789012:table/Books/stream/2015-05-11T21:21:33.291"),
+            None
+        );
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(
+            cloudformation_type("arn:aws:kinesisvideo:us-east-1:123456789012:stream/example-stream-name/0123456789012"),
+            None
+        );
+    }
+}
@@ -0,0 +1,275 @@
+//! Rendering ARNs and parse errors with ANSI colors, for terminals
+//!
+//! These always emit escape codes; deciding whether the output is a terminal that wants them (and honouring
+//! `NO_COLOR`) is up to the caller.
+
+use anstyle::{AnsiColor, Style};
+use core::fmt;
+
+use crate::naive::{Component, NaiveArn, ParseNaiveArnError};
+
+/// The style of each component of an ARN, and of the problem in a parse error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    pub prefix: Style,
+    pub partition: Style,
+    pub service: Style,
+    pub region: Style,
+    pub account_id: Style,
+    pub resource: Style,
+    /// The `:` between components.
+    pub separator: Style,
+    /// The part of the input a parse error is about.
+    pub error: Style,
+    /// The error message.
+    pub message: Style,
+}
+
+impl Palette {
+    /// The style of `component`.
+    pub fn component(&self, component: Component) -> Style {
+        match component {
+            Component::Prefix => self.prefix,
+            Component::Partition => self.partition,
+            Component::Service => self.service,
+            Component::Region => self.region,
+            Component::AccountId => self.account_id,
+            Component::Resource => self.resource,
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette {
+            prefix: Style::new().dimmed(),
+            partition: AnsiColor::Magenta.on_default(),
+            service: AnsiColor::Cyan.on_default().bold(),
+            region: AnsiColor::Green.on_default(),
+            account_id: AnsiColor::Yellow.on_default(),
+            resource: AnsiColor::Blue.on_default().bold(),
+            separator: Style::new().dimmed(),
+            error: AnsiColor::Red.on_default().bold().underline(),
+            message: AnsiColor::Red.on_default(),
+        }
+    }
+}
+
+impl<'a> NaiveArn<'a> {
+    /// Display this ARN with each component in its own color.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let arn = NaiveArn::parse("arn:aws:s3:::my_corporate_bucket").unwrap();
+    /// println!("{}", arn.colored());
+    /// ~~~~
+    pub fn colored(&self) -> Colored<'a> {
+        Colored {
+            arn: *self,
+            palette: Palette::default(),
+        }
+    }
+}
+
+/// Displays an ARN in color, created by [`NaiveArn::colored`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Colored<'a> {
+    arn: NaiveArn<'a>,
+    palette: Palette,
+}
+
+impl<'a> Colored<'a> {
+    /// Use `palette` instead of the default.
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+}
+
+impl<'a> fmt::Display for Colored<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let components = [
+            (Component::Prefix, "arn"),
+            (Component::Partition, self.arn.partition),
+            (Component::Service, self.arn.service),
+            (Component::Region, self.arn.region.unwrap_or_default()),
+            (
+                Component::AccountId,
+                self.arn.account_id.unwrap_or_default(),
+            ),
+            (Component::Resource, self.arn.resource),
+        ];
+
+        for (idx, (component, value)) in components.iter().enumerate() {
+            if idx > 0 {
+                paint(f, self.palette.separator, ":")?;
+            }
+            paint(f, self.palette.component(*component), value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ParseNaiveArnError {
+    /// Display `input`, which this error came from, with the failing component highlighted, followed by the error on
+    /// a line of its own.
+    ///
+    /// The highlight runs from the error's [`position`](Self::position) to the end of the component. An empty or
+    /// missing component is shown as a highlighted placeholder, such as `<resource>`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let input = "arn:aws:s3:us east:123456789012:bucket";
+    /// let err = NaiveArn::parse(input).unwrap_err();
+    /// eprintln!("{}", err.highlight(input));
+    /// ~~~~
+    pub fn highlight<'a>(&'a self, input: &'a str) -> Highlighted<'a> {
+        Highlighted {
+            error: self,
+            input,
+            palette: Palette::default(),
+        }
+    }
+}
+
+/// Displays a parse error with its input, created by [`ParseNaiveArnError::highlight`].
+#[derive(Debug, Clone, Copy)]
+pub struct Highlighted<'a> {
+    error: &'a ParseNaiveArnError,
+    input: &'a str,
+    palette: Palette,
+}
+
+impl<'a> Highlighted<'a> {
+    /// Use `palette` instead of the default.
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// The byte range of the input to highlight.
+    fn span(&self) -> (usize, usize) {
+        let mut start = self.error.position().min(self.input.len());
+        while !self.input.is_char_boundary(start) {
+            start -= 1;
+        }
+
+        let end = match self.error.component() {
+            Some(Component::Resource) | None => self.input.len(),
+            Some(_) => self.input[start..]
+                .find(':')
+                .map_or(self.input.len(), |len| start + len),
+        };
+
+        (start, end)
+    }
+}
+
+impl<'a> fmt::Display for Highlighted<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (start, end) = self.span();
+
+        f.write_str(&self.input[..start])?;
+        if start == end {
+            let component = self.error.component().unwrap_or(Component::Resource);
+            write!(
+                f,
+                "{}<{}>{:#}",
+                self.palette.error, component, self.palette.error
+            )?;
+        } else {
+            paint(f, self.palette.error, &self.input[start..end])?;
+        }
+        f.write_str(&self.input[end..])?;
+
+        write!(
+            f,
+            "\n{}{}{:#}",
+            self.palette.message, self.error, self.palette.message
+        )
+    }
+}
+
+/// Write `s` in `style`.
+fn paint(f: &mut fmt::Formatter, style: Style, s: &str) -> fmt::Result {
+    write!(f, "{}{}{:#}", style, s, style)
+}
+
+#[cfg(test)]
+mod tests {
+    use anstyle::Style;
+
+    use super::Palette;
+    use crate::naive::NaiveArn;
+
+    /// A palette marking each component with a distinct escape code, so tests can see the structure.
+    fn palette() -> Palette {
+        let mark = |n: u8| Style::new().fg_color(Some(anstyle::Ansi256Color(n).into()));
+
+        Palette {
+            prefix: mark(0),
+            partition: mark(1),
+            service: mark(2),
+            region: mark(3),
+            account_id: mark(4),
+            resource: mark(5),
+            separator: Style::new(),
+            error: mark(9),
+            message: Style::new(),
+        }
+    }
+
+    /// Replace the escape codes of [`palette`] with readable markers.
+    fn strip(s: String) -> String {
+        let mut out = s.replace("\u{1b}[0m", "]");
+        for n in 0..10 {
+            out = out.replace(&format!("\u{1b}[38;5;{}m", n), &format!("{}[", n));
+        }
+        out
+    }
+
+    #[test]
+    fn colored() {
+        let arn = NaiveArn::parse("arn:aws:sqs:us-east-1:123456789012:orders").unwrap();
+
+        assert_eq!(
+            strip(arn.colored().palette(palette()).to_string()),
+            "0[arn]:1[aws]:2[sqs]:3[us-east-1]:4[123456789012]:5[orders]"
+        );
+        assert_eq!(
+            strip(
+                NaiveArn::parse("arn:aws:s3:::bucket")
+                    .unwrap()
+                    .colored()
+                    .palette(palette())
+                    .to_string()
+            ),
+            "0[arn]:1[aws]:2[s3]:3[]:4[]:5[bucket]"
+        );
+    }
+
+    #[test]
+    fn highlight() {
+        for (input, expected) in [
+            (
+                "arn:aws:s3:us east:123456789012:bucket",
+                "arn:aws:s3:us9[ east]:123456789012:bucket",
+            ),
+            ("arn:aws:s3:::bucket\n", "arn:aws:s3:::bucket9[\n]"),
+            ("arn:aws:s3:::", "arn:aws:s3:::9[<resource>]"),
+            ("aws:s3:::bucket", "9[aws]:s3:::bucket"),
+        ] {
+            let err = NaiveArn::parse(input).unwrap_err();
+            let rendered = strip(err.highlight(input).palette(palette()).to_string());
+
+            assert_eq!(rendered, format!("{}\n{}", expected, err), "{:?}", input);
+        }
+    }
+}
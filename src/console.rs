@@ -0,0 +1,230 @@
+//! AWS Management Console deep links
+
+use alloc::{borrow::ToOwned, format, string::String};
+
+use crate::naive::{split_resource, NaiveArn};
+use crate::partition::Partition;
+use crate::percent;
+
+impl<'a> NaiveArn<'a> {
+    /// The AWS Management Console URL for the resource, for supported services.
+    ///
+    /// Supported resources are EC2 instances, VPCs, subnets, security groups and volumes; S3 buckets and objects;
+    /// Lambda functions; IAM users, roles and groups; CloudWatch log groups; DynamoDB tables; SNS topics and SQS
+    /// queues. Returns `None` for anything else, or for partitions without a public console.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let arn = NaiveArn::parse("arn:aws:lambda:us-east-1:123456789012:function:my-function").unwrap();
+    /// assert_eq!(
+    ///     arn.console_url().unwrap(),
+    ///     "https://us-east-1.console.aws.amazon.com/lambda/home?region=us-east-1#/functions/my-function"
+    /// );
+    /// ~~~~
+    pub fn console_url(&self) -> Option<String> {
+        let partition = self.known_partition()?;
+        let domain = partition.console_domain()?;
+
+        match self.service {
+            "s3" => return s3_console_url(domain, self),
+            "iam" => return iam_console_url(domain, self.resource),
+            _ => {}
+        }
+
+        let region = self.region?;
+        let host = if partition == Partition::Aws {
+            format!("{}.{}", region, domain)
+        } else {
+            domain.to_owned()
+        };
+
+        let (path, fragment) = match (self.service, split_resource(self.resource)) {
+            ("ec2", ("instance", Some(id))) => {
+                ("ec2", format!("InstanceDetails:instanceId={}", id))
+            }
+            ("ec2", ("vpc", Some(id))) => ("vpcconsole", format!("VpcDetails:VpcId={}", id)),
+            ("ec2", ("subnet", Some(id))) => {
+                ("vpcconsole", format!("SubnetDetails:subnetId={}", id))
+            }
+            ("ec2", ("security-group", Some(id))) => {
+                ("ec2", format!("SecurityGroup:groupId={}", id))
+            }
+            ("ec2", ("volume", Some(id))) => ("ec2", format!("VolumeDetails:volumeId={}", id)),
+            ("lambda", ("function", Some(name))) => {
+                let name = name.split(':').next().unwrap_or(name);
+                ("lambda", format!("/functions/{}", name))
+            }
+            ("logs", ("log-group", Some(name))) => {
+                let name = name.strip_suffix(":*").unwrap_or(name);
+                let name = name.split(":log-stream:").next().unwrap_or(name);
+                (
+                    "cloudwatch",
+                    format!("logsV2:log-groups/log-group/{}", console_escape(name)),
+                )
+            }
+            ("dynamodb", ("table", Some(name))) if !name.contains('/') => {
+                ("dynamodbv2", format!("table?name={}", name))
+            }
+            ("sns", _) if !self.resource.contains(':') => ("sns/v3", format!("/topic/{}", self)),
+            ("sqs", _) => {
+                let queue_url = format!(
+                    "https://sqs.{}.{}/{}/{}",
+                    region,
+                    partition.dns_suffix(),
+                    self.account_id?,
+                    self.resource
+                );
+                (
+                    "sqs/v3",
+                    format!("/queues/{}", percent::encode_component(&queue_url)),
+                )
+            }
+            _ => return None,
+        };
+
+        Some(format!(
+            "https://{}/{}/home?region={}#{}",
+            host, path, region, fragment
+        ))
+    }
+}
+
+fn s3_console_url(domain: &str, arn: &NaiveArn) -> Option<String> {
+    let uri = arn.to_s3_uri()?;
+    let path = &uri["s3://".len()..];
+
+    Some(match path.find('/') {
+        Some(idx) => format!(
+            "https://{}/s3/object/{}?prefix={}",
+            domain,
+            &path[..idx],
+            percent::encode_component(&path[idx + 1..])
+        ),
+        None => format!("https://{}/s3/buckets/{}", domain, path),
+    })
+}
+
+fn iam_console_url(domain: &str, resource: &str) -> Option<String> {
+    let (kind, path) = split_resource(resource);
+    let section = match kind {
+        "user" => "users",
+        "role" => "roles",
+        "group" => "groups",
+        _ => return None,
+    };
+
+    // The console identifies IAM entities by name alone, without their path
+    let name = path?.rsplit('/').next().filter(|name| !name.is_empty())?;
+
+    Some(format!("https://{}/iam/home#/{}/{}", domain, section, name))
+}
+
+/// The CloudWatch console escapes fragments by percent-encoding them and then replacing `%` with `$25`.
+fn console_escape(s: &str) -> String {
+    percent::encode_component(s).replace('%', "$25")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive::NaiveArn;
+
+    fn console_url(arn_str: &str) -> Option<String> {
+        NaiveArn::parse(arn_str).unwrap().console_url()
+    }
+
+    #[test]
+    fn ec2_instance() {
+        assert_eq!(
+            console_url("arn:aws:ec2:us-east-1:123456789012:instance/i-0123456789abcdef0").unwrap(),
+            "https://us-east-1.console.aws.amazon.com/ec2/home?region=us-east-1#InstanceDetails:instanceId=i-0123456789abcdef0"
+        );
+    }
+
+    #[test]
+    fn ec2_vpc() {
+        assert_eq!(
+            console_url("arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98").unwrap(),
+            "https://us-east-1.console.aws.amazon.com/vpcconsole/home?region=us-east-1#VpcDetails:VpcId=vpc-fd580e98"
+        );
+    }
+
+    #[test]
+    fn s3_bucket() {
+        assert_eq!(
+            console_url("arn:aws:s3:::my_corporate_bucket").unwrap(),
+            "https://console.aws.amazon.com/s3/buckets/my_corporate_bucket"
+        );
+    }
+
+    #[test]
+    fn s3_object() {
+        assert_eq!(
+            console_url("arn:aws:s3:::my_corporate_bucket/Development/a b.png").unwrap(),
+            "https://console.aws.amazon.com/s3/object/my_corporate_bucket?prefix=Development%2Fa%20b.png"
+        );
+    }
+
+    #[test]
+    fn lambda_qualified_function() {
+        assert_eq!(
+            console_url("arn:aws:lambda:eu-west-1:123456789012:function:my-function:PROD").unwrap(),
+            "https://eu-west-1.console.aws.amazon.com/lambda/home?region=eu-west-1#/functions/my-function"
+        );
+    }
+
+    #[test]
+    fn iam_role_with_path() {
+        assert_eq!(
+            console_url("arn:aws:iam::123456789012:role/service-role/my-role").unwrap(),
+            "https://console.aws.amazon.com/iam/home#/roles/my-role"
+        );
+    }
+
+    #[test]
+    fn log_group() {
+        assert_eq!(
+            console_url("arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/my-function:*").unwrap(),
+            "https://us-east-1.console.aws.amazon.com/cloudwatch/home?region=us-east-1#logsV2:log-groups/log-group/$252Faws$252Flambda$252Fmy-function"
+        );
+    }
+
+    #[test]
+    fn sqs_queue() {
+        assert_eq!(
+            console_url("arn:aws:sqs:us-east-1:123456789012:my-queue").unwrap(),
+            "https://us-east-1.console.aws.amazon.com/sqs/v3/home?region=us-east-1#/queues/https%3A%2F%2Fsqs.us-east-1.amazonaws.com%2F123456789012%2Fmy-queue"
+        );
+    }
+
+    #[test]
+    fn china_partition() {
+        assert_eq!(
+            console_url("arn:aws-cn:dynamodb:cn-north-1:123456789012:table/Books").unwrap(),
+            "https://console.amazonaws.cn/dynamodbv2/home?region=cn-north-1#table?name=Books"
+        );
+    }
+
+    #[test]
+    fn govcloud_partition() {
+        assert_eq!(
+            console_url("arn:aws-us-gov:sns:us-gov-west-1:123456789012:my-topic").unwrap(),
+            "https://console.amazonaws-us-gov.com/sns/v3/home?region=us-gov-west-1#/topic/arn:aws-us-gov:sns:us-gov-west-1:123456789012:my-topic"
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        assert_eq!(
+            console_url("arn:aws:codecommit:us-east-1:123456789012:MyDemoRepo"),
+            None
+        );
+        assert_eq!(
+            console_url("arn:aws-iso:ec2:us-iso-east-1:123456789012:instance/i-0123"),
+            None
+        );
+        assert_eq!(console_url("arn:aws:iam::123456789012:root"), None);
+    }
+}
@@ -0,0 +1,25 @@
+//! The partition, region and account an ARN is being built for
+
+/// Where a resource lives, for building ARNs from identifiers that don't carry that information themselves (a bare
+/// name, a key ID, an API's short identifier).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Context<'a> {
+    /// See [`NaiveArn::partition`](crate::naive::NaiveArn::partition).
+    pub partition: &'a str,
+
+    /// See [`NaiveArn::region`](crate::naive::NaiveArn::region).
+    pub region: &'a str,
+
+    /// See [`NaiveArn::account_id`](crate::naive::NaiveArn::account_id).
+    pub account_id: &'a str,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(partition: &'a str, region: &'a str, account_id: &'a str) -> Self {
+        Context {
+            partition,
+            region,
+            account_id,
+        }
+    }
+}
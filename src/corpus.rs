@@ -0,0 +1,102 @@
+//! Example ARNs for conformance testing
+//!
+//! The corpus holds real-format ARNs for the services this crate has typed ARNs for (and some it doesn't), ARNs with
+//! validation issues and strings that aren't ARNs at all, each with the result this crate gives for it. Downstream
+//! crates can run their own ARN handling against the same data:
+//!
+//! ~~~~
+//! use arn::corpus::Expected;
+//! use arn::naive::NaiveArn;
+//!
+//! for example in arn::corpus().filter(|example| example.expected == Expected::Valid) {
+//!     assert!(NaiveArn::parse(example.text).is_ok(), "{}", example);
+//! }
+//! ~~~~
+
+use core::fmt;
+
+const CORPUS: &str = include_str!("corpus.txt");
+
+/// The result this crate gives for an [`Example`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Expected {
+    /// The text parses and is valid at [`ValidationLevel::Strict`](crate::validate::ValidationLevel::Strict).
+    Valid,
+    /// The text doesn't parse, or strict validation finds an issue; this is the code of the parse error or of the first
+    /// issue, such as `ARN007` or `ARN202`.
+    Invalid(&'static str),
+}
+
+/// An example from the [`corpus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Example {
+    /// The service namespace for valid ARNs of a known service, or what is being exercised otherwise, such as
+    /// `unknown-service`, `region` or `malformed`.
+    pub category: &'static str,
+    pub expected: Expected,
+    pub text: &'static str,
+}
+
+impl fmt::Display for Example {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}", self.text, self.category)?;
+        match self.expected {
+            Expected::Valid => write!(f, ", valid)"),
+            Expected::Invalid(code) => write!(f, ", {})", code),
+        }
+    }
+}
+
+/// Every example in the corpus, grouped by category.
+pub fn corpus() -> impl Iterator<Item = Example> {
+    CORPUS
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            let mut field = || fields.next().expect("corpus lines have three fields");
+
+            let category = field();
+            let expected = match field() {
+                "valid" => Expected::Valid,
+                code => Expected::Invalid(code),
+            };
+
+            Example {
+                category,
+                expected,
+                text: field(),
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{corpus, Expected};
+    use crate::naive::NaiveArn;
+    use crate::validate::ValidationLevel;
+
+    #[test]
+    fn expected() {
+        for example in corpus() {
+            let actual = match NaiveArn::parse(example.text) {
+                Ok(arn) => arn
+                    .first_issue(ValidationLevel::Strict)
+                    .map(|issue| issue.code()),
+                Err(err) => Some(err.code()),
+            };
+
+            let expected = match example.expected {
+                Expected::Valid => None,
+                Expected::Invalid(code) => Some(code),
+            };
+            assert_eq!(actual, expected, "{}", example);
+        }
+    }
+
+    #[test]
+    fn size() {
+        assert!(corpus().count() >= 200);
+        assert!(corpus().any(|example| example.text.is_empty()));
+    }
+}
@@ -0,0 +1,214 @@
+//! Reading ARNs from a column of a CSV file, such as a Cost Explorer or AWS Config export
+
+use std::{error, fmt, io};
+
+use crate::buf::ArnBuf;
+use crate::naive::ParseNaiveArnError;
+
+/// The column holding the ARNs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column<'a> {
+    /// The column with this header.
+    Name(&'a str),
+    /// The column at this zero-based index.
+    Index(usize),
+}
+
+/// Options for [`read_arn_column`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// Skip rows whose cell is missing or isn't an ARN, instead of yielding an error for them. Skipped rows are counted
+    /// by [`ArnColumn::skipped`].
+    pub skip_invalid: bool,
+}
+
+/// Read the ARNs in `column` of the CSV in `reader`, whose first row is a header.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::csv::{read_arn_column, Column, CsvArnError, CsvOptions};
+///
+/// let csv = "resource_id,cost\narn:aws:s3:::my_corporate_bucket,1.50\ni-0123,2.00\n";
+/// let arns: Vec<_> = read_arn_column(csv.as_bytes(), Column::Name("resource_id"), CsvOptions::default())
+///     .unwrap()
+///     .collect();
+///
+/// assert_eq!(arns[0].as_ref().unwrap().resource, "my_corporate_bucket");
+/// assert!(matches!(arns[1], Err(CsvArnError::InvalidArn { line: 3, .. })));
+/// ~~~~
+pub fn read_arn_column<R: io::Read>(
+    reader: R,
+    column: Column,
+    options: CsvOptions,
+) -> Result<ArnColumn<R>, CsvArnError> {
+    let mut reader = ::csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(reader);
+
+    let index = match column {
+        Column::Name(name) => reader
+            .headers()?
+            .iter()
+            .position(|header| header == name)
+            .ok_or(CsvArnError::UnknownColumn)?,
+        Column::Index(index) => index,
+    };
+
+    Ok(ArnColumn {
+        reader,
+        record: ::csv::StringRecord::new(),
+        index,
+        options,
+        skipped: 0,
+    })
+}
+
+/// Iterator over the ARNs in a CSV column, created by [`read_arn_column`].
+pub struct ArnColumn<R> {
+    reader: ::csv::Reader<R>,
+    record: ::csv::StringRecord,
+    index: usize,
+    options: CsvOptions,
+    skipped: u64,
+}
+
+impl<R> ArnColumn<R> {
+    /// The number of rows skipped so far because of [`CsvOptions::skip_invalid`].
+    pub fn skipped(&self) -> u64 {
+        self.skipped
+    }
+}
+
+impl<R: io::Read> Iterator for ArnColumn<R> {
+    type Item = Result<ArnBuf, CsvArnError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.reader.read_record(&mut self.record) {
+                Ok(true) => {}
+                Ok(false) => return None,
+                Err(err) => return Some(Err(err.into())),
+            }
+
+            let line = self.record.position().map_or(0, |position| position.line());
+            let result = match self.record.get(self.index) {
+                Some(cell) => {
+                    ArnBuf::parse(cell).map_err(|error| CsvArnError::InvalidArn { line, error })
+                }
+                None => Err(CsvArnError::MissingCell { line }),
+            };
+
+            if result.is_err() && self.options.skip_invalid {
+                self.skipped += 1;
+                continue;
+            }
+
+            return Some(result);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CsvArnError {
+    /// The CSV itself couldn't be read.
+    Csv(::csv::Error),
+    /// No header matched the column name.
+    UnknownColumn,
+    /// The row on this (one-based) line has no cell in the column.
+    MissingCell { line: u64 },
+    /// The cell on this (one-based) line isn't an ARN.
+    InvalidArn {
+        line: u64,
+        error: ParseNaiveArnError,
+    },
+}
+
+impl From<::csv::Error> for CsvArnError {
+    fn from(err: ::csv::Error) -> Self {
+        CsvArnError::Csv(err)
+    }
+}
+
+impl fmt::Display for CsvArnError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CsvArnError::Csv(err) => err.fmt(f),
+            CsvArnError::UnknownColumn => write!(f, "No column with that header"),
+            CsvArnError::MissingCell { line } => write!(f, "Line {}: missing ARN column", line),
+            CsvArnError::InvalidArn { line, error } => write!(f, "Line {}: {}", line, error),
+        }
+    }
+}
+
+impl error::Error for CsvArnError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            CsvArnError::Csv(err) => Some(err),
+            CsvArnError::InvalidArn { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_arn_column, Column, CsvArnError, CsvOptions};
+    use crate::naive::ParseNaiveArnError;
+
+    const CSV: &str = "\
+account,resource
+123456789012,arn:aws:sqs:us-east-1:123456789012:orders
+123456789012,not-an-arn
+123456789012
+\"123456789012\",\"arn:aws:s3:::my,bucket\"
+";
+
+    #[test]
+    fn by_name_and_index() {
+        for column in &[Column::Name("resource"), Column::Index(1)] {
+            let results: Vec<_> = read_arn_column(CSV.as_bytes(), *column, CsvOptions::default())
+                .unwrap()
+                .collect();
+
+            assert_eq!(results.len(), 4);
+            assert_eq!(
+                results[0].as_ref().unwrap().to_string(),
+                "arn:aws:sqs:us-east-1:123456789012:orders"
+            );
+            assert!(matches!(
+                results[1],
+                Err(CsvArnError::InvalidArn {
+                    line: 3,
+                    error: ParseNaiveArnError::MissingPrefix { .. }
+                })
+            ));
+            assert!(matches!(
+                results[2],
+                Err(CsvArnError::MissingCell { line: 4 })
+            ));
+            assert_eq!(results[3].as_ref().unwrap().resource, "my,bucket");
+        }
+    }
+
+    #[test]
+    fn skip_invalid() {
+        let mut arns = read_arn_column(
+            CSV.as_bytes(),
+            Column::Name("resource"),
+            CsvOptions { skip_invalid: true },
+        )
+        .unwrap();
+
+        assert_eq!(arns.by_ref().filter(Result::is_ok).count(), 2);
+        assert_eq!(arns.skipped(), 2);
+    }
+
+    #[test]
+    fn unknown_column() {
+        assert!(matches!(
+            read_arn_column(CSV.as_bytes(), Column::Name("arn"), CsvOptions::default()),
+            Err(CsvArnError::UnknownColumn)
+        ));
+    }
+}
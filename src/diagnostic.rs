@@ -0,0 +1,103 @@
+//! `miette` diagnostics for ARN parse errors
+//!
+//! Errors don't hold the input they came from, so attach it when reporting, e.g. with
+//! `miette::Report::new(err).with_source_code(input)`, to have the labels rendered beneath it.
+
+use miette::{Diagnostic, LabeledSpan};
+
+use crate::naive::{Component, ParseNaiveArnError, MAX_LEN};
+
+impl Diagnostic for ParseNaiveArnError {
+    fn code<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+        Some(Box::new(self.code()))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+        let help = match self {
+            ParseNaiveArnError::NotEnoughElements { .. } => {
+                "ARNs have six `:`-separated elements; region and account ID may be empty for global services — \
+                 did you mean `arn:aws:s3:::bucket`?"
+            }
+            ParseNaiveArnError::MissingPrefix { .. } => {
+                "ARNs start with `arn:`, as in `arn:aws:s3:::bucket`"
+            }
+            ParseNaiveArnError::MissingPartition { .. } => {
+                "the partition is `aws` for most regions, `aws-cn` for China and `aws-us-gov` for GovCloud"
+            }
+            ParseNaiveArnError::MissingService { .. } => {
+                "the service namespace comes after the partition, as in `arn:aws:s3:::bucket`"
+            }
+            ParseNaiveArnError::MissingResource { .. } => return None,
+            ParseNaiveArnError::InvalidAccountId { .. } => {
+                "account IDs are 12 digits, and may be empty for resources such as S3 buckets"
+            }
+            ParseNaiveArnError::InvalidRegionFormat { .. } => {
+                "regions are lowercase, as in `us-east-1`; region may be empty for global services — \
+                 did you mean `arn:aws:s3:::bucket`?"
+            }
+            ParseNaiveArnError::InputTooLong { .. } => return None,
+            ParseNaiveArnError::IllegalCharacter {
+                component: Component::Resource,
+                ..
+            } => "control characters, such as newlines, can't appear anywhere in an ARN",
+            ParseNaiveArnError::IllegalCharacter { .. } => {
+                "partitions and service namespaces are lowercase, as in `arn:aws:s3:::bucket`"
+            }
+        };
+
+        Some(Box::new(help))
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let len = match self {
+            ParseNaiveArnError::MissingPrefix { found } => found.len(),
+            ParseNaiveArnError::InputTooLong { length } => length - MAX_LEN,
+            ParseNaiveArnError::IllegalCharacter { found, .. } => found.len_utf8(),
+            ParseNaiveArnError::InvalidAccountId { found, .. }
+            | ParseNaiveArnError::InvalidRegionFormat { found, .. } => found
+                .chars()
+                .find(|c| {
+                    !(c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '*' | '?'))
+                })
+                .map_or(0, char::len_utf8),
+            _ => 0,
+        };
+
+        let label = LabeledSpan::new(
+            Some(format!("expected {}", self.expected())),
+            self.position(),
+            len,
+        );
+        Some(Box::new(std::iter::once(label)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::Diagnostic;
+
+    use crate::naive::NaiveArn;
+
+    #[test]
+    fn labels() {
+        let err = NaiveArn::parse("arn:aws:s3:us east:123456789012:bucket").unwrap_err();
+        let labels: Vec<_> = err.labels().unwrap().collect();
+
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].offset(), 13);
+        assert_eq!(labels[0].len(), 1);
+        assert_eq!(
+            labels[0].label(),
+            Some("expected a region, such as `us-east-1`")
+        );
+        assert!(err.help().unwrap().to_string().contains("global services"));
+    }
+
+    #[test]
+    fn missing_prefix() {
+        let err = NaiveArn::parse("aws:s3:::bucket").unwrap_err();
+        let label = err.labels().unwrap().next().unwrap();
+
+        assert_eq!((label.offset(), label.len()), (0, 4));
+    }
+}
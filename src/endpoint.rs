@@ -0,0 +1,265 @@
+//! Service endpoint URLs for ARNs
+
+use alloc::{format, string::String};
+
+use crate::naive::NaiveArn;
+use crate::partition::Partition;
+
+/// Options selecting an endpoint variant.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EndpointOptions {
+    /// Use the FIPS 140-validated endpoint, e.g. `sqs-fips.us-east-1.amazonaws.com`.
+    pub fips: bool,
+
+    /// Use the dual-stack (IPv4 and IPv6) endpoint, e.g. `sqs.us-east-1.api.aws`.
+    pub dual_stack: bool,
+}
+
+/// Services whose endpoint prefix differs from their ARN namespace.
+const ENDPOINT_PREFIXES: &[(&str, &str)] = &[
+    ("cloudwatch", "monitoring"),
+    ("execute-api", "apigateway"),
+    ("sagemaker", "api.sagemaker"),
+    ("ses", "email"),
+];
+
+/// Hosts of global services, which have no region in their ARNs.
+const GLOBAL_ENDPOINTS: &[(Partition, &str, &str)] = &[
+    (Partition::Aws, "cloudfront", "cloudfront.amazonaws.com"),
+    (Partition::Aws, "iam", "iam.amazonaws.com"),
+    (
+        Partition::Aws,
+        "organizations",
+        "organizations.us-east-1.amazonaws.com",
+    ),
+    (Partition::Aws, "route53", "route53.amazonaws.com"),
+    (Partition::Aws, "sts", "sts.amazonaws.com"),
+    (
+        Partition::AwsCn,
+        "cloudfront",
+        "cloudfront.cn-northwest-1.amazonaws.com.cn",
+    ),
+    (Partition::AwsCn, "iam", "iam.cn-north-1.amazonaws.com.cn"),
+    (
+        Partition::AwsCn,
+        "organizations",
+        "organizations.cn-northwest-1.amazonaws.com.cn",
+    ),
+    (Partition::AwsCn, "route53", "route53.amazonaws.com.cn"),
+    (Partition::AwsUsGov, "iam", "iam.us-gov.amazonaws.com"),
+    (
+        Partition::AwsUsGov,
+        "organizations",
+        "organizations.us-gov-west-1.amazonaws.com",
+    ),
+    (
+        Partition::AwsUsGov,
+        "route53",
+        "route53.us-gov.amazonaws.com",
+    ),
+];
+
+impl<'a> NaiveArn<'a> {
+    /// The default endpoint of the ARN's service in the ARN's region, e.g. `https://sqs.us-east-1.amazonaws.com`.
+    ///
+    /// Returns `None` if the partition is not known, or if the ARN has no region and its service is not a known global
+    /// service.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let arn = NaiveArn::parse("arn:aws-cn:sqs:cn-north-1:123456789012:my-queue").unwrap();
+    /// assert_eq!(arn.endpoint_url().unwrap(), "https://sqs.cn-north-1.amazonaws.com.cn");
+    /// ~~~~
+    pub fn endpoint_url(&self) -> Option<String> {
+        self.endpoint_url_with(EndpointOptions::default())
+    }
+
+    /// The endpoint of the ARN's service in the ARN's region, using the variant selected by `options`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::endpoint::EndpointOptions;
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let arn = NaiveArn::parse("arn:aws:sqs:us-east-1:123456789012:my-queue").unwrap();
+    /// let options = EndpointOptions { fips: true, dual_stack: true };
+    ///
+    /// assert_eq!(arn.endpoint_url_with(options).unwrap(), "https://sqs-fips.us-east-1.api.aws");
+    /// ~~~~
+    pub fn endpoint_url_with(&self, options: EndpointOptions) -> Option<String> {
+        let partition = self.known_partition()?;
+
+        let region = match self.region {
+            Some(region) if !region.contains(['*', '?']) => region,
+            Some(_) => return None,
+            None => {
+                let (_, _, host) = GLOBAL_ENDPOINTS
+                    .iter()
+                    .find(|(p, service, _)| *p == partition && *service == self.service)?;
+
+                return if options.fips || options.dual_stack {
+                    None
+                } else {
+                    Some(format!("https://{}", host))
+                };
+            }
+        };
+
+        let prefix = ENDPOINT_PREFIXES
+            .iter()
+            .find(|(service, _)| *service == self.service)
+            .map_or(self.service, |(_, prefix)| *prefix);
+        let fips = if options.fips { "-fips" } else { "" };
+
+        // S3 predates the `api.aws` dual-stack domains
+        if prefix == "s3" && options.dual_stack {
+            return Some(format!(
+                "https://s3{}.dualstack.{}.{}",
+                fips,
+                region,
+                partition.dns_suffix()
+            ));
+        }
+
+        let dns_suffix = if options.dual_stack {
+            partition.dual_stack_dns_suffix()
+        } else {
+            partition.dns_suffix()
+        };
+
+        Some(format!(
+            "https://{}{}.{}.{}",
+            prefix, fips, region, dns_suffix
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EndpointOptions;
+    use crate::naive::NaiveArn;
+
+    const FIPS: EndpointOptions = EndpointOptions {
+        fips: true,
+        dual_stack: false,
+    };
+    const DUAL_STACK: EndpointOptions = EndpointOptions {
+        fips: false,
+        dual_stack: true,
+    };
+
+    fn endpoint(arn_str: &str, options: EndpointOptions) -> Option<String> {
+        NaiveArn::parse(arn_str).unwrap().endpoint_url_with(options)
+    }
+
+    #[test]
+    fn regional() {
+        let arn_str = "arn:aws:sqs:us-east-1:123456789012:my-queue";
+
+        assert_eq!(
+            endpoint(arn_str, EndpointOptions::default()).unwrap(),
+            "https://sqs.us-east-1.amazonaws.com"
+        );
+        assert_eq!(
+            endpoint(arn_str, FIPS).unwrap(),
+            "https://sqs-fips.us-east-1.amazonaws.com"
+        );
+        assert_eq!(
+            endpoint(arn_str, DUAL_STACK).unwrap(),
+            "https://sqs.us-east-1.api.aws"
+        );
+    }
+
+    #[test]
+    fn endpoint_prefix() {
+        assert_eq!(
+            endpoint(
+                "arn:aws:cloudwatch:us-east-1:123456789012:alarm:MyAlarmName",
+                EndpointOptions::default()
+            )
+            .unwrap(),
+            "https://monitoring.us-east-1.amazonaws.com"
+        );
+    }
+
+    #[test]
+    fn other_partitions() {
+        assert_eq!(
+            endpoint(
+                "arn:aws-cn:lambda:cn-north-1:123456789012:function:my-function",
+                DUAL_STACK
+            )
+            .unwrap(),
+            "https://lambda.cn-north-1.api.amazonwebservices.com.cn"
+        );
+        assert_eq!(
+            endpoint(
+                "arn:aws-iso-b:sqs:us-isob-east-1:123456789012:my-queue",
+                EndpointOptions::default()
+            )
+            .unwrap(),
+            "https://sqs.us-isob-east-1.sc2s.sgov.gov"
+        );
+    }
+
+    #[test]
+    fn s3_dual_stack() {
+        assert_eq!(
+            endpoint(
+                "arn:aws:s3:us-west-2:123456789012:accesspoint/test",
+                DUAL_STACK
+            )
+            .unwrap(),
+            "https://s3.dualstack.us-west-2.amazonaws.com"
+        );
+    }
+
+    #[test]
+    fn global() {
+        assert_eq!(
+            endpoint(
+                "arn:aws:iam::123456789012:role/my-role",
+                EndpointOptions::default()
+            )
+            .unwrap(),
+            "https://iam.amazonaws.com"
+        );
+        assert_eq!(
+            endpoint(
+                "arn:aws-us-gov:iam::123456789012:role/my-role",
+                EndpointOptions::default()
+            )
+            .unwrap(),
+            "https://iam.us-gov.amazonaws.com"
+        );
+    }
+
+    #[test]
+    fn no_endpoint() {
+        assert_eq!(
+            endpoint(
+                "arn:aws:s3:::my_corporate_bucket",
+                EndpointOptions::default()
+            ),
+            None
+        );
+        assert_eq!(
+            endpoint(
+                "arn:aws:sns:*:123456789012:my_corporate_topic",
+                EndpointOptions::default()
+            ),
+            None
+        );
+        assert_eq!(
+            endpoint(
+                "arn:aws-moon:sqs:moon-1:123456789012:my-queue",
+                EndpointOptions::default()
+            ),
+            None
+        );
+    }
+}
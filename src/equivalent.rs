@@ -0,0 +1,140 @@
+//! Whether two ARNs name the same resource
+
+use core::convert::TryFrom;
+
+use crate::naive::NaiveArn;
+use crate::service::lambda::FunctionArn;
+use crate::service::logs::LogsArn;
+use crate::service::secretsmanager::SecretArn;
+
+impl<'a> NaiveArn<'a> {
+    /// Whether `other` names the same resource as this ARN.
+    ///
+    /// Equal ARNs are always equivalent. Beyond that, some services accept more than one ARN for a resource:
+    ///
+    /// * CloudWatch Logs log groups, with and without the trailing `:*`,
+    /// * Secrets Manager secrets, with and without the random six-character suffix, or with different suffixes, and
+    /// * Lambda functions, unqualified and qualified with `$LATEST`.
+    ///
+    /// Secrets with different suffixes may be a secret and one deleted before it with the same name; this treats them
+    /// as the same, as IAM policies and the Secrets Manager APIs do for partial ARNs.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let bare = NaiveArn::parse("arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/f").unwrap();
+    /// let wildcard = NaiveArn::parse("arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/f:*").unwrap();
+    /// assert!(bare.equivalent_to(&wildcard));
+    ///
+    /// let secret = NaiveArn::parse("arn:aws:secretsmanager:us-east-1:123456789012:secret:db-a1B2c3").unwrap();
+    /// let partial = NaiveArn::parse("arn:aws:secretsmanager:us-east-1:123456789012:secret:db").unwrap();
+    /// assert!(secret.equivalent_to(&partial));
+    /// ~~~~
+    pub fn equivalent_to(&self, other: &NaiveArn) -> bool {
+        if self == other {
+            return true;
+        }
+
+        if self.partition != other.partition || self.service != other.service {
+            return false;
+        }
+
+        match self.service {
+            "lambda" => match (FunctionArn::try_from(*self), FunctionArn::try_from(*other)) {
+                (Ok(a), Ok(b)) => unqualified_latest(a) == unqualified_latest(b),
+                _ => false,
+            },
+            "logs" => match (LogsArn::try_from(*self), LogsArn::try_from(*other)) {
+                (Ok(a), Ok(b)) => a == b,
+                _ => false,
+            },
+            "secretsmanager" => match (SecretArn::try_from(*self), SecretArn::try_from(*other)) {
+                (Ok(a), Ok(b)) => a.is_same_secret(&b),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+fn unqualified_latest(arn: FunctionArn) -> FunctionArn {
+    match arn.qualifier {
+        Some("$LATEST") => arn.unqualified(),
+        _ => arn,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive::NaiveArn;
+
+    fn equivalent(a: &str, b: &str) -> bool {
+        let a = NaiveArn::parse(a).unwrap();
+        let b = NaiveArn::parse(b).unwrap();
+
+        assert_eq!(a.equivalent_to(&b), b.equivalent_to(&a));
+        a.equivalent_to(&b)
+    }
+
+    #[test]
+    fn equivalent_arns() {
+        for (a, b) in [
+            (
+                "arn:aws:s3:::my_corporate_bucket",
+                "arn:aws:s3:::my_corporate_bucket",
+            ),
+            (
+                "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/f",
+                "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/f:*",
+            ),
+            (
+                "arn:aws:secretsmanager:us-east-1:123456789012:secret:prod/db-a1B2c3",
+                "arn:aws:secretsmanager:us-east-1:123456789012:secret:prod/db-d4E5f6",
+            ),
+            (
+                "arn:aws:secretsmanager:us-east-1:123456789012:secret:prod/db-a1B2c3",
+                "arn:aws:secretsmanager:us-east-1:123456789012:secret:prod/db",
+            ),
+            (
+                "arn:aws:lambda:us-east-1:123456789012:function:f",
+                "arn:aws:lambda:us-east-1:123456789012:function:f:$LATEST",
+            ),
+        ] {
+            assert!(equivalent(a, b), "{} and {}", a, b);
+        }
+    }
+
+    #[test]
+    fn different_arns() {
+        for (a, b) in [
+            (
+                "arn:aws:s3:::my_corporate_bucket",
+                "arn:aws:s3:::my_corporate_bucket/*",
+            ),
+            (
+                "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/f",
+                "arn:aws:logs:us-east-2:123456789012:log-group:/aws/lambda/f:*",
+            ),
+            (
+                "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/f:*",
+                "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/f:log-stream:s",
+            ),
+            (
+                "arn:aws:secretsmanager:us-east-1:123456789012:secret:prod/db-a1B2c3",
+                "arn:aws:secretsmanager:us-east-1:123456789012:secret:prod/api-a1B2c3",
+            ),
+            (
+                "arn:aws:lambda:us-east-1:123456789012:function:f",
+                "arn:aws:lambda:us-east-1:123456789012:function:f:PROD",
+            ),
+            (
+                "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/f",
+                "arn:aws-cn:logs:us-east-1:123456789012:log-group:/aws/lambda/f:*",
+            ),
+        ] {
+            assert!(!equivalent(a, b), "{} and {}", a, b);
+        }
+    }
+}
@@ -0,0 +1,218 @@
+//! Describing ARNs in plain English
+
+use core::fmt;
+
+use crate::alias::{AccountAliasResolver, AccountLabel};
+use crate::naive::{split_resource, NaiveArn};
+
+/// The full names of the services with ARN namespaces this crate knows, sorted by namespace.
+const SERVICE_NAMES: &[(&str, &str)] = &[
+    ("acm", "AWS Certificate Manager"),
+    ("apigateway", "Amazon API Gateway"),
+    ("athena", "Amazon Athena"),
+    ("backup", "AWS Backup"),
+    ("cloudformation", "AWS CloudFormation"),
+    ("cloudwatch", "Amazon CloudWatch"),
+    ("codebuild", "AWS CodeBuild"),
+    ("codecommit", "AWS CodeCommit"),
+    ("codepipeline", "AWS CodePipeline"),
+    ("cognito-identity", "Amazon Cognito Identity"),
+    ("cognito-idp", "Amazon Cognito User Pools"),
+    ("dynamodb", "Amazon DynamoDB"),
+    ("ec2", "Amazon Elastic Compute Cloud"),
+    ("ecr", "Amazon Elastic Container Registry"),
+    ("ecs", "Amazon Elastic Container Service"),
+    ("eks", "Amazon Elastic Kubernetes Service"),
+    ("elasticache", "Amazon ElastiCache"),
+    ("elasticloadbalancing", "Elastic Load Balancing"),
+    ("events", "Amazon EventBridge"),
+    ("execute-api", "Amazon API Gateway"),
+    ("glue", "AWS Glue"),
+    ("iam", "AWS Identity and Access Management"),
+    ("kinesis", "Amazon Kinesis Data Streams"),
+    ("kms", "AWS Key Management Service"),
+    ("lambda", "AWS Lambda"),
+    ("logs", "Amazon CloudWatch Logs"),
+    ("organizations", "AWS Organizations"),
+    ("rds", "Amazon Relational Database Service"),
+    ("redshift", "Amazon Redshift"),
+    ("route53", "Amazon Route 53"),
+    ("s3", "Amazon Simple Storage Service"),
+    ("s3-object-lambda", "Amazon S3 Object Lambda"),
+    ("s3-outposts", "Amazon S3 on Outposts"),
+    ("sagemaker", "Amazon SageMaker"),
+    ("secretsmanager", "AWS Secrets Manager"),
+    ("sns", "Amazon Simple Notification Service"),
+    ("sqs", "Amazon Simple Queue Service"),
+    ("ssm", "AWS Systems Manager"),
+    ("states", "AWS Step Functions"),
+    ("sts", "AWS Security Token Service"),
+];
+
+/// The full name of the service with this ARN namespace, if it is one this crate knows.
+///
+/// ~~~~
+/// use arn::explain::service_name;
+///
+/// assert_eq!(service_name("sqs"), Some("Amazon Simple Queue Service"));
+/// assert_eq!(service_name("unicorn"), None);
+/// ~~~~
+pub fn service_name(service: &str) -> Option<&'static str> {
+    SERVICE_NAMES
+        .binary_search_by_key(&service, |(namespace, _)| namespace)
+        .ok()
+        .map(|idx| SERVICE_NAMES[idx].1)
+}
+
+/// The components of an ARN, with the names of its partition and service where they are known.
+///
+/// Displays as a single line, such as
+/// `service: ec2 — Amazon Elastic Compute Cloud; region: us-east-1; account: 123456789012; resource type: vpc; resource id: vpc-fd580e98`.
+/// The partition is only mentioned when it isn't `aws`, and the region and account only when the ARN has them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    pub partition: &'a str,
+    pub partition_name: Option<&'static str>,
+    pub service: &'a str,
+    pub service_name: Option<&'static str>,
+    pub region: Option<&'a str>,
+    pub account_id: Option<&'a str>,
+    /// The alias of the account, see [`Explanation::resolve_account_alias`].
+    pub account_alias: Option<&'a str>,
+    /// The part of the resource before the first `/` or `:`, if it has one.
+    pub resource_type: Option<&'a str>,
+    pub resource_id: &'a str,
+}
+
+impl<'a> NaiveArn<'a> {
+    /// Break this ARN down into its components for display.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let arn = NaiveArn::parse("arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98").unwrap();
+    /// let explanation = arn.explain();
+    ///
+    /// assert_eq!(explanation.service_name, Some("Amazon Elastic Compute Cloud"));
+    /// assert_eq!(explanation.resource_type, Some("vpc"));
+    /// assert_eq!(explanation.resource_id, "vpc-fd580e98");
+    /// ~~~~
+    pub fn explain(&self) -> Explanation<'a> {
+        let (resource_type, resource_id) = match split_resource(self.resource) {
+            (resource_type, Some(resource_id)) => (Some(resource_type), resource_id),
+            (resource_id, None) => (None, resource_id),
+        };
+
+        Explanation {
+            partition: self.partition,
+            partition_name: self.known_partition().map(|partition| partition.name()),
+            service: self.service,
+            service_name: service_name(self.service),
+            region: self.region,
+            account_id: self.account_id,
+            account_alias: None,
+            resource_type,
+            resource_id,
+        }
+    }
+}
+
+impl<'a> Explanation<'a> {
+    /// Look up the alias of the account in `resolver`, to display the account as `123456789012 (prod-payments)`.
+    ///
+    /// ~~~~
+    /// # #[cfg(feature = "std")]
+    /// # {
+    /// use std::collections::HashMap;
+    ///
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let aliases: HashMap<_, _> = [("123456789012".to_owned(), "prod-payments".to_owned())].into();
+    /// let arn = NaiveArn::parse("arn:aws:sqs:us-east-1:123456789012:orders").unwrap();
+    ///
+    /// assert_eq!(
+    ///     arn.explain().resolve_account_alias(&aliases).to_string(),
+    ///     "service: sqs — Amazon Simple Queue Service; region: us-east-1; account: 123456789012 (prod-payments); \
+    ///      resource id: orders"
+    /// );
+    /// # }
+    /// ~~~~
+    pub fn resolve_account_alias<R: AccountAliasResolver + ?Sized>(
+        mut self,
+        resolver: &'a R,
+    ) -> Self {
+        self.account_alias = self
+            .account_id
+            .and_then(|account_id| resolver.alias(account_id));
+        self
+    }
+}
+
+impl<'a> fmt::Display for Explanation<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.partition != "aws" {
+            write!(f, "partition: {}", self.partition)?;
+            if let Some(name) = self.partition_name {
+                write!(f, " — {}", name)?;
+            }
+            write!(f, "; ")?;
+        }
+
+        write!(f, "service: {}", self.service)?;
+        if let Some(name) = self.service_name {
+            write!(f, " — {}", name)?;
+        }
+        if let Some(region) = self.region {
+            write!(f, "; region: {}", region)?;
+        }
+        if let Some(account_id) = self.account_id {
+            let label = AccountLabel {
+                account_id,
+                alias: self.account_alias,
+            };
+            write!(f, "; account: {}", label)?;
+        }
+        if let Some(resource_type) = self.resource_type {
+            write!(f, "; resource type: {}", resource_type)?;
+        }
+        write!(f, "; resource id: {}", self.resource_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SERVICE_NAMES;
+    use crate::naive::NaiveArn;
+
+    #[test]
+    fn service_names_sorted() {
+        assert!(SERVICE_NAMES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn display() {
+        for (arn_str, explanation) in [
+            (
+                "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98",
+                "service: ec2 — Amazon Elastic Compute Cloud; region: us-east-1; account: 123456789012; \
+                 resource type: vpc; resource id: vpc-fd580e98",
+            ),
+            (
+                "arn:aws:s3:::my_corporate_bucket",
+                "service: s3 — Amazon Simple Storage Service; resource id: my_corporate_bucket",
+            ),
+            (
+                "arn:aws-cn:unicorn:cn-north-1:123456789012:horn:left",
+                "partition: aws-cn — AWS China; service: unicorn; region: cn-north-1; account: 123456789012; \
+                 resource type: horn; resource id: left",
+            ),
+        ] {
+            assert_eq!(
+                NaiveArn::parse(arn_str).unwrap().explain().to_string(),
+                explanation
+            );
+        }
+    }
+}
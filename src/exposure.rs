@@ -0,0 +1,315 @@
+//! Classifying ARNs, such as the principals of resource policies, by whether they reach outside trusted accounts
+
+use alloc::collections::BTreeSet;
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::fmt;
+
+use crate::alias::AccountAliasResolver;
+use crate::buf::ArnBuf;
+use crate::naive::NaiveArn;
+
+/// How far outside the trusted accounts an ARN reaches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Exposure {
+    /// The ARN is in a trusted account or organization.
+    Internal,
+    /// The ARN is in an untrusted account in the trusted partition.
+    CrossAccount,
+    /// The ARN is in another partition.
+    CrossPartition,
+    /// The ARN's account or partition is a wildcard, so it may match any account.
+    Wildcard,
+    /// The ARN doesn't name an account, like an S3 bucket's.
+    Unknown,
+}
+
+impl fmt::Display for Exposure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Exposure::Internal => "internal",
+            Exposure::CrossAccount => "cross-account",
+            Exposure::CrossPartition => "cross-partition",
+            Exposure::Wildcard => "wildcard",
+            Exposure::Unknown => "unknown",
+        })
+    }
+}
+
+/// Classifies ARNs against a list of trusted accounts and AWS Organizations in a partition.
+///
+/// An ARN is [`Internal`](Exposure::Internal) if its account is trusted, or if it is an AWS Organizations ARN (of
+/// an account, organizational unit or policy) in a trusted organization. ARNs owned by AWS, with the account `aws`,
+/// are internal too.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::exposure::{Exposure, ExposureAnalyzer};
+/// use arn::naive::NaiveArn;
+///
+/// let analyzer = ExposureAnalyzer::new("aws")
+///     .trust_account("111111111111")
+///     .trust_organization("o-a1b2c3d4e5");
+///
+/// let principals = [
+///     "arn:aws:iam::111111111111:role/Deploy",
+///     "arn:aws:iam::222222222222:root",
+///     "arn:aws:iam::*:role/Deploy",
+///     "arn:aws:organizations::111111111111:account/o-a1b2c3d4e5/333333333333",
+/// ];
+/// let report = analyzer.analyze(principals.iter().map(|arn| NaiveArn::parse(arn).unwrap()));
+///
+/// assert_eq!(report.count(Exposure::Internal), 2);
+/// assert_eq!(
+///     report.exposed().map(|finding| finding.to_string()).collect::<Vec<_>>(),
+///     ["cross-account: arn:aws:iam::222222222222:root", "wildcard: arn:aws:iam::*:role/Deploy"]
+/// );
+/// ~~~~
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExposureAnalyzer {
+    partition: String,
+    accounts: BTreeSet<String>,
+    organizations: BTreeSet<String>,
+}
+
+impl ExposureAnalyzer {
+    /// An analyzer for `partition`, trusting nothing until accounts or organizations are added.
+    pub fn new(partition: &str) -> Self {
+        ExposureAnalyzer {
+            partition: partition.to_owned(),
+            accounts: BTreeSet::new(),
+            organizations: BTreeSet::new(),
+        }
+    }
+
+    pub fn trust_account(mut self, account_id: &str) -> Self {
+        self.accounts.insert(account_id.to_owned());
+        self
+    }
+
+    pub fn trust_accounts<'a, I>(mut self, account_ids: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        self.accounts
+            .extend(account_ids.into_iter().map(str::to_owned));
+        self
+    }
+
+    /// Trust the organization with the ID `organization_id`, such as `o-a1b2c3d4e5`.
+    pub fn trust_organization(mut self, organization_id: &str) -> Self {
+        self.organizations.insert(organization_id.to_owned());
+        self
+    }
+
+    /// Classify `arn`.
+    pub fn classify(&self, arn: &NaiveArn) -> Exposure {
+        let is_wildcard = |s: &str| s.contains(['*', '?']);
+
+        if is_wildcard(arn.partition) || arn.account_id.is_some_and(is_wildcard) {
+            return Exposure::Wildcard;
+        }
+        if arn.partition != self.partition {
+            return Exposure::CrossPartition;
+        }
+
+        let account_id = match arn.account_id {
+            Some(account_id) => account_id,
+            None => return Exposure::Unknown,
+        };
+
+        let in_trusted_organization = arn.service == "organizations"
+            && arn
+                .resource
+                .split('/')
+                .nth(1)
+                .is_some_and(|organization_id| self.organizations.contains(organization_id));
+
+        if account_id == "aws" || self.accounts.contains(account_id) || in_trusted_organization {
+            Exposure::Internal
+        } else {
+            Exposure::CrossAccount
+        }
+    }
+
+    /// Classify every ARN in `arns`.
+    pub fn analyze<'a, I>(&self, arns: I) -> ExposureReport
+    where
+        I: IntoIterator<Item = NaiveArn<'a>>,
+    {
+        ExposureReport {
+            findings: arns
+                .into_iter()
+                .map(|arn| ExposureFinding {
+                    exposure: self.classify(&arn),
+                    arn: arn.into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The result of [`ExposureAnalyzer::analyze`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExposureReport {
+    /// A finding for every ARN, in the order they were given.
+    pub findings: Vec<ExposureFinding>,
+}
+
+impl ExposureReport {
+    /// The number of ARNs classified as `exposure`.
+    pub fn count(&self, exposure: Exposure) -> usize {
+        self.findings
+            .iter()
+            .filter(|finding| finding.exposure == exposure)
+            .count()
+    }
+
+    /// The findings for ARNs that aren't [`Internal`](Exposure::Internal).
+    pub fn exposed(&self) -> impl Iterator<Item = &ExposureFinding> {
+        self.findings
+            .iter()
+            .filter(|finding| finding.exposure != Exposure::Internal)
+    }
+}
+
+/// The classification of an ARN.
+///
+/// Displays as `exposure: arn`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExposureFinding {
+    pub arn: ArnBuf,
+    pub exposure: Exposure,
+}
+
+impl ExposureFinding {
+    /// Display the finding with the alias of the ARN's account from `resolver`, as
+    /// `cross-account: arn:aws:iam::222222222222:root (partner)`.
+    pub fn display_with<'a, R>(&'a self, resolver: &'a R) -> impl fmt::Display + 'a
+    where
+        R: AccountAliasResolver + ?Sized,
+    {
+        LabelledFinding {
+            finding: self,
+            alias: self
+                .arn
+                .account_id
+                .as_deref()
+                .and_then(|account_id| resolver.alias(account_id)),
+        }
+    }
+}
+
+impl fmt::Display for ExposureFinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.exposure, self.arn)
+    }
+}
+
+struct LabelledFinding<'a> {
+    finding: &'a ExposureFinding,
+    alias: Option<&'a str>,
+}
+
+impl<'a> fmt::Display for LabelledFinding<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.finding.fmt(f)?;
+        if let Some(alias) = self.alias {
+            write!(f, " ({})", alias)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{Exposure, ExposureAnalyzer};
+    use crate::naive::NaiveArn;
+
+    fn classify(arn_str: &str) -> Exposure {
+        ExposureAnalyzer::new("aws")
+            .trust_accounts(["111111111111", "222222222222"])
+            .trust_organization("o-a1b2c3d4e5")
+            .classify(&NaiveArn::parse(arn_str).unwrap())
+    }
+
+    #[test]
+    fn internal() {
+        for arn_str in [
+            "arn:aws:iam::111111111111:root",
+            "arn:aws:sts::222222222222:assumed-role/Deploy/session",
+            "arn:aws:iam::aws:policy/ReadOnlyAccess",
+            "arn:aws:organizations::999999999999:ou/o-a1b2c3d4e5/ou-ab12-cdef3456",
+            // Organizations ARNs are in the management account.
+            "arn:aws:organizations::111111111111:account/o-zzzzzzzzzz/333333333333",
+        ] {
+            assert_eq!(classify(arn_str), Exposure::Internal, "{}", arn_str);
+        }
+    }
+
+    #[test]
+    fn exposed() {
+        for (arn_str, exposure) in [
+            ("arn:aws:iam::333333333333:root", Exposure::CrossAccount),
+            (
+                "arn:aws:organizations::999999999999:account/o-zzzzzzzzzz/333333333333",
+                Exposure::CrossAccount,
+            ),
+            (
+                "arn:aws-cn:iam::111111111111:root",
+                Exposure::CrossPartition,
+            ),
+            ("arn:aws:iam::1111111111*:root", Exposure::Wildcard),
+            ("arn:*:iam::111111111111:root", Exposure::Wildcard),
+            ("arn:aws:s3:::my_corporate_bucket", Exposure::Unknown),
+        ] {
+            assert_eq!(classify(arn_str), exposure, "{}", arn_str);
+        }
+    }
+
+    #[test]
+    fn report() {
+        let analyzer = ExposureAnalyzer::new("aws").trust_account("111111111111");
+        let report = analyzer.analyze(
+            [
+                "arn:aws:iam::111111111111:root",
+                "arn:aws:iam::333333333333:root",
+                "arn:aws:iam::444444444444:root",
+            ]
+            .iter()
+            .map(|arn| NaiveArn::parse(arn).unwrap()),
+        );
+
+        assert_eq!(report.findings.len(), 3);
+        assert_eq!(report.count(Exposure::CrossAccount), 2);
+        assert_eq!(report.exposed().count(), 2);
+    }
+
+    #[test]
+    fn display_with_aliases() {
+        let aliases: BTreeMap<String, String> =
+            [("222222222222".to_owned(), "partner".to_owned())].into();
+        let report = ExposureAnalyzer::new("aws").analyze(
+            [
+                "arn:aws:iam::222222222222:root",
+                "arn:aws:iam::333333333333:root",
+            ]
+            .iter()
+            .map(|arn| NaiveArn::parse(arn).unwrap()),
+        );
+
+        assert_eq!(
+            report
+                .findings
+                .iter()
+                .map(|finding| finding.display_with(&aliases).to_string())
+                .collect::<Vec<_>>(),
+            [
+                "cross-account: arn:aws:iam::222222222222:root (partner)",
+                "cross-account: arn:aws:iam::333333333333:root"
+            ]
+        );
+    }
+}
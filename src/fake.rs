@@ -0,0 +1,270 @@
+//! Generating realistic ARNs for test fixtures
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::buf::ArnBuf;
+use crate::partition::Partition;
+
+const SERVICES: &[&str] = &[
+    "dynamodb",
+    "ec2",
+    "ecr",
+    "iam",
+    "kms",
+    "lambda",
+    "logs",
+    "s3",
+    "secretsmanager",
+    "sns",
+    "sqs",
+    "states",
+];
+
+const WORDS: &[&str] = &[
+    "analytics",
+    "api",
+    "audit",
+    "billing",
+    "events",
+    "ingest",
+    "orders",
+    "payments",
+    "reports",
+    "search",
+    "sessions",
+    "users",
+];
+
+const ENVIRONMENTS: &[&str] = &["dev", "prod", "staging", "test"];
+
+const ALPHANUMERIC: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Generates plausible ARNs: a service this crate has typed ARNs for, a real region in the chosen partition, a
+/// random account ID and a resource in that service's shape. Every ARN generated is valid at
+/// [`ValidationLevel::Strict`](crate::validate::ValidationLevel::Strict).
+///
+/// The same seed always generates the same ARNs with the same versions of this crate and `rand`.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::fake::Generator;
+/// use arn::partition::Partition;
+///
+/// let mut generator = Generator::seeded(42);
+/// let arn = generator.arn_in(Partition::AwsCn);
+///
+/// assert_eq!(arn.partition, "aws-cn");
+/// assert_eq!(Generator::seeded(42).arn_in(Partition::AwsCn), arn);
+/// ~~~~
+#[derive(Debug, Clone)]
+pub struct Generator<R = StdRng> {
+    rng: R,
+}
+
+impl Generator {
+    /// A generator seeded with `seed`.
+    pub fn seeded(seed: u64) -> Self {
+        Generator {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl<R: Rng> Generator<R> {
+    /// A generator drawing from `rng`.
+    pub fn from_rng(rng: R) -> Self {
+        Generator { rng }
+    }
+
+    /// An ARN in the `aws` partition.
+    pub fn arn(&mut self) -> ArnBuf {
+        self.arn_in(Partition::Aws)
+    }
+
+    /// An ARN in `partition`.
+    pub fn arn_in(&mut self, partition: Partition) -> ArnBuf {
+        let service = *SERVICES.choose(&mut self.rng).unwrap();
+        self.service_arn_in(partition, service)
+            .expect("every generated service is supported")
+    }
+
+    /// An ARN for `service` in `partition`, or `None` if this generator doesn't know the service's resource shapes.
+    pub fn service_arn_in(&mut self, partition: Partition, service: &str) -> Option<ArnBuf> {
+        let region = Some(self.region(partition).to_owned());
+        let account_id = Some(self.account_id());
+
+        let (region, account_id, resource) = match service {
+            "dynamodb" => (region, account_id, format!("table/{}", self.name())),
+            "ec2" => {
+                let (resource_type, prefix) = *[
+                    ("instance", "i"),
+                    ("security-group", "sg"),
+                    ("subnet", "subnet"),
+                    ("volume", "vol"),
+                    ("vpc", "vpc"),
+                ]
+                .choose(&mut self.rng)
+                .unwrap();
+                let id = self.hex(17);
+                (
+                    region,
+                    account_id,
+                    format!("{}/{}-{}", resource_type, prefix, id),
+                )
+            }
+            "ecr" => {
+                let resource = format!("repository/{}/{}", self.word(), self.word());
+                (region, account_id, resource)
+            }
+            "iam" => {
+                let resource_type = *["role", "user", "policy"].choose(&mut self.rng).unwrap();
+                let resource = format!("{}/{}", resource_type, self.pascal_name());
+                (None, account_id, resource)
+            }
+            "kms" => (region, account_id, format!("key/{}", self.uuid())),
+            "lambda" => (region, account_id, format!("function:{}", self.name())),
+            "logs" => {
+                let resource = format!("log-group:/aws/lambda/{}", self.name());
+                (region, account_id, resource)
+            }
+            "s3" => {
+                let resource = format!("{}-{}", self.name(), self.rng.gen_range(1000..10000));
+                (None, None, resource)
+            }
+            "secretsmanager" => {
+                let resource = format!(
+                    "secret:{}/{}-{}",
+                    self.word(),
+                    self.word(),
+                    self.alphanumeric(6)
+                );
+                (region, account_id, resource)
+            }
+            "sns" | "sqs" => (region, account_id, self.name()),
+            "states" => {
+                let resource = format!("stateMachine:{}", self.pascal_name());
+                (region, account_id, resource)
+            }
+            _ => return None,
+        };
+
+        Some(ArnBuf {
+            partition: partition.id().to_owned(),
+            service: service.to_owned(),
+            region,
+            account_id,
+            resource,
+        })
+    }
+
+    /// A random region in `partition`.
+    pub fn region(&mut self, partition: Partition) -> &'static str {
+        partition.regions().choose(&mut self.rng).unwrap()
+    }
+
+    /// A random 12-digit account ID.
+    pub fn account_id(&mut self) -> String {
+        format!(
+            "{:012}",
+            self.rng.gen_range(100_000_000_000u64..1_000_000_000_000)
+        )
+    }
+
+    fn word(&mut self) -> &'static str {
+        WORDS.choose(&mut self.rng).unwrap()
+    }
+
+    /// A name like `orders-prod`.
+    fn name(&mut self) -> String {
+        let environment = ENVIRONMENTS.choose(&mut self.rng).unwrap();
+        format!("{}-{}", self.word(), environment)
+    }
+
+    /// A name like `OrdersReports`.
+    fn pascal_name(&mut self) -> String {
+        [self.word(), self.word()]
+            .iter()
+            .map(|word| word[..1].to_uppercase() + &word[1..])
+            .collect()
+    }
+
+    fn hex(&mut self, len: usize) -> String {
+        (0..len)
+            .map(|_| std::char::from_digit(self.rng.gen_range(0..16), 16).unwrap())
+            .collect()
+    }
+
+    fn alphanumeric(&mut self, len: usize) -> String {
+        (0..len)
+            .map(|_| *ALPHANUMERIC.choose(&mut self.rng).unwrap() as char)
+            .collect()
+    }
+
+    fn uuid(&mut self) -> String {
+        format!(
+            "{}-{}-4{}-{}{}-{}",
+            self.hex(8),
+            self.hex(4),
+            self.hex(3),
+            ["8", "9", "a", "b"].choose(&mut self.rng).unwrap(),
+            self.hex(3),
+            self.hex(12)
+        )
+    }
+}
+
+impl<R: Rng> Iterator for Generator<R> {
+    type Item = ArnBuf;
+
+    /// Equivalent to [`Generator::arn`]; never returns `None`.
+    fn next(&mut self) -> Option<ArnBuf> {
+        Some(self.arn())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Generator, SERVICES};
+    use crate::partition::Partition;
+    use crate::validate::ValidationLevel;
+
+    #[test]
+    fn strictly_valid() {
+        let mut generator = Generator::seeded(0);
+
+        for &partition in Partition::ALL {
+            for _ in 0..200 {
+                let arn = generator.arn_in(partition);
+                assert_eq!(
+                    arn.as_naive().validate(ValidationLevel::Strict),
+                    Ok(()),
+                    "{}",
+                    arn
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn every_service() {
+        let mut generator = Generator::seeded(0);
+
+        for service in SERVICES {
+            let arn = generator.service_arn_in(Partition::Aws, service).unwrap();
+            assert_eq!(arn.service, *service);
+            assert!(arn.as_naive().interpret().is_known(), "{}", arn);
+        }
+        assert_eq!(generator.service_arn_in(Partition::Aws, "unicorn"), None);
+    }
+
+    #[test]
+    fn seeded() {
+        let arns: Vec<_> = Generator::seeded(7).take(10).collect();
+
+        assert_eq!(Generator::seeded(7).take(10).collect::<Vec<_>>(), arns);
+        assert_ne!(Generator::seeded(8).take(10).collect::<Vec<_>>(), arns);
+    }
+}
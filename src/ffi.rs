@@ -0,0 +1,303 @@
+//! A C interface, for linking the crate into C, C++ or Go services
+//!
+//! Build the library as a `cdylib` or `staticlib` with
+//! `cargo rustc --lib --release --features ffi --crate-type cdylib`, and include `include/arn.h`.
+//!
+//! Every function returns [`ARN_OK`] on success, the number of an error code on failure (`7` for `ARN007`, `202` for
+//! `ARN202`, as documented on [`ParseNaiveArnError::code`]), or one of the negative `ARN_ERR_*` codes if it was called
+//! incorrectly. Results are written through out-pointers, which are left untouched on failure (apart from the contents
+//! of a buffer too small to format into).
+
+use core::ffi::{c_char, c_int, CStr};
+use core::{ptr, slice, str};
+
+use crate::naive::{NaiveArn, ParseNaiveArnError};
+use crate::validate::ValidationLevel;
+
+pub const ARN_OK: c_int = 0;
+/// A required pointer argument was null.
+pub const ARN_ERR_NULL: c_int = -1;
+/// A string argument wasn't valid UTF-8.
+pub const ARN_ERR_UTF8: c_int = -2;
+/// The output buffer was too small.
+pub const ARN_ERR_BUFFER_TOO_SMALL: c_int = -3;
+/// The validation level wasn't one of the `ARN_LEVEL_*` constants.
+pub const ARN_ERR_INVALID_LEVEL: c_int = -4;
+
+pub const ARN_LEVEL_SYNTAX: c_int = 0;
+pub const ARN_LEVEL_STANDARD: c_int = 1;
+pub const ARN_LEVEL_STRICT: c_int = 2;
+
+/// A string borrowed from the input, which isn't NUL-terminated. `ptr` is null for a missing region or account ID.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ArnStr {
+    pub ptr: *const c_char,
+    pub len: usize,
+}
+
+/// The components of an ARN; see [`NaiveArn`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ArnParts {
+    pub partition: ArnStr,
+    pub service: ArnStr,
+    pub region: ArnStr,
+    pub account_id: ArnStr,
+    pub resource: ArnStr,
+}
+
+impl ArnParts {
+    /// # Safety
+    ///
+    /// The strings must be null or point to `len` readable bytes, valid for `'a`.
+    unsafe fn as_naive<'a>(self) -> Result<NaiveArn<'a>, c_int> {
+        Ok(NaiveArn {
+            partition: self.partition.as_str()?.ok_or(ARN_ERR_NULL)?,
+            service: self.service.as_str()?.ok_or(ARN_ERR_NULL)?,
+            region: self.region.as_str()?,
+            account_id: self.account_id.as_str()?,
+            resource: self.resource.as_str()?.ok_or(ARN_ERR_NULL)?,
+        })
+    }
+}
+
+impl ArnStr {
+    const NULL: ArnStr = ArnStr {
+        ptr: ptr::null(),
+        len: 0,
+    };
+
+    fn new(s: &str) -> Self {
+        ArnStr {
+            ptr: s.as_ptr().cast(),
+            len: s.len(),
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must be null or point to `len` readable bytes, valid for `'a`.
+    unsafe fn as_str<'a>(self) -> Result<Option<&'a str>, c_int> {
+        if self.ptr.is_null() {
+            return Ok(None);
+        }
+
+        str::from_utf8(slice::from_raw_parts(self.ptr.cast(), self.len))
+            .map(Some)
+            .map_err(|_| ARN_ERR_UTF8)
+    }
+}
+
+/// The number of a code such as `ARN007`.
+fn code_number(code: &str) -> c_int {
+    code["ARN".len()..].parse().unwrap_or(c_int::MAX)
+}
+
+/// # Safety
+///
+/// `input` must be null or a NUL-terminated string valid for `'a`.
+unsafe fn input<'a>(input: *const c_char) -> Result<&'a str, c_int> {
+    if input.is_null() {
+        return Err(ARN_ERR_NULL);
+    }
+
+    CStr::from_ptr(input).to_str().map_err(|_| ARN_ERR_UTF8)
+}
+
+/// # Safety
+///
+/// `input` must be null or a NUL-terminated string valid for `'a`, and `error_position` null or valid for writes.
+unsafe fn parse<'a>(
+    input: *const c_char,
+    error_position: *mut usize,
+) -> Result<NaiveArn<'a>, c_int> {
+    NaiveArn::parse(self::input(input)?).map_err(|err: ParseNaiveArnError| {
+        if let Some(position) = error_position.as_mut() {
+            *position = err.position();
+        }
+        code_number(err.code())
+    })
+}
+
+/// Parse the NUL-terminated `input` into `out`, whose strings borrow from `input`.
+///
+/// On a parse error, the byte offset of the error is written to `error_position` unless it is null.
+///
+/// # Safety
+///
+/// `input` must be a NUL-terminated string, `out` must be valid for writes and `error_position` must be null or valid
+/// for writes.
+#[no_mangle]
+pub unsafe extern "C" fn arn_parse(
+    input: *const c_char,
+    out: *mut ArnParts,
+    error_position: *mut usize,
+) -> c_int {
+    let out = match out.as_mut() {
+        Some(out) => out,
+        None => return ARN_ERR_NULL,
+    };
+    let arn = match parse(input, error_position) {
+        Ok(arn) => arn,
+        Err(code) => return code,
+    };
+
+    *out = ArnParts {
+        partition: ArnStr::new(arn.partition),
+        service: ArnStr::new(arn.service),
+        region: arn.region.map_or(ArnStr::NULL, ArnStr::new),
+        account_id: arn.account_id.map_or(ArnStr::NULL, ArnStr::new),
+        resource: ArnStr::new(arn.resource),
+    };
+    ARN_OK
+}
+
+/// Parse and validate the NUL-terminated `input` at `level`, one of the `ARN_LEVEL_*` constants, returning the code of
+/// the first problem found.
+///
+/// # Safety
+///
+/// `input` must be a NUL-terminated string and `error_position` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn arn_validate(
+    input: *const c_char,
+    level: c_int,
+    error_position: *mut usize,
+) -> c_int {
+    let level = match level {
+        ARN_LEVEL_SYNTAX => ValidationLevel::Syntax,
+        ARN_LEVEL_STANDARD => ValidationLevel::Standard,
+        ARN_LEVEL_STRICT => ValidationLevel::Strict,
+        _ => return ARN_ERR_INVALID_LEVEL,
+    };
+    let arn = match parse(input, error_position) {
+        Ok(arn) => arn,
+        Err(code) => return code,
+    };
+
+    match arn.first_issue(level) {
+        Some(issue) => code_number(issue.code()),
+        None => ARN_OK,
+    }
+}
+
+/// Format `arn` into `buf` as a NUL-terminated string of at most `len` bytes, including the NUL, and write its length,
+/// excluding the NUL, to `written` unless it is null.
+///
+/// # Safety
+///
+/// The strings of `arn` must be null or point to their `len` bytes, `buf` must be valid for `len` bytes of writes and
+/// `written` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn arn_format(
+    arn: *const ArnParts,
+    buf: *mut c_char,
+    len: usize,
+    written: *mut usize,
+) -> c_int {
+    let arn = match arn.as_ref() {
+        Some(arn) => *arn,
+        None => return ARN_ERR_NULL,
+    };
+    if buf.is_null() || len == 0 {
+        return ARN_ERR_NULL;
+    }
+
+    let arn = match arn.as_naive() {
+        Ok(arn) => arn,
+        Err(code) => return code,
+    };
+
+    // Leave room for the NUL.
+    let buf = slice::from_raw_parts_mut(buf.cast::<u8>(), len);
+    let formatted = match arn.format_into(&mut buf[..len - 1]) {
+        Some(formatted) => formatted.len(),
+        None => return ARN_ERR_BUFFER_TOO_SMALL,
+    };
+
+    buf[formatted] = 0;
+    if let Some(written) = written.as_mut() {
+        *written = formatted;
+    }
+    ARN_OK
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ffi::{c_char, c_int, CStr};
+    use core::ptr;
+    use std::ffi::CString;
+
+    use super::{
+        arn_format, arn_parse, arn_validate, ArnParts, ArnStr, ARN_ERR_BUFFER_TOO_SMALL,
+        ARN_ERR_INVALID_LEVEL, ARN_ERR_NULL, ARN_LEVEL_STANDARD, ARN_LEVEL_SYNTAX, ARN_OK,
+    };
+
+    fn parts(input: &CString) -> Result<ArnParts, (c_int, usize)> {
+        let mut out = ArnParts {
+            partition: ArnStr::NULL,
+            service: ArnStr::NULL,
+            region: ArnStr::NULL,
+            account_id: ArnStr::NULL,
+            resource: ArnStr::NULL,
+        };
+        let mut position = 0;
+
+        match unsafe { arn_parse(input.as_ptr(), &mut out, &mut position) } {
+            ARN_OK => Ok(out),
+            code => Err((code, position)),
+        }
+    }
+
+    #[test]
+    fn parse_and_format() {
+        let input = CString::new("arn:aws:s3:::my_corporate_bucket").unwrap();
+        let arn = parts(&input).unwrap();
+
+        assert!(arn.region.ptr.is_null());
+        assert_eq!(
+            unsafe { arn.resource.as_str() },
+            Ok(Some("my_corporate_bucket"))
+        );
+
+        let mut buf = [1 as c_char; 64];
+        let mut written = 0;
+        assert_eq!(
+            unsafe { arn_format(&arn, buf.as_mut_ptr(), buf.len(), &mut written) },
+            ARN_OK
+        );
+        assert_eq!(written, input.as_bytes().len());
+        assert_eq!(unsafe { CStr::from_ptr(buf.as_ptr()) }, input.as_c_str());
+
+        assert_eq!(
+            unsafe { arn_format(&arn, buf.as_mut_ptr(), written, ptr::null_mut()) },
+            ARN_ERR_BUFFER_TOO_SMALL
+        );
+        assert_eq!(
+            unsafe { arn_format(&arn, buf.as_mut_ptr(), written + 1, ptr::null_mut()) },
+            ARN_OK
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let input = CString::new("arn:aws:sqs:US-EAST-1:123456789012:orders").unwrap();
+        assert_eq!(parts(&input).unwrap_err(), (7, 12));
+
+        assert_eq!(
+            unsafe { arn_parse(ptr::null(), ptr::null_mut(), ptr::null_mut()) },
+            ARN_ERR_NULL
+        );
+    }
+
+    #[test]
+    fn validate() {
+        let input = CString::new("arn:aws:sqs:us-east:123456789012:orders").unwrap();
+        let validate = |level| unsafe { arn_validate(input.as_ptr(), level, ptr::null_mut()) };
+
+        assert_eq!(validate(ARN_LEVEL_SYNTAX), ARN_OK);
+        assert_eq!(validate(ARN_LEVEL_STANDARD), 202);
+        assert_eq!(validate(7), ARN_ERR_INVALID_LEVEL);
+    }
+}
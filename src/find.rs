@@ -0,0 +1,188 @@
+//! Finding ARNs embedded in free text, such as logs and error messages
+
+use core::ops::Range;
+
+use crate::naive::{NaiveArn, ParseNaiveArnError};
+
+/// Which characters end an ARN found in text.
+///
+/// A candidate starts at every `arn:` that isn't part of a longer word (so `warn:` is skipped), and runs until the
+/// first enabled boundary character or the end of the text. Trailing `.`, `;`, `!` and `?` are dropped, as they
+/// usually end the surrounding sentence rather than the ARN.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::find::ArnFinder;
+///
+/// let text = "AccessDenied: arn:aws:sts::123456789012:assumed-role/Admin/alice is not authorized to perform: \
+///             s3:GetObject on resource: \"arn:aws:s3:::secrets/key.txt\".";
+/// let arns: Vec<_> = ArnFinder::default()
+///     .find_iter(text)
+///     .map(|(range, arn)| (&text[range], arn.is_ok()))
+///     .collect();
+///
+/// assert_eq!(arns, [
+///     ("arn:aws:sts::123456789012:assumed-role/Admin/alice", true),
+///     ("arn:aws:s3:::secrets/key.txt", true),
+/// ]);
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArnFinder {
+    /// End ARNs at whitespace.
+    pub whitespace: bool,
+
+    /// End ARNs at `"`, `'` and `` ` ``.
+    pub quotes: bool,
+
+    /// End ARNs at `,`.
+    pub commas: bool,
+
+    /// End ARNs at `(`, `)`, `[`, `]`, `{`, `}`, `<` and `>`.
+    pub brackets: bool,
+}
+
+impl Default for ArnFinder {
+    /// Every boundary enabled.
+    fn default() -> Self {
+        ArnFinder {
+            whitespace: true,
+            quotes: true,
+            commas: true,
+            brackets: true,
+        }
+    }
+}
+
+impl ArnFinder {
+    /// Iterate over the byte range of every ARN candidate in `text`, with the result of parsing it.
+    pub fn find_iter<'t>(&self, text: &'t str) -> FindArns<'t> {
+        FindArns {
+            finder: *self,
+            text,
+            pos: 0,
+        }
+    }
+
+    fn is_boundary(&self, c: char) -> bool {
+        (self.whitespace && c.is_whitespace())
+            || (self.quotes && matches!(c, '"' | '\'' | '`'))
+            || (self.commas && c == ',')
+            || (self.brackets && matches!(c, '(' | ')' | '[' | ']' | '{' | '}' | '<' | '>'))
+    }
+}
+
+/// Iterator over the ARN candidates in a string, created by [`ArnFinder::find_iter`].
+#[derive(Debug, Clone)]
+pub struct FindArns<'t> {
+    finder: ArnFinder,
+    text: &'t str,
+    pos: usize,
+}
+
+impl<'t> Iterator for FindArns<'t> {
+    type Item = (Range<usize>, Result<NaiveArn<'t>, ParseNaiveArnError>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self.pos + self.text[self.pos..].find("arn:")?;
+            self.pos = start + "arn:".len();
+
+            let preceded_by_word = self.text[..start]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '-');
+            if preceded_by_word {
+                continue;
+            }
+
+            let candidate = &self.text[start..];
+            let len = candidate
+                .find(|c| self.finder.is_boundary(c))
+                .unwrap_or(candidate.len());
+            let candidate = candidate[..len].trim_end_matches(['.', ';', '!', '?']);
+
+            let end = start + candidate.len();
+            self.pos = end.max(self.pos);
+
+            return Some((start..end, NaiveArn::parse(candidate)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArnFinder;
+    use crate::naive::{Component, ParseNaiveArnError};
+
+    fn find(finder: ArnFinder, text: &str) -> Vec<&str> {
+        finder
+            .find_iter(text)
+            .map(|(range, _)| &text[range])
+            .collect()
+    }
+
+    #[test]
+    fn boundaries() {
+        let text = r#"{"Resource": ["arn:aws:s3:::a", "arn:aws:s3:::b"]} (arn:aws:s3:::c) arn:aws:s3:::d,arn:aws:s3:::e."#;
+
+        assert_eq!(
+            find(ArnFinder::default(), text),
+            [
+                "arn:aws:s3:::a",
+                "arn:aws:s3:::b",
+                "arn:aws:s3:::c",
+                "arn:aws:s3:::d",
+                "arn:aws:s3:::e",
+            ]
+        );
+    }
+
+    #[test]
+    fn configurable() {
+        let text = "Resources: arn:aws:s3:::my bucket,arn:aws:s3:::other";
+
+        let finder = ArnFinder {
+            whitespace: false,
+            ..ArnFinder::default()
+        };
+        assert_eq!(
+            find(finder, text),
+            ["arn:aws:s3:::my bucket", "arn:aws:s3:::other"]
+        );
+
+        let finder = ArnFinder {
+            commas: false,
+            ..ArnFinder::default()
+        };
+        assert_eq!(
+            find(finder, text),
+            ["arn:aws:s3:::my", "arn:aws:s3:::other"]
+        );
+    }
+
+    #[test]
+    fn words_and_errors() {
+        let text = "warn: arn:aws:s3 failed, _arn:aws:s3:::x";
+        let found: Vec<_> = ArnFinder::default().find_iter(text).collect();
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(&text[found[0].0.clone()], "arn:aws:s3");
+        assert_eq!(
+            found[0].1,
+            Err(ParseNaiveArnError::NotEnoughElements {
+                component: Component::Region,
+                position: 10
+            })
+        );
+    }
+
+    #[test]
+    fn byte_ranges() {
+        let text = "héllo arn:aws:s3:::bucket";
+        let (range, arn) = ArnFinder::default().find_iter(text).next().unwrap();
+
+        assert_eq!(range, 7..text.len());
+        assert_eq!(arn.unwrap().resource, "bucket");
+    }
+}
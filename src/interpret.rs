@@ -0,0 +1,313 @@
+//! Dispatching an ARN to the typed ARN of its service
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::acm::CertificateArn;
+use crate::service::apigateway::{ApiGatewayArn, ExecuteApiArn};
+use crate::service::athena::AthenaArn;
+use crate::service::backup::BackupArn;
+use crate::service::cloudformation::CloudFormationArn;
+use crate::service::cloudwatch::CloudWatchArn;
+use crate::service::cognito::{IdentityPoolArn, UserPoolArn};
+use crate::service::devtools::{CodeBuildArn, CodeCommitArn, CodePipelineArn};
+use crate::service::dynamodb::DynamoDbArn;
+use crate::service::ec2::Ec2Arn;
+use crate::service::ecr::EcrArn;
+use crate::service::ecs::EcsArn;
+use crate::service::eks::EksArn;
+use crate::service::elasticache::ElastiCacheArn;
+use crate::service::elbv2::ElbV2Arn;
+use crate::service::events::EventBridgeArn;
+use crate::service::glue::GlueArn;
+use crate::service::iam::IamArn;
+use crate::service::kinesis::KinesisArn;
+use crate::service::kms::KmsArn;
+use crate::service::lambda::LambdaArn;
+use crate::service::logs::LogsArn;
+use crate::service::organizations::OrganizationsArn;
+use crate::service::rds::RdsArn;
+use crate::service::redshift::RedshiftArn;
+use crate::service::route53::Route53Arn;
+use crate::service::s3::{
+    S3AccessPointArn, S3Arn, S3MultiRegionAccessPointArn, S3ObjectLambdaAccessPointArn,
+};
+use crate::service::s3outposts::S3OutpostsArn;
+use crate::service::sagemaker::SageMakerArn;
+use crate::service::secretsmanager::SecretArn;
+use crate::service::sns::SnsArn;
+use crate::service::sqs::SqsArn;
+use crate::service::ssm::SsmParameterArn;
+use crate::service::states::StatesArn;
+use crate::service::sts::StsArn;
+use crate::service::ParseServiceArnError;
+
+/// An ARN, interpreted as the typed ARN of its service where this crate understands it
+///
+/// Each variant holds the type from the corresponding [`service`](crate::service) module. More variants will be added
+/// as more services are supported, so matches need a wildcard arm; ARNs of services that gain a variant will stop
+/// being [`Unknown`](ResourceKind::Unknown).
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::interpret::ResourceKind;
+/// use arn::naive::NaiveArn;
+///
+/// let arn = NaiveArn::parse("arn:aws:sqs:us-east-1:123456789012:orders").unwrap();
+/// match arn.interpret() {
+///     ResourceKind::Sqs(queue) => assert_eq!(queue.name, "orders"),
+///     other => panic!("{:?}", other),
+/// }
+///
+/// let arn = NaiveArn::parse("arn:aws:gamelift:us-east-1:123456789012:fleet/fleet-1234").unwrap();
+/// assert!(!arn.interpret().is_known());
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceKind<'a> {
+    Acm(CertificateArn<'a>),
+    ApiGateway(ApiGatewayArn<'a>),
+    Athena(AthenaArn<'a>),
+    Backup(BackupArn<'a>),
+    CloudFormation(CloudFormationArn<'a>),
+    CloudWatch(CloudWatchArn<'a>),
+    CodeBuild(CodeBuildArn<'a>),
+    CodeCommit(CodeCommitArn<'a>),
+    CodePipeline(CodePipelineArn<'a>),
+    CognitoIdentityPool(IdentityPoolArn<'a>),
+    CognitoUserPool(UserPoolArn<'a>),
+    DynamoDb(DynamoDbArn<'a>),
+    Ec2(Ec2Arn<'a>),
+    Ecr(EcrArn<'a>),
+    Ecs(EcsArn<'a>),
+    Eks(EksArn<'a>),
+    ElastiCache(ElastiCacheArn<'a>),
+    ElbV2(ElbV2Arn<'a>),
+    EventBridge(EventBridgeArn<'a>),
+    ExecuteApi(ExecuteApiArn<'a>),
+    Glue(GlueArn<'a>),
+    Iam(IamArn<'a>),
+    Kinesis(KinesisArn<'a>),
+    Kms(KmsArn<'a>),
+    Lambda(LambdaArn<'a>),
+    Logs(LogsArn<'a>),
+    Organizations(OrganizationsArn<'a>),
+    Rds(RdsArn<'a>),
+    Redshift(RedshiftArn<'a>),
+    Route53(Route53Arn<'a>),
+    S3(S3Arn<'a>),
+    S3AccessPoint(S3AccessPointArn<'a>),
+    S3MultiRegionAccessPoint(S3MultiRegionAccessPointArn<'a>),
+    S3ObjectLambdaAccessPoint(S3ObjectLambdaAccessPointArn<'a>),
+    S3Outposts(S3OutpostsArn<'a>),
+    SageMaker(SageMakerArn<'a>),
+    SecretsManager(SecretArn<'a>),
+    Sns(SnsArn<'a>),
+    Sqs(SqsArn<'a>),
+    Ssm(SsmParameterArn<'a>),
+    States(StatesArn<'a>),
+    Sts(StsArn<'a>),
+    /// An ARN for a service this crate has no typed ARN for, or that its service's typed ARN rejected.
+    Unknown(NaiveArn<'a>),
+}
+
+impl<'a> ResourceKind<'a> {
+    /// Whether the ARN was interpreted as a typed ARN.
+    pub fn is_known(&self) -> bool {
+        !matches!(self, ResourceKind::Unknown(_))
+    }
+}
+
+impl<'a> NaiveArn<'a> {
+    /// Interpret this ARN as the typed ARN of its service.
+    ///
+    /// ARNs of unsupported services, and ARNs their service's typed ARN fails to parse, are returned as
+    /// [`ResourceKind::Unknown`].
+    pub fn interpret(&self) -> ResourceKind<'a> {
+        match try_interpret(*self) {
+            Some(Ok(kind)) => kind,
+            _ => ResourceKind::Unknown(*self),
+        }
+    }
+}
+
+/// The typed ARN for `arn`, or the error its service's typed ARN failed with. `None` if this crate has no typed ARN for
+/// the service.
+pub(crate) fn try_interpret<'a>(
+    arn: NaiveArn<'a>,
+) -> Option<Result<ResourceKind<'a>, ParseServiceArnError>> {
+    let kind = match arn.service {
+        "acm" => CertificateArn::try_from(arn).map(ResourceKind::Acm),
+        "apigateway" => ApiGatewayArn::try_from(arn).map(ResourceKind::ApiGateway),
+        "athena" => AthenaArn::try_from(arn).map(ResourceKind::Athena),
+        "backup" => BackupArn::try_from(arn).map(ResourceKind::Backup),
+        "cloudformation" => CloudFormationArn::try_from(arn).map(ResourceKind::CloudFormation),
+        "cloudwatch" => CloudWatchArn::try_from(arn).map(ResourceKind::CloudWatch),
+        "codebuild" => CodeBuildArn::try_from(arn).map(ResourceKind::CodeBuild),
+        "codecommit" => CodeCommitArn::try_from(arn).map(ResourceKind::CodeCommit),
+        "codepipeline" => CodePipelineArn::try_from(arn).map(ResourceKind::CodePipeline),
+        "cognito-identity" => IdentityPoolArn::try_from(arn).map(ResourceKind::CognitoIdentityPool),
+        "cognito-idp" => UserPoolArn::try_from(arn).map(ResourceKind::CognitoUserPool),
+        "dynamodb" => DynamoDbArn::try_from(arn).map(ResourceKind::DynamoDb),
+        "ec2" => Ec2Arn::try_from(arn).map(ResourceKind::Ec2),
+        "ecr" => EcrArn::try_from(arn).map(ResourceKind::Ecr),
+        "ecs" => EcsArn::try_from(arn).map(ResourceKind::Ecs),
+        "eks" => EksArn::try_from(arn).map(ResourceKind::Eks),
+        "elasticache" => ElastiCacheArn::try_from(arn).map(ResourceKind::ElastiCache),
+        "elasticloadbalancing" => ElbV2Arn::try_from(arn).map(ResourceKind::ElbV2),
+        "events" => EventBridgeArn::try_from(arn).map(ResourceKind::EventBridge),
+        "execute-api" => ExecuteApiArn::try_from(arn).map(ResourceKind::ExecuteApi),
+        "glue" => GlueArn::try_from(arn).map(ResourceKind::Glue),
+        "iam" => IamArn::try_from(arn).map(ResourceKind::Iam),
+        "kinesis" => KinesisArn::try_from(arn).map(ResourceKind::Kinesis),
+        "kms" => KmsArn::try_from(arn).map(ResourceKind::Kms),
+        "lambda" => LambdaArn::try_from(arn).map(ResourceKind::Lambda),
+        "logs" => LogsArn::try_from(arn).map(ResourceKind::Logs),
+        "organizations" => OrganizationsArn::try_from(arn).map(ResourceKind::Organizations),
+        "rds" => RdsArn::try_from(arn).map(ResourceKind::Rds),
+        "redshift" => RedshiftArn::try_from(arn).map(ResourceKind::Redshift),
+        "route53" => Route53Arn::try_from(arn).map(ResourceKind::Route53),
+        "s3" => S3Arn::try_from(arn)
+            .map(ResourceKind::S3)
+            .or_else(|_| S3AccessPointArn::try_from(arn).map(ResourceKind::S3AccessPoint))
+            .or_else(|_| {
+                S3MultiRegionAccessPointArn::try_from(arn)
+                    .map(ResourceKind::S3MultiRegionAccessPoint)
+            }),
+        "s3-object-lambda" => {
+            S3ObjectLambdaAccessPointArn::try_from(arn).map(ResourceKind::S3ObjectLambdaAccessPoint)
+        }
+        "s3-outposts" => S3OutpostsArn::try_from(arn).map(ResourceKind::S3Outposts),
+        "sagemaker" => SageMakerArn::try_from(arn).map(ResourceKind::SageMaker),
+        "secretsmanager" => SecretArn::try_from(arn).map(ResourceKind::SecretsManager),
+        "sns" => SnsArn::try_from(arn).map(ResourceKind::Sns),
+        "sqs" => SqsArn::try_from(arn).map(ResourceKind::Sqs),
+        "ssm" => SsmParameterArn::try_from(arn).map(ResourceKind::Ssm),
+        "states" => StatesArn::try_from(arn).map(ResourceKind::States),
+        "sts" => StsArn::try_from(arn).map(ResourceKind::Sts),
+        _ => return None,
+    };
+
+    Some(kind)
+}
+
+impl<'a> fmt::Display for ResourceKind<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ResourceKind::Acm(arn) => arn.fmt(f),
+            ResourceKind::ApiGateway(arn) => arn.fmt(f),
+            ResourceKind::Athena(arn) => arn.fmt(f),
+            ResourceKind::Backup(arn) => arn.fmt(f),
+            ResourceKind::CloudFormation(arn) => arn.fmt(f),
+            ResourceKind::CloudWatch(arn) => arn.fmt(f),
+            ResourceKind::CodeBuild(arn) => arn.fmt(f),
+            ResourceKind::CodeCommit(arn) => arn.fmt(f),
+            ResourceKind::CodePipeline(arn) => arn.fmt(f),
+            ResourceKind::CognitoIdentityPool(arn) => arn.fmt(f),
+            ResourceKind::CognitoUserPool(arn) => arn.fmt(f),
+            ResourceKind::DynamoDb(arn) => arn.fmt(f),
+            ResourceKind::Ec2(arn) => arn.fmt(f),
+            ResourceKind::Ecr(arn) => arn.fmt(f),
+            ResourceKind::Ecs(arn) => arn.fmt(f),
+            ResourceKind::Eks(arn) => arn.fmt(f),
+            ResourceKind::ElastiCache(arn) => arn.fmt(f),
+            ResourceKind::ElbV2(arn) => arn.fmt(f),
+            ResourceKind::EventBridge(arn) => arn.fmt(f),
+            ResourceKind::ExecuteApi(arn) => arn.fmt(f),
+            ResourceKind::Glue(arn) => arn.fmt(f),
+            ResourceKind::Iam(arn) => arn.fmt(f),
+            ResourceKind::Kinesis(arn) => arn.fmt(f),
+            ResourceKind::Kms(arn) => arn.fmt(f),
+            ResourceKind::Lambda(arn) => arn.fmt(f),
+            ResourceKind::Logs(arn) => arn.fmt(f),
+            ResourceKind::Organizations(arn) => arn.fmt(f),
+            ResourceKind::Rds(arn) => arn.fmt(f),
+            ResourceKind::Redshift(arn) => arn.fmt(f),
+            ResourceKind::Route53(arn) => arn.fmt(f),
+            ResourceKind::S3(arn) => arn.fmt(f),
+            ResourceKind::S3AccessPoint(arn) => arn.fmt(f),
+            ResourceKind::S3MultiRegionAccessPoint(arn) => arn.fmt(f),
+            ResourceKind::S3ObjectLambdaAccessPoint(arn) => arn.fmt(f),
+            ResourceKind::S3Outposts(arn) => arn.fmt(f),
+            ResourceKind::SageMaker(arn) => arn.fmt(f),
+            ResourceKind::SecretsManager(arn) => arn.fmt(f),
+            ResourceKind::Sns(arn) => arn.fmt(f),
+            ResourceKind::Sqs(arn) => arn.fmt(f),
+            ResourceKind::Ssm(arn) => arn.fmt(f),
+            ResourceKind::States(arn) => arn.fmt(f),
+            ResourceKind::Sts(arn) => arn.fmt(f),
+            ResourceKind::Unknown(arn) => arn.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResourceKind;
+    use crate::naive::NaiveArn;
+
+    fn interpret(arn_str: &str) -> ResourceKind<'_> {
+        NaiveArn::parse(arn_str).unwrap().interpret()
+    }
+
+    #[test]
+    fn known() {
+        for arn_str in &[
+            "arn:aws:acm:us-east-1:123456789012:certificate/12345678-1234-1234-1234-123456789012",
+            "arn:aws:apigateway:us-east-1::/restapis/a1b2c3d4e5",
+            "arn:aws:athena:us-east-1:123456789012:workgroup/primary",
+            "arn:aws:backup:us-east-1:123456789012:backup-vault:prod",
+            "arn:aws:cloudwatch:us-east-1:123456789012:alarm:high-cpu",
+            "arn:aws:codebuild:us-east-1:123456789012:project/my-project",
+            "arn:aws:dynamodb:us-east-1:123456789012:table/Books",
+            "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98",
+            "arn:aws:elasticloadbalancing:us-west-2:123456789012:targetgroup/my-targets/73e2d6bc24d8a067",
+            "arn:aws:execute-api:us-east-1:123456789012:a1b2c3d4e5/prod/GET/pets",
+            "arn:aws:iam::123456789012:role/Admin",
+            "arn:aws:lambda:us-east-1:123456789012:function:my-function",
+            "arn:aws:rds:us-east-1:123456789012:db:my-mysql-instance-1",
+            "arn:aws:s3:::my_corporate_bucket",
+            "arn:aws:s3:us-west-2:123456789012:accesspoint/test",
+            "arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap",
+            "arn:aws:sns:us-east-1:123456789012:my_corporate_topic",
+            "arn:aws:sqs:us-east-1:123456789012:orders",
+            "arn:aws:sts::123456789012:assumed-role/Admin/alice",
+        ] {
+            let kind = interpret(arn_str);
+
+            assert!(kind.is_known(), "{}", arn_str);
+            assert_eq!(kind.to_string(), *arn_str);
+        }
+    }
+
+    #[test]
+    fn s3_variants() {
+        assert!(matches!(
+            interpret("arn:aws:s3:::my_corporate_bucket/exampleobject.png"),
+            ResourceKind::S3(_)
+        ));
+        assert!(matches!(
+            interpret("arn:aws:s3:us-west-2:123456789012:accesspoint/test"),
+            ResourceKind::S3AccessPoint(_)
+        ));
+        assert!(matches!(
+            interpret("arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap"),
+            ResourceKind::S3MultiRegionAccessPoint(_)
+        ));
+    }
+
+    #[test]
+    fn unknown() {
+        for arn_str in &[
+            "arn:aws:gamelift:us-east-1:123456789012:fleet/fleet-1234",
+            "arn:aws:sqs:us-east-1:123456789012:orders/extra",
+            "arn:aws:iam::123456789012:something/else",
+        ] {
+            let arn = NaiveArn::parse(arn_str).unwrap();
+
+            assert_eq!(arn.interpret(), ResourceKind::Unknown(arn), "{}", arn_str);
+        }
+    }
+}
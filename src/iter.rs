@@ -0,0 +1,225 @@
+//! Parsing every string from an iterator as an ARN
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::buf::ArnBuf;
+use crate::naive::ParseNaiveArnError;
+
+/// Adds [`parse_arns`](ParseArnsExt::parse_arns) to iterators of strings.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::iter::ParseArnsExt;
+///
+/// let lines = ["arn:aws:s3:::my_corporate_bucket", "not an arn", "arn:aws:sqs:us-east-1:123456789012:orders"];
+///
+/// assert!(lines.iter().parse_arns().fail_fast().is_err());
+///
+/// let mut arns = lines.iter().parse_arns().skip_invalid();
+/// assert_eq!(arns.by_ref().count(), 2);
+/// assert_eq!(arns.skipped(), 1);
+///
+/// let parsed = lines.iter().parse_arns().collect_errors();
+/// assert_eq!(parsed.arns.len(), 2);
+/// assert_eq!(parsed.errors[0].index, 1);
+/// ~~~~
+pub trait ParseArnsExt: Iterator + Sized
+where
+    Self::Item: AsRef<str>,
+{
+    /// Parse each string, yielding the ARN or the error with the index of the string.
+    fn parse_arns(self) -> ParseArns<Self> {
+        ParseArns {
+            inner: self,
+            index: 0,
+        }
+    }
+}
+
+impl<I> ParseArnsExt for I
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+}
+
+/// Iterator over parsed ARNs, created by [`ParseArnsExt::parse_arns`].
+#[derive(Debug, Clone)]
+pub struct ParseArns<I> {
+    inner: I,
+    index: usize,
+}
+
+impl<I> ParseArns<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    /// Every ARN, or the first error.
+    pub fn fail_fast(self) -> Result<Vec<ArnBuf>, IndexedParseError> {
+        self.collect()
+    }
+
+    /// Only the ARNs, counting the strings that aren't.
+    pub fn skip_invalid(self) -> SkipInvalid<I> {
+        SkipInvalid {
+            inner: self,
+            skipped: 0,
+        }
+    }
+
+    /// Every ARN and every error.
+    pub fn collect_errors(self) -> ParsedArns {
+        let mut parsed = ParsedArns::default();
+        for result in self {
+            match result {
+                Ok(arn) => parsed.arns.push(arn),
+                Err(err) => parsed.errors.push(err),
+            }
+        }
+        parsed
+    }
+}
+
+impl<I> Iterator for ParseArns<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Result<ArnBuf, IndexedParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = self.inner.next()?;
+        let index = self.index;
+        self.index += 1;
+
+        Some(ArnBuf::parse(s.as_ref()).map_err(|error| IndexedParseError { index, error }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Iterator over the valid ARNs, created by [`ParseArns::skip_invalid`].
+#[derive(Debug, Clone)]
+pub struct SkipInvalid<I> {
+    inner: ParseArns<I>,
+    skipped: usize,
+}
+
+impl<I> SkipInvalid<I> {
+    /// The number of strings skipped so far.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+}
+
+impl<I> Iterator for SkipInvalid<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = ArnBuf;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for result in &mut self.inner {
+            match result {
+                Ok(arn) => return Some(arn),
+                Err(_) => self.skipped += 1,
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+/// The result of [`ParseArns::collect_errors`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedArns {
+    pub arns: Vec<ArnBuf>,
+    /// The errors, in the order of their strings.
+    pub errors: Vec<IndexedParseError>,
+}
+
+/// A string that isn't an ARN, with its zero-based index in the iterator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedParseError {
+    pub index: usize,
+    pub error: ParseNaiveArnError,
+}
+
+impl fmt::Display for IndexedParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "item {}: {}", self.index, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndexedParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IndexedParseError, ParseArnsExt};
+    use crate::naive::ParseNaiveArnError;
+
+    const LINES: &[&str] = &[
+        "arn:aws:s3:::my_corporate_bucket",
+        "aws:s3:::my_corporate_bucket",
+        "arn:aws:iam::123456789012:role/Admin",
+        "",
+    ];
+
+    #[test]
+    fn fail_fast() {
+        let err = LINES.iter().parse_arns().fail_fast().unwrap_err();
+
+        assert_eq!(err.index, 1);
+        assert_eq!(err.to_string(), "item 1: ARN002: Missing 'arn:' prefix");
+        assert_eq!(LINES[..1].iter().parse_arns().fail_fast().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn skip_invalid() {
+        let lines: Vec<String> = LINES.iter().map(|line| line.to_string()).collect();
+        let mut arns = lines.into_iter().parse_arns().skip_invalid();
+
+        assert_eq!(arns.next().unwrap().service, "s3");
+        assert_eq!(arns.skipped(), 0);
+        assert_eq!(arns.next().unwrap().service, "iam");
+        assert_eq!(arns.skipped(), 1);
+        assert_eq!(arns.next(), None);
+        assert_eq!(arns.skipped(), 2);
+    }
+
+    #[test]
+    fn collect_errors() {
+        let parsed = LINES.iter().parse_arns().collect_errors();
+
+        assert_eq!(parsed.arns.len(), 2);
+        assert_eq!(
+            parsed
+                .errors
+                .iter()
+                .map(|err| err.index)
+                .collect::<Vec<_>>(),
+            [1, 3]
+        );
+        assert!(matches!(
+            parsed.errors[1],
+            IndexedParseError {
+                error: ParseNaiveArnError::MissingPrefix { .. },
+                ..
+            }
+        ));
+    }
+}
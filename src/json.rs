@@ -0,0 +1,133 @@
+//! Finding ARNs in JSON documents
+
+use serde_json::Value;
+
+use crate::naive::NaiveArn;
+
+/// Iterate over every string in `value` that parses as an ARN, with its [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901)
+/// location, in document order.
+///
+/// Only whole string values are considered: object keys, and ARNs embedded in longer strings, are not. Use
+/// [`ArnFinder`](crate::find::ArnFinder) on the strings for the latter.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::json::find_arns;
+/// use serde_json::json;
+///
+/// let policy = json!({
+///     "Statement": [{
+///         "Effect": "Allow",
+///         "Principal": { "AWS": "arn:aws:iam::123456789012:root" },
+///         "Resource": ["arn:aws:s3:::my_corporate_bucket", "arn:aws:s3:::my_corporate_bucket/*"],
+///     }]
+/// });
+///
+/// let found: Vec<_> = find_arns(&policy)
+///     .map(|(pointer, arn)| format!("{} {}", pointer, arn.resource))
+///     .collect();
+/// assert_eq!(found, [
+///     "/Statement/0/Principal/AWS root",
+///     "/Statement/0/Resource/0 my_corporate_bucket",
+///     "/Statement/0/Resource/1 my_corporate_bucket/*",
+/// ]);
+/// ~~~~
+pub fn find_arns(value: &Value) -> JsonArns<'_> {
+    JsonArns {
+        stack: vec![(String::new(), value)],
+    }
+}
+
+/// Iterator over the ARNs in a JSON document, created by [`find_arns`].
+#[derive(Debug, Clone)]
+pub struct JsonArns<'a> {
+    /// Values still to visit, with their pointers, in reverse document order.
+    stack: Vec<(String, &'a Value)>,
+}
+
+impl<'a> Iterator for JsonArns<'a> {
+    type Item = (String, NaiveArn<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((pointer, value)) = self.stack.pop() {
+            match value {
+                Value::String(s) => {
+                    if let Ok(arn) = NaiveArn::parse(s) {
+                        return Some((pointer, arn));
+                    }
+                }
+                Value::Array(values) => {
+                    for (idx, value) in values.iter().enumerate().rev() {
+                        self.stack.push((format!("{}/{}", pointer, idx), value));
+                    }
+                }
+                Value::Object(map) => {
+                    for (key, value) in map.iter().rev() {
+                        self.stack
+                            .push((format!("{}/{}", pointer, escape(key)), value));
+                    }
+                }
+                Value::Null | Value::Bool(_) | Value::Number(_) => {}
+            }
+        }
+
+        None
+    }
+}
+
+/// Escape `~` and `/` in a JSON Pointer reference token.
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_arns;
+    use serde_json::json;
+
+    #[test]
+    fn pointers_resolve() {
+        let event = json!({
+            "detail": {
+                "resources": [
+                    { "ARN": "arn:aws:ec2:us-east-1:123456789012:instance/i-0123" },
+                    { "ARN": "not-an-arn" },
+                ],
+                "a/b~c": "arn:aws:sqs:us-east-1:123456789012:orders",
+                "count": 2,
+            },
+            "source": "aws.ec2",
+        });
+
+        let found: Vec<_> = find_arns(&event).collect();
+        assert_eq!(found.len(), 2);
+
+        for (pointer, arn) in found {
+            assert_eq!(
+                event.pointer(&pointer).and_then(|value| value.as_str()),
+                Some(arn.to_string().as_str()),
+                "{}",
+                pointer
+            );
+        }
+    }
+
+    #[test]
+    fn escaping() {
+        let value = json!({ "a/b~c": "arn:aws:s3:::bucket" });
+        let (pointer, _) = find_arns(&value).next().unwrap();
+
+        assert_eq!(pointer, "/a~1b~0c");
+    }
+
+    #[test]
+    fn root_string() {
+        let value = json!("arn:aws:s3:::bucket");
+        let (pointer, arn) = find_arns(&value).next().unwrap();
+
+        assert_eq!(pointer, "");
+        assert_eq!(arn.resource, "bucket");
+        assert_eq!(find_arns(&json!(["arn:", 1, null])).count(), 0);
+    }
+}
@@ -0,0 +1,169 @@
+//! Lenient parsing, for ARNs from messy, human-edited sources such as spreadsheets and tickets
+
+use core::iter;
+
+use crate::naive::{parse_elements, NaiveArn, ParseNaiveArnError, MAX_LEN};
+
+/// What [`NaiveArn::parse_with`] tolerates beyond what [`NaiveArn::parse`] accepts.
+///
+/// The default tolerates nothing, parsing exactly like [`NaiveArn::parse`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    /// Accept the `arn:` prefix in any case, such as `ARN:`.
+    pub case_insensitive_prefix: bool,
+    /// Ignore whitespace before and after the ARN.
+    pub trim: bool,
+    /// Accept ARNs without the `arn:` prefix, like `aws:s3:::my_corporate_bucket`.
+    pub allow_missing_prefix: bool,
+}
+
+impl ParseOptions {
+    /// Every option enabled.
+    pub fn lenient() -> Self {
+        ParseOptions {
+            case_insensitive_prefix: true,
+            trim: true,
+            allow_missing_prefix: true,
+        }
+    }
+}
+
+impl<'a> NaiveArn<'a> {
+    /// Parse `s`, tolerating what `options` allows.
+    ///
+    /// Error positions are byte offsets into `s`, before any trimming.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::lenient::ParseOptions;
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let expected = NaiveArn::parse("arn:aws:s3:::my_corporate_bucket").unwrap();
+    ///
+    /// for arn_str in ["  ARN:aws:s3:::my_corporate_bucket\n", "aws:s3:::my_corporate_bucket"] {
+    ///     assert_eq!(NaiveArn::parse_with(arn_str, ParseOptions::lenient()), Ok(expected));
+    ///     assert!(NaiveArn::parse_with(arn_str, ParseOptions::default()).is_err());
+    /// }
+    /// ~~~~
+    pub fn parse_with(s: &'a str, options: ParseOptions) -> Result<Self, ParseNaiveArnError> {
+        let (input, leading) = if options.trim {
+            let trimmed = s.trim_start();
+            (trimmed.trim_end(), s.len() - trimmed.len())
+        } else {
+            (s, 0)
+        };
+
+        if input.len() > MAX_LEN {
+            return Err(ParseNaiveArnError::InputTooLong {
+                length: input.len(),
+            });
+        }
+
+        let prefix = input.get(.."arn:".len());
+        let has_prefix = prefix == Some("arn:")
+            || (options.case_insensitive_prefix
+                && prefix.is_some_and(|prefix| prefix.eq_ignore_ascii_case("arn:")));
+
+        if has_prefix {
+            let elements = iter::once("arn").chain(input["arn:".len()..].splitn(5, ':'));
+            parse_elements(input, elements).map_err(|err| err.map_position(|p| p + leading))
+        } else if options.allow_missing_prefix {
+            // Positions are counted from the `arn:` this pretends the input started with.
+            let elements = iter::once("arn").chain(input.splitn(5, ':'));
+            parse_elements(input, elements)
+                .map_err(|err| err.map_position(|p| p - "arn:".len() + leading))
+        } else {
+            NaiveArn::parse(input).map_err(|err| err.map_position(|p| p + leading))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseOptions;
+    use crate::naive::{Component, NaiveArn, ParseNaiveArnError};
+
+    const ARN: &str = "arn:aws:sqs:us-east-1:123456789012:orders";
+
+    fn parse(s: &str, options: ParseOptions) -> Result<NaiveArn<'_>, ParseNaiveArnError> {
+        NaiveArn::parse_with(s, options)
+    }
+
+    #[test]
+    fn default_is_strict() {
+        for arn_str in [
+            ARN,
+            "arn:aws:s3:::",
+            "ARN:aws:s3:::bucket",
+            " arn:aws:s3:::bucket",
+        ] {
+            assert_eq!(
+                parse(arn_str, ParseOptions::default()),
+                NaiveArn::parse(arn_str)
+            );
+        }
+    }
+
+    #[test]
+    fn each_option() {
+        let expected = NaiveArn::parse(ARN);
+
+        let options = ParseOptions {
+            case_insensitive_prefix: true,
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            parse("Arn:aws:sqs:us-east-1:123456789012:orders", options),
+            expected
+        );
+        assert!(parse(" arn:aws:sqs:us-east-1:123456789012:orders", options).is_err());
+
+        let options = ParseOptions {
+            trim: true,
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            parse("\t arn:aws:sqs:us-east-1:123456789012:orders \r\n", options),
+            expected
+        );
+        assert!(parse("aws:sqs:us-east-1:123456789012:orders", options).is_err());
+
+        let options = ParseOptions {
+            allow_missing_prefix: true,
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            parse("aws:sqs:us-east-1:123456789012:orders", options),
+            expected
+        );
+        assert_eq!(parse(ARN, options), expected);
+    }
+
+    #[test]
+    fn error_positions() {
+        assert_eq!(
+            parse(
+                "  aws::us-east-1:123456789012:orders",
+                ParseOptions::lenient()
+            ),
+            Err(ParseNaiveArnError::MissingService { position: 6 })
+        );
+        assert_eq!(
+            parse("  aws:sqs", ParseOptions::lenient()),
+            Err(ParseNaiveArnError::NotEnoughElements {
+                component: Component::Region,
+                position: 9
+            })
+        );
+        assert_eq!(
+            parse(
+                " ARN:aws:sqs:us-east-1:12_4:orders",
+                ParseOptions::lenient()
+            )
+            .unwrap_err()
+            .position(),
+            25
+        );
+    }
+}
@@ -1 +1,112 @@
+//! Parsing, validating and building AWS ARNs
+//!
+//! The crate is `no_std` without its default `std` feature, which adds `std::error::Error` implementations and the
+//! `io`-based [`bulk`] validation. The `alloc` feature (implied by `std`) adds owned ARNs, typed service ARNs and
+//! everything else that allocates; without it, parsing into a [`NaiveArn`](naive::NaiveArn), validation up to
+//! [`ValidationLevel::Standard`](validate::ValidationLevel::Standard), finding, spans, explanations and formatting into
+//! a fixed buffer still work, without a heap.
+
+// Tests always link `std`, for the test harness and so that they can use its prelude
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod account;
+pub mod alias;
+#[cfg(feature = "quickcheck")]
+mod arbitrary;
+#[cfg(feature = "alloc")]
+pub mod buf;
+#[cfg(feature = "std")]
+pub mod bulk;
+#[cfg(feature = "cache")]
+pub mod cache;
+#[cfg(feature = "alloc")]
+pub mod cloudcontrol;
+#[cfg(feature = "alloc")]
+mod cloudformation;
+#[cfg(feature = "color")]
+pub mod color;
+#[cfg(feature = "alloc")]
+mod console;
+pub mod context;
+#[cfg(feature = "test-fixtures")]
+pub mod corpus;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "miette")]
+mod diagnostic;
+#[cfg(feature = "alloc")]
+pub mod endpoint;
+#[cfg(feature = "alloc")]
+mod equivalent;
+pub mod explain;
+#[cfg(feature = "alloc")]
+pub mod exposure;
+#[cfg(feature = "fake")]
+pub mod fake;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod find;
+#[cfg(feature = "alloc")]
+pub mod interpret;
+#[cfg(feature = "alloc")]
+pub mod iter;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod lenient;
+pub mod lossless;
+#[cfg(feature = "uniffi")]
+mod mobile;
 pub mod naive;
+#[cfg(feature = "alloc")]
+pub mod normalize;
+#[cfg(feature = "alloc")]
+pub mod partial;
+pub mod partition;
+#[cfg(feature = "alloc")]
+pub mod pattern;
+#[cfg(feature = "alloc")]
+mod percent;
+#[cfg(feature = "alloc")]
+pub mod policy;
+pub mod principal;
+#[cfg(feature = "python")]
+mod python;
+#[cfg(feature = "alloc")]
+pub mod redact;
+#[cfg(feature = "alloc")]
+pub mod region;
+#[cfg(feature = "alloc")]
+pub mod resolve;
+#[cfg(feature = "rewrite")]
+pub mod rewrite;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "alloc")]
+pub mod service;
+pub mod span;
+#[cfg(feature = "alloc")]
+pub mod stats;
+#[cfg(feature = "alloc")]
+pub mod template;
+#[cfg(feature = "terraform")]
+pub mod terraform;
+#[cfg(feature = "tower")]
+pub mod tower;
+#[cfg(feature = "alloc")]
+pub mod translate;
+pub mod validate;
+#[cfg(feature = "serde")]
+pub mod validated;
+#[cfg(feature = "alloc")]
+pub mod validator;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "test-fixtures")]
+pub use corpus::corpus;
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
@@ -0,0 +1,127 @@
+//! ARNs that reproduce their input exactly
+
+use core::fmt;
+use core::ops::Range;
+
+use crate::naive::{Component, NaiveArn, ParseNaiveArnError};
+use crate::span::ArnSpans;
+
+/// An ARN that keeps the exact text it was parsed from, for when an ARN is signed or hashed and must be reproduced
+/// byte for byte.
+///
+/// [`Display`](fmt::Display) writes the original text, and equality and hashing compare it. Components are the exact
+/// substrings of the input, so an empty region or account ID is an empty string rather than `None`, and stays
+/// distinguishable from a component that an owned ARN leaves out.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::lossless::LosslessArn;
+/// use arn::naive::Component;
+///
+/// let arn_str = "arn:aws:s3:::my_corporate_bucket/a:b";
+/// let arn = LosslessArn::parse(arn_str).unwrap();
+///
+/// assert_eq!(arn.to_string(), arn_str);
+/// assert_eq!(arn.component(Component::Region), "");
+/// assert_eq!(arn.component(Component::Resource), "my_corporate_bucket/a:b");
+/// assert_eq!(arn.to_naive().region, None);
+/// ~~~~
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LosslessArn<'a> {
+    source: &'a str,
+    spans: ArnSpans,
+}
+
+impl<'a> LosslessArn<'a> {
+    /// Parse `s`, accepting exactly what [`NaiveArn::parse`] accepts.
+    pub fn parse(s: &'a str) -> Result<Self, ParseNaiveArnError> {
+        let spans = NaiveArn::parse(s)?.spans();
+        Ok(LosslessArn { source: s, spans })
+    }
+
+    /// The text this ARN was parsed from.
+    pub fn as_str(&self) -> &'a str {
+        self.source
+    }
+
+    /// `component` exactly as it appeared in the input.
+    pub fn component(&self, component: Component) -> &'a str {
+        &self.source[self.span(component)]
+    }
+
+    /// The byte range of `component` in the input.
+    pub fn span(&self, component: Component) -> Range<usize> {
+        match component {
+            Component::Prefix => 0.."arn".len(),
+            Component::Partition => self.spans.partition.clone(),
+            Component::Service => self.spans.service.clone(),
+            Component::Region => self.spans.region.clone(),
+            Component::AccountId => self.spans.account_id.clone(),
+            Component::Resource => self.spans.resource.clone(),
+        }
+    }
+
+    /// This ARN as a [`NaiveArn`], with empty components as `None`.
+    pub fn to_naive(&self) -> NaiveArn<'a> {
+        let optional = |value: &'a str| Some(value).filter(|value| !value.is_empty());
+
+        NaiveArn {
+            partition: self.component(Component::Partition),
+            service: self.component(Component::Service),
+            region: optional(self.component(Component::Region)),
+            account_id: optional(self.component(Component::AccountId)),
+            resource: self.component(Component::Resource),
+        }
+    }
+}
+
+impl<'a> From<LosslessArn<'a>> for NaiveArn<'a> {
+    fn from(arn: LosslessArn<'a>) -> Self {
+        arn.to_naive()
+    }
+}
+
+impl<'a> fmt::Display for LosslessArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LosslessArn;
+    use crate::naive::{Component, NaiveArn};
+
+    #[test]
+    fn round_trip() {
+        for arn_str in [
+            "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98",
+            "arn:aws:s3:::my_corporate_bucket",
+            "arn:aws:s3:::my corporate bucket/ä/",
+            "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/f:*",
+            "arn:aws:sns:us-east-1:123456789012:topic:",
+        ] {
+            let arn = LosslessArn::parse(arn_str).unwrap();
+
+            assert_eq!(arn.to_string(), arn_str);
+            assert_eq!(arn.as_str(), arn_str);
+            assert_eq!(arn.to_naive(), NaiveArn::parse(arn_str).unwrap());
+        }
+    }
+
+    #[test]
+    fn components() {
+        let arn = LosslessArn::parse("arn:aws:iam::123456789012:role/Admin").unwrap();
+
+        assert_eq!(arn.component(Component::Prefix), "arn");
+        assert_eq!(arn.component(Component::Region), "");
+        assert_eq!(arn.span(Component::Region), 12..12);
+        assert_eq!(arn.component(Component::AccountId), "123456789012");
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(LosslessArn::parse("arn:aws:s3:::").is_err());
+    }
+}
@@ -0,0 +1,182 @@
+//! Kotlin and Swift bindings, generated with UniFFI
+//!
+//! Build the library as a `cdylib` with `cargo rustc --lib --release --features uniffi --crate-type cdylib`, then
+//! generate the bindings from it with `uniffi-bindgen generate --library target/release/libarn.so --language kotlin`
+//! (or `swift`).
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::buf::ArnBuf;
+use crate::naive::{NaiveArn, ParseNaiveArnError};
+use crate::pattern::ArnPattern as Pattern;
+use crate::validate::ValidationLevel;
+
+/// The components of an ARN.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct Arn {
+    pub partition: String,
+    pub service: String,
+    pub region: Option<String>,
+    pub account_id: Option<String>,
+    pub resource: String,
+}
+
+impl From<ArnBuf> for Arn {
+    fn from(arn: ArnBuf) -> Self {
+        Arn {
+            partition: arn.partition,
+            service: arn.service,
+            region: arn.region,
+            account_id: arn.account_id,
+            resource: arn.resource,
+        }
+    }
+}
+
+/// A string that isn't an ARN or ARN pattern.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Error)]
+pub enum ArnError {
+    /// `code` is the stable code of the error, such as `ARN007`, and `position` the byte offset of the problem.
+    Parse {
+        code: String,
+        message: String,
+        position: u64,
+    },
+}
+
+impl From<ParseNaiveArnError> for ArnError {
+    fn from(err: ParseNaiveArnError) -> Self {
+        ArnError::Parse {
+            code: err.code().to_owned(),
+            message: err.to_string(),
+            position: err.position() as u64,
+        }
+    }
+}
+
+impl fmt::Display for ArnError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArnError::Parse { message, .. } => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for ArnError {}
+
+/// How thoroughly to validate; see [`ValidationLevel`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum Level {
+    Syntax,
+    Standard,
+    Strict,
+}
+
+impl From<Level> for ValidationLevel {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::Syntax => ValidationLevel::Syntax,
+            Level::Standard => ValidationLevel::Standard,
+            Level::Strict => ValidationLevel::Strict,
+        }
+    }
+}
+
+/// A problem found by [`validate`], with its stable code, such as `ARN202`.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct Issue {
+    pub code: String,
+    pub message: String,
+}
+
+/// Parse `arn`.
+#[uniffi::export]
+pub fn parse(arn: String) -> Result<Arn, ArnError> {
+    Ok(ArnBuf::parse(&arn)?.into())
+}
+
+/// Format the components of `arn`, without checking them.
+#[uniffi::export]
+pub fn format(arn: Arn) -> String {
+    NaiveArn {
+        partition: &arn.partition,
+        service: &arn.service,
+        region: arn.region.as_deref(),
+        account_id: arn.account_id.as_deref(),
+        resource: &arn.resource,
+    }
+    .to_string()
+}
+
+/// Every issue with `arn` at `level`, which is empty if it is valid.
+#[uniffi::export]
+pub fn validate(arn: String, level: Level) -> Result<Vec<Issue>, ArnError> {
+    let issues = NaiveArn::parse(&arn)?
+        .validate(level.into())
+        .err()
+        .unwrap_or_default();
+
+    Ok(issues
+        .into_iter()
+        .map(|issue| Issue {
+            code: issue.code().to_owned(),
+            message: issue.to_string(),
+        })
+        .collect())
+}
+
+/// An IAM-style ARN pattern, such as `arn:aws:s3:::prod-*`.
+#[derive(Debug, uniffi::Object)]
+pub struct ArnPattern(Pattern);
+
+#[uniffi::export]
+impl ArnPattern {
+    #[uniffi::constructor]
+    pub fn new(pattern: String) -> Result<Arc<Self>, ArnError> {
+        Ok(Arc::new(ArnPattern(Pattern::parse(&pattern)?)))
+    }
+
+    /// Whether `arn` is an ARN matching this pattern.
+    pub fn matches(&self, arn: String) -> bool {
+        NaiveArn::parse(&arn).is_ok_and(|arn| self.0.matches(&arn))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format, parse, validate, ArnError, ArnPattern, Level};
+
+    #[test]
+    fn round_trip() {
+        let arn_str = "arn:aws:iam::123456789012:role/Admin";
+        let arn = parse(arn_str.to_owned()).unwrap();
+
+        assert_eq!(arn.region, None);
+        assert_eq!(format(arn), arn_str);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            parse("arn:aws:s3".to_owned()),
+            Err(ArnError::Parse { code, position: 10, .. }) if code == "ARN001"
+        ));
+
+        let issues = validate(
+            "arn:aws:sqs:us-east:123456789012:orders".to_owned(),
+            Level::Standard,
+        )
+        .unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code, "ARN202");
+    }
+
+    #[test]
+    fn pattern() {
+        let pattern = ArnPattern::new("arn:aws:s3:::prod-*".to_owned()).unwrap();
+
+        assert!(pattern.matches("arn:aws:s3:::prod-logs".to_owned()));
+        assert!(!pattern.matches("prod-logs".to_owned()));
+    }
+}
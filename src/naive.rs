@@ -1,7 +1,8 @@
 //! `arn:partition:service:region:account-id:resource` formatted ARN
 
-use std::iter::Iterator;
-use std::{error, fmt};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, format, string::String};
+use core::fmt;
 
 /// `arn:partition:service:region:account-id:resource` formatted ARN
 ///
@@ -12,7 +13,7 @@ use std::{error, fmt};
 ///
 /// let arn = NaiveArn::parse("arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98").unwrap();
 /// ~~~~
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct NaiveArn<'a> {
     /// The partition that the resource is in. For standard AWS regions, the partition is "aws". If you have resources in
     /// other partitions, the partition is "aws-partitionname". For example, the partition for resources in the China
@@ -41,49 +42,204 @@ pub struct NaiveArn<'a> {
 
 impl<'a> NaiveArn<'a> {
     pub fn parse(s: &'a str) -> Result<Self, ParseNaiveArnError> {
-        let mut elements = s.splitn(6, ':');
+        if s.len() > MAX_LEN {
+            return Err(ParseNaiveArnError::InputTooLong { length: s.len() });
+        }
+
+        parse_elements(s, s.splitn(6, ':'))
+    }
+
+    /// Reject characters that can't appear in each component.
+    fn check_characters(&self) -> Result<(), ParseNaiveArnError> {
+        let spans = self.spans();
+
+        check_component(Component::Partition, self.partition, spans.partition.start)?;
+        check_component(Component::Service, self.service, spans.service.start)?;
+        check_component(
+            Component::Region,
+            self.region.unwrap_or_default(),
+            spans.region.start,
+        )?;
+        check_component(
+            Component::AccountId,
+            self.account_id.unwrap_or_default(),
+            spans.account_id.start,
+        )?;
+        check_component(Component::Resource, self.resource, spans.resource.start)
+    }
+
+    /// Write this ARN into `buf`, returning the written part of it, or `None` if it doesn't fit.
+    ///
+    /// Unlike `to_string`, this doesn't need an allocator.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let arn = NaiveArn::parse("arn:aws:s3:::my_corporate_bucket").unwrap();
+    /// let mut buf = [0; 64];
+    ///
+    /// assert_eq!(arn.format_into(&mut buf), Some("arn:aws:s3:::my_corporate_bucket"));
+    /// assert_eq!(arn.format_into(&mut [0; 8]), None);
+    /// ~~~~
+    pub fn format_into<'b>(&self, buf: &'b mut [u8]) -> Option<&'b str> {
+        let mut writer = SliceWriter { buf, len: 0 };
+        fmt::Write::write_fmt(&mut writer, format_args!("{}", self)).ok()?;
+
+        let SliceWriter { buf, len } = writer;
+        core::str::from_utf8(&buf[..len]).ok()
+    }
+}
+
+/// A [`fmt::Write`] into a fixed buffer, failing once it is full.
+struct SliceWriter<'b> {
+    buf: &'b mut [u8],
+    len: usize,
+}
+
+impl<'b> fmt::Write for SliceWriter<'b> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let dest = self
+            .buf
+            .get_mut(self.len..self.len + s.len())
+            .ok_or(fmt::Error)?;
+        dest.copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+}
 
-        if elements.next() != Some("arn") {
-            return Err(ParseNaiveArnError::MissingPrefix);
+/// Check the characters of `value`, a `component` starting at byte `start`. Only the resource may contain anything
+/// other than lowercase letters, digits and `-` (and the `*` and `?` wildcards, for patterns), and nothing may contain
+/// control characters.
+pub(crate) fn check_component(
+    component: Component,
+    value: &str,
+    start: usize,
+) -> Result<(), ParseNaiveArnError> {
+    for (idx, c) in value.char_indices() {
+        let position = start + idx;
+
+        if c.is_control() {
+            return Err(ParseNaiveArnError::IllegalCharacter {
+                component,
+                position,
+                found: c,
+            });
+        }
+        if component == Component::Resource
+            || c.is_ascii_lowercase()
+            || c.is_ascii_digit()
+            || matches!(c, '-' | '*' | '?')
+        {
+            continue;
         }
 
-        let partition = match elements.next() {
-            None => return Err(ParseNaiveArnError::NotEnoughElements),
-            Some("") => return Err(ParseNaiveArnError::MissingPartition),
-            Some(partition) => partition,
-        };
-
-        let service = match elements.next() {
-            None => return Err(ParseNaiveArnError::NotEnoughElements),
-            Some("") => return Err(ParseNaiveArnError::MissingService),
-            Some(service) => service,
-        };
-
-        let region = match elements.next() {
-            None => return Err(ParseNaiveArnError::NotEnoughElements),
-            Some("") => None,
-            Some(region) => Some(region),
-        };
-
-        let account_id = match elements.next() {
-            None => return Err(ParseNaiveArnError::NotEnoughElements),
-            Some("") => None,
-            Some(account_id) => Some(account_id),
-        };
-
-        let resource = match elements.next() {
-            None => return Err(ParseNaiveArnError::NotEnoughElements),
-            Some("") => return Err(ParseNaiveArnError::MissingResource),
-            Some(resource) => resource,
-        };
-
-        Ok(NaiveArn {
-            partition,
-            service,
-            region,
-            account_id,
-            resource,
-        })
+        return Err(match component {
+            Component::Region => ParseNaiveArnError::InvalidRegionFormat {
+                position,
+                #[cfg(feature = "alloc")]
+                found: value.to_owned(),
+            },
+            Component::AccountId => ParseNaiveArnError::InvalidAccountId {
+                position,
+                #[cfg(feature = "alloc")]
+                found: value.to_owned(),
+            },
+            _ => ParseNaiveArnError::IllegalCharacter {
+                component,
+                position,
+                found: c,
+            },
+        });
+    }
+
+    Ok(())
+}
+
+/// The longest ARN accepted, in bytes, matching the limit IAM places on ARNs in policies.
+pub const MAX_LEN: usize = 2048;
+
+/// Build an ARN from the `:`-separated `elements` of `s` and check its characters.
+pub(crate) fn parse_elements<'a>(
+    s: &'a str,
+    elements: impl Iterator<Item = &'a str>,
+) -> Result<NaiveArn<'a>, ParseNaiveArnError> {
+    let arn = from_elements(s, elements)?;
+    arn.check_characters()?;
+    Ok(arn)
+}
+
+/// Build an ARN from the `:`-separated `elements` of `s`, tracking the byte offset of each so errors can report it.
+#[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+pub(crate) fn from_elements<'a>(
+    s: &'a str,
+    mut elements: impl Iterator<Item = &'a str>,
+) -> Result<NaiveArn<'a>, ParseNaiveArnError> {
+    let mut position = 0;
+    let mut next = |component| match elements.next() {
+        Some(element) => {
+            let start = position;
+            position += element.len() + 1;
+            Ok((element, start))
+        }
+        // Every element was followed by a `:` except the last, so this is the end of the input.
+        None => Err(ParseNaiveArnError::NotEnoughElements {
+            component,
+            position: position.saturating_sub(1),
+        }),
+    };
+
+    let prefix = next(Component::Prefix)?.0;
+    if prefix != "arn" {
+        return Err(ParseNaiveArnError::MissingPrefix {
+            #[cfg(feature = "alloc")]
+            found: s[..s.len().min(prefix.len() + 1)].to_owned(),
+        });
+    }
+
+    let partition = match next(Component::Partition)? {
+        ("", position) => return Err(ParseNaiveArnError::MissingPartition { position }),
+        (partition, _) => partition,
+    };
+
+    let service = match next(Component::Service)? {
+        ("", position) => return Err(ParseNaiveArnError::MissingService { position }),
+        (service, _) => service,
+    };
+
+    let region = match next(Component::Region)? {
+        ("", _) => None,
+        (region, _) => Some(region),
+    };
+
+    let account_id = match next(Component::AccountId)? {
+        ("", _) => None,
+        (account_id, _) => Some(account_id),
+    };
+
+    let resource = match next(Component::Resource)? {
+        ("", position) => return Err(ParseNaiveArnError::MissingResource { position }),
+        (resource, _) => resource,
+    };
+
+    Ok(NaiveArn {
+        partition,
+        service,
+        region,
+        account_id,
+        resource,
+    })
+}
+
+/// Split a resource into its type and the remainder, on the first `/` or `:`.
+///
+/// Resources without a delimiter (an S3 bucket, an SQS queue) are returned whole, with no remainder.
+pub(crate) fn split_resource(resource: &str) -> (&str, Option<&str>) {
+    match resource.find(['/', ':']) {
+        Some(idx) => (&resource[..idx], Some(&resource[idx + 1..])),
+        None => (resource, None),
     }
 }
 
@@ -101,32 +257,250 @@ impl<'a> fmt::Display for NaiveArn<'a> {
     }
 }
 
-#[derive(Debug, PartialEq)]
+/// A component of an ARN, in the order they appear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Component {
+    /// The leading `arn`.
+    Prefix,
+    Partition,
+    Service,
+    Region,
+    AccountId,
+    Resource,
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Component::Prefix => "prefix",
+            Component::Partition => "partition",
+            Component::Service => "service",
+            Component::Region => "region",
+            Component::AccountId => "account ID",
+            Component::Resource => "resource",
+        })
+    }
+}
+
+/// Why a string isn't an ARN. Positions are byte offsets into the input.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum ParseNaiveArnError {
-    NotEnoughElements,
-    MissingPrefix,
-    MissingPartition,
-    MissingService,
-    MissingResource,
+    /// The input ended, at `position`, before `component`.
+    NotEnoughElements {
+        component: Component,
+        position: usize,
+    },
+    /// The input doesn't start with `arn:`; it starts with `found` (up to and including the first `:`).
+    MissingPrefix {
+        #[cfg(feature = "alloc")]
+        found: String,
+    },
+    /// The partition starting at `position` is empty.
+    MissingPartition { position: usize },
+    /// The service starting at `position` is empty.
+    MissingService { position: usize },
+    /// The resource starting at `position` is empty.
+    MissingResource { position: usize },
+    /// The account ID `found` has a character, at `position`, that no account ID (or wildcard) can contain.
+    InvalidAccountId {
+        position: usize,
+        #[cfg(feature = "alloc")]
+        found: String,
+    },
+    /// The region `found` has a character, at `position`, that no region (or wildcard) can contain.
+    InvalidRegionFormat {
+        position: usize,
+        #[cfg(feature = "alloc")]
+        found: String,
+    },
+    /// The input is `length` bytes, longer than [`MAX_LEN`].
+    InputTooLong { length: usize },
+    /// `component` has the character `found`, at `position`, which it can't contain.
+    IllegalCharacter {
+        component: Component,
+        position: usize,
+        found: char,
+    },
+}
+
+impl ParseNaiveArnError {
+    /// The byte offset in the input where the problem is.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::{Component, NaiveArn};
+    ///
+    /// let err = NaiveArn::parse("arn:aws::us-east-1:123456789012:vpc/vpc-fd580e98").unwrap_err();
+    /// assert_eq!(err.position(), 8);
+    /// assert_eq!(err.component(), Some(Component::Service));
+    /// ~~~~
+    pub fn position(&self) -> usize {
+        match self {
+            ParseNaiveArnError::NotEnoughElements { position, .. }
+            | ParseNaiveArnError::MissingPartition { position }
+            | ParseNaiveArnError::MissingService { position }
+            | ParseNaiveArnError::MissingResource { position }
+            | ParseNaiveArnError::InvalidAccountId { position, .. }
+            | ParseNaiveArnError::InvalidRegionFormat { position, .. }
+            | ParseNaiveArnError::IllegalCharacter { position, .. } => *position,
+            ParseNaiveArnError::MissingPrefix { .. } => 0,
+            ParseNaiveArnError::InputTooLong { .. } => MAX_LEN,
+        }
+    }
+
+    /// The component being parsed when the problem was found, or `None` if the problem is with the input as a whole.
+    pub fn component(&self) -> Option<Component> {
+        match self {
+            ParseNaiveArnError::NotEnoughElements { component, .. }
+            | ParseNaiveArnError::IllegalCharacter { component, .. } => Some(*component),
+            ParseNaiveArnError::MissingPrefix { .. } => Some(Component::Prefix),
+            ParseNaiveArnError::MissingPartition { .. } => Some(Component::Partition),
+            ParseNaiveArnError::MissingService { .. } => Some(Component::Service),
+            ParseNaiveArnError::MissingResource { .. } => Some(Component::Resource),
+            ParseNaiveArnError::InvalidAccountId { .. } => Some(Component::AccountId),
+            ParseNaiveArnError::InvalidRegionFormat { .. } => Some(Component::Region),
+            ParseNaiveArnError::InputTooLong { .. } => None,
+        }
+    }
+
+    /// What the parser expected to find, for messages like "expected `arn:` prefix, found `aws:`".
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let err = NaiveArn::parse("aws:s3:::my_corporate_bucket").unwrap_err();
+    /// assert_eq!(
+    ///     format!("expected {}, found {}", err.expected(), err.found()),
+    ///     "expected `arn:` prefix, found `aws:`"
+    /// );
+    /// # }
+    /// ~~~~
+    pub fn expected(&self) -> &'static str {
+        match self {
+            ParseNaiveArnError::NotEnoughElements { .. } => "six `:`-separated elements",
+            ParseNaiveArnError::MissingPrefix { .. } => "`arn:` prefix",
+            ParseNaiveArnError::MissingPartition { .. } => "a partition, such as `aws`",
+            ParseNaiveArnError::MissingService { .. } => "a service namespace, such as `s3`",
+            ParseNaiveArnError::MissingResource { .. } => "a resource",
+            ParseNaiveArnError::InvalidAccountId { .. } => "a 12-digit account ID",
+            ParseNaiveArnError::InvalidRegionFormat { .. } => "a region, such as `us-east-1`",
+            ParseNaiveArnError::InputTooLong { .. } => "at most 2048 bytes",
+            ParseNaiveArnError::IllegalCharacter {
+                component: Component::Resource,
+                ..
+            } => "no control characters",
+            ParseNaiveArnError::IllegalCharacter { .. } => "lowercase letters, digits and `-`",
+        }
+    }
+
+    /// What the parser found instead of what it [`expected`](ParseNaiveArnError::expected).
+    #[cfg(feature = "alloc")]
+    pub fn found(&self) -> String {
+        match self {
+            ParseNaiveArnError::NotEnoughElements { .. } => "the end of the input".to_owned(),
+            ParseNaiveArnError::MissingPrefix { found }
+            | ParseNaiveArnError::InvalidAccountId { found, .. }
+            | ParseNaiveArnError::InvalidRegionFormat { found, .. } => format!("`{}`", found),
+            ParseNaiveArnError::MissingPartition { .. }
+            | ParseNaiveArnError::MissingService { .. }
+            | ParseNaiveArnError::MissingResource { .. } => "an empty element".to_owned(),
+            ParseNaiveArnError::InputTooLong { length } => format!("{} bytes", length),
+            ParseNaiveArnError::IllegalCharacter { found, .. } => format!("{:?}", found),
+        }
+    }
+}
+
+impl ParseNaiveArnError {
+    /// This error with its position, if it has one, mapped with `f`, for inputs that were adjusted before parsing.
+    pub(crate) fn map_position(mut self, f: impl FnOnce(usize) -> usize) -> Self {
+        match &mut self {
+            ParseNaiveArnError::NotEnoughElements { position, .. }
+            | ParseNaiveArnError::MissingPartition { position }
+            | ParseNaiveArnError::MissingService { position }
+            | ParseNaiveArnError::MissingResource { position }
+            | ParseNaiveArnError::InvalidAccountId { position, .. }
+            | ParseNaiveArnError::InvalidRegionFormat { position, .. }
+            | ParseNaiveArnError::IllegalCharacter { position, .. } => *position = f(*position),
+            ParseNaiveArnError::MissingPrefix { .. } | ParseNaiveArnError::InputTooLong { .. } => {}
+        }
+        self
+    }
+
+    /// A stable identifier for the kind of error, which won't change meaning between versions of this crate.
+    ///
+    /// | Code     | Variant                                           |
+    /// |----------|---------------------------------------------------|
+    /// | `ARN001` | [`NotEnoughElements`](Self::NotEnoughElements)    |
+    /// | `ARN002` | [`MissingPrefix`](Self::MissingPrefix)            |
+    /// | `ARN003` | [`MissingPartition`](Self::MissingPartition)      |
+    /// | `ARN004` | [`MissingService`](Self::MissingService)          |
+    /// | `ARN005` | [`MissingResource`](Self::MissingResource)        |
+    /// | `ARN006` | [`InvalidAccountId`](Self::InvalidAccountId)      |
+    /// | `ARN007` | [`InvalidRegionFormat`](Self::InvalidRegionFormat) |
+    /// | `ARN008` | [`InputTooLong`](Self::InputTooLong)              |
+    /// | `ARN009` | [`IllegalCharacter`](Self::IllegalCharacter)      |
+    ///
+    /// Codes `ARN1xx` are used by [`ParseServiceArnError`](crate::service::ParseServiceArnError) and `ARN2xx` by
+    /// [`ValidationIssue`](crate::validate::ValidationIssue).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let err = NaiveArn::parse("aws:s3:::my_corporate_bucket").unwrap_err();
+    /// assert_eq!(err.code(), "ARN002");
+    /// assert_eq!(err.to_string(), "ARN002: Missing 'arn:' prefix");
+    /// ~~~~
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParseNaiveArnError::NotEnoughElements { .. } => "ARN001",
+            ParseNaiveArnError::MissingPrefix { .. } => "ARN002",
+            ParseNaiveArnError::MissingPartition { .. } => "ARN003",
+            ParseNaiveArnError::MissingService { .. } => "ARN004",
+            ParseNaiveArnError::MissingResource { .. } => "ARN005",
+            ParseNaiveArnError::InvalidAccountId { .. } => "ARN006",
+            ParseNaiveArnError::InvalidRegionFormat { .. } => "ARN007",
+            ParseNaiveArnError::InputTooLong { .. } => "ARN008",
+            ParseNaiveArnError::IllegalCharacter { .. } => "ARN009",
+        }
+    }
 }
 
 impl fmt::Display for ParseNaiveArnError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: ", self.code())?;
+
         match self {
-            ParseNaiveArnError::NotEnoughElements => write!(f, "Not enough elements"),
-            ParseNaiveArnError::MissingPrefix => write!(f, "Missing 'arn:' prefix"),
-            ParseNaiveArnError::MissingPartition => write!(f, "Missing partition element"),
-            ParseNaiveArnError::MissingService => write!(f, "Missing service element"),
-            ParseNaiveArnError::MissingResource => write!(f, "Missing resource element"),
+            ParseNaiveArnError::NotEnoughElements { .. } => write!(f, "Not enough elements"),
+            ParseNaiveArnError::MissingPrefix { .. } => write!(f, "Missing 'arn:' prefix"),
+            ParseNaiveArnError::MissingPartition { .. } => write!(f, "Missing partition element"),
+            ParseNaiveArnError::MissingService { .. } => write!(f, "Missing service element"),
+            ParseNaiveArnError::MissingResource { .. } => write!(f, "Missing resource element"),
+            ParseNaiveArnError::InvalidAccountId { .. } => write!(f, "Invalid account ID element"),
+            ParseNaiveArnError::InvalidRegionFormat { .. } => write!(f, "Invalid region element"),
+            ParseNaiveArnError::InputTooLong { .. } => {
+                write!(f, "ARN is longer than {} bytes", MAX_LEN)
+            }
+            ParseNaiveArnError::IllegalCharacter { component, .. } => {
+                write!(f, "Illegal character in {} element", component)
+            }
         }
     }
 }
 
-impl error::Error for ParseNaiveArnError {}
+#[cfg(feature = "std")]
+impl std::error::Error for ParseNaiveArnError {}
 
 #[cfg(test)]
 mod tests {
-    use super::{NaiveArn, ParseNaiveArnError};
+    use super::{Component, NaiveArn, ParseNaiveArnError, MAX_LEN};
 
     #[test]
     fn resource_type_with_slash() {
@@ -257,7 +631,13 @@ mod tests {
         let arn_str = "something:aws:s3:::my_corporate_bucket";
         let arn = NaiveArn::parse(arn_str);
 
-        assert_eq!(arn, Err(ParseNaiveArnError::MissingPrefix))
+        assert_eq!(
+            arn,
+            Err(ParseNaiveArnError::MissingPrefix {
+                #[cfg(feature = "alloc")]
+                found: "something:".to_owned()
+            })
+        )
     }
 
     #[test]
@@ -265,7 +645,13 @@ mod tests {
         let arn_str = "";
         let arn = NaiveArn::parse(arn_str);
 
-        assert_eq!(arn, Err(ParseNaiveArnError::MissingPrefix))
+        assert_eq!(
+            arn,
+            Err(ParseNaiveArnError::MissingPrefix {
+                #[cfg(feature = "alloc")]
+                found: String::new()
+            })
+        )
     }
 
     #[test]
@@ -273,7 +659,10 @@ mod tests {
         let arn_str = "arn:";
         let arn = NaiveArn::parse(arn_str);
 
-        assert_eq!(arn, Err(ParseNaiveArnError::MissingPartition))
+        assert_eq!(
+            arn,
+            Err(ParseNaiveArnError::MissingPartition { position: 4 })
+        )
     }
 
     #[test]
@@ -281,7 +670,13 @@ mod tests {
         let arn_str = "arn:aws:a4b:us-east-1:123456789012";
         let arn = NaiveArn::parse(arn_str);
 
-        assert_eq!(arn, Err(ParseNaiveArnError::NotEnoughElements))
+        assert_eq!(
+            arn,
+            Err(ParseNaiveArnError::NotEnoughElements {
+                component: Component::Resource,
+                position: 34
+            })
+        )
     }
 
     #[test]
@@ -289,7 +684,10 @@ mod tests {
         let arn_str = "arn::ec2:us-east-1:123456789012:vpc/vpc-fd580e98";
         let arn = NaiveArn::parse(arn_str);
 
-        assert_eq!(arn, Err(ParseNaiveArnError::MissingPartition))
+        assert_eq!(
+            arn,
+            Err(ParseNaiveArnError::MissingPartition { position: 4 })
+        )
     }
 
     #[test]
@@ -297,7 +695,7 @@ mod tests {
         let arn_str = "arn:aws::us-east-1:123456789012:vpc/vpc-fd580e98";
         let arn = NaiveArn::parse(arn_str);
 
-        assert_eq!(arn, Err(ParseNaiveArnError::MissingService))
+        assert_eq!(arn, Err(ParseNaiveArnError::MissingService { position: 8 }))
     }
 
     #[test]
@@ -305,7 +703,10 @@ mod tests {
         let arn_str = "arn:aws:ec2:us-east-1:123456789012:";
         let arn = NaiveArn::parse(arn_str);
 
-        assert_eq!(arn, Err(ParseNaiveArnError::MissingResource))
+        assert_eq!(
+            arn,
+            Err(ParseNaiveArnError::MissingResource { position: 35 })
+        )
     }
 
     #[test]
@@ -412,4 +813,99 @@ mod tests {
 
         assert_eq!(arn.to_string(), arn_str);
     }
+
+    #[test]
+    fn malformed_arn_too_long() {
+        let arn_str = format!("arn:aws:s3:::{}", "a".repeat(MAX_LEN));
+        let arn = NaiveArn::parse(&arn_str);
+
+        assert_eq!(
+            arn,
+            Err(ParseNaiveArnError::InputTooLong {
+                length: MAX_LEN + 13
+            })
+        )
+    }
+
+    #[test]
+    fn malformed_arn_illegal_characters() {
+        for (arn_str, err) in [
+            (
+                "arn:aws:sqs:us-east-1:123456789012:orders\n",
+                ParseNaiveArnError::IllegalCharacter {
+                    component: Component::Resource,
+                    position: 41,
+                    found: '\n',
+                },
+            ),
+            (
+                "arn:aws:EC2:us-east-1:123456789012:vpc/vpc-fd580e98",
+                ParseNaiveArnError::IllegalCharacter {
+                    component: Component::Service,
+                    position: 8,
+                    found: 'E',
+                },
+            ),
+            (
+                "arn:aws:ec2:us east 1:123456789012:vpc/vpc-fd580e98",
+                ParseNaiveArnError::InvalidRegionFormat {
+                    position: 14,
+                    #[cfg(feature = "alloc")]
+                    found: "us east 1".to_owned(),
+                },
+            ),
+            (
+                "arn:aws:ec2:us-east-1:1234.5678:vpc/vpc-fd580e98",
+                ParseNaiveArnError::InvalidAccountId {
+                    position: 26,
+                    #[cfg(feature = "alloc")]
+                    found: "1234.5678".to_owned(),
+                },
+            ),
+        ] {
+            assert_eq!(NaiveArn::parse(arn_str), Err(err), "{:?}", arn_str);
+        }
+
+        assert!(NaiveArn::parse("arn:aws:s3:*:*:bucket/My Key?").is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn expected_and_found() {
+        for (arn_str, expected, found) in [
+            (
+                "arn:aws:s3",
+                "six `:`-separated elements",
+                "the end of the input",
+            ),
+            (
+                "arn:aws:::123456789012:x",
+                "a service namespace, such as `s3`",
+                "an empty element",
+            ),
+            (
+                "arn:aws:sqs:US-EAST-1:123456789012:q",
+                "a region, such as `us-east-1`",
+                "`US-EAST-1`",
+            ),
+            (
+                "arn:aws:sqs:us-east-1:123456789012:q\t",
+                "no control characters",
+                "'\\t'",
+            ),
+        ] {
+            let err = NaiveArn::parse(arn_str).unwrap_err();
+            assert_eq!((err.expected(), err.found().as_str()), (expected, found));
+        }
+    }
+
+    #[test]
+    fn format_into() {
+        let arn_str = "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98";
+        let arn = NaiveArn::parse(arn_str).unwrap();
+
+        let mut buf = [0; 51];
+        assert_eq!(arn.format_into(&mut buf), Some(arn_str));
+        assert_eq!(arn.format_into(&mut buf[..50]), None);
+    }
 }
@@ -0,0 +1,174 @@
+//! ARNs in a canonical form, for comparing and deduplicating ARNs from different sources
+
+use alloc::string::String;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::buf::ArnBuf;
+use crate::naive::{NaiveArn, ParseNaiveArnError};
+
+/// An ARN normalized on construction, so that equality and hashing ignore differences that don't change the resource
+/// it names:
+///
+/// * the `arn` prefix, partition, service and region are lowercased,
+/// * an account ID written with hyphens, as the console shows them, has them removed, and
+/// * an empty region or account ID is absent.
+///
+/// The resource is left alone, as it is case-sensitive for most services.
+///
+/// # Example
+///
+/// ~~~~
+/// use std::collections::HashSet;
+///
+/// use arn::normalize::NormalizedArn;
+///
+/// let arns: HashSet<NormalizedArn> = [
+///     "arn:aws:sqs:us-east-1:123456789012:orders",
+///     "ARN:AWS:SQS:US-EAST-1:123456789012:orders",
+///     "arn:aws:sqs:us-east-1:1234-5678-9012:orders",
+/// ]
+/// .iter()
+/// .map(|arn| arn.parse().unwrap())
+/// .collect();
+///
+/// assert_eq!(arns.len(), 1);
+/// ~~~~
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NormalizedArn(ArnBuf);
+
+impl NormalizedArn {
+    /// Parse `s`, ignoring the case of everything before the resource.
+    pub fn parse(s: &str) -> Result<Self, ParseNaiveArnError> {
+        // Lowercasing ASCII keeps every byte where it was, so error positions still point into `s`.
+        let resource_start = s.match_indices(':').nth(4).map_or(s.len(), |(idx, _)| idx);
+        let mut lowercased = String::with_capacity(s.len());
+        lowercased.push_str(&s[..resource_start].to_ascii_lowercase());
+        lowercased.push_str(&s[resource_start..]);
+
+        NaiveArn::parse(&lowercased).map(NormalizedArn::from)
+    }
+
+    /// The normalized ARN.
+    pub fn as_arn(&self) -> &ArnBuf {
+        &self.0
+    }
+
+    /// Borrow the normalized ARN as a [`NaiveArn`].
+    pub fn as_naive(&self) -> NaiveArn<'_> {
+        self.0.as_naive()
+    }
+
+    pub fn into_inner(self) -> ArnBuf {
+        self.0
+    }
+}
+
+impl From<ArnBuf> for NormalizedArn {
+    fn from(mut arn: ArnBuf) -> Self {
+        arn.partition.make_ascii_lowercase();
+        arn.service.make_ascii_lowercase();
+
+        arn.region = arn
+            .region
+            .filter(|region| !region.is_empty())
+            .map(|region| region.to_ascii_lowercase());
+
+        arn.account_id = arn
+            .account_id
+            .filter(|account_id| !account_id.is_empty())
+            .map(|account_id| {
+                let digits: String = account_id.chars().filter(|&c| c != '-').collect();
+                if digits.len() == 12 && digits.bytes().all(|b| b.is_ascii_digit()) {
+                    digits
+                } else {
+                    account_id
+                }
+            });
+
+        NormalizedArn(arn)
+    }
+}
+
+impl<'a> From<NaiveArn<'a>> for NormalizedArn {
+    fn from(arn: NaiveArn<'a>) -> Self {
+        NormalizedArn::from(ArnBuf::from(arn))
+    }
+}
+
+impl From<NormalizedArn> for ArnBuf {
+    fn from(arn: NormalizedArn) -> Self {
+        arn.0
+    }
+}
+
+impl FromStr for NormalizedArn {
+    type Err = ParseNaiveArnError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NormalizedArn::parse(s)
+    }
+}
+
+impl fmt::Display for NormalizedArn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NormalizedArn;
+    use crate::buf::ArnBuf;
+    use crate::naive::{Component, ParseNaiveArnError};
+
+    #[test]
+    fn normalizes() {
+        for (arn_str, normalized) in [
+            (
+                "ARN:AWS:SQS:US-EAST-1:1234-5678-9012:Orders",
+                "arn:aws:sqs:us-east-1:123456789012:Orders",
+            ),
+            (
+                "arn:aws:iam::aws:policy/AdministratorAccess",
+                "arn:aws:iam::aws:policy/AdministratorAccess",
+            ),
+            (
+                "arn:aws:s3:::My-Bucket/Key:With:Colons",
+                "arn:aws:s3:::My-Bucket/Key:With:Colons",
+            ),
+        ] {
+            assert_eq!(
+                NormalizedArn::parse(arn_str).unwrap().to_string(),
+                normalized
+            );
+        }
+    }
+
+    #[test]
+    fn empty_components() {
+        let arn = ArnBuf {
+            partition: "aws".to_owned(),
+            service: "s3".to_owned(),
+            region: Some(String::new()),
+            account_id: Some(String::new()),
+            resource: "my_corporate_bucket".to_owned(),
+        };
+
+        assert_eq!(
+            NormalizedArn::from(arn),
+            NormalizedArn::parse("arn:aws:s3:::my_corporate_bucket").unwrap()
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            NormalizedArn::parse("ARN:AWS:S3"),
+            Err(ParseNaiveArnError::NotEnoughElements {
+                component: Component::Region,
+                position: 10
+            })
+        );
+    }
+}
@@ -0,0 +1,198 @@
+//! Recovering what we can from malformed ARNs
+
+use alloc::{borrow::ToOwned, vec::Vec};
+
+use crate::naive::{check_component, Component, NaiveArn, ParseNaiveArnError, MAX_LEN};
+
+/// The components recovered from a possibly malformed ARN by [`NaiveArn::parse_partial`].
+///
+/// A component is `None` if it is missing, empty or invalid; the reason it is missing or invalid is in `errors`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PartialArn<'a> {
+    pub partition: Option<&'a str>,
+    pub service: Option<&'a str>,
+    pub region: Option<&'a str>,
+    pub account_id: Option<&'a str>,
+    pub resource: Option<&'a str>,
+    /// Every problem found, in the order of the input.
+    pub errors: Vec<ParseNaiveArnError>,
+}
+
+impl<'a> PartialArn<'a> {
+    /// The ARN, if it parsed without errors.
+    pub fn arn(&self) -> Option<NaiveArn<'a>> {
+        if !self.errors.is_empty() {
+            return None;
+        }
+
+        Some(NaiveArn {
+            partition: self.partition?,
+            service: self.service?,
+            region: self.region,
+            account_id: self.account_id,
+            resource: self.resource?,
+        })
+    }
+}
+
+impl<'a> NaiveArn<'a> {
+    /// Parse as much of `s` as possible, collecting every error rather than stopping at the first.
+    ///
+    /// A missing `arn:` prefix is skipped over, so `aws:s3:::bucket` recovers its partition, service and resource.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::{NaiveArn, ParseNaiveArnError};
+    ///
+    /// let partial = NaiveArn::parse_partial("arn:aws:sqs:us east 1:123456789012:orders");
+    ///
+    /// assert_eq!(partial.service, Some("sqs"));
+    /// assert_eq!(partial.account_id, Some("123456789012"));
+    /// assert_eq!(partial.region, None);
+    /// assert!(matches!(partial.errors[..], [ParseNaiveArnError::InvalidRegionFormat { .. }]));
+    /// ~~~~
+    pub fn parse_partial(s: &'a str) -> PartialArn<'a> {
+        let mut partial = PartialArn::default();
+
+        if s.len() > MAX_LEN {
+            partial
+                .errors
+                .push(ParseNaiveArnError::InputTooLong { length: s.len() });
+        }
+
+        let prefix = s.split(':').next().unwrap_or_default();
+        let body_start = if prefix == "arn" {
+            prefix.len() + 1
+        } else {
+            let found = &s[..s.len().min(prefix.len() + 1)];
+            partial.errors.push(ParseNaiveArnError::MissingPrefix {
+                found: found.to_owned(),
+            });
+
+            // Assume `aws...:` is a partition with the prefix left off, and anything else is junk in place of the prefix.
+            if prefix.starts_with("aws") {
+                0
+            } else {
+                prefix.len() + 1
+            }
+        };
+
+        let mut elements = Vec::new();
+        if let Some(body) = s.get(body_start..) {
+            let mut position = body_start;
+            for element in body.splitn(5, ':') {
+                elements.push((element, position));
+                position += element.len() + 1;
+            }
+        }
+
+        let components = [
+            Component::Partition,
+            Component::Service,
+            Component::Region,
+            Component::AccountId,
+            Component::Resource,
+        ];
+        for (idx, component) in components.iter().copied().enumerate() {
+            let (value, position) = match elements.get(idx) {
+                Some(&element) => element,
+                None => {
+                    partial.errors.push(ParseNaiveArnError::NotEnoughElements {
+                        component,
+                        position: s.len(),
+                    });
+                    break;
+                }
+            };
+
+            let missing = match component {
+                Component::Partition => Some(ParseNaiveArnError::MissingPartition { position }),
+                Component::Service => Some(ParseNaiveArnError::MissingService { position }),
+                Component::Resource => Some(ParseNaiveArnError::MissingResource { position }),
+                _ => None,
+            };
+            if value.is_empty() {
+                partial.errors.extend(missing);
+                continue;
+            }
+
+            if let Err(err) = check_component(component, value, position) {
+                partial.errors.push(err);
+                continue;
+            }
+
+            let slot = match component {
+                Component::Partition => &mut partial.partition,
+                Component::Service => &mut partial.service,
+                Component::Region => &mut partial.region,
+                Component::AccountId => &mut partial.account_id,
+                _ => &mut partial.resource,
+            };
+            *slot = Some(value);
+        }
+
+        partial
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive::{Component, NaiveArn, ParseNaiveArnError};
+
+    #[test]
+    fn valid() {
+        let arn_str = "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98";
+        let partial = NaiveArn::parse_partial(arn_str);
+
+        assert_eq!(partial.errors, []);
+        assert_eq!(partial.arn(), NaiveArn::parse(arn_str).ok());
+    }
+
+    #[test]
+    fn missing_prefix() {
+        let partial = NaiveArn::parse_partial("aws:s3:::my_corporate_bucket");
+
+        assert_eq!(partial.partition, Some("aws"));
+        assert_eq!(partial.service, Some("s3"));
+        assert_eq!(partial.resource, Some("my_corporate_bucket"));
+        assert_eq!(
+            partial.errors,
+            [ParseNaiveArnError::MissingPrefix {
+                found: "aws:".to_owned()
+            }]
+        );
+        assert_eq!(partial.arn(), None);
+
+        let partial = NaiveArn::parse_partial("ARN:aws:s3:::my_corporate_bucket");
+        assert_eq!(partial.service, Some("s3"));
+    }
+
+    #[test]
+    fn several_errors() {
+        let partial = NaiveArn::parse_partial("arn::lambda:US-EAST-1:1234 5678");
+
+        assert_eq!(partial.service, Some("lambda"));
+        assert_eq!(partial.partition, None);
+        assert_eq!(partial.region, None);
+        assert_eq!(partial.account_id, None);
+        assert_eq!(
+            partial.errors,
+            [
+                ParseNaiveArnError::MissingPartition { position: 4 },
+                ParseNaiveArnError::InvalidRegionFormat {
+                    position: 12,
+                    found: "US-EAST-1".to_owned()
+                },
+                ParseNaiveArnError::InvalidAccountId {
+                    position: 26,
+                    found: "1234 5678".to_owned()
+                },
+                ParseNaiveArnError::NotEnoughElements {
+                    component: Component::Resource,
+                    position: 31
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,606 @@
+//! AWS partitions
+//!
+//! A partition is a group of regions with its own DNS suffix, console and identity boundary. Every ARN names the
+//! partition its resource lives in.
+//!
+//! Partitions this crate doesn't know about, such as private or newly launched ISO partitions, can be described with a
+//! [`PartitionInfo`] and, with the `std` feature, [registered](Partition::register) at runtime to be recognised
+//! everywhere a built-in partition is.
+
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::sync::{PoisonError, RwLock};
+
+use crate::naive::NaiveArn;
+
+/// A known AWS partition, with the metadata needed to build endpoints and URLs for it.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::partition::Partition;
+///
+/// let partition: Partition = "aws-cn".parse().unwrap();
+/// assert_eq!(partition.dns_suffix(), "amazonaws.com.cn");
+/// assert_eq!(Partition::of_region("us-gov-west-1"), Some(Partition::AwsUsGov));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Partition {
+    /// `aws`, the standard commercial partition
+    Aws,
+    /// `aws-cn`, the China (Beijing and Ningxia) regions
+    AwsCn,
+    /// `aws-us-gov`, the AWS GovCloud (US) regions
+    AwsUsGov,
+    /// `aws-iso`, the US ISO regions
+    AwsIso,
+    /// `aws-iso-b`, the US ISOB regions
+    AwsIsoB,
+    /// `aws-iso-e`, the EU ISOE regions
+    AwsIsoE,
+    /// `aws-iso-f`, the US ISOF regions
+    AwsIsoF,
+    /// `aws-eusc`, the AWS European Sovereign Cloud
+    AwsEusc,
+    /// A partition described by the user, see [`Partition::register`].
+    Custom(&'static PartitionInfo),
+}
+
+/// The description of a partition this crate doesn't know about.
+///
+/// The fields are public so that a table of partitions can be written (or generated) as `static` items.
+///
+/// # Example
+///
+/// ~~~~
+/// # #[cfg(feature = "std")]
+/// # {
+/// use arn::naive::NaiveArn;
+/// use arn::partition::{Partition, PartitionInfo};
+///
+/// static AWS_ISO_X: PartitionInfo = PartitionInfo {
+///     id: "aws-iso-x",
+///     name: "AWS ISOX",
+///     dns_suffix: "x.example.gov",
+///     dual_stack_dns_suffix: "x.example.gov",
+///     implicit_global_region: "us-isox-east-1",
+///     regions: &["us-isox-east-1"],
+///     region_prefix: Some("us-isox-"),
+///     console_domain: None,
+/// };
+///
+/// let partition = Partition::register(&AWS_ISO_X).unwrap();
+/// assert_eq!(Partition::of_region("us-isox-west-1"), Some(partition));
+///
+/// let arn = NaiveArn::parse("arn:aws-iso-x:sqs:us-isox-east-1:123456789012:orders").unwrap();
+/// assert_eq!(arn.known_partition(), Some(partition));
+/// assert_eq!(arn.endpoint_url().unwrap(), "https://sqs.us-isox-east-1.x.example.gov");
+/// # }
+/// ~~~~
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct PartitionInfo {
+    /// See [`Partition::id`].
+    pub id: &'static str,
+    /// See [`Partition::name`].
+    pub name: &'static str,
+    /// See [`Partition::dns_suffix`].
+    pub dns_suffix: &'static str,
+    /// See [`Partition::dual_stack_dns_suffix`].
+    pub dual_stack_dns_suffix: &'static str,
+    /// See [`Partition::implicit_global_region`].
+    pub implicit_global_region: &'static str,
+    /// See [`Partition::regions`].
+    pub regions: &'static [&'static str],
+    /// The prefix of every region in the partition, such as `us-isob-`, for recognising regions that aren't listed.
+    pub region_prefix: Option<&'static str>,
+    /// See [`Partition::console_domain`].
+    pub console_domain: Option<&'static str>,
+}
+
+#[cfg(feature = "std")]
+static REGISTRY: RwLock<Vec<&'static PartitionInfo>> = RwLock::new(Vec::new());
+
+impl Partition {
+    /// Every built-in partition.
+    pub const ALL: &'static [Partition] = &[
+        Partition::Aws,
+        Partition::AwsCn,
+        Partition::AwsUsGov,
+        Partition::AwsIso,
+        Partition::AwsIsoB,
+        Partition::AwsIsoE,
+        Partition::AwsIsoF,
+        Partition::AwsEusc,
+    ];
+
+    /// The partition identifier, as it appears in ARNs.
+    pub fn id(self) -> &'static str {
+        match self {
+            Partition::Aws => "aws",
+            Partition::AwsCn => "aws-cn",
+            Partition::AwsUsGov => "aws-us-gov",
+            Partition::AwsIso => "aws-iso",
+            Partition::AwsIsoB => "aws-iso-b",
+            Partition::AwsIsoE => "aws-iso-e",
+            Partition::AwsIsoF => "aws-iso-f",
+            Partition::AwsEusc => "aws-eusc",
+            Partition::Custom(info) => info.id,
+        }
+    }
+
+    /// A human-readable name for the partition.
+    pub fn name(self) -> &'static str {
+        match self {
+            Partition::Aws => "AWS Standard",
+            Partition::AwsCn => "AWS China",
+            Partition::AwsUsGov => "AWS GovCloud (US)",
+            Partition::AwsIso => "AWS ISO (US)",
+            Partition::AwsIsoB => "AWS ISOB (US)",
+            Partition::AwsIsoE => "AWS ISOE (Europe)",
+            Partition::AwsIsoF => "AWS ISOF",
+            Partition::AwsEusc => "AWS European Sovereign Cloud",
+            Partition::Custom(info) => info.name,
+        }
+    }
+
+    /// The DNS suffix of service endpoints, e.g. `sqs.us-east-1.amazonaws.com`.
+    pub fn dns_suffix(self) -> &'static str {
+        match self {
+            Partition::Aws | Partition::AwsUsGov => "amazonaws.com",
+            Partition::AwsCn => "amazonaws.com.cn",
+            Partition::AwsIso => "c2s.ic.gov",
+            Partition::AwsIsoB => "sc2s.sgov.gov",
+            Partition::AwsIsoE => "cloud.adc-e.uk",
+            Partition::AwsIsoF => "csp.hci.ic.gov",
+            Partition::AwsEusc => "amazonaws.eu",
+            Partition::Custom(info) => info.dns_suffix,
+        }
+    }
+
+    /// The DNS suffix of dual-stack (IPv4 and IPv6) service endpoints, e.g. `sqs.us-east-1.api.aws`.
+    pub fn dual_stack_dns_suffix(self) -> &'static str {
+        match self {
+            Partition::Aws | Partition::AwsUsGov => "api.aws",
+            Partition::AwsCn => "api.amazonwebservices.com.cn",
+            Partition::AwsEusc => "api.amazonwebservices.eu",
+            Partition::AwsIso | Partition::AwsIsoB | Partition::AwsIsoE | Partition::AwsIsoF => {
+                self.dns_suffix()
+            }
+            Partition::Custom(info) => info.dual_stack_dns_suffix,
+        }
+    }
+
+    /// The region that global services (such as IAM) in this partition are homed in.
+    pub fn implicit_global_region(self) -> &'static str {
+        match self {
+            Partition::Aws => "us-east-1",
+            Partition::AwsCn => "cn-northwest-1",
+            Partition::AwsUsGov => "us-gov-west-1",
+            Partition::AwsIso => "us-iso-east-1",
+            Partition::AwsIsoB => "us-isob-east-1",
+            Partition::AwsIsoE => "eu-isoe-west-1",
+            Partition::AwsIsoF => "us-isof-south-1",
+            Partition::AwsEusc => "eusc-de-east-1",
+            Partition::Custom(info) => info.implicit_global_region,
+        }
+    }
+
+    /// The regions known to be in this partition.
+    ///
+    /// New regions are launched regularly, so this list is not exhaustive; [`Partition::of_region`] also recognises
+    /// regions that follow the partition's naming scheme.
+    pub fn regions(self) -> &'static [&'static str] {
+        match self {
+            Partition::Aws => &[
+                "af-south-1",
+                "ap-east-1",
+                "ap-east-2",
+                "ap-northeast-1",
+                "ap-northeast-2",
+                "ap-northeast-3",
+                "ap-south-1",
+                "ap-south-2",
+                "ap-southeast-1",
+                "ap-southeast-2",
+                "ap-southeast-3",
+                "ap-southeast-4",
+                "ap-southeast-5",
+                "ap-southeast-7",
+                "ca-central-1",
+                "ca-west-1",
+                "eu-central-1",
+                "eu-central-2",
+                "eu-north-1",
+                "eu-south-1",
+                "eu-south-2",
+                "eu-west-1",
+                "eu-west-2",
+                "eu-west-3",
+                "il-central-1",
+                "me-central-1",
+                "me-south-1",
+                "mx-central-1",
+                "sa-east-1",
+                "us-east-1",
+                "us-east-2",
+                "us-west-1",
+                "us-west-2",
+            ],
+            Partition::AwsCn => &["cn-north-1", "cn-northwest-1"],
+            Partition::AwsUsGov => &["us-gov-east-1", "us-gov-west-1"],
+            Partition::AwsIso => &["us-iso-east-1", "us-iso-west-1"],
+            Partition::AwsIsoB => &["us-isob-east-1"],
+            Partition::AwsIsoE => &["eu-isoe-west-1"],
+            Partition::AwsIsoF => &["us-isof-east-1", "us-isof-south-1"],
+            Partition::AwsEusc => &["eusc-de-east-1"],
+            Partition::Custom(info) => info.regions,
+        }
+    }
+
+    /// The AWS Management Console domain, for partitions that have a public console.
+    pub fn console_domain(self) -> Option<&'static str> {
+        match self {
+            Partition::Aws => Some("console.aws.amazon.com"),
+            Partition::AwsCn => Some("console.amazonaws.cn"),
+            Partition::AwsUsGov => Some("console.amazonaws-us-gov.com"),
+            Partition::Custom(info) => info.console_domain,
+            _ => None,
+        }
+    }
+
+    /// The partition containing `region`, either because it is a known region or because it follows the partition's
+    /// region naming scheme.
+    pub fn of_region(region: &str) -> Option<Partition> {
+        if let Some(partition) = Partition::ALL
+            .iter()
+            .find(|partition| partition.regions().contains(&region))
+        {
+            return Some(*partition);
+        }
+
+        // Registered partitions come before the built-in naming schemes, which would claim `us-isox-east-1` for `aws`.
+        if let Some(partition) = Partition::find_registered(|info| {
+            info.regions.contains(&region)
+                || info
+                    .region_prefix
+                    .is_some_and(|prefix| region.starts_with(prefix))
+        }) {
+            return Some(partition);
+        }
+
+        let (prefix, number) = region.rsplit_once('-')?;
+        if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let mut labels = prefix.splitn(2, '-');
+        match (labels.next()?, labels.next()?) {
+            ("us", rest) if rest.starts_with("gov-") => Some(Partition::AwsUsGov),
+            ("us", rest) if rest.starts_with("isob-") => Some(Partition::AwsIsoB),
+            ("us", rest) if rest.starts_with("isof-") => Some(Partition::AwsIsoF),
+            ("us", rest) if rest.starts_with("iso-") => Some(Partition::AwsIso),
+            ("eu", rest) if rest.starts_with("isoe-") => Some(Partition::AwsIsoE),
+            ("eusc", _) => Some(Partition::AwsEusc),
+            ("cn", _) => Some(Partition::AwsCn),
+            ("af", _)
+            | ("ap", _)
+            | ("ca", _)
+            | ("eu", _)
+            | ("il", _)
+            | ("me", _)
+            | ("mx", _)
+            | ("sa", _)
+            | ("us", _) => Some(Partition::Aws),
+            _ => None,
+        }
+    }
+
+    /// The partition whose endpoints use `dns_suffix`.
+    ///
+    /// Some partitions share a DNS suffix (GovCloud uses `amazonaws.com`), so the endpoint's `region` is used to tell
+    /// them apart when it is known.
+    pub fn from_dns_suffix(dns_suffix: &str, region: Option<&str>) -> Option<Partition> {
+        region
+            .and_then(Partition::of_region)
+            .filter(|partition| partition.dns_suffix() == dns_suffix)
+            .or_else(|| {
+                Partition::ALL
+                    .iter()
+                    .copied()
+                    .find(|partition| partition.dns_suffix() == dns_suffix)
+            })
+            .or_else(|| Partition::find_registered(|info| info.dns_suffix == dns_suffix))
+    }
+
+    /// Register a partition, so that it is recognised by [`FromStr`], [`Partition::of_region`] and everything built on
+    /// them, such as validation and endpoint URLs.
+    ///
+    /// Registration lasts for the rest of the program. A partition described at runtime, rather than in a `static`,
+    /// can be registered with [`Box::leak`].
+    ///
+    /// The partition must list at least one region.
+    #[cfg(feature = "std")]
+    pub fn register(info: &'static PartitionInfo) -> Result<Partition, RegisterPartitionError> {
+        if info.regions.is_empty() {
+            return Err(RegisterPartitionError::NoRegions);
+        }
+
+        let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+
+        let duplicate = Partition::ALL
+            .iter()
+            .map(|partition| partition.id())
+            .chain(registry.iter().map(|registered| registered.id))
+            .any(|id| id == info.id);
+        if duplicate {
+            return Err(RegisterPartitionError::DuplicateId);
+        }
+
+        registry.push(info);
+        Ok(Partition::Custom(info))
+    }
+
+    /// The first registered partition matching `f`.
+    #[cfg_attr(not(feature = "std"), allow(unused_variables))]
+    fn find_registered(f: impl FnMut(&&'static PartitionInfo) -> bool) -> Option<Partition> {
+        #[cfg(feature = "std")]
+        return REGISTRY
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .copied()
+            .find(f)
+            .map(Partition::Custom);
+
+        #[cfg(not(feature = "std"))]
+        None
+    }
+}
+
+impl<'a> NaiveArn<'a> {
+    /// The ARN's partition, if it is a known one.
+    pub fn known_partition(&self) -> Option<Partition> {
+        self.partition.parse().ok()
+    }
+}
+
+impl FromStr for Partition {
+    type Err = ParsePartitionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Partition::ALL
+            .iter()
+            .find(|partition| partition.id() == s)
+            .copied()
+            .or_else(|| Partition::find_registered(|info| info.id == s))
+            .ok_or(ParsePartitionError::UnknownPartition)
+    }
+}
+
+impl fmt::Display for Partition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParsePartitionError {
+    UnknownPartition,
+}
+
+impl fmt::Display for ParsePartitionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParsePartitionError::UnknownPartition => write!(f, "Unknown partition"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParsePartitionError {}
+
+#[derive(Debug, PartialEq)]
+pub enum RegisterPartitionError {
+    /// A built-in or registered partition already has the ID.
+    DuplicateId,
+    /// The partition lists no regions.
+    NoRegions,
+}
+
+impl fmt::Display for RegisterPartitionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegisterPartitionError::DuplicateId => write!(f, "Partition ID is already in use"),
+            RegisterPartitionError::NoRegions => write!(f, "Partition has no regions"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RegisterPartitionError {}
+
+/// Split `host` into the endpoint labels in front of a known DNS suffix, and that suffix.
+#[cfg(feature = "alloc")]
+pub(crate) fn split_host(host: &str) -> Option<(&str, &'static str)> {
+    let split = |suffix: &'static str| {
+        let prefix = host.strip_suffix(suffix)?.strip_suffix('.')?;
+        Some((prefix, suffix))
+    };
+
+    Partition::ALL
+        .iter()
+        .find_map(|partition| split(partition.dns_suffix()))
+        .or_else(|| {
+            Partition::find_registered(|info| split(info.dns_suffix).is_some())
+                .and_then(|partition| split(partition.dns_suffix()))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParsePartitionError, Partition};
+    #[cfg(feature = "std")]
+    use super::{PartitionInfo, RegisterPartitionError};
+    use crate::naive::NaiveArn;
+
+    #[test]
+    fn round_trip() {
+        for partition in Partition::ALL {
+            assert_eq!(partition.id().parse::<Partition>(), Ok(*partition));
+            assert_eq!(partition.to_string(), partition.id());
+        }
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(
+            "aws-moon".parse::<Partition>(),
+            Err(ParsePartitionError::UnknownPartition)
+        );
+    }
+
+    #[test]
+    fn dns_suffixes() {
+        assert_eq!(Partition::Aws.dns_suffix(), "amazonaws.com");
+        assert_eq!(Partition::AwsCn.dns_suffix(), "amazonaws.com.cn");
+        assert_eq!(Partition::AwsUsGov.dns_suffix(), "amazonaws.com");
+        assert_eq!(Partition::AwsIsoB.dns_suffix(), "sc2s.sgov.gov");
+        assert_eq!(
+            Partition::AwsCn.dual_stack_dns_suffix(),
+            "api.amazonwebservices.com.cn"
+        );
+    }
+
+    #[test]
+    fn from_dns_suffix() {
+        assert_eq!(
+            Partition::from_dns_suffix("amazonaws.com", None),
+            Some(Partition::Aws)
+        );
+        assert_eq!(
+            Partition::from_dns_suffix("amazonaws.com", Some("us-gov-east-1")),
+            Some(Partition::AwsUsGov)
+        );
+        assert_eq!(
+            Partition::from_dns_suffix("amazonaws.com", Some("cn-north-1")),
+            Some(Partition::Aws)
+        );
+        assert_eq!(
+            Partition::from_dns_suffix("amazonaws.com.cn", None),
+            Some(Partition::AwsCn)
+        );
+        assert_eq!(Partition::from_dns_suffix("example.com", None), None);
+    }
+
+    #[test]
+    fn regions_belong_to_their_partition() {
+        for partition in Partition::ALL {
+            assert!(partition
+                .regions()
+                .contains(&partition.implicit_global_region()));
+
+            for region in partition.regions() {
+                assert_eq!(Partition::of_region(region), Some(*partition), "{}", region);
+            }
+        }
+    }
+
+    #[test]
+    fn unlisted_regions() {
+        assert_eq!(Partition::of_region("ap-southeast-9"), Some(Partition::Aws));
+        assert_eq!(
+            Partition::of_region("us-gov-central-1"),
+            Some(Partition::AwsUsGov)
+        );
+        assert_eq!(
+            Partition::of_region("us-isob-west-1"),
+            Some(Partition::AwsIsoB)
+        );
+        assert_eq!(Partition::of_region("cn-south-1"), Some(Partition::AwsCn));
+        assert_eq!(Partition::of_region("*"), None);
+        assert_eq!(Partition::of_region("us-east"), None);
+        assert_eq!(Partition::of_region("xx-east-1"), None);
+    }
+
+    #[test]
+    fn known_partition() {
+        let arn = NaiveArn::parse("arn:aws-us-gov:s3:::my_corporate_bucket").unwrap();
+        assert_eq!(arn.known_partition(), Some(Partition::AwsUsGov));
+
+        let arn = NaiveArn::parse("arn:aws-moon:s3:::my_corporate_bucket").unwrap();
+        assert_eq!(arn.known_partition(), None);
+    }
+
+    // Registration is global, so each test registers partitions with IDs of its own.
+    #[test]
+    #[cfg(feature = "std")]
+    fn register() {
+        static AWS_ISO_T: PartitionInfo = PartitionInfo {
+            id: "aws-iso-t",
+            name: "AWS ISOT",
+            dns_suffix: "t.example.gov",
+            dual_stack_dns_suffix: "t.example.gov",
+            implicit_global_region: "us-isot-east-1",
+            regions: &["us-isot-east-1", "eu-isot-west-1"],
+            region_prefix: Some("us-isot-"),
+            console_domain: None,
+        };
+
+        let partition = Partition::register(&AWS_ISO_T).unwrap();
+        assert_eq!(partition.id(), "aws-iso-t");
+        assert_eq!("aws-iso-t".parse::<Partition>(), Ok(partition));
+        assert_eq!(Partition::of_region("eu-isot-west-1"), Some(partition));
+        assert_eq!(Partition::of_region("us-isot-south-2"), Some(partition));
+        assert_eq!(
+            Partition::from_dns_suffix("t.example.gov", None),
+            Some(partition)
+        );
+        assert_eq!(
+            Partition::register(&AWS_ISO_T),
+            Err(RegisterPartitionError::DuplicateId)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn register_builtin_id() {
+        let info = Box::leak(Box::new(PartitionInfo {
+            id: "aws-cn",
+            name: "AWS China",
+            dns_suffix: "example.cn",
+            dual_stack_dns_suffix: "example.cn",
+            implicit_global_region: "cn-north-1",
+            regions: &["cn-north-1"],
+            region_prefix: None,
+            console_domain: None,
+        }));
+
+        assert_eq!(
+            Partition::register(info),
+            Err(RegisterPartitionError::DuplicateId)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn register_without_regions() {
+        static AWS_ISO_R: PartitionInfo = PartitionInfo {
+            id: "aws-iso-r",
+            name: "AWS ISOR",
+            dns_suffix: "r.example.gov",
+            dual_stack_dns_suffix: "r.example.gov",
+            implicit_global_region: "us-isor-east-1",
+            regions: &[],
+            region_prefix: Some("us-isor-"),
+            console_domain: None,
+        };
+
+        assert_eq!(
+            Partition::register(&AWS_ISO_R),
+            Err(RegisterPartitionError::NoRegions)
+        );
+        assert_eq!("aws-iso-r".parse::<Partition>().ok(), None);
+    }
+}
@@ -0,0 +1,229 @@
+//! IAM-style ARN patterns, such as `arn:aws:s3:::prod-*`
+
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::fmt;
+use core::str::FromStr;
+
+use crate::naive::{NaiveArn, ParseNaiveArnError};
+
+/// An ARN pattern as used in the `Resource` element of IAM policies.
+///
+/// Each component is matched separately. Within a component, `*` matches any sequence of characters (including `/`
+/// and `:` in the resource) and `?` matches any single character. An empty region or account ID in the pattern only
+/// matches an empty component. The pattern `*` on its own matches every ARN.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::naive::NaiveArn;
+/// use arn::pattern::ArnPattern;
+///
+/// let pattern = ArnPattern::parse("arn:aws:s3:::prod-*").unwrap();
+///
+/// assert!(pattern.matches(&NaiveArn::parse("arn:aws:s3:::prod-logs/2020/01/01.gz").unwrap()));
+/// assert!(!pattern.matches(&NaiveArn::parse("arn:aws:s3:::dev-logs").unwrap()));
+/// ~~~~
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArnPattern {
+    /// `None` for the match-everything `*` pattern.
+    components: Option<[String; 5]>,
+}
+
+impl ArnPattern {
+    pub fn parse(s: &str) -> Result<Self, ParseNaiveArnError> {
+        if s == "*" {
+            return Ok(ArnPattern { components: None });
+        }
+
+        let arn = NaiveArn::parse(s)?;
+
+        Ok(ArnPattern {
+            components: Some([
+                arn.partition.to_owned(),
+                arn.service.to_owned(),
+                arn.region.unwrap_or_default().to_owned(),
+                arn.account_id.unwrap_or_default().to_owned(),
+                arn.resource.to_owned(),
+            ]),
+        })
+    }
+
+    /// Whether `arn` matches this pattern.
+    pub fn matches(&self, arn: &NaiveArn) -> bool {
+        let components = match &self.components {
+            Some(components) => components,
+            None => return true,
+        };
+
+        let values = [
+            arn.partition,
+            arn.service,
+            arn.region.unwrap_or_default(),
+            arn.account_id.unwrap_or_default(),
+            arn.resource,
+        ];
+
+        components
+            .iter()
+            .zip(values.iter())
+            .all(|(pattern, value)| glob_match(pattern, value))
+    }
+
+    /// Whether this pattern contains no wildcards, and so matches exactly one ARN.
+    pub fn is_literal(&self) -> bool {
+        match &self.components {
+            Some(components) => !components.iter().any(|c| c.contains(['*', '?'])),
+            None => false,
+        }
+    }
+}
+
+/// Match `text` against `pattern`, where `*` matches any sequence and `?` matches any single character.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` seen in the pattern, and the text position it is currently matched up to
+    let mut backtrack = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+impl FromStr for ArnPattern {
+    type Err = ParseNaiveArnError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ArnPattern::parse(s)
+    }
+}
+
+impl fmt::Display for ArnPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.components {
+            Some([partition, service, region, account_id, resource]) => write!(
+                f,
+                "arn:{}:{}:{}:{}:{}",
+                partition, service, region, account_id, resource
+            ),
+            None => f.write_str("*"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{glob_match, ArnPattern};
+    use crate::naive::NaiveArn;
+
+    fn matches(pattern: &str, arn_str: &str) -> bool {
+        ArnPattern::parse(pattern)
+            .unwrap()
+            .matches(&NaiveArn::parse(arn_str).unwrap())
+    }
+
+    #[test]
+    fn glob() {
+        assert!(glob_match("", ""));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("a*c", "abbbc"));
+        assert!(glob_match("a?c", "abc"));
+        assert!(glob_match("*b*b*", "abcbd"));
+        assert!(!glob_match("a?c", "ac"));
+        assert!(!glob_match("a*c", "abcd"));
+        assert!(!glob_match("", "a"));
+    }
+
+    #[test]
+    fn literal() {
+        let arn_str = "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98";
+        let pattern = ArnPattern::parse(arn_str).unwrap();
+
+        assert!(pattern.is_literal());
+        assert!(matches(arn_str, arn_str));
+        assert!(!matches(
+            arn_str,
+            "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e99"
+        ));
+    }
+
+    #[test]
+    fn resource_wildcards_span_delimiters() {
+        assert!(matches(
+            "arn:aws:logs:us-east-1:123456789012:log-group:*",
+            "arn:aws:logs:us-east-1:123456789012:log-group:my-log-group*:log-stream:my-log-stream*"
+        ));
+        assert!(matches(
+            "arn:aws:s3:::my_corporate_bucket/*",
+            "arn:aws:s3:::my_corporate_bucket/Development/exampleobject.png"
+        ));
+    }
+
+    #[test]
+    fn component_wildcards() {
+        assert!(matches(
+            "arn:aws:sns:*:123456789012:my_corporate_topic",
+            "arn:aws:sns:eu-west-1:123456789012:my_corporate_topic"
+        ));
+        assert!(matches(
+            "arn:aws:sns:*:123456789012:my_corporate_topic",
+            "arn:aws:sns::123456789012:my_corporate_topic"
+        ));
+        assert!(matches(
+            "arn:aws:ec2:us-east-?:*:instance/*",
+            "arn:aws:ec2:us-east-2:123456789012:instance/i-0123"
+        ));
+        assert!(!matches(
+            "arn:aws:ec2:us-east-?:*:instance/*",
+            "arn:aws:ec2:us-west-2:123456789012:instance/i-0123"
+        ));
+    }
+
+    #[test]
+    fn wildcards_do_not_cross_components() {
+        assert!(!matches(
+            "arn:aws:s3:::*",
+            "arn:aws:s3:us-east-1:123456789012:accesspoint/test"
+        ));
+    }
+
+    #[test]
+    fn match_everything() {
+        let pattern = ArnPattern::parse("*").unwrap();
+
+        assert!(!pattern.is_literal());
+        assert!(matches("*", "arn:aws-cn:s3:::my_corporate_bucket"));
+        assert_eq!(pattern.to_string(), "*");
+    }
+
+    #[test]
+    fn display() {
+        let pattern_str = "arn:aws:s3:::prod-*";
+
+        assert_eq!(
+            ArnPattern::parse(pattern_str).unwrap().to_string(),
+            pattern_str
+        );
+    }
+}
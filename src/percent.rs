@@ -0,0 +1,79 @@
+//! Percent-encoding for the URL forms of ARNs
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+/// Percent-encode everything except RFC 3986 unreserved characters and `/`.
+pub(crate) fn encode_path(s: &str) -> String {
+    encode(s, b"/")
+}
+
+/// Percent-encode everything except RFC 3986 unreserved characters.
+pub(crate) fn encode_component(s: &str) -> String {
+    encode(s, b"")
+}
+
+fn encode(s: &str, keep: &[u8]) -> String {
+    let mut encoded = String::with_capacity(s.len());
+
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ if keep.contains(&byte) => encoded.push(byte as char),
+            _ => write!(encoded, "%{:02X}", byte).unwrap(),
+        }
+    }
+
+    encoded
+}
+
+/// Decode `%XX` escapes. Returns `None` for truncated or non-hex escapes, or if the result is not UTF-8.
+pub(crate) fn decode(s: &str) -> Option<String> {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        if bytes[idx] == b'%' {
+            // `from_str_radix` alone would accept a sign, such as `%+1`
+            let hex = s.get(idx + 1..idx + 3)?;
+            if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            idx += 3;
+        } else {
+            decoded.push(bytes[idx]);
+            idx += 1;
+        }
+    }
+
+    String::from_utf8(decoded).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode_component, encode_path};
+
+    #[test]
+    fn encode() {
+        assert_eq!(encode_path("a b/c+d:é"), "a%20b/c%2Bd%3A%C3%A9");
+        assert_eq!(encode_component("a b/c"), "a%20b%2Fc");
+    }
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(decode("a%20b/c%2bd%3A%C3%A9").unwrap(), "a b/c+d:é");
+    }
+
+    #[test]
+    fn decode_invalid() {
+        assert_eq!(decode("a%2"), None);
+        assert_eq!(decode("a%zz"), None);
+        assert_eq!(decode("%+1"), None);
+        assert_eq!(decode("key%+1.txt"), None);
+        assert_eq!(decode("%FF"), None);
+    }
+}
@@ -0,0 +1,234 @@
+//! Generating the `Resource` element of IAM policy statements
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::naive::NaiveArn;
+
+/// The `Resource` element of an IAM policy statement, built from a list of ARNs.
+///
+/// Duplicate ARNs are dropped, keeping the first occurrence. A single ARN renders as a string, several as an array.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::naive::NaiveArn;
+/// use arn::policy::ResourceElement;
+///
+/// let bucket = NaiveArn::parse("arn:aws:s3:::my_corporate_bucket").unwrap();
+/// let element = ResourceElement::new().s3_buckets_and_objects().arn(&bucket);
+///
+/// assert_eq!(
+///     element.to_string(),
+///     r#""Resource": ["arn:aws:s3:::my_corporate_bucket", "arn:aws:s3:::my_corporate_bucket/*"]"#
+/// );
+/// ~~~~
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResourceElement {
+    resources: Vec<String>,
+    widen_s3_objects: bool,
+    s3_buckets_and_objects: bool,
+}
+
+impl ResourceElement {
+    pub fn new() -> Self {
+        ResourceElement::default()
+    }
+
+    /// Treat S3 object ARNs as prefixes, granting access to every object under them: `bucket/logs` becomes
+    /// `bucket/logs/*`. Applies to ARNs added after this is set.
+    pub fn widen_s3_objects(mut self) -> Self {
+        self.widen_s3_objects = true;
+        self
+    }
+
+    /// Emit both the bucket ARN and the `bucket/*` object ARN for every S3 ARN, as most S3 permissions need both.
+    /// Applies to ARNs added after this is set.
+    pub fn s3_buckets_and_objects(mut self) -> Self {
+        self.s3_buckets_and_objects = true;
+        self
+    }
+
+    pub fn arn(mut self, arn: &NaiveArn) -> Self {
+        self.push(arn);
+        self
+    }
+
+    pub fn arns<'a, I>(mut self, arns: I) -> Self
+    where
+        I: IntoIterator<Item = NaiveArn<'a>>,
+    {
+        for arn in arns {
+            self.push(&arn);
+        }
+        self
+    }
+
+    /// The resources, in the order they will be rendered.
+    pub fn resources(&self) -> &[String] {
+        &self.resources
+    }
+
+    fn push(&mut self, arn: &NaiveArn) {
+        // `Some(None)` for S3 buckets, `Some(Some((bucket, key)))` for S3 objects
+        let s3_path = arn.to_s3_uri().map(|_| arn.resource.split_once('/'));
+
+        match s3_path {
+            Some(None) if self.s3_buckets_and_objects => {
+                self.insert(arn.to_string());
+                self.insert(format!("{}/*", arn));
+            }
+            Some(Some((bucket, key))) => {
+                if self.s3_buckets_and_objects {
+                    let bucket_arn = NaiveArn {
+                        resource: bucket,
+                        ..*arn
+                    };
+                    self.insert(bucket_arn.to_string());
+                }
+
+                if self.widen_s3_objects && !key.ends_with('*') {
+                    let separator = if key.ends_with('/') || key.is_empty() {
+                        ""
+                    } else {
+                        "/"
+                    };
+                    self.insert(format!("{}{}*", arn, separator));
+                } else {
+                    self.insert(arn.to_string());
+                }
+            }
+            _ => self.insert(arn.to_string()),
+        }
+    }
+
+    fn insert(&mut self, resource: String) {
+        if !self.resources.contains(&resource) {
+            self.resources.push(resource);
+        }
+    }
+}
+
+impl fmt::Display for ResourceElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("\"Resource\": ")?;
+
+        match self.resources.as_slice() {
+            [resource] => write_json_string(f, resource),
+            resources => {
+                f.write_str("[")?;
+                for (idx, resource) in resources.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write_json_string(f, resource)?;
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+fn write_json_string(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    f.write_str("\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResourceElement;
+    use crate::naive::NaiveArn;
+
+    fn arn(arn_str: &str) -> NaiveArn<'_> {
+        NaiveArn::parse(arn_str).unwrap()
+    }
+
+    #[test]
+    fn single_resource() {
+        let element =
+            ResourceElement::new().arn(&arn("arn:aws:sqs:us-east-1:123456789012:my-queue"));
+
+        assert_eq!(
+            element.to_string(),
+            r#""Resource": "arn:aws:sqs:us-east-1:123456789012:my-queue""#
+        );
+    }
+
+    #[test]
+    fn multiple_resources_deduplicated() {
+        let element = ResourceElement::new().arns(vec![
+            arn("arn:aws:sqs:us-east-1:123456789012:a"),
+            arn("arn:aws:sqs:us-east-1:123456789012:b"),
+            arn("arn:aws:sqs:us-east-1:123456789012:a"),
+        ]);
+
+        assert_eq!(
+            element.to_string(),
+            r#""Resource": ["arn:aws:sqs:us-east-1:123456789012:a", "arn:aws:sqs:us-east-1:123456789012:b"]"#
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(ResourceElement::new().to_string(), r#""Resource": []"#);
+    }
+
+    #[test]
+    fn widen_s3_objects() {
+        let element = ResourceElement::new().widen_s3_objects().arns(vec![
+            arn("arn:aws:s3:::bucket/logs"),
+            arn("arn:aws:s3:::bucket/data/"),
+            arn("arn:aws:s3:::bucket/reports/*"),
+            arn("arn:aws:s3:::bucket"),
+        ]);
+
+        assert_eq!(
+            element.resources(),
+            [
+                "arn:aws:s3:::bucket/logs/*",
+                "arn:aws:s3:::bucket/data/*",
+                "arn:aws:s3:::bucket/reports/*",
+                "arn:aws:s3:::bucket",
+            ]
+        );
+    }
+
+    #[test]
+    fn s3_buckets_and_objects() {
+        let element = ResourceElement::new()
+            .s3_buckets_and_objects()
+            .arns(vec![arn("arn:aws:s3:::a"), arn("arn:aws:s3:::b/key")]);
+
+        assert_eq!(
+            element.resources(),
+            [
+                "arn:aws:s3:::a",
+                "arn:aws:s3:::a/*",
+                "arn:aws:s3:::b",
+                "arn:aws:s3:::b/key",
+            ]
+        );
+    }
+
+    #[test]
+    fn json_escaping() {
+        let element = ResourceElement::new().arn(&arn(r#"arn:aws:s3:::bucket/"quoted"\key"#));
+
+        assert_eq!(
+            element.to_string(),
+            r#""Resource": "arn:aws:s3:::bucket/\"quoted\"\\key""#
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! Principals, as they appear in the `Principal` element of IAM policies
+
+use core::fmt;
+
+use crate::account::AccountId;
+use crate::naive::{NaiveArn, ParseNaiveArnError};
+
+/// What a principal string names.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::principal::PrincipalKind;
+///
+/// assert_eq!(PrincipalKind::parse("*"), Ok(PrincipalKind::Everyone));
+/// assert!(matches!(PrincipalKind::parse("123456789012"), Ok(PrincipalKind::Account(_))));
+/// assert_eq!(PrincipalKind::parse("ec2.amazonaws.com"), Ok(PrincipalKind::Service("ec2.amazonaws.com")));
+///
+/// let role = PrincipalKind::parse("arn:aws:iam::123456789012:role/Admin").unwrap();
+/// assert_eq!(role.account_id(), Some("123456789012"));
+/// assert_eq!(role.arn().unwrap().resource, "role/Admin");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrincipalKind<'a> {
+    /// `*`, anyone, including anonymous users.
+    Everyone,
+    /// A bare 12-digit account ID, short for the account's root ARN.
+    Account(AccountId<'a>),
+    /// An ARN, such as a role, user or account root.
+    Arn(NaiveArn<'a>),
+    /// An AWS service, such as `ec2.amazonaws.com`.
+    Service(&'a str),
+    /// The 64-hex-digit canonical user ID of an account or CloudFront origin access identity.
+    CanonicalUser(&'a str),
+}
+
+impl<'a> PrincipalKind<'a> {
+    /// Classify the principal `s`.
+    ///
+    /// Anything starting with `arn:` must be a valid ARN.
+    pub fn parse(s: &'a str) -> Result<Self, ParsePrincipalError> {
+        if s == "*" {
+            return Ok(PrincipalKind::Everyone);
+        }
+
+        if s.starts_with("arn:") {
+            return NaiveArn::parse(s)
+                .map(PrincipalKind::Arn)
+                .map_err(ParsePrincipalError::InvalidArn);
+        }
+
+        if let Ok(account_id) = AccountId::parse(s) {
+            return Ok(PrincipalKind::Account(account_id));
+        }
+
+        if s.len() == 64
+            && s.bytes()
+                .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+        {
+            return Ok(PrincipalKind::CanonicalUser(s));
+        }
+
+        if is_service(s) {
+            return Ok(PrincipalKind::Service(s));
+        }
+
+        Err(ParsePrincipalError::Unrecognized)
+    }
+
+    /// The ARN, for [`PrincipalKind::Arn`].
+    pub fn arn(&self) -> Option<NaiveArn<'a>> {
+        match self {
+            PrincipalKind::Arn(arn) => Some(*arn),
+            _ => None,
+        }
+    }
+
+    /// The account the principal is in, for account IDs and ARNs with an account ID.
+    pub fn account_id(&self) -> Option<&'a str> {
+        match self {
+            PrincipalKind::Account(account_id) => Some(account_id.as_str()),
+            PrincipalKind::Arn(arn) => arn.account_id,
+            _ => None,
+        }
+    }
+}
+
+/// Whether `s` looks like a service principal: lowercase DNS labels under `amazonaws.com` or `amazonaws.com.cn`.
+fn is_service(s: &str) -> bool {
+    let service = match s
+        .strip_suffix(".amazonaws.com")
+        .or_else(|| s.strip_suffix(".amazonaws.com.cn"))
+    {
+        Some(service) => service,
+        None => return false,
+    };
+
+    service.split('.').all(|label| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && label
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+    })
+}
+
+impl<'a> fmt::Display for PrincipalKind<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PrincipalKind::Everyone => f.write_str("*"),
+            PrincipalKind::Account(account_id) => account_id.fmt(f),
+            PrincipalKind::Arn(arn) => arn.fmt(f),
+            PrincipalKind::Service(service) => f.write_str(service),
+            PrincipalKind::CanonicalUser(id) => f.write_str(id),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParsePrincipalError {
+    /// The principal starts with `arn:` but isn't a valid ARN.
+    InvalidArn(ParseNaiveArnError),
+    /// The principal isn't any kind of principal.
+    Unrecognized,
+}
+
+impl fmt::Display for ParsePrincipalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParsePrincipalError::InvalidArn(err) => err.fmt(f),
+            ParsePrincipalError::Unrecognized => write!(f, "Unrecognized principal"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParsePrincipalError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParsePrincipalError::InvalidArn(err) => Some(err),
+            ParsePrincipalError::Unrecognized => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParsePrincipalError, PrincipalKind};
+    use crate::naive::NaiveArn;
+
+    #[test]
+    fn kinds() {
+        let canonical_user = "79a59df900b949e55d96a1e698fbacedfd6e09d98eacf8f8d5218e7cd47ef2be";
+
+        for (principal, expected) in [
+            ("*", PrincipalKind::Everyone),
+            (
+                "arn:aws:iam::123456789012:root",
+                PrincipalKind::Arn(NaiveArn::parse("arn:aws:iam::123456789012:root").unwrap()),
+            ),
+            (
+                "lambda.amazonaws.com",
+                PrincipalKind::Service("lambda.amazonaws.com"),
+            ),
+            (
+                "states.cn-north-1.amazonaws.com.cn",
+                PrincipalKind::Service("states.cn-north-1.amazonaws.com.cn"),
+            ),
+            (canonical_user, PrincipalKind::CanonicalUser(canonical_user)),
+        ] {
+            let kind = PrincipalKind::parse(principal).unwrap();
+
+            assert_eq!(kind, expected);
+            assert_eq!(kind.to_string(), principal);
+        }
+
+        let account = PrincipalKind::parse("123456789012").unwrap();
+        assert_eq!(account.account_id(), Some("123456789012"));
+        assert_eq!(account.arn(), None);
+    }
+
+    #[test]
+    fn unrecognized() {
+        for principal in [
+            "",
+            "**",
+            "12345678901",
+            "ec2.amazonaws.com.evil.example",
+            ".amazonaws.com",
+            "EC2.amazonaws.com",
+        ] {
+            assert_eq!(
+                PrincipalKind::parse(principal),
+                Err(ParsePrincipalError::Unrecognized),
+                "{}",
+                principal
+            );
+        }
+
+        assert!(matches!(
+            PrincipalKind::parse("arn:aws:iam::123456789012"),
+            Err(ParsePrincipalError::InvalidArn(_))
+        ));
+    }
+}
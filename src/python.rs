@@ -0,0 +1,249 @@
+//! Python bindings, as an extension module named `arn`
+//!
+//! Build the library as a `cdylib` with `cargo rustc --lib --release --features python --crate-type cdylib`, and
+//! install `libarn.so` as `arn.so` (or `arn.pyd` on Windows) somewhere on the Python path.
+//!
+//! ~~~~python
+//! import arn
+//!
+//! bucket = arn.Arn("arn:aws:s3:::my_corporate_bucket")
+//! assert arn.ArnPattern("arn:aws:s3:::my_*").matches(bucket)
+//!
+//! try:
+//!     arn.Arn("arn:aws:sqs:us east 1:123456789012:orders")
+//! except arn.ArnError as e:
+//!     assert e.code == "ARN007"
+//! ~~~~
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::buf::ArnBuf;
+use crate::naive::ParseNaiveArnError;
+use crate::pattern::ArnPattern;
+use crate::validate::{ValidationIssue, ValidationLevel};
+
+create_exception!(
+    arn,
+    ArnError,
+    PyValueError,
+    "An invalid ARN, with its stable `code` and, for parse errors, the byte `position` of the problem."
+);
+
+fn arn_error(py: Python<'_>, code: &str, message: String, position: Option<usize>) -> PyErr {
+    let err = ArnError::new_err(message);
+    let value = err.value(py);
+    // Setting attributes on a fresh exception instance can't fail.
+    let _ = value.setattr("code", code);
+    let _ = value.setattr("position", position);
+    err
+}
+
+fn parse_error(py: Python<'_>, err: ParseNaiveArnError) -> PyErr {
+    arn_error(py, err.code(), err.to_string(), Some(err.position()))
+}
+
+fn validation_error(py: Python<'_>, issue: ValidationIssue) -> PyErr {
+    arn_error(py, issue.code(), issue.to_string(), None)
+}
+
+fn validation_level(level: &str) -> PyResult<ValidationLevel> {
+    level
+        .parse()
+        .map_err(|err: crate::validate::ParseValidationLevelError| {
+            PyValueError::new_err(err.to_string())
+        })
+}
+
+/// An ARN, parsed from a string.
+#[pyclass(name = "Arn", module = "arn", frozen, eq, hash, skip_from_py_object)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PyArn(ArnBuf);
+
+#[pymethods]
+impl PyArn {
+    /// Parse `arn`, raising `ArnError` if it isn't one.
+    #[new]
+    fn new(py: Python<'_>, arn: &str) -> PyResult<Self> {
+        ArnBuf::parse(arn)
+            .map(PyArn)
+            .map_err(|err| parse_error(py, err))
+    }
+
+    #[getter]
+    fn partition(&self) -> &str {
+        &self.0.partition
+    }
+
+    #[getter]
+    fn service(&self) -> &str {
+        &self.0.service
+    }
+
+    #[getter]
+    fn region(&self) -> Option<&str> {
+        self.0.region.as_deref()
+    }
+
+    #[getter]
+    fn account_id(&self) -> Option<&str> {
+        self.0.account_id.as_deref()
+    }
+
+    #[getter]
+    fn resource(&self) -> &str {
+        &self.0.resource
+    }
+
+    /// The `(code, message)` of every issue found at `level`: `"syntax"`, `"standard"` or `"strict"`.
+    #[pyo3(signature = (level = "strict"))]
+    fn issues(&self, level: &str) -> PyResult<Vec<(&'static str, String)>> {
+        let issues = self
+            .0
+            .as_naive()
+            .validate(validation_level(level)?)
+            .err()
+            .unwrap_or_default();
+
+        Ok(issues
+            .into_iter()
+            .map(|issue| (issue.code(), issue.to_string()))
+            .collect())
+    }
+
+    /// Raise `ArnError` for the first issue found at `level`: `"syntax"`, `"standard"` or `"strict"`.
+    #[pyo3(signature = (level = "strict"))]
+    fn validate(&self, py: Python<'_>, level: &str) -> PyResult<()> {
+        match self.0.as_naive().first_issue(validation_level(level)?) {
+            Some(issue) => Err(validation_error(py, issue)),
+            None => Ok(()),
+        }
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Arn('{}')", self.0)
+    }
+}
+
+/// An ARN or a string to parse as one.
+#[derive(FromPyObject)]
+enum ArnLike<'py> {
+    Arn(PyRef<'py, PyArn>),
+    Str(String),
+}
+
+/// An IAM-style ARN pattern, such as `arn:aws:s3:::prod-*`.
+#[pyclass(
+    name = "ArnPattern",
+    module = "arn",
+    frozen,
+    eq,
+    hash,
+    skip_from_py_object
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PyArnPattern(ArnPattern);
+
+#[pymethods]
+impl PyArnPattern {
+    /// Parse `pattern`, raising `ArnError` if it isn't one.
+    #[new]
+    fn new(py: Python<'_>, pattern: &str) -> PyResult<Self> {
+        ArnPattern::parse(pattern)
+            .map(PyArnPattern)
+            .map_err(|err| parse_error(py, err))
+    }
+
+    /// Whether `arn`, an `Arn` or a string, matches this pattern. Strings that aren't ARNs don't match.
+    fn matches(&self, arn: ArnLike<'_>) -> bool {
+        match arn {
+            ArnLike::Arn(arn) => self.0.matches(&arn.0.as_naive()),
+            ArnLike::Str(arn) => {
+                ArnBuf::parse(&arn).is_ok_and(|arn| self.0.matches(&arn.as_naive()))
+            }
+        }
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ArnPattern('{}')", self.0)
+    }
+}
+
+/// The `arn` extension module.
+#[pymodule]
+#[pyo3(name = "arn")]
+fn arn_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyArn>()?;
+    m.add_class::<PyArnPattern>()?;
+    m.add("ArnError", m.py().get_type::<ArnError>())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::prelude::*;
+
+    use super::{ArnError, ArnLike, PyArn, PyArnPattern};
+
+    #[test]
+    fn arn() {
+        Python::initialize();
+        Python::attach(|py| {
+            let arn = PyArn::new(py, "arn:aws:sqs:us-east:123456789012:orders").unwrap();
+
+            assert_eq!(arn.region(), Some("us-east"));
+            assert_eq!(arn.issues("syntax").unwrap(), []);
+            assert_eq!(arn.issues("standard").unwrap()[0].0, "ARN202");
+            assert!(arn.issues("lenient").is_err());
+
+            let err = arn.validate(py, "strict").unwrap_err();
+            assert!(err.is_instance_of::<ArnError>(py));
+            assert_eq!(
+                err.value(py)
+                    .getattr("code")
+                    .unwrap()
+                    .extract::<String>()
+                    .unwrap(),
+                "ARN202"
+            );
+        });
+    }
+
+    #[test]
+    fn parse_error() {
+        Python::initialize();
+        Python::attach(|py| {
+            let err = PyArn::new(py, "arn:aws:s3").unwrap_err();
+            let position: Option<usize> = err
+                .value(py)
+                .getattr("position")
+                .unwrap()
+                .extract()
+                .unwrap();
+
+            assert_eq!(position, Some(10));
+        });
+    }
+
+    #[test]
+    fn pattern() {
+        Python::initialize();
+        Python::attach(|py| {
+            let pattern = PyArnPattern::new(py, "arn:aws:s3:::prod-*").unwrap();
+            let arn = Py::new(py, PyArn::new(py, "arn:aws:s3:::prod-logs").unwrap()).unwrap();
+
+            assert!(pattern.matches(ArnLike::Arn(arn.borrow(py))));
+            assert!(pattern.matches(ArnLike::Str("arn:aws:s3:::prod-logs".to_owned())));
+            assert!(!pattern.matches(ArnLike::Str("prod-logs".to_owned())));
+        });
+    }
+}
@@ -0,0 +1,218 @@
+//! Account ID redaction, for sharing logs and bug reports that contain ARNs
+
+use alloc::{borrow::ToOwned, string::String};
+#[cfg(feature = "pseudonymize")]
+use alloc::{format, vec::Vec};
+
+use crate::account::AccountId;
+use crate::buf::ArnBuf;
+use crate::naive::NaiveArn;
+
+const MASK: &str = "************";
+
+/// How account IDs are redacted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Redaction {
+    /// Replace the account ID with `************`.
+    Mask,
+
+    /// Replace the account ID with a 12-digit pseudonym derived from an HMAC-SHA256 of it under `key`.
+    ///
+    /// The same account ID always maps to the same pseudonym for a given key, so redacted ARNs from the same account
+    /// can still be correlated, while recovering the original ID requires the key.
+    #[cfg(feature = "pseudonymize")]
+    Hmac(Vec<u8>),
+}
+
+impl Redaction {
+    #[cfg_attr(not(feature = "pseudonymize"), allow(unused_variables))]
+    fn redact(&self, account_id: &str) -> String {
+        match self {
+            Redaction::Mask => MASK.to_owned(),
+            #[cfg(feature = "pseudonymize")]
+            Redaction::Hmac(key) => pseudonym(key, account_id),
+        }
+    }
+}
+
+#[cfg(feature = "pseudonymize")]
+fn pseudonym(key: &[u8], account_id: &str) -> String {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(account_id.as_bytes());
+    let digest = mac.finalize().into_bytes();
+
+    let mut prefix = [0; 8];
+    prefix.copy_from_slice(&digest[..8]);
+    format!("{:012}", u64::from_be_bytes(prefix) % 1_000_000_000_000)
+}
+
+impl<'a> NaiveArn<'a> {
+    /// This ARN with its account ID masked as `************`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let arn = NaiveArn::parse("arn:aws:iam::123456789012:role/my-role").unwrap();
+    /// assert_eq!(arn.redact_account().to_string(), "arn:aws:iam::************:role/my-role");
+    /// ~~~~
+    pub fn redact_account(&self) -> ArnBuf {
+        self.redact_account_with(&Redaction::Mask)
+    }
+
+    /// This ARN with its account ID redacted using `redaction`.
+    ///
+    /// Like [`scrub`], only 12-digit account IDs are redacted, so pseudo-accounts such as `aws` are kept.
+    pub fn redact_account_with(&self, redaction: &Redaction) -> ArnBuf {
+        let mut arn = ArnBuf::from(*self);
+        if let Some(account_id) = self.account_id {
+            if AccountId::parse(account_id).is_ok() {
+                arn.account_id = Some(redaction.redact(account_id));
+            }
+        }
+        arn
+    }
+}
+
+/// Redact the account IDs of every ARN in `text`, leaving everything else untouched.
+///
+/// Only 12-digit account IDs in the account component of `arn:`-prefixed strings are redacted; other
+/// account-component values (such as `aws` in AWS managed policy ARNs) are left as they are.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::redact::{scrub, Redaction};
+///
+/// let log = "AccessDenied: arn:aws:sts::123456789012:assumed-role/Admin/alice is not authorized";
+/// assert_eq!(
+///     scrub(log, &Redaction::Mask),
+///     "AccessDenied: arn:aws:sts::************:assumed-role/Admin/alice is not authorized"
+/// );
+/// ~~~~
+pub fn scrub(text: &str, redaction: &Redaction) -> String {
+    let mut scrubbed = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(idx) = rest.find("arn:") {
+        let (before, candidate) = rest.split_at(idx);
+        scrubbed.push_str(before);
+
+        match account_id_span(candidate) {
+            Some((start, end)) => {
+                scrubbed.push_str(&candidate[..start]);
+                scrubbed.push_str(&redaction.redact(&candidate[start..end]));
+                rest = &candidate[end..];
+            }
+            None => {
+                scrubbed.push_str("arn:");
+                rest = &candidate["arn:".len()..];
+            }
+        }
+    }
+
+    scrubbed.push_str(rest);
+    scrubbed
+}
+
+/// The byte range of the account ID in an `arn:partition:service:region:account-id:...` prefix of `s`.
+fn account_id_span(s: &str) -> Option<(usize, usize)> {
+    let mut start = 0;
+
+    // Skip the prefix, partition, service and region; none of them may contain whitespace
+    for _ in 0..4 {
+        let len = s[start..].find(|c: char| c == ':' || c.is_whitespace())?;
+        if !s[start + len..].starts_with(':') {
+            return None;
+        }
+        start += len + 1;
+    }
+
+    let end = start + 12;
+    match (s.get(start..end), s.get(end..end + 1)) {
+        (Some(account_id), Some(":")) if account_id.bytes().all(|b| b.is_ascii_digit()) => {
+            Some((start, end))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{scrub, Redaction};
+    use crate::naive::NaiveArn;
+
+    #[test]
+    fn redact_account() {
+        let arn = NaiveArn::parse("arn:aws:sqs:us-east-1:123456789012:my-queue").unwrap();
+
+        assert_eq!(
+            arn.redact_account().to_string(),
+            "arn:aws:sqs:us-east-1:************:my-queue"
+        );
+    }
+
+    #[test]
+    fn redact_no_account() {
+        let arn = NaiveArn::parse("arn:aws:s3:::my_corporate_bucket").unwrap();
+
+        assert_eq!(arn.redact_account().as_naive(), arn);
+    }
+
+    #[test]
+    fn redact_keeps_pseudo_account() {
+        let text = "arn:aws:iam::aws:policy/ReadOnlyAccess";
+        let arn = NaiveArn::parse(text).unwrap();
+
+        assert_eq!(arn.redact_account().to_string(), text);
+        assert_eq!(scrub(text, &Redaction::Mask), text);
+    }
+
+    #[test]
+    fn scrub_text() {
+        let text = r#"{"Principal": "arn:aws:iam::123456789012:root", "Resource": ["arn:aws:s3:::bucket", "arn:aws:sqs:us-east-1:210987654321:q"]}"#;
+
+        assert_eq!(
+            scrub(text, &Redaction::Mask),
+            r#"{"Principal": "arn:aws:iam::************:root", "Resource": ["arn:aws:s3:::bucket", "arn:aws:sqs:us-east-1:************:q"]}"#
+        );
+    }
+
+    #[test]
+    fn scrub_leaves_non_account_ids() {
+        for text in &[
+            "arn:aws:iam::aws:policy/AdministratorAccess",
+            "warn: 123456789012 arn: is a prefix",
+            "arn:aws:sqs:us-east-1:1234567890123:too-long",
+            "arn:aws:sqs us-east-1:123456789012:space",
+        ] {
+            assert_eq!(scrub(text, &Redaction::Mask), *text);
+        }
+    }
+
+    #[cfg(feature = "pseudonymize")]
+    #[test]
+    fn hmac_pseudonym() {
+        let arn = NaiveArn::parse("arn:aws:sqs:us-east-1:123456789012:my-queue").unwrap();
+
+        let first = arn.redact_account_with(&Redaction::Hmac(b"secret".to_vec()));
+        let second = arn.redact_account_with(&Redaction::Hmac(b"secret".to_vec()));
+        let other_key = arn.redact_account_with(&Redaction::Hmac(b"other".to_vec()));
+
+        let pseudonym = first.account_id.as_deref().unwrap();
+        assert_eq!(pseudonym.len(), 12);
+        assert!(pseudonym.bytes().all(|b| b.is_ascii_digit()));
+        assert_ne!(pseudonym, "123456789012");
+
+        assert_eq!(first, second);
+        assert_ne!(first, other_key);
+        assert_eq!(
+            scrub(&arn.to_string(), &Redaction::Hmac(b"secret".to_vec())),
+            first.to_string()
+        );
+    }
+}
@@ -0,0 +1,369 @@
+//! A typed set of regions
+
+use alloc::{borrow::ToOwned, string::String};
+use core::convert::Infallible;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::naive::NaiveArn;
+use crate::partition::Partition;
+
+/// An AWS region, such as `us-east-1`, carrying the partition it is in.
+///
+/// Every region in the [`regions`](Partition::regions) of a built-in partition has a variant, and any other region is
+/// kept in [`Region::Other`], so parsing never fails. Regions convert into the `String` an [`ArnBuf`](crate::buf::ArnBuf)
+/// holds, and a [`Validator`](crate::validator::Validator) can restrict ARNs to a set of them.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::naive::NaiveArn;
+/// use arn::partition::Partition;
+/// use arn::region::Region;
+///
+/// let arn = NaiveArn::parse("arn:aws-cn:sqs:cn-north-1:123456789012:orders").unwrap();
+/// let region = arn.known_region().unwrap();
+///
+/// assert_eq!(region, Region::CnNorth1);
+/// assert_eq!(region.partition(), Some(Partition::AwsCn));
+///
+/// let region: Region = "us-isox-east-1".parse().unwrap();
+/// assert_eq!(region, Region::Other("us-isox-east-1".to_owned()));
+/// ~~~~
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Region {
+    /// `af-south-1`
+    AfSouth1,
+    /// `ap-east-1`
+    ApEast1,
+    /// `ap-east-2`
+    ApEast2,
+    /// `ap-northeast-1`
+    ApNortheast1,
+    /// `ap-northeast-2`
+    ApNortheast2,
+    /// `ap-northeast-3`
+    ApNortheast3,
+    /// `ap-south-1`
+    ApSouth1,
+    /// `ap-south-2`
+    ApSouth2,
+    /// `ap-southeast-1`
+    ApSoutheast1,
+    /// `ap-southeast-2`
+    ApSoutheast2,
+    /// `ap-southeast-3`
+    ApSoutheast3,
+    /// `ap-southeast-4`
+    ApSoutheast4,
+    /// `ap-southeast-5`
+    ApSoutheast5,
+    /// `ap-southeast-7`
+    ApSoutheast7,
+    /// `ca-central-1`
+    CaCentral1,
+    /// `ca-west-1`
+    CaWest1,
+    /// `cn-north-1`
+    CnNorth1,
+    /// `cn-northwest-1`
+    CnNorthwest1,
+    /// `eu-central-1`
+    EuCentral1,
+    /// `eu-central-2`
+    EuCentral2,
+    /// `eu-isoe-west-1`
+    EuIsoeWest1,
+    /// `eu-north-1`
+    EuNorth1,
+    /// `eu-south-1`
+    EuSouth1,
+    /// `eu-south-2`
+    EuSouth2,
+    /// `eu-west-1`
+    EuWest1,
+    /// `eu-west-2`
+    EuWest2,
+    /// `eu-west-3`
+    EuWest3,
+    /// `eusc-de-east-1`
+    EuscDeEast1,
+    /// `il-central-1`
+    IlCentral1,
+    /// `me-central-1`
+    MeCentral1,
+    /// `me-south-1`
+    MeSouth1,
+    /// `mx-central-1`
+    MxCentral1,
+    /// `sa-east-1`
+    SaEast1,
+    /// `us-east-1`
+    UsEast1,
+    /// `us-east-2`
+    UsEast2,
+    /// `us-gov-east-1`
+    UsGovEast1,
+    /// `us-gov-west-1`
+    UsGovWest1,
+    /// `us-iso-east-1`
+    UsIsoEast1,
+    /// `us-iso-west-1`
+    UsIsoWest1,
+    /// `us-isob-east-1`
+    UsIsobEast1,
+    /// `us-isof-east-1`
+    UsIsofEast1,
+    /// `us-isof-south-1`
+    UsIsofSouth1,
+    /// `us-west-1`
+    UsWest1,
+    /// `us-west-2`
+    UsWest2,
+    /// A region without a variant of its own, such as a newly launched one.
+    ///
+    /// This only holds regions that are not otherwise known; [`Region::from`] never returns `Other("us-east-1")`.
+    Other(String),
+}
+
+impl Region {
+    /// Every region with a variant of its own, sorted by name.
+    pub const ALL: &'static [Region] = &[
+        Region::AfSouth1,
+        Region::ApEast1,
+        Region::ApEast2,
+        Region::ApNortheast1,
+        Region::ApNortheast2,
+        Region::ApNortheast3,
+        Region::ApSouth1,
+        Region::ApSouth2,
+        Region::ApSoutheast1,
+        Region::ApSoutheast2,
+        Region::ApSoutheast3,
+        Region::ApSoutheast4,
+        Region::ApSoutheast5,
+        Region::ApSoutheast7,
+        Region::CaCentral1,
+        Region::CaWest1,
+        Region::CnNorth1,
+        Region::CnNorthwest1,
+        Region::EuCentral1,
+        Region::EuCentral2,
+        Region::EuIsoeWest1,
+        Region::EuNorth1,
+        Region::EuSouth1,
+        Region::EuSouth2,
+        Region::EuWest1,
+        Region::EuWest2,
+        Region::EuWest3,
+        Region::EuscDeEast1,
+        Region::IlCentral1,
+        Region::MeCentral1,
+        Region::MeSouth1,
+        Region::MxCentral1,
+        Region::SaEast1,
+        Region::UsEast1,
+        Region::UsEast2,
+        Region::UsGovEast1,
+        Region::UsGovWest1,
+        Region::UsIsoEast1,
+        Region::UsIsoWest1,
+        Region::UsIsobEast1,
+        Region::UsIsofEast1,
+        Region::UsIsofSouth1,
+        Region::UsWest1,
+        Region::UsWest2,
+    ];
+
+    /// The region, as it appears in ARNs.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Region::AfSouth1 => "af-south-1",
+            Region::ApEast1 => "ap-east-1",
+            Region::ApEast2 => "ap-east-2",
+            Region::ApNortheast1 => "ap-northeast-1",
+            Region::ApNortheast2 => "ap-northeast-2",
+            Region::ApNortheast3 => "ap-northeast-3",
+            Region::ApSouth1 => "ap-south-1",
+            Region::ApSouth2 => "ap-south-2",
+            Region::ApSoutheast1 => "ap-southeast-1",
+            Region::ApSoutheast2 => "ap-southeast-2",
+            Region::ApSoutheast3 => "ap-southeast-3",
+            Region::ApSoutheast4 => "ap-southeast-4",
+            Region::ApSoutheast5 => "ap-southeast-5",
+            Region::ApSoutheast7 => "ap-southeast-7",
+            Region::CaCentral1 => "ca-central-1",
+            Region::CaWest1 => "ca-west-1",
+            Region::CnNorth1 => "cn-north-1",
+            Region::CnNorthwest1 => "cn-northwest-1",
+            Region::EuCentral1 => "eu-central-1",
+            Region::EuCentral2 => "eu-central-2",
+            Region::EuIsoeWest1 => "eu-isoe-west-1",
+            Region::EuNorth1 => "eu-north-1",
+            Region::EuSouth1 => "eu-south-1",
+            Region::EuSouth2 => "eu-south-2",
+            Region::EuWest1 => "eu-west-1",
+            Region::EuWest2 => "eu-west-2",
+            Region::EuWest3 => "eu-west-3",
+            Region::EuscDeEast1 => "eusc-de-east-1",
+            Region::IlCentral1 => "il-central-1",
+            Region::MeCentral1 => "me-central-1",
+            Region::MeSouth1 => "me-south-1",
+            Region::MxCentral1 => "mx-central-1",
+            Region::SaEast1 => "sa-east-1",
+            Region::UsEast1 => "us-east-1",
+            Region::UsEast2 => "us-east-2",
+            Region::UsGovEast1 => "us-gov-east-1",
+            Region::UsGovWest1 => "us-gov-west-1",
+            Region::UsIsoEast1 => "us-iso-east-1",
+            Region::UsIsoWest1 => "us-iso-west-1",
+            Region::UsIsobEast1 => "us-isob-east-1",
+            Region::UsIsofEast1 => "us-isof-east-1",
+            Region::UsIsofSouth1 => "us-isof-south-1",
+            Region::UsWest1 => "us-west-1",
+            Region::UsWest2 => "us-west-2",
+            Region::Other(region) => region,
+        }
+    }
+
+    /// The partition the region is in.
+    ///
+    /// For [`Region::Other`] this is [`Partition::of_region`], so it is `None` for regions that don't follow the naming
+    /// scheme of a known partition.
+    pub fn partition(&self) -> Option<Partition> {
+        match self {
+            Region::CnNorth1 | Region::CnNorthwest1 => Some(Partition::AwsCn),
+            Region::UsGovEast1 | Region::UsGovWest1 => Some(Partition::AwsUsGov),
+            Region::UsIsoEast1 | Region::UsIsoWest1 => Some(Partition::AwsIso),
+            Region::UsIsobEast1 => Some(Partition::AwsIsoB),
+            Region::EuIsoeWest1 => Some(Partition::AwsIsoE),
+            Region::UsIsofEast1 | Region::UsIsofSouth1 => Some(Partition::AwsIsoF),
+            Region::EuscDeEast1 => Some(Partition::AwsEusc),
+            Region::Other(region) => Partition::of_region(region),
+            _ => Some(Partition::Aws),
+        }
+    }
+
+    /// Whether this is a region with a variant of its own, rather than [`Region::Other`].
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Region::Other(_))
+    }
+}
+
+impl<'a> NaiveArn<'a> {
+    /// The ARN's region as a [`Region`], if it has one.
+    pub fn known_region(&self) -> Option<Region> {
+        self.region.map(Region::from)
+    }
+}
+
+impl From<&str> for Region {
+    fn from(region: &str) -> Self {
+        Region::ALL
+            .binary_search_by(|known| known.as_str().cmp(region))
+            .map(|idx| Region::ALL[idx].clone())
+            .unwrap_or_else(|_| Region::Other(region.to_owned()))
+    }
+}
+
+impl From<Region> for String {
+    fn from(region: Region) -> Self {
+        match region {
+            Region::Other(region) => region,
+            region => region.as_str().to_owned(),
+        }
+    }
+}
+
+impl FromStr for Region {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Region::from(s))
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq<str> for Region {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for Region {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Region;
+    use crate::buf::ArnBuf;
+    use crate::partition::Partition;
+
+    #[test]
+    fn round_trip() {
+        for region in Region::ALL {
+            assert_eq!(&Region::from(region.as_str()), region);
+            assert_eq!(region.to_string().parse::<Region>(), Ok(region.clone()));
+            assert_eq!(String::from(region.clone()), region.as_str());
+        }
+    }
+
+    #[test]
+    fn sorted() {
+        assert!(Region::ALL
+            .windows(2)
+            .all(|pair| pair[0].as_str() < pair[1].as_str()));
+    }
+
+    #[test]
+    fn matches_partitions() {
+        let listed: usize = Partition::ALL
+            .iter()
+            .map(|partition| partition.regions().len())
+            .sum();
+        assert_eq!(Region::ALL.len(), listed);
+
+        for region in Region::ALL {
+            assert_eq!(
+                region.partition(),
+                Partition::of_region(region.as_str()),
+                "{}",
+                region
+            );
+        }
+    }
+
+    #[test]
+    fn other() {
+        let region = Region::from("us-gov-north-1");
+
+        assert_eq!(region, Region::Other("us-gov-north-1".to_owned()));
+        assert_eq!(region, "us-gov-north-1");
+        assert_eq!(region.partition(), Some(Partition::AwsUsGov));
+        assert!(!region.is_known());
+        assert_eq!(Region::from("moon-base-1").partition(), None);
+    }
+
+    #[test]
+    fn build() {
+        let arn = ArnBuf {
+            partition: Region::EuWest1.partition().unwrap().to_string(),
+            service: "sqs".to_owned(),
+            region: Some(Region::EuWest1.into()),
+            account_id: Some("123456789012".to_owned()),
+            resource: "orders".to_owned(),
+        };
+
+        assert_eq!(arn.to_string(), "arn:aws:sqs:eu-west-1:123456789012:orders");
+        assert_eq!(arn.as_naive().known_region(), Some(Region::EuWest1));
+    }
+}
@@ -0,0 +1,583 @@
+//! Converting between full ARNs and the short identifiers that many AWS APIs accept, such as a function or bucket name
+
+use alloc::borrow::{Cow, ToOwned};
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::account::AccountId;
+use crate::buf::ArnBuf;
+use crate::context::Context;
+use crate::naive::{NaiveArn, ParseNaiveArnError};
+use crate::service::dynamodb::DynamoDbTableArn;
+use crate::service::ecr::EcrArn;
+use crate::service::kinesis::KinesisStreamArn;
+use crate::service::kms::{KeyIdentifier, KmsArn, KmsResource};
+use crate::service::lambda::FunctionArn;
+use crate::service::logs::LogGroupArn;
+use crate::service::s3::S3Arn;
+use crate::service::sns::SnsTopicArn;
+use crate::service::sqs::SqsArn;
+use crate::service::ssm::SsmParameterArn;
+use crate::service::states::StateMachineArn;
+use crate::service::{ParseServiceArnError, Service};
+use crate::validate::ValidationLevel;
+
+/// Expands short identifiers into full ARNs in a [`Context`].
+///
+/// | Service    | Short identifier                                             | Resource                          |
+/// |------------|--------------------------------------------------------------|-----------------------------------|
+/// | `dynamodb` | table name                                                   | `table/name`                      |
+/// | `ecr`      | repository name                                              | `repository/name`                 |
+/// | `kinesis`  | stream name                                                  | `stream/name`                     |
+/// | `kms`      | key ID or `alias/name`                                       | `key/id` or `alias/name`          |
+/// | `lambda`   | `name[:qualifier]` or `account-id:function:name[:qualifier]` | `function:name[:qualifier]`       |
+/// | `logs`     | log group name                                               | `log-group:name`                  |
+/// | `s3`       | bucket name                                                  | `name`, with no region or account |
+/// | `sns`      | topic name                                                   | `name`                            |
+/// | `sqs`      | queue name or queue URL                                      | `name`                            |
+/// | `ssm`      | parameter name, with or without a leading `/`                | `parameter/name`                  |
+/// | `states`   | state machine name                                           | `stateMachine:name`               |
+///
+/// The region and account come from the context, except where the identifier carries them (a queue URL, or a
+/// Lambda function name with an account ID). Expanded ARNs are validated like the service's typed ARN.
+/// [`NaiveArn::short_identifier`] goes the other way.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::context::Context;
+/// use arn::resolve::{Resolved, Resolver};
+/// use arn::service::Service;
+///
+/// let resolver = Resolver::new(Context::new("aws", "us-east-1", "123456789012"));
+///
+/// let resolved = resolver.resolve(&Service::Lambda, "my-function:PROD").unwrap();
+/// assert!(resolved.was_expanded());
+/// assert_eq!(resolved.arn().to_string(), "arn:aws:lambda:us-east-1:123456789012:function:my-function:PROD");
+///
+/// let resolved = resolver
+///     .resolve(&Service::Sqs, "https://sqs.eu-west-1.amazonaws.com/210987654321/orders")
+///     .unwrap();
+/// assert_eq!(resolved.arn().to_string(), "arn:aws:sqs:eu-west-1:210987654321:orders");
+///
+/// let resolved = resolver.resolve(&Service::S3, "arn:aws:s3:::my_corporate_bucket").unwrap();
+/// assert!(matches!(resolved, Resolved::Arn(_)));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Resolver<'a> {
+    context: Context<'a>,
+}
+
+impl<'a> Resolver<'a> {
+    pub fn new(context: Context<'a>) -> Self {
+        Resolver { context }
+    }
+
+    /// The ARN `identifier` refers to in `service`.
+    ///
+    /// An `identifier` that is already an ARN is returned as it is, as long as it is an ARN of `service`.
+    pub fn resolve(
+        &self,
+        service: &Service,
+        identifier: &str,
+    ) -> Result<Resolved, ResolveIdentifierError> {
+        if identifier.starts_with("arn:") {
+            let arn = NaiveArn::parse(identifier)?;
+            if *service != arn.service {
+                return Err(ResolveIdentifierError::WrongService);
+            }
+            return Ok(Resolved::Arn(arn.into()));
+        }
+
+        let arn = self.expand(service, identifier)?;
+        validate(service, arn.as_naive())?;
+
+        Ok(Resolved::Expanded(arn))
+    }
+
+    fn expand(
+        &self,
+        service: &Service,
+        identifier: &str,
+    ) -> Result<ArnBuf, ResolveIdentifierError> {
+        let resource = match service {
+            Service::DynamoDb => format!("table/{}", identifier),
+            Service::Ecr => format!("repository/{}", identifier),
+            Service::Kinesis => format!("stream/{}", identifier),
+            Service::Kms => {
+                return KeyIdentifier::parse(identifier)
+                    .map(|key| key.to_arn(&self.context))
+                    .map_err(|_| {
+                        ResolveIdentifierError::InvalidIdentifier(
+                            ParseServiceArnError::InvalidResource,
+                        )
+                    });
+            }
+            Service::Lambda => {
+                if let Some((account_id, function)) = identifier.split_once(":function:") {
+                    if AccountId::parse(account_id).is_err() {
+                        return Err(ResolveIdentifierError::InvalidIdentifier(
+                            ParseServiceArnError::InvalidResource,
+                        ));
+                    }
+                    return Ok(ArnBuf {
+                        account_id: Some(account_id.to_owned()),
+                        ..self.regional(service, format!("function:{}", function))
+                    });
+                }
+                format!("function:{}", identifier)
+            }
+            Service::Logs => format!("log-group:{}", identifier),
+            Service::S3 => {
+                return Ok(ArnBuf {
+                    partition: self.context.partition.to_owned(),
+                    service: service.as_str().to_owned(),
+                    region: None,
+                    account_id: None,
+                    resource: identifier.to_owned(),
+                })
+            }
+            Service::Sns => identifier.to_owned(),
+            Service::Sqs => match SqsArn::from_queue_url(identifier) {
+                Some(queue) => {
+                    return Ok(ArnBuf {
+                        partition: queue.partition.to_owned(),
+                        service: service.as_str().to_owned(),
+                        region: Some(queue.region.to_owned()),
+                        account_id: Some(queue.account_id.to_owned()),
+                        resource: queue.name.to_owned(),
+                    })
+                }
+                None => identifier.to_owned(),
+            },
+            Service::Ssm => format!(
+                "parameter/{}",
+                identifier.strip_prefix('/').unwrap_or(identifier)
+            ),
+            Service::States => format!("stateMachine:{}", identifier),
+            _ => return Err(ResolveIdentifierError::Unsupported),
+        };
+
+        Ok(self.regional(service, resource))
+    }
+
+    /// An ARN of `resource` in the context's partition, region and account.
+    fn regional(&self, service: &Service, resource: String) -> ArnBuf {
+        ArnBuf {
+            partition: self.context.partition.to_owned(),
+            service: service.as_str().to_owned(),
+            region: Some(self.context.region.to_owned()),
+            account_id: Some(self.context.account_id.to_owned()),
+            resource,
+        }
+    }
+}
+
+impl<'a> NaiveArn<'a> {
+    /// The identifier that SDK calls for this ARN's service take, which is often shorter than the ARN.
+    ///
+    /// This is the inverse of [`Resolver::resolve`] in the ARN's partition, region and account. Identifiers without an
+    /// account, such as a table name, only refer to this resource when the call is made in the ARN's account and
+    /// region.
+    ///
+    /// | Service    | Identifier                                                 |
+    /// |------------|------------------------------------------------------------|
+    /// | `dynamodb` | table name                                                 |
+    /// | `ecr`      | repository name                                            |
+    /// | `kinesis`  | stream name                                                |
+    /// | `kms`      | key ID or `alias/name`                                     |
+    /// | `lambda`   | `name[:qualifier]`                                         |
+    /// | `logs`     | log group name                                             |
+    /// | `s3`       | bucket name                                                |
+    /// | `sns`      | the ARN                                                    |
+    /// | `sqs`      | queue URL                                                  |
+    /// | `ssm`      | parameter name, with a leading `/` if it is hierarchical   |
+    /// | `states`   | the ARN                                                    |
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let short = |arn: &str| NaiveArn::parse(arn).unwrap().short_identifier().unwrap().into_owned();
+    ///
+    /// assert_eq!(short("arn:aws:lambda:us-east-1:123456789012:function:my-function:PROD"), "my-function:PROD");
+    /// assert_eq!(short("arn:aws:dynamodb:us-east-1:123456789012:table/Orders"), "Orders");
+    /// assert_eq!(
+    ///     short("arn:aws:sqs:us-east-1:123456789012:orders"),
+    ///     "https://sqs.us-east-1.amazonaws.com/123456789012/orders"
+    /// );
+    /// ~~~~
+    pub fn short_identifier(&self) -> Result<Cow<'a, str>, ResolveIdentifierError> {
+        let arn = *self;
+
+        let identifier = match Service::from(arn.service) {
+            Service::DynamoDb => DynamoDbTableArn::try_from(arn)?.name,
+            Service::Ecr => match EcrArn::try_from(arn)? {
+                EcrArn {
+                    repository,
+                    image: None,
+                    ..
+                } => repository,
+                _ => {
+                    return Err(ResolveIdentifierError::InvalidIdentifier(
+                        ParseServiceArnError::UnknownResourceType,
+                    ))
+                }
+            },
+            Service::Kinesis => KinesisStreamArn::try_from(arn)?.name,
+            Service::Kms => match KmsArn::try_from(arn)?.resource {
+                KmsResource::Key(id) => id,
+                KmsResource::Alias(_) => arn.resource,
+            },
+            Service::Lambda => {
+                FunctionArn::try_from(arn)?;
+                &arn.resource["function:".len()..]
+            }
+            Service::Logs => LogGroupArn::try_from(arn)?.name,
+            Service::S3 => match S3Arn::try_from(arn)? {
+                S3Arn {
+                    bucket, key: None, ..
+                } => bucket,
+                _ => {
+                    return Err(ResolveIdentifierError::InvalidIdentifier(
+                        ParseServiceArnError::UnknownResourceType,
+                    ))
+                }
+            },
+            Service::Sns => return Ok(Cow::Owned(SnsTopicArn::try_from(arn)?.to_string())),
+            Service::Sqs => {
+                return SqsArn::try_from(arn)?
+                    .queue_url()
+                    .map(Cow::Owned)
+                    .ok_or(ResolveIdentifierError::Unsupported)
+            }
+            Service::Ssm => {
+                return Ok(Cow::Owned(SsmParameterArn::try_from(arn)?.parameter_name()))
+            }
+            Service::States => return Ok(Cow::Owned(StateMachineArn::try_from(arn)?.to_string())),
+            _ => return Err(ResolveIdentifierError::Unsupported),
+        };
+
+        Ok(Cow::Borrowed(identifier))
+    }
+}
+
+/// Check that an expanded `arn` is valid for `service`.
+fn validate(service: &Service, arn: NaiveArn) -> Result<(), ParseServiceArnError> {
+    if arn.first_issue(ValidationLevel::Syntax).is_some() {
+        return Err(ParseServiceArnError::InvalidResource);
+    }
+
+    match service {
+        Service::DynamoDb => DynamoDbTableArn::try_from(arn).map(drop),
+        Service::Ecr => EcrArn::try_from(arn).map(drop),
+        Service::Kinesis => KinesisStreamArn::try_from(arn).map(drop),
+        Service::Lambda => FunctionArn::try_from(arn).map(drop),
+        Service::Logs => LogGroupArn::try_from(arn).map(drop),
+        Service::S3 => S3Arn::try_from(arn).map(drop),
+        Service::Sns => SnsTopicArn::try_from(arn).map(drop),
+        Service::Sqs => SqsArn::try_from(arn).map(drop),
+        Service::Ssm => SsmParameterArn::try_from(arn).map(drop),
+        Service::States => StateMachineArn::try_from(arn).map(drop),
+        _ => Ok(()),
+    }
+}
+
+/// The result of [`Resolver::resolve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resolved {
+    /// The identifier was already an ARN.
+    Arn(ArnBuf),
+    /// The identifier was a short identifier, expanded into this ARN.
+    Expanded(ArnBuf),
+}
+
+impl Resolved {
+    pub fn arn(&self) -> &ArnBuf {
+        match self {
+            Resolved::Arn(arn) | Resolved::Expanded(arn) => arn,
+        }
+    }
+
+    pub fn into_arn(self) -> ArnBuf {
+        match self {
+            Resolved::Arn(arn) | Resolved::Expanded(arn) => arn,
+        }
+    }
+
+    /// Whether the identifier was a short identifier, rather than an ARN.
+    pub fn was_expanded(&self) -> bool {
+        matches!(self, Resolved::Expanded(_))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ResolveIdentifierError {
+    /// The identifier starts with `arn:` but isn't a valid ARN.
+    Parse(ParseNaiveArnError),
+    /// The identifier is an ARN of a different service.
+    WrongService,
+    /// The short identifiers of the service aren't known, or for an SQS queue URL, the partition isn't known.
+    Unsupported,
+    /// The identifier doesn't expand into a valid ARN of the service.
+    InvalidIdentifier(ParseServiceArnError),
+}
+
+impl From<ParseNaiveArnError> for ResolveIdentifierError {
+    fn from(err: ParseNaiveArnError) -> Self {
+        ResolveIdentifierError::Parse(err)
+    }
+}
+
+impl From<ParseServiceArnError> for ResolveIdentifierError {
+    fn from(err: ParseServiceArnError) -> Self {
+        ResolveIdentifierError::InvalidIdentifier(err)
+    }
+}
+
+impl fmt::Display for ResolveIdentifierError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ResolveIdentifierError::Parse(err) => err.fmt(f),
+            ResolveIdentifierError::WrongService => write!(f, "ARN is for a different service"),
+            ResolveIdentifierError::Unsupported => {
+                write!(f, "Short identifiers aren't supported for this service")
+            }
+            ResolveIdentifierError::InvalidIdentifier(err) => {
+                write!(f, "Invalid identifier: {}", err)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ResolveIdentifierError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ResolveIdentifierError::Parse(err) => Some(err),
+            ResolveIdentifierError::InvalidIdentifier(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ResolveIdentifierError, Resolved, Resolver};
+    use crate::context::Context;
+    use crate::naive::NaiveArn;
+    use crate::service::{ParseServiceArnError, Service};
+
+    const CONTEXT: Context<'static> = Context {
+        partition: "aws",
+        region: "us-east-1",
+        account_id: "123456789012",
+    };
+
+    fn resolve(service: Service, identifier: &str) -> Result<String, ResolveIdentifierError> {
+        Resolver::new(CONTEXT)
+            .resolve(&service, identifier)
+            .map(|resolved| resolved.arn().to_string())
+    }
+
+    #[test]
+    fn expand() {
+        for (service, identifier, arn) in [
+            (
+                Service::DynamoDb,
+                "Orders",
+                "arn:aws:dynamodb:us-east-1:123456789012:table/Orders",
+            ),
+            (
+                Service::Ecr,
+                "team/app",
+                "arn:aws:ecr:us-east-1:123456789012:repository/team/app",
+            ),
+            (
+                Service::Kinesis,
+                "clicks",
+                "arn:aws:kinesis:us-east-1:123456789012:stream/clicks",
+            ),
+            (
+                Service::Kms,
+                "alias/app",
+                "arn:aws:kms:us-east-1:123456789012:alias/app",
+            ),
+            (
+                Service::Lambda,
+                "my-function",
+                "arn:aws:lambda:us-east-1:123456789012:function:my-function",
+            ),
+            (
+                Service::Lambda,
+                "210987654321:function:my-function:3",
+                "arn:aws:lambda:us-east-1:210987654321:function:my-function:3",
+            ),
+            (
+                Service::Logs,
+                "/aws/lambda/my-function",
+                "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/my-function",
+            ),
+            (
+                Service::S3,
+                "my_corporate_bucket",
+                "arn:aws:s3:::my_corporate_bucket",
+            ),
+            (
+                Service::Sns,
+                "alerts",
+                "arn:aws:sns:us-east-1:123456789012:alerts",
+            ),
+            (
+                Service::Sqs,
+                "orders.fifo",
+                "arn:aws:sqs:us-east-1:123456789012:orders.fifo",
+            ),
+            (
+                Service::Ssm,
+                "/app/db/password",
+                "arn:aws:ssm:us-east-1:123456789012:parameter/app/db/password",
+            ),
+            (
+                Service::Ssm,
+                "password",
+                "arn:aws:ssm:us-east-1:123456789012:parameter/password",
+            ),
+            (
+                Service::States,
+                "checkout",
+                "arn:aws:states:us-east-1:123456789012:stateMachine:checkout",
+            ),
+        ] {
+            assert_eq!(resolve(service, identifier).unwrap(), arn);
+        }
+    }
+
+    #[test]
+    fn already_an_arn() {
+        let arn = "arn:aws:lambda:eu-west-1:210987654321:function:my-function";
+        let resolved = Resolver::new(CONTEXT)
+            .resolve(&Service::Lambda, arn)
+            .unwrap();
+
+        assert!(!resolved.was_expanded());
+        assert_eq!(resolved, Resolved::Arn(arn.parse().unwrap()));
+        assert_eq!(
+            resolve(Service::Sqs, arn),
+            Err(ResolveIdentifierError::WrongService)
+        );
+        assert!(matches!(
+            resolve(Service::Lambda, "arn:aws:lambda"),
+            Err(ResolveIdentifierError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            resolve(Service::Sqs, "my.queue"),
+            Err(ResolveIdentifierError::InvalidIdentifier(
+                ParseServiceArnError::InvalidResource
+            ))
+        );
+        assert!(resolve(Service::Lambda, "1234:function:my-function").is_err());
+        assert!(resolve(Service::Kms, "not-a-key").is_err());
+        assert!(resolve(Service::Sns, "").is_err());
+        assert_eq!(
+            resolve(Service::SecretsManager, "db-password"),
+            Err(ResolveIdentifierError::Unsupported)
+        );
+    }
+
+    #[test]
+    fn short_identifier_round_trip() {
+        for (arn, identifier) in [
+            (
+                "arn:aws:dynamodb:us-east-1:123456789012:table/Orders",
+                "Orders",
+            ),
+            (
+                "arn:aws:ecr:us-east-1:123456789012:repository/team/app",
+                "team/app",
+            ),
+            (
+                "arn:aws:kinesis:us-east-1:123456789012:stream/clicks",
+                "clicks",
+            ),
+            (
+                "arn:aws:kms:us-east-1:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab",
+                "1234abcd-12ab-34cd-56ef-1234567890ab",
+            ),
+            ("arn:aws:kms:us-east-1:123456789012:alias/app", "alias/app"),
+            (
+                "arn:aws:lambda:us-east-1:123456789012:function:my-function:3",
+                "my-function:3",
+            ),
+            (
+                "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/my-function",
+                "/aws/lambda/my-function",
+            ),
+            ("arn:aws:s3:::my_corporate_bucket", "my_corporate_bucket"),
+            (
+                "arn:aws:sns:us-east-1:123456789012:alerts",
+                "arn:aws:sns:us-east-1:123456789012:alerts",
+            ),
+            (
+                "arn:aws:sqs:us-east-1:123456789012:orders",
+                "https://sqs.us-east-1.amazonaws.com/123456789012/orders",
+            ),
+            (
+                "arn:aws:ssm:us-east-1:123456789012:parameter/app/db/password",
+                "/app/db/password",
+            ),
+            (
+                "arn:aws:ssm:us-east-1:123456789012:parameter/password",
+                "password",
+            ),
+            (
+                "arn:aws:states:us-east-1:123456789012:stateMachine:checkout",
+                "arn:aws:states:us-east-1:123456789012:stateMachine:checkout",
+            ),
+        ] {
+            let parsed = NaiveArn::parse(arn).unwrap();
+            let short = parsed.short_identifier().unwrap();
+
+            assert_eq!(short, identifier);
+            assert_eq!(
+                resolve(parsed.known_service(), &short).unwrap(),
+                arn,
+                "{}",
+                arn
+            );
+        }
+    }
+
+    #[test]
+    fn no_short_identifier() {
+        let short = |arn: &'static str| NaiveArn::parse(arn).unwrap().short_identifier();
+
+        assert_eq!(
+            short("arn:aws:s3:::my_corporate_bucket/key"),
+            Err(ResolveIdentifierError::InvalidIdentifier(
+                ParseServiceArnError::UnknownResourceType
+            ))
+        );
+        assert_eq!(
+            short("arn:aws:lambda:us-east-1:123456789012:layer:my-layer:1"),
+            Err(ResolveIdentifierError::InvalidIdentifier(
+                ParseServiceArnError::UnknownResourceType
+            ))
+        );
+        assert_eq!(
+            short("arn:aws-moon:sqs:moon-1:123456789012:orders"),
+            Err(ResolveIdentifierError::Unsupported)
+        );
+        assert_eq!(
+            short("arn:aws:iam::123456789012:role/Admin"),
+            Err(ResolveIdentifierError::Unsupported)
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//! Rule-based ARN rewriting, for promoting resources between accounts, regions and environments
+
+use regex::Regex;
+
+use crate::buf::ArnBuf;
+use crate::naive::NaiveArn;
+use crate::pattern::ArnPattern;
+
+/// An ordered list of [`Rule`]s applied to ARNs.
+///
+/// Every rule whose pattern matches is applied, in order, and each rule sees the ARN as rewritten by the rules before
+/// it. ARNs that match no rule are returned unchanged.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::naive::NaiveArn;
+/// use arn::pattern::ArnPattern;
+/// use arn::rewrite::{ArnRewriter, Rule};
+/// use regex::Regex;
+///
+/// let rewriter = ArnRewriter::new()
+///     .rule(Rule::new(ArnPattern::parse("arn:aws:*:*:111111111111:*").unwrap()).set_account_id("222222222222"))
+///     .rule(
+///         Rule::new(ArnPattern::parse("arn:aws:s3:::*").unwrap())
+///             .replace_resource(Regex::new("^dev-([^/]+)").unwrap(), "prod-$1"),
+///     );
+///
+/// let arn = NaiveArn::parse("arn:aws:sqs:us-east-1:111111111111:dev-queue").unwrap();
+/// assert_eq!(rewriter.rewrite(&arn).to_string(), "arn:aws:sqs:us-east-1:222222222222:dev-queue");
+///
+/// let arn = NaiveArn::parse("arn:aws:s3:::dev-assets/logo.png").unwrap();
+/// assert_eq!(rewriter.rewrite(&arn).to_string(), "arn:aws:s3:::prod-assets/logo.png");
+/// ~~~~
+#[derive(Debug, Clone, Default)]
+pub struct ArnRewriter {
+    rules: Vec<Rule>,
+}
+
+impl ArnRewriter {
+    pub fn new() -> Self {
+        ArnRewriter::default()
+    }
+
+    /// Append a rule, to be applied after the existing ones.
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Apply the rules to `arn`.
+    pub fn rewrite(&self, arn: &NaiveArn) -> ArnBuf {
+        let mut arn = ArnBuf::from(*arn);
+
+        for rule in &self.rules {
+            if rule.pattern.matches(&arn.as_naive()) {
+                rule.apply(&mut arn);
+            }
+        }
+
+        arn
+    }
+
+    /// Apply the rules to every ARN in an inventory.
+    pub fn rewrite_all<'a, I>(&self, arns: I) -> Vec<ArnBuf>
+    where
+        I: IntoIterator<Item = NaiveArn<'a>>,
+    {
+        arns.into_iter().map(|arn| self.rewrite(&arn)).collect()
+    }
+}
+
+/// A rewrite applied to ARNs that match a pattern.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pattern: ArnPattern,
+    partition: Option<String>,
+    service: Option<String>,
+    region: Option<Option<String>>,
+    account_id: Option<Option<String>>,
+    resource: Vec<ResourceRewrite>,
+}
+
+#[derive(Debug, Clone)]
+enum ResourceRewrite {
+    Set(String),
+    Replace(Regex, String),
+}
+
+impl Rule {
+    /// A rule applying to ARNs that match `pattern`, which changes nothing until actions are added.
+    pub fn new(pattern: ArnPattern) -> Self {
+        Rule {
+            pattern,
+            partition: None,
+            service: None,
+            region: None,
+            account_id: None,
+            resource: Vec::new(),
+        }
+    }
+
+    pub fn set_partition(mut self, partition: impl Into<String>) -> Self {
+        self.partition = Some(partition.into());
+        self
+    }
+
+    pub fn set_service(mut self, service: impl Into<String>) -> Self {
+        self.service = Some(service.into());
+        self
+    }
+
+    pub fn set_region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(Some(region.into()));
+        self
+    }
+
+    /// Remove the region, as for global resources.
+    pub fn clear_region(mut self) -> Self {
+        self.region = Some(None);
+        self
+    }
+
+    pub fn set_account_id(mut self, account_id: impl Into<String>) -> Self {
+        self.account_id = Some(Some(account_id.into()));
+        self
+    }
+
+    /// Remove the account ID, as for S3 buckets.
+    pub fn clear_account_id(mut self) -> Self {
+        self.account_id = Some(None);
+        self
+    }
+
+    /// Replace the whole resource.
+    pub fn set_resource(mut self, resource: impl Into<String>) -> Self {
+        self.resource.push(ResourceRewrite::Set(resource.into()));
+        self
+    }
+
+    /// Replace the first match of `regex` in the resource with `replacement`, which may refer to capture groups as
+    /// described in [`Regex::replace`].
+    pub fn replace_resource(mut self, regex: Regex, replacement: impl Into<String>) -> Self {
+        self.resource
+            .push(ResourceRewrite::Replace(regex, replacement.into()));
+        self
+    }
+
+    fn apply(&self, arn: &mut ArnBuf) {
+        if let Some(partition) = &self.partition {
+            arn.partition = partition.clone();
+        }
+
+        if let Some(service) = &self.service {
+            arn.service = service.clone();
+        }
+
+        if let Some(region) = &self.region {
+            arn.region = region.clone();
+        }
+
+        if let Some(account_id) = &self.account_id {
+            arn.account_id = account_id.clone();
+        }
+
+        for rewrite in &self.resource {
+            arn.resource = match rewrite {
+                ResourceRewrite::Set(resource) => resource.clone(),
+                ResourceRewrite::Replace(regex, replacement) => regex
+                    .replace(&arn.resource, replacement.as_str())
+                    .into_owned(),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::{ArnRewriter, Rule};
+    use crate::naive::NaiveArn;
+    use crate::pattern::ArnPattern;
+
+    fn rule(pattern: &str) -> Rule {
+        Rule::new(ArnPattern::parse(pattern).unwrap())
+    }
+
+    fn rewrite(rewriter: &ArnRewriter, arn_str: &str) -> String {
+        rewriter
+            .rewrite(&NaiveArn::parse(arn_str).unwrap())
+            .to_string()
+    }
+
+    #[test]
+    fn no_matching_rule() {
+        let rewriter = ArnRewriter::new().rule(rule("arn:aws:s3:::*").set_resource("other"));
+        let arn_str = "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98";
+
+        assert_eq!(rewrite(&rewriter, arn_str), arn_str);
+    }
+
+    #[test]
+    fn rules_apply_in_order() {
+        let rewriter = ArnRewriter::new()
+            .rule(rule("arn:aws:*:us-east-1:*:*").set_region("eu-west-1"))
+            .rule(rule("arn:aws:*:eu-west-1:111111111111:*").set_account_id("222222222222"))
+            .rule(rule("arn:aws:*:*:111111111111:*").set_account_id("333333333333"));
+
+        assert_eq!(
+            rewrite(&rewriter, "arn:aws:sqs:us-east-1:111111111111:my-queue"),
+            "arn:aws:sqs:eu-west-1:222222222222:my-queue"
+        );
+    }
+
+    #[test]
+    fn regex_captures() {
+        let rewriter =
+            ArnRewriter::new().rule(rule("arn:aws:lambda:*:*:function:*").replace_resource(
+                Regex::new(r"^function:(\w+)-dev$").unwrap(),
+                "function:$1-prod",
+            ));
+
+        assert_eq!(
+            rewrite(
+                &rewriter,
+                "arn:aws:lambda:us-east-1:123456789012:function:checkout-dev"
+            ),
+            "arn:aws:lambda:us-east-1:123456789012:function:checkout-prod"
+        );
+    }
+
+    #[test]
+    fn cross_partition() {
+        let rewriter = ArnRewriter::new().rule(
+            rule("arn:aws:*:us-east-1:*:*")
+                .set_partition("aws-cn")
+                .set_region("cn-north-1"),
+        );
+
+        assert_eq!(
+            rewrite(
+                &rewriter,
+                "arn:aws:sns:us-east-1:123456789012:my_corporate_topic"
+            ),
+            "arn:aws-cn:sns:cn-north-1:123456789012:my_corporate_topic"
+        );
+    }
+
+    #[test]
+    fn clear_components() {
+        let rewriter = ArnRewriter::new().rule(
+            rule("arn:aws:s3:*:*:accesspoint/*")
+                .clear_region()
+                .clear_account_id()
+                .set_resource("my_corporate_bucket"),
+        );
+
+        assert_eq!(
+            rewrite(
+                &rewriter,
+                "arn:aws:s3:us-west-2:123456789012:accesspoint/test"
+            ),
+            "arn:aws:s3:::my_corporate_bucket"
+        );
+    }
+
+    #[test]
+    fn inventory() {
+        let rewriter = ArnRewriter::new().rule(rule("*").set_account_id("222222222222"));
+        let inventory = [
+            "arn:aws:sqs:us-east-1:111111111111:a",
+            "arn:aws:sqs:us-east-1:111111111111:b",
+        ];
+
+        let rewritten: Vec<String> = rewriter
+            .rewrite_all(inventory.iter().map(|s| NaiveArn::parse(s).unwrap()))
+            .iter()
+            .map(|arn| arn.to_string())
+            .collect();
+
+        assert_eq!(
+            rewritten,
+            vec![
+                "arn:aws:sqs:us-east-1:222222222222:a",
+                "arn:aws:sqs:us-east-1:222222222222:b"
+            ]
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! `serde` support, serializing ARNs as strings
+//!
+//! Deserializing checks the syntax of an ARN, as [`NaiveArn::parse`] does; wrap the target in one of the
+//! [`validated`](crate::validated) types to validate it further.
+
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::buf::ArnBuf;
+use crate::naive::NaiveArn;
+
+impl<'a> Serialize for NaiveArn<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl Serialize for ArnBuf {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes a [`NaiveArn`] borrowed from the input, which fails for inputs that have to be unescaped.
+impl<'de: 'a, 'a> Deserialize<'de> for NaiveArn<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <&'de str>::deserialize(deserializer)?;
+        NaiveArn::parse(s).map_err(de::Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for ArnBuf {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ArnBufVisitor)
+    }
+}
+
+struct ArnBufVisitor;
+
+impl<'de> Visitor<'de> for ArnBufVisitor {
+    type Value = ArnBuf;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an ARN")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<ArnBuf, E> {
+        ArnBuf::parse(s).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::value::{BorrowedStrDeserializer, Error};
+    use serde::Deserialize;
+
+    use crate::buf::ArnBuf;
+    use crate::naive::NaiveArn;
+
+    fn deserializer(s: &str) -> BorrowedStrDeserializer<'_, Error> {
+        BorrowedStrDeserializer::new(s)
+    }
+
+    #[test]
+    fn deserialize() {
+        let arn_str = "arn:aws:s3:::my_corporate_bucket";
+
+        assert_eq!(
+            ArnBuf::deserialize(deserializer(arn_str)).unwrap(),
+            ArnBuf::parse(arn_str).unwrap()
+        );
+        assert_eq!(
+            NaiveArn::deserialize(deserializer(arn_str)).unwrap(),
+            NaiveArn::parse(arn_str).unwrap()
+        );
+
+        let err = ArnBuf::deserialize(deserializer("aws:s3:::my_corporate_bucket")).unwrap_err();
+        assert_eq!(err.to_string(), "ARN002: Missing 'arn:' prefix");
+    }
+}
@@ -0,0 +1,345 @@
+//! AWS Certificate Manager (`arn:partition:acm:region:account-id:certificate/certificate-id`) ARNs
+
+use alloc::{borrow::ToOwned, format};
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::buf::ArnBuf;
+use crate::naive::NaiveArn;
+use crate::service::{is_uuid, regional, ParseServiceArnError};
+
+/// An ACM certificate ARN
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::acm::CertificateArn;
+///
+/// let arn = CertificateArn::parse("arn:aws:acm:us-east-1:123456789012:certificate/12345678-1234-1234-1234-123456789012").unwrap();
+/// assert_eq!(arn.id, "12345678-1234-1234-1234-123456789012");
+/// assert!(arn.is_cloudfront_compatible());
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CertificateArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    /// The certificate ID, a UUID.
+    pub id: &'a str,
+}
+
+impl<'a> CertificateArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        CertificateArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// Whether `other` is the same certificate, comparing the ID case-insensitively.
+    ///
+    /// Managed renewals keep the certificate's ARN, so a renewed certificate is still the same certificate.
+    pub fn is_same_certificate(&self, other: &CertificateArn) -> bool {
+        self.can_replace(other) && self.id.eq_ignore_ascii_case(other.id)
+    }
+
+    /// Whether this certificate can stand in for `other` in a load balancer or API configuration: re-issued
+    /// certificates get a new ID, and can only be attached where the original was if they are in the same partition,
+    /// region and account.
+    pub fn can_replace(&self, other: &CertificateArn) -> bool {
+        self.partition == other.partition
+            && self.region == other.region
+            && self.account_id == other.account_id
+    }
+
+    /// Whether CloudFront can use this certificate, which must be in `us-east-1`.
+    pub fn is_cloudfront_compatible(&self) -> bool {
+        self.region == "us-east-1"
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for CertificateArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "acm" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let id = arn
+            .resource
+            .strip_prefix("certificate/")
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+
+        if !is_uuid(id) {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        Ok(CertificateArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            id,
+        })
+    }
+}
+
+impl<'a> fmt::Display for CertificateArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:acm:{}:{}:certificate/{}",
+            self.partition, self.region, self.account_id, self.id
+        )
+    }
+}
+
+/// Where a certificate ARN comes from.
+///
+/// Before ACM, certificates for load balancers and CloudFront were uploaded to IAM as server certificates, and older
+/// configurations still refer to them by their IAM ARN. IAM server certificates are global and named; ACM
+/// certificates are regional and have a UUID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArnFormat {
+    /// `arn:partition:iam::account-id:server-certificate/path/name`
+    IamServerCertificate,
+    /// `arn:partition:acm:region:account-id:certificate/certificate-id`
+    Acm,
+}
+
+/// Detect whether a certificate ARN is an IAM server certificate or an ACM certificate.
+///
+/// Returns `None` for other ARNs.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::naive::NaiveArn;
+/// use arn::service::acm::{format_of, ArnFormat};
+///
+/// let arn = NaiveArn::parse("arn:aws:iam::123456789012:server-certificate/ProdServerCert").unwrap();
+/// assert_eq!(format_of(&arn), Some(ArnFormat::IamServerCertificate));
+/// ~~~~
+pub fn format_of(arn: &NaiveArn) -> Option<ArnFormat> {
+    if server_certificate_name(arn).is_some() {
+        Some(ArnFormat::IamServerCertificate)
+    } else if CertificateArn::try_from(*arn).is_ok() {
+        Some(ArnFormat::Acm)
+    } else {
+        None
+    }
+}
+
+/// Convert an IAM server certificate ARN to the ARN of the ACM certificate that replaced it, given the ACM
+/// certificate's region and ID.
+///
+/// The ID can't be derived from the IAM ARN, so it has to come from the import or request that created the ACM
+/// certificate. ACM ARNs are returned unchanged.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::naive::NaiveArn;
+/// use arn::service::acm::to_acm;
+///
+/// let arn = NaiveArn::parse("arn:aws:iam::123456789012:server-certificate/ProdServerCert").unwrap();
+/// assert_eq!(
+///     to_acm(&arn, "us-east-1", "12345678-1234-1234-1234-123456789012").unwrap().to_string(),
+///     "arn:aws:acm:us-east-1:123456789012:certificate/12345678-1234-1234-1234-123456789012"
+/// );
+/// ~~~~
+pub fn to_acm(arn: &NaiveArn, region: &str, id: &str) -> Option<ArnBuf> {
+    match format_of(arn)? {
+        ArnFormat::Acm => Some(ArnBuf::from(*arn)),
+        ArnFormat::IamServerCertificate if is_uuid(id) && !region.is_empty() => Some(ArnBuf {
+            partition: arn.partition.to_owned(),
+            service: "acm".to_owned(),
+            region: Some(region.to_owned()),
+            account_id: arn.account_id.map(str::to_owned),
+            resource: format!("certificate/{}", id),
+        }),
+        ArnFormat::IamServerCertificate => None,
+    }
+}
+
+/// Convert an ACM certificate ARN to the ARN of the IAM server certificate it was exported to or imported from,
+/// given the server certificate's name (optionally with a path, as `/path/name`).
+///
+/// IAM server certificate ARNs are returned unchanged.
+pub fn to_iam_server_certificate(arn: &NaiveArn, name: &str) -> Option<ArnBuf> {
+    match format_of(arn)? {
+        ArnFormat::IamServerCertificate => Some(ArnBuf::from(*arn)),
+        ArnFormat::Acm => {
+            let name = name.trim_start_matches('/');
+            if name.is_empty() || name.ends_with('/') {
+                return None;
+            }
+
+            Some(ArnBuf {
+                partition: arn.partition.to_owned(),
+                service: "iam".to_owned(),
+                region: None,
+                account_id: arn.account_id.map(str::to_owned),
+                resource: format!("server-certificate/{}", name),
+            })
+        }
+    }
+}
+
+/// The name of an IAM server certificate, without its path.
+pub fn server_certificate_name<'a>(arn: &NaiveArn<'a>) -> Option<&'a str> {
+    if arn.service != "iam" || arn.region.is_some() || arn.account_id.is_none() {
+        return None;
+    }
+
+    arn.resource
+        .strip_prefix("server-certificate/")?
+        .rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        format_of, server_certificate_name, to_acm, to_iam_server_certificate, ArnFormat,
+        CertificateArn,
+    };
+    use crate::naive::NaiveArn;
+    use crate::service::ParseServiceArnError;
+
+    const CERTIFICATE: &str =
+        "arn:aws:acm:eu-west-1:123456789012:certificate/12345678-1234-1234-1234-123456789012";
+    const SERVER_CERTIFICATE: &str =
+        "arn:aws:iam::123456789012:server-certificate/division_abc/ProdServerCert";
+
+    #[test]
+    fn certificate() {
+        let arn = CertificateArn::parse(CERTIFICATE).unwrap();
+
+        assert_eq!(
+            arn,
+            CertificateArn {
+                partition: "aws",
+                region: "eu-west-1",
+                account_id: "123456789012",
+                id: "12345678-1234-1234-1234-123456789012",
+            }
+        );
+        assert!(!arn.is_cloudfront_compatible());
+        assert_eq!(arn.to_string(), CERTIFICATE);
+    }
+
+    #[test]
+    fn comparison() {
+        let arn = CertificateArn::parse(CERTIFICATE).unwrap();
+        let upper = CertificateArn {
+            id: "12345678-1234-1234-1234-1234567890AB",
+            ..arn
+        };
+        let reissued = CertificateArn {
+            id: "87654321-4321-4321-4321-210987654321",
+            ..arn
+        };
+        let other_region = CertificateArn {
+            region: "us-east-1",
+            ..arn
+        };
+
+        assert!(arn.is_same_certificate(&arn));
+        assert!(!arn.is_same_certificate(&upper));
+        assert!(upper.is_same_certificate(&CertificateArn {
+            id: "12345678-1234-1234-1234-1234567890ab",
+            ..arn
+        }));
+        assert!(!arn.is_same_certificate(&reissued));
+        assert!(reissued.can_replace(&arn));
+        assert!(!other_region.can_replace(&arn));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            CertificateArn::parse("arn:aws:acm:eu-west-1:123456789012:certificate/12345678"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            CertificateArn::parse("arn:aws:acm:eu-west-1:123456789012:certificate/12345678-1234-1234-1234-12345678901z"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            CertificateArn::parse(
+                "arn:aws:acm:eu-west-1:123456789012:12345678-1234-1234-1234-123456789012"
+            ),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+
+    #[test]
+    fn detection() {
+        let acm = NaiveArn::parse(CERTIFICATE).unwrap();
+        let iam = NaiveArn::parse(SERVER_CERTIFICATE).unwrap();
+
+        assert_eq!(format_of(&acm), Some(ArnFormat::Acm));
+        assert_eq!(format_of(&iam), Some(ArnFormat::IamServerCertificate));
+        assert_eq!(server_certificate_name(&iam), Some("ProdServerCert"));
+        assert_eq!(server_certificate_name(&acm), None);
+
+        for arn_str in &[
+            "arn:aws:iam::123456789012:role/ProdServerCert",
+            "arn:aws:iam::123456789012:server-certificate/",
+            "arn:aws:acm:eu-west-1:123456789012:certificate/12345678",
+        ] {
+            assert_eq!(
+                format_of(&NaiveArn::parse(arn_str).unwrap()),
+                None,
+                "{}",
+                arn_str
+            );
+        }
+    }
+
+    #[test]
+    fn upgrade() {
+        let iam = NaiveArn::parse(SERVER_CERTIFICATE).unwrap();
+        let acm = NaiveArn::parse(CERTIFICATE).unwrap();
+
+        assert_eq!(
+            to_acm(&iam, "eu-west-1", "12345678-1234-1234-1234-123456789012")
+                .unwrap()
+                .to_string(),
+            CERTIFICATE
+        );
+        assert_eq!(to_acm(&iam, "eu-west-1", "ProdServerCert"), None);
+        assert_eq!(
+            to_acm(&acm, "us-east-1", "87654321-4321-4321-4321-210987654321")
+                .unwrap()
+                .to_string(),
+            CERTIFICATE
+        );
+    }
+
+    #[test]
+    fn downgrade() {
+        let iam = NaiveArn::parse(SERVER_CERTIFICATE).unwrap();
+        let acm = NaiveArn::parse(CERTIFICATE).unwrap();
+
+        assert_eq!(
+            to_iam_server_certificate(&acm, "/division_abc/ProdServerCert")
+                .unwrap()
+                .to_string(),
+            SERVER_CERTIFICATE
+        );
+        assert_eq!(
+            to_iam_server_certificate(&acm, "ProdServerCert")
+                .unwrap()
+                .to_string(),
+            "arn:aws:iam::123456789012:server-certificate/ProdServerCert"
+        );
+        assert_eq!(to_iam_server_certificate(&acm, "/"), None);
+        assert_eq!(
+            to_iam_server_certificate(&iam, "Other")
+                .unwrap()
+                .to_string(),
+            SERVER_CERTIFICATE
+        );
+    }
+}
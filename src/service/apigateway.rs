@@ -0,0 +1,533 @@
+//! Amazon API Gateway (`arn:partition:execute-api:region:account-id:api-id/stage/method/path`) helpers
+
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+};
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::buf::ArnBuf;
+use crate::naive::NaiveArn;
+use crate::partition::{self, Partition};
+use crate::pattern;
+use crate::percent;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// An `execute-api` ARN, `api-id/stage/method/path`, as used to grant permission to invoke an API
+///
+/// In IAM and Lambda authorizer policies any part may be a `*` wildcard, and trailing parts may be left off
+/// entirely, e.g. `api-id/*`.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::apigateway::ExecuteApiArn;
+///
+/// let policy = ExecuteApiArn::parse("arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h/prod/GET/pets/*").unwrap();
+/// let request = ExecuteApiArn::parse("arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h/prod/GET/pets/42").unwrap();
+///
+/// assert_eq!(request.stage, Some("prod"));
+/// assert_eq!(request.method, Some("GET"));
+/// assert_eq!(request.path, Some("pets/42"));
+/// assert!(policy.is_wildcard());
+/// assert!(policy.matches(&request));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExecuteApiArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub api_id: &'a str,
+    pub stage: Option<&'a str>,
+    /// The HTTP method, or the route key for WebSocket APIs, e.g. `$connect`.
+    pub method: Option<&'a str>,
+    /// The resource path, without its leading `/`.
+    pub path: Option<&'a str>,
+}
+
+impl<'a> ExecuteApiArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        ExecuteApiArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// Whether any part of the ARN is a wildcard.
+    pub fn is_wildcard(&self) -> bool {
+        self.to_string().contains('*')
+    }
+
+    /// Whether this ARN, treated as a policy resource where `*` matches anything (including `/`), grants access to
+    /// `request`.
+    pub fn matches(&self, request: &ExecuteApiArn) -> bool {
+        let (pattern, text) = (self.to_string(), request.to_string());
+
+        // Unlike in `ArnPattern`s, `?` is not a wildcard in API Gateway policies
+        if pattern.contains('?') {
+            return pattern == text;
+        }
+
+        pattern::glob_match(&pattern, &text)
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for ExecuteApiArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "execute-api" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let mut parts = arn.resource.splitn(4, '/');
+        let api_id = non_empty(parts.next().unwrap_or_default())?;
+        let stage = parts.next().map(non_empty).transpose()?;
+        let method = parts.next().map(non_empty).transpose()?;
+        let path = parts.next();
+
+        Ok(ExecuteApiArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            api_id,
+            stage,
+            method,
+            path,
+        })
+    }
+}
+
+impl<'a> fmt::Display for ExecuteApiArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:execute-api:{}:{}:{}",
+            self.partition, self.region, self.account_id, self.api_id
+        )?;
+
+        for part in [self.stage, self.method, self.path].iter().flatten() {
+            write!(f, "/{}", part)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An `apigateway` management ARN, whose resource is a path like `/restapis/api-id/stages/prod`
+///
+/// These ARNs have no account ID.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::apigateway::ApiGatewayArn;
+///
+/// let arn = ApiGatewayArn::parse("arn:aws:apigateway:us-east-1::/restapis/8kjmp19d1h/stages/prod").unwrap();
+/// assert_eq!(arn.api_id(), Some("8kjmp19d1h"));
+/// assert_eq!(arn.segments().collect::<Vec<_>>(), vec!["restapis", "8kjmp19d1h", "stages", "prod"]);
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ApiGatewayArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    /// The resource path, including its leading `/`.
+    pub path: &'a str,
+}
+
+impl<'a> ApiGatewayArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        ApiGatewayArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The path segments, e.g. `restapis`, `api-id`, `stages`, `prod`.
+    pub fn segments(&self) -> impl Iterator<Item = &'a str> + 'a {
+        self.path[1..].split('/')
+    }
+
+    /// The REST (`/restapis/id`) or HTTP and WebSocket (`/apis/id`) API ID, if the path is under an API.
+    pub fn api_id(&self) -> Option<&'a str> {
+        let mut segments = self.segments();
+
+        match (segments.next(), segments.next()) {
+            (Some("restapis"), Some(id)) | (Some("apis"), Some(id)) if !id.is_empty() => Some(id),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for ApiGatewayArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "apigateway" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let region = arn.region.ok_or(ParseServiceArnError::MissingRegion)?;
+
+        if arn.account_id.is_some() {
+            return Err(ParseServiceArnError::UnexpectedAccountId);
+        }
+
+        if !arn.resource.starts_with('/') || arn.resource.len() == 1 {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        Ok(ApiGatewayArn {
+            partition: arn.partition,
+            region,
+            path: arn.resource,
+        })
+    }
+}
+
+impl<'a> fmt::Display for ApiGatewayArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:apigateway:{}::{}",
+            self.partition, self.region, self.path
+        )
+    }
+}
+
+impl<'a> NaiveArn<'a> {
+    /// Render an `execute-api` ARN for a concrete stage and path as the API's default invoke URL.
+    ///
+    /// The HTTP method is not part of the URL, so it is dropped. Returns `None` if this is not an `execute-api` ARN,
+    /// if the stage or path contain wildcards, or if the partition is not known.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let arn = NaiveArn::parse("arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h/prod/GET/pets/42").unwrap();
+    /// assert_eq!(
+    ///     arn.to_invoke_url().unwrap(),
+    ///     "https://8kjmp19d1h.execute-api.us-east-1.amazonaws.com/prod/pets/42"
+    /// );
+    /// ~~~~
+    pub fn to_invoke_url(&self) -> Option<String> {
+        if self.service != "execute-api" {
+            return None;
+        }
+
+        let region = self.region?;
+        let dns_suffix = self.known_partition()?.dns_suffix();
+
+        let mut parts = self.resource.splitn(4, '/');
+        let (api_id, stage, _method, path) =
+            (parts.next()?, parts.next()?, parts.next()?, parts.next());
+        let path = path.unwrap_or_default();
+
+        if api_id.is_empty()
+            || stage.is_empty()
+            || api_id.contains('*')
+            || stage.contains('*')
+            || path.contains('*')
+        {
+            return None;
+        }
+
+        Some(format!(
+            "https://{}.execute-api.{}.{}/{}/{}",
+            api_id,
+            region,
+            dns_suffix,
+            stage,
+            percent::encode_path(path)
+        ))
+    }
+}
+
+impl ArnBuf {
+    /// Parse an API's default invoke URL into the `execute-api` ARN for a request made with `method`.
+    ///
+    /// Invoke URLs identify neither the account nor the method, so both must be supplied. The query string and
+    /// fragment are ignored.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::buf::ArnBuf;
+    ///
+    /// let arn = ArnBuf::from_invoke_url(
+    ///     "https://8kjmp19d1h.execute-api.us-east-1.amazonaws.com/prod/pets/42?verbose=true",
+    ///     "GET",
+    ///     "123456789012",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(arn.to_string(), "arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h/prod/GET/pets/42");
+    /// ~~~~
+    pub fn from_invoke_url(
+        url: &str,
+        method: &str,
+        account_id: &str,
+    ) -> Result<Self, ParseInvokeUrlError> {
+        let rest = url
+            .strip_prefix("https://")
+            .ok_or(ParseInvokeUrlError::UnsupportedScheme)?;
+
+        let rest = match rest.find(['?', '#']) {
+            Some(idx) => &rest[..idx],
+            None => rest,
+        };
+
+        let (host, path) = match rest.find('/') {
+            Some(idx) => (&rest[..idx], &rest[idx + 1..]),
+            None => (rest, ""),
+        };
+
+        let (prefix, dns_suffix) =
+            partition::split_host(host).ok_or(ParseInvokeUrlError::UnknownHost)?;
+        let (api_id, region) = prefix
+            .split_once(".execute-api.")
+            .filter(|(api_id, region)| !api_id.is_empty() && !region.is_empty())
+            .ok_or(ParseInvokeUrlError::UnknownHost)?;
+        let partition = Partition::from_dns_suffix(dns_suffix, Some(region))
+            .ok_or(ParseInvokeUrlError::UnknownHost)?;
+
+        let path = percent::decode(path).ok_or(ParseInvokeUrlError::InvalidEncoding)?;
+        let (stage, path) = match path.split_once('/') {
+            Some((stage, path)) => (stage, path),
+            None => (path.as_str(), ""),
+        };
+
+        if stage.is_empty() {
+            return Err(ParseInvokeUrlError::MissingStage);
+        }
+
+        Ok(ArnBuf {
+            partition: partition.id().to_owned(),
+            service: "execute-api".to_owned(),
+            region: Some(region.to_owned()),
+            account_id: Some(account_id.to_owned()),
+            resource: format!("{}/{}/{}/{}", api_id, stage, method, path),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseInvokeUrlError {
+    UnsupportedScheme,
+    UnknownHost,
+    MissingStage,
+    InvalidEncoding,
+}
+
+impl fmt::Display for ParseInvokeUrlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseInvokeUrlError::UnsupportedScheme => write!(f, "Expected an 'https://' URL"),
+            ParseInvokeUrlError::UnknownHost => write!(f, "Host is not an execute-api endpoint"),
+            ParseInvokeUrlError::MissingStage => write!(f, "Missing stage"),
+            ParseInvokeUrlError::InvalidEncoding => write!(f, "Invalid percent-encoding in path"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseInvokeUrlError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{ApiGatewayArn, ExecuteApiArn, ParseInvokeUrlError};
+    use crate::buf::ArnBuf;
+    use crate::naive::NaiveArn;
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn round_trip() {
+        let arn_str = "arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h/prod/POST/pets/my pet";
+        let url = NaiveArn::parse(arn_str).unwrap().to_invoke_url().unwrap();
+
+        assert_eq!(
+            url,
+            "https://8kjmp19d1h.execute-api.us-east-1.amazonaws.com/prod/pets/my%20pet"
+        );
+        assert_eq!(
+            ArnBuf::from_invoke_url(&url, "POST", "123456789012")
+                .unwrap()
+                .to_string(),
+            arn_str
+        );
+    }
+
+    #[test]
+    fn stage_root() {
+        let arn_str = "arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h/prod/GET/";
+        let url = NaiveArn::parse(arn_str).unwrap().to_invoke_url().unwrap();
+
+        assert_eq!(
+            url,
+            "https://8kjmp19d1h.execute-api.us-east-1.amazonaws.com/prod/"
+        );
+        assert_eq!(
+            ArnBuf::from_invoke_url(&url, "GET", "123456789012")
+                .unwrap()
+                .to_string(),
+            arn_str
+        );
+    }
+
+    #[test]
+    fn other_partitions() {
+        let arn = ArnBuf::from_invoke_url(
+            "https://8kjmp19d1h.execute-api.cn-north-1.amazonaws.com.cn/prod/pets",
+            "GET",
+            "123456789012",
+        )
+        .unwrap();
+        assert_eq!(arn.partition, "aws-cn");
+
+        let arn = ArnBuf::from_invoke_url(
+            "https://8kjmp19d1h.execute-api.us-gov-west-1.amazonaws.com/prod/pets",
+            "GET",
+            "123456789012",
+        )
+        .unwrap();
+        assert_eq!(arn.partition, "aws-us-gov");
+    }
+
+    #[test]
+    fn wildcards() {
+        let arn =
+            NaiveArn::parse("arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h/*/*/*").unwrap();
+
+        assert_eq!(arn.to_invoke_url(), None);
+    }
+
+    #[test]
+    fn not_execute_api() {
+        let arn = NaiveArn::parse("arn:aws:apigateway:us-east-1::/restapis/8kjmp19d1h").unwrap();
+
+        assert_eq!(arn.to_invoke_url(), None);
+    }
+
+    #[test]
+    fn malformed_urls() {
+        assert_eq!(
+            ArnBuf::from_invoke_url(
+                "http://8kjmp19d1h.execute-api.us-east-1.amazonaws.com/prod",
+                "GET",
+                "123456789012"
+            ),
+            Err(ParseInvokeUrlError::UnsupportedScheme)
+        );
+        assert_eq!(
+            ArnBuf::from_invoke_url("https://api.example.com/prod", "GET", "123456789012"),
+            Err(ParseInvokeUrlError::UnknownHost)
+        );
+        assert_eq!(
+            ArnBuf::from_invoke_url(
+                "https://sqs.us-east-1.amazonaws.com/prod",
+                "GET",
+                "123456789012"
+            ),
+            Err(ParseInvokeUrlError::UnknownHost)
+        );
+        assert_eq!(
+            ArnBuf::from_invoke_url(
+                "https://8kjmp19d1h.execute-api.us-east-1.amazonaws.com/",
+                "GET",
+                "123456789012"
+            ),
+            Err(ParseInvokeUrlError::MissingStage)
+        );
+    }
+
+    #[test]
+    fn typed_execute_api() {
+        let arn_str =
+            "arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h/prod/POST/pets/42/toys";
+        let arn = ExecuteApiArn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn,
+            ExecuteApiArn {
+                partition: "aws",
+                region: "us-east-1",
+                account_id: "123456789012",
+                api_id: "8kjmp19d1h",
+                stage: Some("prod"),
+                method: Some("POST"),
+                path: Some("pets/42/toys"),
+            }
+        );
+        assert!(!arn.is_wildcard());
+        assert_eq!(arn.to_string(), arn_str);
+
+        let arn_str = "arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h/*";
+        let arn = ExecuteApiArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.stage, Some("*"));
+        assert_eq!(arn.method, None);
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn execute_api_wildcards() {
+        let request = ExecuteApiArn::parse(
+            "arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h/prod/GET/pets/42",
+        )
+        .unwrap();
+        let matches = |policy: &str| ExecuteApiArn::parse(policy).unwrap().matches(&request);
+
+        assert!(matches(
+            "arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h/*"
+        ));
+        assert!(matches(
+            "arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h/*/GET/*"
+        ));
+        assert!(matches("arn:aws:execute-api:*:*:8kjmp19d1h/prod/*/pets/*"));
+        assert!(matches(
+            "arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h/prod/GET/pets/42"
+        ));
+        assert!(!matches(
+            "arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h/dev/*"
+        ));
+        assert!(!matches(
+            "arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h/prod/POST/*"
+        ));
+        assert!(!matches(
+            "arn:aws:execute-api:us-east-1:123456789012:a1b2c3d4e5/*"
+        ));
+    }
+
+    #[test]
+    fn typed_apigateway() {
+        let arn_str =
+            "arn:aws:apigateway:us-east-1::/restapis/8kjmp19d1h/resources/a1b2c3/methods/GET";
+        let arn = ApiGatewayArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.region, "us-east-1");
+        assert_eq!(arn.api_id(), Some("8kjmp19d1h"));
+        assert_eq!(arn.segments().count(), 6);
+        assert_eq!(arn.to_string(), arn_str);
+
+        let arn = ApiGatewayArn::parse("arn:aws:apigateway:us-east-1::/apis/a1b2c3d4e5").unwrap();
+        assert_eq!(arn.api_id(), Some("a1b2c3d4e5"));
+
+        let arn = ApiGatewayArn::parse("arn:aws:apigateway:us-east-1::/apikeys/abc123").unwrap();
+        assert_eq!(arn.api_id(), None);
+    }
+
+    #[test]
+    fn typed_invalid() {
+        assert_eq!(
+            ExecuteApiArn::parse("arn:aws:execute-api:us-east-1:123456789012:8kjmp19d1h//GET"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            ApiGatewayArn::parse("arn:aws:apigateway:us-east-1:123456789012:/restapis/8kjmp19d1h"),
+            Err(ParseServiceArnError::UnexpectedAccountId)
+        );
+        assert_eq!(
+            ApiGatewayArn::parse("arn:aws:apigateway:us-east-1::restapis/8kjmp19d1h"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+    }
+}
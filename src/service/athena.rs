@@ -0,0 +1,148 @@
+//! Amazon Athena (`arn:partition:athena:region:account-id:workgroup/name`) ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// The resource an [`AthenaArn`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AthenaResource<'a> {
+    /// `workgroup/name`
+    WorkGroup { name: &'a str },
+    /// `datacatalog/name`
+    DataCatalog { name: &'a str },
+}
+
+/// An Athena ARN
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::athena::{AthenaArn, AthenaResource};
+///
+/// let arn = AthenaArn::parse("arn:aws:athena:us-east-1:123456789012:workgroup/primary").unwrap();
+/// assert_eq!(arn.resource, AthenaResource::WorkGroup { name: "primary" });
+/// assert_eq!(arn.name(), "primary");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AthenaArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub resource: AthenaResource<'a>,
+}
+
+impl<'a> AthenaArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        AthenaArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The work group's or data catalog's name.
+    pub fn name(&self) -> &'a str {
+        match self.resource {
+            AthenaResource::WorkGroup { name } | AthenaResource::DataCatalog { name } => name,
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for AthenaArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "athena" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let (resource_type, name) = arn
+            .resource
+            .split_once('/')
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+
+        let resource = match resource_type {
+            "workgroup" => AthenaResource::WorkGroup {
+                name: non_empty(name)?,
+            },
+            "datacatalog" => AthenaResource::DataCatalog {
+                name: non_empty(name)?,
+            },
+            _ => return Err(ParseServiceArnError::UnknownResourceType),
+        };
+
+        if name.contains('/') {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        Ok(AthenaArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            resource,
+        })
+    }
+}
+
+impl<'a> fmt::Display for AthenaArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:athena:{}:{}:",
+            self.partition, self.region, self.account_id
+        )?;
+
+        match self.resource {
+            AthenaResource::WorkGroup { name } => write!(f, "workgroup/{}", name),
+            AthenaResource::DataCatalog { name } => write!(f, "datacatalog/{}", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AthenaArn, AthenaResource};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn resources() {
+        for (arn_str, resource) in &[
+            (
+                "arn:aws:athena:us-east-1:123456789012:workgroup/primary",
+                AthenaResource::WorkGroup { name: "primary" },
+            ),
+            (
+                "arn:aws:athena:us-east-1:123456789012:datacatalog/AwsDataCatalog",
+                AthenaResource::DataCatalog {
+                    name: "AwsDataCatalog",
+                },
+            ),
+        ] {
+            let arn = AthenaArn::parse(arn_str).unwrap();
+
+            assert_eq!(arn.resource, *resource);
+            assert_eq!(arn.to_string(), *arn_str);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:athena:us-east-1:123456789012:{}", resource);
+            AthenaArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(
+            parse("workgroup/"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("workgroup/primary/extra"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("capacity-reservation/reserved"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! AWS Backup (`arn:partition:backup:region:account-id:backup-vault:name`) ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// The resource a [`BackupArn`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BackupResource<'a> {
+    /// `backup-vault:name`
+    Vault { name: &'a str },
+    /// `recovery-point:id`
+    RecoveryPoint { id: &'a str },
+    /// `backup-plan:id`
+    Plan { id: &'a str },
+}
+
+/// An AWS Backup ARN
+///
+/// Recovery points of services without their own snapshot type (EFS, DynamoDB with advanced features, ...) have
+/// `backup` ARNs. Others, such as EBS snapshots, are identified by the owning service's ARN instead.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::backup::{BackupArn, BackupResource};
+///
+/// let arn = BackupArn::parse("arn:aws:backup:us-east-1:123456789012:recovery-point:1EB3B5E7-9EB0-435A-A80B-108B488B0D45").unwrap();
+/// assert_eq!(arn.resource, BackupResource::RecoveryPoint { id: "1EB3B5E7-9EB0-435A-A80B-108B488B0D45" });
+/// assert_eq!(
+///     arn.vault_arn("prod").to_string(),
+///     "arn:aws:backup:us-east-1:123456789012:backup-vault:prod"
+/// );
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BackupArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub resource: BackupResource<'a>,
+}
+
+impl<'a> BackupArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        BackupArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The vault's name, if this is a vault ARN.
+    pub fn vault_name(&self) -> Option<&'a str> {
+        match self.resource {
+            BackupResource::Vault { name } => Some(name),
+            _ => None,
+        }
+    }
+
+    /// The ARN of the vault `name` in this ARN's account and region.
+    ///
+    /// Recovery point ARNs don't record their vault, but a recovery point is always stored in a vault in the same
+    /// account and region, so this builds the ARN to join against once the vault name is known (e.g. from
+    /// `ListRecoveryPointsByBackupVault`).
+    pub fn vault_arn(&self, name: &'a str) -> BackupArn<'a> {
+        BackupArn {
+            resource: BackupResource::Vault { name },
+            ..*self
+        }
+    }
+
+    /// Whether `vault` could hold this recovery point: it is a vault in the same partition, region and account.
+    pub fn could_be_in(&self, vault: &BackupArn) -> bool {
+        matches!(self.resource, BackupResource::RecoveryPoint { .. })
+            && matches!(vault.resource, BackupResource::Vault { .. })
+            && self.partition == vault.partition
+            && self.region == vault.region
+            && self.account_id == vault.account_id
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for BackupArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "backup" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let (resource_type, id) = arn
+            .resource
+            .split_once(':')
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+
+        if id.contains([':', '/']) {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+        let id = non_empty(id)?;
+
+        let resource = match resource_type {
+            "backup-vault" => BackupResource::Vault { name: id },
+            "recovery-point" => BackupResource::RecoveryPoint { id },
+            "backup-plan" => BackupResource::Plan { id },
+            _ => return Err(ParseServiceArnError::UnknownResourceType),
+        };
+
+        Ok(BackupArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            resource,
+        })
+    }
+}
+
+impl<'a> fmt::Display for BackupArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:backup:{}:{}:",
+            self.partition, self.region, self.account_id
+        )?;
+
+        match self.resource {
+            BackupResource::Vault { name } => write!(f, "backup-vault:{}", name),
+            BackupResource::RecoveryPoint { id } => write!(f, "recovery-point:{}", id),
+            BackupResource::Plan { id } => write!(f, "backup-plan:{}", id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BackupArn, BackupResource};
+    use crate::service::ParseServiceArnError;
+
+    const RECOVERY_POINT: &str =
+        "arn:aws:backup:us-east-1:123456789012:recovery-point:1EB3B5E7-9EB0-435A-A80B-108B488B0D45";
+
+    #[test]
+    fn resources() {
+        for (arn_str, resource) in &[
+            (
+                "arn:aws:backup:us-east-1:123456789012:backup-vault:prod",
+                BackupResource::Vault { name: "prod" },
+            ),
+            (
+                RECOVERY_POINT,
+                BackupResource::RecoveryPoint {
+                    id: "1EB3B5E7-9EB0-435A-A80B-108B488B0D45",
+                },
+            ),
+            (
+                "arn:aws:backup:us-east-1:123456789012:backup-plan:8F81F553-3A74-4A3F-B93D-B3360DC80C50",
+                BackupResource::Plan {
+                    id: "8F81F553-3A74-4A3F-B93D-B3360DC80C50",
+                },
+            ),
+        ] {
+            let arn = BackupArn::parse(arn_str).unwrap();
+
+            assert_eq!(arn.resource, *resource);
+            assert_eq!(arn.to_string(), *arn_str);
+        }
+    }
+
+    #[test]
+    fn vaults() {
+        let recovery_point = BackupArn::parse(RECOVERY_POINT).unwrap();
+        let vault = recovery_point.vault_arn("prod");
+
+        assert_eq!(vault.vault_name(), Some("prod"));
+        assert_eq!(recovery_point.vault_name(), None);
+        assert!(recovery_point.could_be_in(&vault));
+        assert!(!vault.could_be_in(&recovery_point));
+
+        let elsewhere =
+            BackupArn::parse("arn:aws:backup:us-west-2:123456789012:backup-vault:prod").unwrap();
+        assert!(!recovery_point.could_be_in(&elsewhere));
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:backup:us-east-1:123456789012:{}", resource);
+            BackupArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(
+            parse("backup-vault:"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("backup-vault:prod:extra"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("backup-vault/prod"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            parse("report-plan:weekly"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
@@ -0,0 +1,238 @@
+//! AWS CloudFormation (`arn:partition:cloudformation:region:account-id:stack/name/id`) ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// A CloudFormation stack or stack set ARN
+///
+/// Both carry a unique ID after the name, which changes when a stack is deleted and recreated with the same name.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::cloudformation::CloudFormationArn;
+///
+/// let arn = CloudFormationArn::parse("arn:aws:cloudformation:us-east-1:123456789012:stack/my-stack/c9d3e4a0-1c2b-11ee-9f3c-0a1b2c3d4e5f").unwrap();
+/// assert_eq!(arn.name(), "my-stack");
+/// assert_eq!(arn.id(), "c9d3e4a0-1c2b-11ee-9f3c-0a1b2c3d4e5f");
+///
+/// let redeployed = CloudFormationArn::parse("arn:aws:cloudformation:us-east-1:123456789012:stack/my-stack/0f1e2d3c-4b5a-11ee-8d7c-6b5a4f3e2d1c").unwrap();
+/// assert!(arn.is_same_stack_name(&redeployed));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CloudFormationArn<'a> {
+    /// `stack/name/id`
+    Stack {
+        partition: &'a str,
+        region: &'a str,
+        account_id: &'a str,
+        name: &'a str,
+        id: &'a str,
+    },
+    /// `stackset/name:id`
+    StackSet {
+        partition: &'a str,
+        region: &'a str,
+        account_id: &'a str,
+        name: &'a str,
+        id: &'a str,
+    },
+}
+
+impl<'a> CloudFormationArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        CloudFormationArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The human-readable stack or stack set name.
+    pub fn name(&self) -> &'a str {
+        self.parts().3
+    }
+
+    /// The unique ID assigned when the stack or stack set was created.
+    pub fn id(&self) -> &'a str {
+        self.parts().4
+    }
+
+    /// Whether `other` is the same kind of resource with the same name, in the same account and region, ignoring
+    /// the unique ID.
+    pub fn is_same_stack_name(&self, other: &CloudFormationArn) -> bool {
+        let (partition, region, account_id, name, _) = self.parts();
+        let (other_partition, other_region, other_account_id, other_name, _) = other.parts();
+
+        core::mem::discriminant(self) == core::mem::discriminant(other)
+            && partition == other_partition
+            && region == other_region
+            && account_id == other_account_id
+            && name == other_name
+    }
+
+    fn parts(&self) -> (&'a str, &'a str, &'a str, &'a str, &'a str) {
+        match *self {
+            CloudFormationArn::Stack {
+                partition,
+                region,
+                account_id,
+                name,
+                id,
+            }
+            | CloudFormationArn::StackSet {
+                partition,
+                region,
+                account_id,
+                name,
+                id,
+            } => (partition, region, account_id, name, id),
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for CloudFormationArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "cloudformation" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let (resource_type, rest) = arn
+            .resource
+            .split_once('/')
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+
+        let separator = match resource_type {
+            "stack" => '/',
+            "stackset" => ':',
+            _ => return Err(ParseServiceArnError::UnknownResourceType),
+        };
+        let (name, id) = rest
+            .split_once(separator)
+            .ok_or(ParseServiceArnError::InvalidResource)?;
+
+        if name.contains(['/', ':']) || id.contains(['/', ':']) {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        let (partition, name, id) = (arn.partition, non_empty(name)?, non_empty(id)?);
+
+        Ok(match resource_type {
+            "stack" => CloudFormationArn::Stack {
+                partition,
+                region,
+                account_id,
+                name,
+                id,
+            },
+            _ => CloudFormationArn::StackSet {
+                partition,
+                region,
+                account_id,
+                name,
+                id,
+            },
+        })
+    }
+}
+
+impl<'a> fmt::Display for CloudFormationArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (partition, region, account_id, name, id) = self.parts();
+        write!(
+            f,
+            "arn:{}:cloudformation:{}:{}:",
+            partition, region, account_id
+        )?;
+
+        match self {
+            CloudFormationArn::Stack { .. } => write!(f, "stack/{}/{}", name, id),
+            CloudFormationArn::StackSet { .. } => write!(f, "stackset/{}:{}", name, id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CloudFormationArn;
+    use crate::service::ParseServiceArnError;
+
+    const STACK: &str = "arn:aws:cloudformation:us-east-1:123456789012:stack/my-stack/c9d3e4a0-1c2b-11ee-9f3c-0a1b2c3d4e5f";
+    const STACK_SET: &str = "arn:aws:cloudformation:us-east-1:123456789012:stackset/my-stack:4f7b2c3d-aaaa-bbbb-cccc-0123456789ab";
+
+    #[test]
+    fn stack() {
+        let arn = CloudFormationArn::parse(STACK).unwrap();
+
+        assert_eq!(
+            arn,
+            CloudFormationArn::Stack {
+                partition: "aws",
+                region: "us-east-1",
+                account_id: "123456789012",
+                name: "my-stack",
+                id: "c9d3e4a0-1c2b-11ee-9f3c-0a1b2c3d4e5f",
+            }
+        );
+        assert_eq!(arn.to_string(), STACK);
+    }
+
+    #[test]
+    fn stack_set() {
+        let arn = CloudFormationArn::parse(STACK_SET).unwrap();
+
+        assert!(matches!(arn, CloudFormationArn::StackSet { .. }));
+        assert_eq!(arn.name(), "my-stack");
+        assert_eq!(arn.id(), "4f7b2c3d-aaaa-bbbb-cccc-0123456789ab");
+        assert_eq!(arn.to_string(), STACK_SET);
+    }
+
+    #[test]
+    fn same_stack_name() {
+        let stack = CloudFormationArn::parse(STACK).unwrap();
+        let redeployed = CloudFormationArn::parse(
+            "arn:aws:cloudformation:us-east-1:123456789012:stack/my-stack/0f1e2d3c-4b5a-11ee-8d7c-6b5a4f3e2d1c",
+        )
+        .unwrap();
+        let other_region = CloudFormationArn::parse(
+            "arn:aws:cloudformation:us-west-2:123456789012:stack/my-stack/c9d3e4a0-1c2b-11ee-9f3c-0a1b2c3d4e5f",
+        )
+        .unwrap();
+        let stack_set = CloudFormationArn::parse(STACK_SET).unwrap();
+
+        assert!(stack.is_same_stack_name(&redeployed));
+        assert!(!stack.is_same_stack_name(&other_region));
+        assert!(!stack.is_same_stack_name(&stack_set));
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:cloudformation:us-east-1:123456789012:{}", resource);
+            CloudFormationArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(
+            parse("stack/my-stack"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("stack//0123"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("stack/my-stack/0123/extra"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("stackset/my-stack/0123"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("changeSet/my-change-set/0123"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
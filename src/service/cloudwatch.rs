@@ -0,0 +1,175 @@
+//! Amazon CloudWatch (`arn:partition:cloudwatch:region:account-id:alarm:name`) ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{global, non_empty, regional, ParseServiceArnError};
+
+/// A CloudWatch alarm or dashboard ARN
+///
+/// Alarm names follow a `:`, not a `/`, and may contain spaces and further `:` characters. Dashboards are global,
+/// so their ARNs have no region.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::cloudwatch::CloudWatchArn;
+///
+/// let arn = CloudWatchArn::parse("arn:aws:cloudwatch:us-east-1:123456789012:alarm:High CPU: web/api").unwrap();
+/// assert!(matches!(arn, CloudWatchArn::Alarm { name: "High CPU: web/api", .. }));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CloudWatchArn<'a> {
+    /// `alarm:name`
+    Alarm {
+        partition: &'a str,
+        region: &'a str,
+        account_id: &'a str,
+        name: &'a str,
+    },
+    /// `dashboard/name`
+    Dashboard {
+        partition: &'a str,
+        account_id: &'a str,
+        name: &'a str,
+    },
+}
+
+impl<'a> CloudWatchArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        CloudWatchArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The alarm or dashboard name.
+    pub fn name(&self) -> &'a str {
+        match self {
+            CloudWatchArn::Alarm { name, .. } | CloudWatchArn::Dashboard { name, .. } => name,
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for CloudWatchArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "cloudwatch" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        if let Some(name) = arn.resource.strip_prefix("alarm:") {
+            let (region, account_id) = regional(arn)?;
+
+            return Ok(CloudWatchArn::Alarm {
+                partition: arn.partition,
+                region,
+                account_id,
+                name: non_empty(name)?,
+            });
+        }
+
+        if let Some(name) = arn.resource.strip_prefix("dashboard/") {
+            return Ok(CloudWatchArn::Dashboard {
+                partition: arn.partition,
+                account_id: global(arn)?,
+                name: non_empty(name)?,
+            });
+        }
+
+        Err(ParseServiceArnError::UnknownResourceType)
+    }
+}
+
+impl<'a> fmt::Display for CloudWatchArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CloudWatchArn::Alarm {
+                partition,
+                region,
+                account_id,
+                name,
+            } => write!(
+                f,
+                "arn:{}:cloudwatch:{}:{}:alarm:{}",
+                partition, region, account_id, name
+            ),
+            CloudWatchArn::Dashboard {
+                partition,
+                account_id,
+                name,
+            } => write!(
+                f,
+                "arn:{}:cloudwatch::{}:dashboard/{}",
+                partition, account_id, name
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CloudWatchArn;
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn alarm() {
+        let arn_str = "arn:aws:cloudwatch:us-east-1:123456789012:alarm:TargetTracking-table/orders-AlarmHigh-1a2b3c";
+        let arn = CloudWatchArn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn,
+            CloudWatchArn::Alarm {
+                partition: "aws",
+                region: "us-east-1",
+                account_id: "123456789012",
+                name: "TargetTracking-table/orders-AlarmHigh-1a2b3c",
+            }
+        );
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn alarm_with_colons_and_spaces() {
+        let arn_str = "arn:aws:cloudwatch:us-east-1:123456789012:alarm:awsec2-i-0123:High CPU";
+        let arn = CloudWatchArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.name(), "awsec2-i-0123:High CPU");
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn dashboard() {
+        let arn_str = "arn:aws:cloudwatch::123456789012:dashboard/Service Health";
+        let arn = CloudWatchArn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn,
+            CloudWatchArn::Dashboard {
+                partition: "aws",
+                account_id: "123456789012",
+                name: "Service Health",
+            }
+        );
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            CloudWatchArn::parse("arn:aws:cloudwatch:us-east-1:123456789012:alarm:"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            CloudWatchArn::parse("arn:aws:cloudwatch::123456789012:alarm:High CPU"),
+            Err(ParseServiceArnError::MissingRegion)
+        );
+        assert_eq!(
+            CloudWatchArn::parse("arn:aws:cloudwatch:us-east-1:123456789012:dashboard/Health"),
+            Err(ParseServiceArnError::UnexpectedRegion)
+        );
+        assert_eq!(
+            CloudWatchArn::parse("arn:aws:cloudwatch:us-east-1:123456789012:alarm/High CPU"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
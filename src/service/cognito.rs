@@ -0,0 +1,250 @@
+//! Amazon Cognito user pool (`arn:partition:cognito-idp:region:account-id:userpool/pool-id`) and identity pool
+//! (`arn:partition:cognito-identity:region:account-id:identitypool/pool-id`) ARNs
+
+use alloc::{format, string::String};
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::partition::Partition;
+use crate::service::{is_uuid, regional, ParseServiceArnError};
+
+/// A Cognito user pool ARN
+///
+/// Pool IDs are validated: the pool's region, an `_`, then an alphanumeric suffix. The region must match the ARN's.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::cognito::UserPoolArn;
+///
+/// let arn = UserPoolArn::parse("arn:aws:cognito-idp:us-east-1:123456789012:userpool/us-east-1_AbCdEfGhI").unwrap();
+/// assert_eq!(arn.id, "us-east-1_AbCdEfGhI");
+/// assert_eq!(arn.issuer_url().unwrap(), "https://cognito-idp.us-east-1.amazonaws.com/us-east-1_AbCdEfGhI");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UserPoolArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub id: &'a str,
+}
+
+impl<'a> UserPoolArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        UserPoolArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The issuer of the pool's tokens, e.g. `https://cognito-idp.us-east-1.amazonaws.com/us-east-1_AbCdEfGhI`.
+    ///
+    /// Returns `None` for partitions this crate doesn't know the DNS suffix of.
+    pub fn issuer_url(&self) -> Option<String> {
+        let partition: Partition = self.partition.parse().ok()?;
+
+        Some(format!(
+            "https://cognito-idp.{}.{}/{}",
+            self.region,
+            partition.dns_suffix(),
+            self.id
+        ))
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for UserPoolArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "cognito-idp" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let id = match arn.resource.split_once('/') {
+            Some(("userpool", id)) => id,
+            _ => return Err(ParseServiceArnError::UnknownResourceType),
+        };
+
+        match id.split_once('_') {
+            Some((pool_region, suffix))
+                if pool_region == region
+                    && !suffix.is_empty()
+                    && suffix.bytes().all(|b| b.is_ascii_alphanumeric()) =>
+            {
+                Ok(UserPoolArn {
+                    partition: arn.partition,
+                    region,
+                    account_id,
+                    id,
+                })
+            }
+            _ => Err(ParseServiceArnError::InvalidResource),
+        }
+    }
+}
+
+impl<'a> fmt::Display for UserPoolArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:cognito-idp:{}:{}:userpool/{}",
+            self.partition, self.region, self.account_id, self.id
+        )
+    }
+}
+
+/// A Cognito identity pool ARN
+///
+/// Pool IDs are validated: the pool's region, a `:`, then a UUID. The region must match the ARN's.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::cognito::IdentityPoolArn;
+///
+/// let arn = IdentityPoolArn::parse("arn:aws:cognito-identity:us-east-1:123456789012:identitypool/us-east-1:1cf667a2-49a6-454b-9e45-23199e0a8f1c").unwrap();
+/// assert_eq!(arn.id, "us-east-1:1cf667a2-49a6-454b-9e45-23199e0a8f1c");
+/// assert_eq!(arn.uuid(), "1cf667a2-49a6-454b-9e45-23199e0a8f1c");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IdentityPoolArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub id: &'a str,
+}
+
+impl<'a> IdentityPoolArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        IdentityPoolArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The pool ID without its region prefix.
+    pub fn uuid(&self) -> &'a str {
+        &self.id[self.region.len() + 1..]
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for IdentityPoolArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "cognito-identity" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let id = match arn.resource.split_once('/') {
+            Some(("identitypool", id)) => id,
+            _ => return Err(ParseServiceArnError::UnknownResourceType),
+        };
+
+        match id.split_once(':') {
+            Some((pool_region, uuid)) if pool_region == region && is_uuid(uuid) => {
+                Ok(IdentityPoolArn {
+                    partition: arn.partition,
+                    region,
+                    account_id,
+                    id,
+                })
+            }
+            _ => Err(ParseServiceArnError::InvalidResource),
+        }
+    }
+}
+
+impl<'a> fmt::Display for IdentityPoolArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:cognito-identity:{}:{}:identitypool/{}",
+            self.partition, self.region, self.account_id, self.id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IdentityPoolArn, UserPoolArn};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn user_pool() {
+        let arn_str =
+            "arn:aws-cn:cognito-idp:cn-north-1:123456789012:userpool/cn-north-1_AbCdEfGhI";
+        let arn = UserPoolArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.id, "cn-north-1_AbCdEfGhI");
+        assert_eq!(arn.to_string(), arn_str);
+        assert_eq!(
+            arn.issuer_url().unwrap(),
+            "https://cognito-idp.cn-north-1.amazonaws.com.cn/cn-north-1_AbCdEfGhI"
+        );
+    }
+
+    #[test]
+    fn user_pool_invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:cognito-idp:us-east-1:123456789012:{}", resource);
+            UserPoolArn::parse(&arn).map(|_| ())
+        };
+
+        for resource in &[
+            "userpool/AbCdEfGhI",
+            "userpool/us-east-1_",
+            "userpool/us-west-2_AbCdEfGhI",
+            "userpool/us-east-1_AbCd-EfGhI",
+        ] {
+            assert_eq!(
+                parse(resource),
+                Err(ParseServiceArnError::InvalidResource),
+                "{}",
+                resource
+            );
+        }
+
+        assert_eq!(
+            parse("identitypool/us-east-1_AbCdEfGhI"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+
+    #[test]
+    fn identity_pool() {
+        let arn_str = "arn:aws:cognito-identity:us-east-1:123456789012:identitypool/us-east-1:1cf667a2-49a6-454b-9e45-23199e0a8f1c";
+        let arn = IdentityPoolArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.uuid(), "1cf667a2-49a6-454b-9e45-23199e0a8f1c");
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn identity_pool_invalid() {
+        let parse = |resource: &str| {
+            let arn = format!(
+                "arn:aws:cognito-identity:us-east-1:123456789012:{}",
+                resource
+            );
+            IdentityPoolArn::parse(&arn).map(|_| ())
+        };
+
+        for resource in &[
+            "identitypool/1cf667a2-49a6-454b-9e45-23199e0a8f1c",
+            "identitypool/eu-west-1:1cf667a2-49a6-454b-9e45-23199e0a8f1c",
+            "identitypool/us-east-1:1cf667a2-49a6-454b-9e45-23199EXAMPLE",
+        ] {
+            assert_eq!(
+                parse(resource),
+                Err(ParseServiceArnError::InvalidResource),
+                "{}",
+                resource
+            );
+        }
+
+        assert_eq!(
+            IdentityPoolArn::parse(
+                "arn:aws:cognito-idp:us-east-1:123456789012:identitypool/us-east-1:1cf667a2-49a6-454b-9e45-23199e0a8f1c"
+            ),
+            Err(ParseServiceArnError::WrongService)
+        );
+    }
+}
@@ -0,0 +1,317 @@
+//! AWS developer tools: CodeBuild (`arn:partition:codebuild:region:account-id:project/...`), CodePipeline and
+//! CodeCommit ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{is_uuid, non_empty, regional, ParseServiceArnError};
+
+/// The resource a [`CodeBuildArn`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodeBuildResource<'a> {
+    /// `project/name`
+    Project { name: &'a str },
+    /// `build/project-name:build-uuid`
+    Build { project: &'a str, id: &'a str },
+}
+
+/// A CodeBuild project or build ARN
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::devtools::{CodeBuildArn, CodeBuildResource};
+///
+/// let arn = CodeBuildArn::parse("arn:aws:codebuild:us-east-1:123456789012:build/my-project:b1e6661e-e4f2-4156-9ab9-82a19e5a0c1f").unwrap();
+/// assert_eq!(arn.resource, CodeBuildResource::Build { project: "my-project", id: "b1e6661e-e4f2-4156-9ab9-82a19e5a0c1f" });
+/// assert_eq!(arn.project(), "my-project");
+/// assert_eq!(arn.project_arn().to_string(), "arn:aws:codebuild:us-east-1:123456789012:project/my-project");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CodeBuildArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub resource: CodeBuildResource<'a>,
+}
+
+impl<'a> CodeBuildArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        CodeBuildArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The project's name, or the name of the project a build ran in.
+    pub fn project(&self) -> &'a str {
+        match self.resource {
+            CodeBuildResource::Project { name } => name,
+            CodeBuildResource::Build { project, .. } => project,
+        }
+    }
+
+    /// The ARN of the project, or of the project a build ran in.
+    pub fn project_arn(&self) -> CodeBuildArn<'a> {
+        CodeBuildArn {
+            resource: CodeBuildResource::Project {
+                name: self.project(),
+            },
+            ..*self
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for CodeBuildArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "codebuild" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+
+        let resource = match arn.resource.split_once('/') {
+            Some(("project", name)) if !name.contains('/') => CodeBuildResource::Project {
+                name: non_empty(name)?,
+            },
+            Some(("build", build)) if !build.contains('/') => match build.split_once(':') {
+                Some((project, id)) if is_uuid(id) => CodeBuildResource::Build {
+                    project: non_empty(project)?,
+                    id,
+                },
+                _ => return Err(ParseServiceArnError::InvalidResource),
+            },
+            Some(("project", _)) | Some(("build", _)) => {
+                return Err(ParseServiceArnError::InvalidResource)
+            }
+            _ => return Err(ParseServiceArnError::UnknownResourceType),
+        };
+
+        Ok(CodeBuildArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            resource,
+        })
+    }
+}
+
+impl<'a> fmt::Display for CodeBuildArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:codebuild:{}:{}:",
+            self.partition, self.region, self.account_id
+        )?;
+
+        match self.resource {
+            CodeBuildResource::Project { name } => write!(f, "project/{}", name),
+            CodeBuildResource::Build { project, id } => write!(f, "build/{}:{}", project, id),
+        }
+    }
+}
+
+/// A CodePipeline pipeline ARN, whose resource is the bare pipeline name
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::devtools::CodePipelineArn;
+///
+/// let arn = CodePipelineArn::parse("arn:aws:codepipeline:us-east-1:123456789012:my-pipeline").unwrap();
+/// assert_eq!(arn.name, "my-pipeline");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CodePipelineArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub name: &'a str,
+}
+
+impl<'a> CodePipelineArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        CodePipelineArn::try_from(NaiveArn::parse(s)?)
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for CodePipelineArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "codepipeline" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+
+        // Stage and action ARNs, `pipeline/stage/action`, aren't supported
+        if arn.resource.contains('/') {
+            return Err(ParseServiceArnError::UnknownResourceType);
+        }
+
+        Ok(CodePipelineArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            name: non_empty(arn.resource)?,
+        })
+    }
+}
+
+impl<'a> fmt::Display for CodePipelineArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:codepipeline:{}:{}:{}",
+            self.partition, self.region, self.account_id, self.name
+        )
+    }
+}
+
+/// A CodeCommit repository ARN, whose resource is the bare repository name
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::devtools::CodeCommitArn;
+///
+/// let arn = CodeCommitArn::parse("arn:aws:codecommit:us-east-1:123456789012:my-repo").unwrap();
+/// assert_eq!(arn.name, "my-repo");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CodeCommitArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub name: &'a str,
+}
+
+impl<'a> CodeCommitArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        CodeCommitArn::try_from(NaiveArn::parse(s)?)
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for CodeCommitArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "codecommit" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+
+        if arn.resource.contains(['/', ':']) {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        Ok(CodeCommitArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            name: non_empty(arn.resource)?,
+        })
+    }
+}
+
+impl<'a> fmt::Display for CodeCommitArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:codecommit:{}:{}:{}",
+            self.partition, self.region, self.account_id, self.name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CodeBuildArn, CodeBuildResource, CodeCommitArn, CodePipelineArn};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn codebuild() {
+        let arn_str = "arn:aws:codebuild:us-east-1:123456789012:project/my-project";
+        let arn = CodeBuildArn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn.resource,
+            CodeBuildResource::Project { name: "my-project" }
+        );
+        assert_eq!(arn.project_arn(), arn);
+        assert_eq!(arn.to_string(), arn_str);
+
+        let build_str = "arn:aws:codebuild:us-east-1:123456789012:build/my-project:b1e6661e-e4f2-4156-9ab9-82a19e5a0c1f";
+        let build = CodeBuildArn::parse(build_str).unwrap();
+
+        assert_eq!(build.project(), "my-project");
+        assert_eq!(build.project_arn(), arn);
+        assert_eq!(build.to_string(), build_str);
+    }
+
+    #[test]
+    fn codebuild_invalid() {
+        for (arn_str, err) in &[
+            (
+                "arn:aws:codebuild:us-east-1:123456789012:build/my-project",
+                ParseServiceArnError::InvalidResource,
+            ),
+            (
+                "arn:aws:codebuild:us-east-1:123456789012:build/my-project:42",
+                ParseServiceArnError::InvalidResource,
+            ),
+            (
+                "arn:aws:codebuild:us-east-1:123456789012:build/:b1e6661e-e4f2-4156-9ab9-82a19e5a0c1f",
+                ParseServiceArnError::InvalidResource,
+            ),
+            (
+                "arn:aws:codebuild:us-east-1:123456789012:project/",
+                ParseServiceArnError::InvalidResource,
+            ),
+            (
+                "arn:aws:codebuild:us-east-1:123456789012:report-group/my-reports",
+                ParseServiceArnError::UnknownResourceType,
+            ),
+            (
+                "arn:aws:codebuild::123456789012:project/my-project",
+                ParseServiceArnError::MissingRegion,
+            ),
+        ] {
+            assert_eq!(CodeBuildArn::parse(arn_str).as_ref(), Err(err), "{}", arn_str);
+        }
+    }
+
+    #[test]
+    fn codepipeline() {
+        let arn_str = "arn:aws:codepipeline:us-east-1:123456789012:my-pipeline";
+        let arn = CodePipelineArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.name, "my-pipeline");
+        assert_eq!(arn.to_string(), arn_str);
+        assert_eq!(
+            CodePipelineArn::parse(
+                "arn:aws:codepipeline:us-east-1:123456789012:my-pipeline/Build/Compile"
+            ),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+
+    #[test]
+    fn codecommit() {
+        let arn_str = "arn:aws:codecommit:us-east-1:123456789012:my-repo";
+        let arn = CodeCommitArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.name, "my-repo");
+        assert_eq!(arn.to_string(), arn_str);
+        assert_eq!(
+            CodeCommitArn::parse("arn:aws:codecommit:us-east-1:123456789012:my-repo/branch"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            CodeCommitArn::parse("arn:aws:codebuild:us-east-1:123456789012:my-repo"),
+            Err(ParseServiceArnError::WrongService)
+        );
+    }
+}
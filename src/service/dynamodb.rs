@@ -0,0 +1,215 @@
+//! Amazon DynamoDB (`arn:partition:dynamodb:region:account-id:table/...`) ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// A DynamoDB table ARN
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynamoDbTableArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub name: &'a str,
+}
+
+impl<'a> DynamoDbTableArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        DynamoDbTableArn::try_from(NaiveArn::parse(s)?)
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for DynamoDbTableArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        match DynamoDbArn::try_from(arn)? {
+            DynamoDbArn::Table(table) => Ok(table),
+            _ => Err(ParseServiceArnError::UnknownResourceType),
+        }
+    }
+}
+
+impl<'a> fmt::Display for DynamoDbTableArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:dynamodb:{}:{}:table/{}",
+            self.partition, self.region, self.account_id, self.name
+        )
+    }
+}
+
+/// A DynamoDB table, or an index, stream or backup of a table
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::dynamodb::DynamoDbArn;
+///
+/// let arn = DynamoDbArn::parse("arn:aws:dynamodb:us-east-1:123456789012:table/books_table/stream/2015-05-11T21:21:33.291").unwrap();
+/// assert!(matches!(arn, DynamoDbArn::Stream { label: "2015-05-11T21:21:33.291", .. }));
+/// assert_eq!(arn.table_arn().to_string(), "arn:aws:dynamodb:us-east-1:123456789012:table/books_table");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DynamoDbArn<'a> {
+    Table(DynamoDbTableArn<'a>),
+    /// `table/Name/index/IndexName`
+    Index {
+        table: DynamoDbTableArn<'a>,
+        name: &'a str,
+    },
+    /// `table/Name/stream/Label`, where the label is the stream's creation timestamp.
+    Stream {
+        table: DynamoDbTableArn<'a>,
+        label: &'a str,
+    },
+    /// `table/Name/backup/BackupId`
+    Backup {
+        table: DynamoDbTableArn<'a>,
+        id: &'a str,
+    },
+}
+
+impl<'a> DynamoDbArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        DynamoDbArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The table, or the table an index, stream or backup belongs to.
+    pub fn table_arn(&self) -> DynamoDbTableArn<'a> {
+        match *self {
+            DynamoDbArn::Table(table)
+            | DynamoDbArn::Index { table, .. }
+            | DynamoDbArn::Stream { table, .. }
+            | DynamoDbArn::Backup { table, .. } => table,
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for DynamoDbArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "dynamodb" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let mut parts = arn.resource.split('/');
+
+        if parts.next() != Some("table") {
+            return Err(ParseServiceArnError::UnknownResourceType);
+        }
+
+        let table = DynamoDbTableArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            name: non_empty(parts.next().unwrap_or_default())?,
+        };
+        let nested = match (parts.next(), parts.next()) {
+            (None, _) => return Ok(DynamoDbArn::Table(table)),
+            (Some(nested_type), Some(id)) => (nested_type, non_empty(id)?),
+            (Some(_), None) => return Err(ParseServiceArnError::InvalidResource),
+        };
+
+        if parts.next().is_some() {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        match nested {
+            ("index", name) => Ok(DynamoDbArn::Index { table, name }),
+            ("stream", label) => Ok(DynamoDbArn::Stream { table, label }),
+            ("backup", id) => Ok(DynamoDbArn::Backup { table, id }),
+            _ => Err(ParseServiceArnError::UnknownResourceType),
+        }
+    }
+}
+
+impl<'a> fmt::Display for DynamoDbArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DynamoDbArn::Table(table) => table.fmt(f),
+            DynamoDbArn::Index { table, name } => write!(f, "{}/index/{}", table, name),
+            DynamoDbArn::Stream { table, label } => write!(f, "{}/stream/{}", table, label),
+            DynamoDbArn::Backup { table, id } => write!(f, "{}/backup/{}", table, id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynamoDbArn, DynamoDbTableArn};
+    use crate::service::ParseServiceArnError;
+
+    const TABLE: &str = "arn:aws:dynamodb:us-east-1:123456789012:table/books_table";
+
+    #[test]
+    fn table() {
+        let arn = DynamoDbArn::parse(TABLE).unwrap();
+
+        assert_eq!(
+            arn,
+            DynamoDbArn::Table(DynamoDbTableArn {
+                partition: "aws",
+                region: "us-east-1",
+                account_id: "123456789012",
+                name: "books_table",
+            })
+        );
+        assert_eq!(arn.to_string(), TABLE);
+    }
+
+    #[test]
+    fn nested() {
+        for (suffix, expected) in &[
+            ("/index/title-index", "Index"),
+            ("/stream/2015-05-11T21:21:33.291", "Stream"),
+            ("/backup/01489173575360-b308cd7d", "Backup"),
+        ] {
+            let arn_str = format!("{}{}", TABLE, suffix);
+            let arn = DynamoDbArn::parse(&arn_str).unwrap();
+
+            assert!(format!("{:?}", arn).starts_with(expected), "{}", arn_str);
+            assert_eq!(arn.table_arn().to_string(), TABLE);
+            assert_eq!(arn.to_string(), arn_str);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:dynamodb:us-east-1:123456789012:{}", resource);
+            DynamoDbArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(parse("table/"), Err(ParseServiceArnError::InvalidResource));
+        assert_eq!(
+            parse("table/books_table/index"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("table/books_table/index/"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("table/books_table/index/title-index/extra"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("table/books_table/export/01234567890123-a1b2c3d4"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            parse("global-table/books_table"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            DynamoDbTableArn::parse(&format!("{}/index/title-index", TABLE)),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! Amazon EC2 (`arn:partition:ec2:region:account-id:resource-type/resource-id`) ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{lookup, non_empty, ParseServiceArnError};
+
+/// The kind of resource an [`Ec2Arn`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Ec2ResourceKind {
+    DhcpOptions,
+    ElasticIp,
+    Image,
+    Instance,
+    InternetGateway,
+    KeyPair,
+    LaunchTemplate,
+    NatGateway,
+    NetworkAcl,
+    NetworkInterface,
+    PlacementGroup,
+    RouteTable,
+    SecurityGroup,
+    Snapshot,
+    Subnet,
+    TransitGateway,
+    Volume,
+    Vpc,
+    VpcEndpoint,
+    VpcPeeringConnection,
+}
+
+impl Ec2ResourceKind {
+    /// `(resource type, kind)`, sorted by resource type.
+    const ALL: &'static [(&'static str, Ec2ResourceKind)] = &[
+        ("dhcp-options", Ec2ResourceKind::DhcpOptions),
+        ("elastic-ip", Ec2ResourceKind::ElasticIp),
+        ("image", Ec2ResourceKind::Image),
+        ("instance", Ec2ResourceKind::Instance),
+        ("internet-gateway", Ec2ResourceKind::InternetGateway),
+        ("key-pair", Ec2ResourceKind::KeyPair),
+        ("launch-template", Ec2ResourceKind::LaunchTemplate),
+        ("natgateway", Ec2ResourceKind::NatGateway),
+        ("network-acl", Ec2ResourceKind::NetworkAcl),
+        ("network-interface", Ec2ResourceKind::NetworkInterface),
+        ("placement-group", Ec2ResourceKind::PlacementGroup),
+        ("route-table", Ec2ResourceKind::RouteTable),
+        ("security-group", Ec2ResourceKind::SecurityGroup),
+        ("snapshot", Ec2ResourceKind::Snapshot),
+        ("subnet", Ec2ResourceKind::Subnet),
+        ("transit-gateway", Ec2ResourceKind::TransitGateway),
+        ("volume", Ec2ResourceKind::Volume),
+        ("vpc", Ec2ResourceKind::Vpc),
+        ("vpc-endpoint", Ec2ResourceKind::VpcEndpoint),
+        (
+            "vpc-peering-connection",
+            Ec2ResourceKind::VpcPeeringConnection,
+        ),
+    ];
+
+    fn from_resource_type(resource_type: &str) -> Option<Self> {
+        lookup(Self::ALL, resource_type)
+    }
+
+    /// The resource type, as it appears in the ARN.
+    pub fn resource_type(self) -> &'static str {
+        match self {
+            Ec2ResourceKind::DhcpOptions => "dhcp-options",
+            Ec2ResourceKind::ElasticIp => "elastic-ip",
+            Ec2ResourceKind::Image => "image",
+            Ec2ResourceKind::Instance => "instance",
+            Ec2ResourceKind::InternetGateway => "internet-gateway",
+            Ec2ResourceKind::KeyPair => "key-pair",
+            Ec2ResourceKind::LaunchTemplate => "launch-template",
+            Ec2ResourceKind::NatGateway => "natgateway",
+            Ec2ResourceKind::NetworkAcl => "network-acl",
+            Ec2ResourceKind::NetworkInterface => "network-interface",
+            Ec2ResourceKind::PlacementGroup => "placement-group",
+            Ec2ResourceKind::RouteTable => "route-table",
+            Ec2ResourceKind::SecurityGroup => "security-group",
+            Ec2ResourceKind::Snapshot => "snapshot",
+            Ec2ResourceKind::Subnet => "subnet",
+            Ec2ResourceKind::TransitGateway => "transit-gateway",
+            Ec2ResourceKind::Volume => "volume",
+            Ec2ResourceKind::Vpc => "vpc",
+            Ec2ResourceKind::VpcEndpoint => "vpc-endpoint",
+            Ec2ResourceKind::VpcPeeringConnection => "vpc-peering-connection",
+        }
+    }
+}
+
+/// An EC2 ARN
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::ec2::{Ec2Arn, Ec2ResourceKind};
+///
+/// let arn = Ec2Arn::parse("arn:aws:ec2:us-east-1:123456789012:instance/i-0123456789abcdef0").unwrap();
+/// assert_eq!(arn.kind, Ec2ResourceKind::Instance);
+/// assert_eq!(arn.id, "i-0123456789abcdef0");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ec2Arn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    /// The owning account, which is absent for public AMIs and snapshots.
+    pub account_id: Option<&'a str>,
+    pub kind: Ec2ResourceKind,
+    pub id: &'a str,
+}
+
+impl<'a> Ec2Arn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        Ec2Arn::try_from(NaiveArn::parse(s)?)
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for Ec2Arn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "ec2" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let region = arn.region.ok_or(ParseServiceArnError::MissingRegion)?;
+        let (resource_type, id) = arn
+            .resource
+            .split_once('/')
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+        let kind = Ec2ResourceKind::from_resource_type(resource_type)
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+
+        if id.contains('/') {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        Ok(Ec2Arn {
+            partition: arn.partition,
+            region,
+            account_id: arn.account_id,
+            kind,
+            id: non_empty(id)?,
+        })
+    }
+}
+
+impl<'a> fmt::Display for Ec2Arn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:ec2:{}:{}:{}/{}",
+            self.partition,
+            self.region,
+            self.account_id.unwrap_or_default(),
+            self.kind.resource_type(),
+            self.id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ec2Arn, Ec2ResourceKind};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn resource_types_sorted() {
+        assert!(Ec2ResourceKind::ALL.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn kinds() {
+        for (resource_type, kind) in Ec2ResourceKind::ALL {
+            let arn_str = format!(
+                "arn:aws:ec2:us-east-1:123456789012:{}/id-0123",
+                resource_type
+            );
+            let arn = Ec2Arn::parse(&arn_str).unwrap();
+
+            assert_eq!(arn.kind, *kind);
+            assert_eq!(arn.kind.resource_type(), *resource_type);
+            assert_eq!(arn.id, "id-0123");
+            assert_eq!(arn.to_string(), arn_str);
+        }
+    }
+
+    #[test]
+    fn public_image() {
+        let arn_str = "arn:aws:ec2:us-east-1::image/ami-0abcdef1234567890";
+        let arn = Ec2Arn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.account_id, None);
+        assert_eq!(arn.kind, Ec2ResourceKind::Image);
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:ec2:us-east-1:123456789012:{}", resource);
+            Ec2Arn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(
+            parse("instance/"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("instance/i-0123/extra"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("capacity-reservation/cr-0123"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            parse("i-0123"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            Ec2Arn::parse("arn:aws:ec2::123456789012:instance/i-0123"),
+            Err(ParseServiceArnError::MissingRegion)
+        );
+    }
+}
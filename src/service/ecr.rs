@@ -0,0 +1,258 @@
+//! Amazon ECR (`arn:partition:ecr:region:account-id:repository/name`) ARNs
+
+use alloc::{format, string::String};
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::partition::Partition;
+use crate::service::{regional, ParseServiceArnError};
+
+/// A specific image in an [`EcrArn`] repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageReference<'a> {
+    /// A tag, written `repository/name:tag`.
+    Tag(&'a str),
+    /// A digest, including its algorithm, written `repository/name@sha256:...`.
+    Digest(&'a str),
+}
+
+/// An ECR repository ARN, optionally suffixed with an image tag or digest
+///
+/// Repository names are validated against ECR's naming rules: lowercase alphanumeric components separated by `.`,
+/// `_` or `-`, optionally namespaced with `/`, up to 256 characters.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::ecr::{EcrArn, ImageReference};
+///
+/// let arn = EcrArn::parse("arn:aws:ecr:us-east-1:123456789012:repository/team/web-app:v1.2").unwrap();
+/// assert_eq!(arn.repository, "team/web-app");
+/// assert_eq!(arn.image, Some(ImageReference::Tag("v1.2")));
+///
+/// assert_eq!(arn.registry().unwrap(), "123456789012.dkr.ecr.us-east-1.amazonaws.com");
+/// assert_eq!(arn.image_uri().unwrap(), "123456789012.dkr.ecr.us-east-1.amazonaws.com/team/web-app:v1.2");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EcrArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub repository: &'a str,
+    pub image: Option<ImageReference<'a>>,
+}
+
+impl<'a> EcrArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        EcrArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The registry host, e.g. `123456789012.dkr.ecr.us-east-1.amazonaws.com`.
+    ///
+    /// Returns `None` if the partition isn't known, since its DNS suffix can't be determined.
+    pub fn registry(&self) -> Option<String> {
+        let partition: Partition = self.partition.parse().ok()?;
+
+        Some(format!(
+            "{}.dkr.ecr.{}.{}",
+            self.account_id,
+            self.region,
+            partition.dns_suffix()
+        ))
+    }
+
+    /// The image URI used by container runtimes, `registry/repository[:tag|@digest]`.
+    pub fn image_uri(&self) -> Option<String> {
+        let registry = self.registry()?;
+
+        Some(match self.image {
+            Some(ImageReference::Tag(tag)) => format!("{}/{}:{}", registry, self.repository, tag),
+            Some(ImageReference::Digest(digest)) => {
+                format!("{}/{}@{}", registry, self.repository, digest)
+            }
+            None => format!("{}/{}", registry, self.repository),
+        })
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for EcrArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "ecr" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let rest = arn
+            .resource
+            .strip_prefix("repository/")
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+
+        let (repository, image) = match rest.split_once('@') {
+            Some((repository, digest)) => (repository, Some(ImageReference::Digest(digest))),
+            None => match rest.split_once(':') {
+                Some((repository, tag)) => (repository, Some(ImageReference::Tag(tag))),
+                None => (rest, None),
+            },
+        };
+
+        let valid_image = match image {
+            Some(ImageReference::Tag(tag)) => is_valid_tag(tag),
+            Some(ImageReference::Digest(digest)) => is_valid_digest(digest),
+            None => true,
+        };
+
+        if !is_valid_repository_name(repository) || !valid_image {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        Ok(EcrArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            repository,
+            image,
+        })
+    }
+}
+
+impl<'a> fmt::Display for EcrArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:ecr:{}:{}:repository/{}",
+            self.partition, self.region, self.account_id, self.repository
+        )?;
+
+        match self.image {
+            Some(ImageReference::Tag(tag)) => write!(f, ":{}", tag),
+            Some(ImageReference::Digest(digest)) => write!(f, "@{}", digest),
+            None => Ok(()),
+        }
+    }
+}
+
+fn is_valid_repository_name(name: &str) -> bool {
+    let is_component = |component: &str| {
+        !component.is_empty()
+            && component.split(['.', '_', '-']).all(|part| {
+                !part.is_empty()
+                    && part
+                        .bytes()
+                        .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+            })
+    };
+
+    (2..=256).contains(&name.len()) && name.split('/').all(is_component)
+}
+
+fn is_valid_tag(tag: &str) -> bool {
+    (1..=300).contains(&tag.len())
+        && tag
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'.' || b == b'_' || b == b'-')
+}
+
+fn is_valid_digest(digest: &str) -> bool {
+    match digest.split_once(':') {
+        Some((algorithm, hex)) => {
+            !algorithm.is_empty()
+                && algorithm.bytes().all(|b| b.is_ascii_alphanumeric())
+                && !hex.is_empty()
+                && hex.bytes().all(|b| b.is_ascii_hexdigit())
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EcrArn, ImageReference};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn repository() {
+        let arn_str = "arn:aws:ecr:us-east-1:123456789012:repository/web-app";
+        let arn = EcrArn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn,
+            EcrArn {
+                partition: "aws",
+                region: "us-east-1",
+                account_id: "123456789012",
+                repository: "web-app",
+                image: None,
+            }
+        );
+        assert_eq!(
+            arn.image_uri().unwrap(),
+            "123456789012.dkr.ecr.us-east-1.amazonaws.com/web-app"
+        );
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn images() {
+        let arn_str = "arn:aws-cn:ecr:cn-north-1:123456789012:repository/team/web-app@sha256:94afd1f2e64d908bc90dbca0035a5b567eccab45da5e7e5c5b0b1d4b5f2c7d1e";
+        let arn = EcrArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.repository, "team/web-app");
+        assert_eq!(
+            arn.image,
+            Some(ImageReference::Digest(
+                "sha256:94afd1f2e64d908bc90dbca0035a5b567eccab45da5e7e5c5b0b1d4b5f2c7d1e"
+            ))
+        );
+        assert_eq!(
+            arn.registry().unwrap(),
+            "123456789012.dkr.ecr.cn-north-1.amazonaws.com.cn"
+        );
+        assert_eq!(arn.to_string(), arn_str);
+
+        let arn_str = "arn:aws:ecr:us-east-1:123456789012:repository/web-app:latest";
+        let arn = EcrArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.image, Some(ImageReference::Tag("latest")));
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:ecr:us-east-1:123456789012:{}", resource);
+            EcrArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(
+            parse("repository/Web-App"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("repository/a"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("repository/web--app"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("repository/team//web-app"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("repository/web-app:"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("repository/web-app@sha256:xyz"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("registry/web-app"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
@@ -0,0 +1,446 @@
+//! Amazon ECS (`arn:partition:ecs:region:account-id:resource-type/...`) helpers
+
+use alloc::format;
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::buf::ArnBuf;
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// Resource types whose ARNs gained the cluster name in the 2018 long ARN format change.
+const CLUSTER_QUALIFIED_TYPES: &[&str] = &["container-instance", "service", "task"];
+
+/// The generation of an ECS task, service or container instance ARN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArnFormat {
+    /// `task/task-id`, without the cluster name
+    Legacy,
+    /// `task/cluster-name/task-id`
+    ClusterQualified,
+}
+
+/// The resource an [`EcsArn`] refers to.
+///
+/// The `cluster` of tasks, services and container instances is `None` for legacy format ARNs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EcsResource<'a> {
+    Cluster {
+        name: &'a str,
+    },
+    Service {
+        cluster: Option<&'a str>,
+        name: &'a str,
+    },
+    Task {
+        cluster: Option<&'a str>,
+        id: &'a str,
+    },
+    ContainerInstance {
+        cluster: Option<&'a str>,
+        id: &'a str,
+    },
+    /// `task-definition/family[:revision]`
+    TaskDefinition {
+        family: &'a str,
+        revision: Option<u32>,
+    },
+}
+
+/// An ECS ARN
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::ecs::{EcsArn, EcsResource};
+///
+/// let arn = EcsArn::parse("arn:aws:ecs:us-east-1:123456789012:task/my-cluster/1234567890abcdef0").unwrap();
+/// assert_eq!(arn.resource, EcsResource::Task { cluster: Some("my-cluster"), id: "1234567890abcdef0" });
+/// assert_eq!(arn.cluster(), Some("my-cluster"));
+///
+/// let arn = EcsArn::parse("arn:aws:ecs:us-east-1:123456789012:task-definition/my-task:7").unwrap();
+/// assert_eq!(arn.revision(), Some(7));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EcsArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub resource: EcsResource<'a>,
+}
+
+impl<'a> EcsArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        EcsArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The cluster's name, or the name of the cluster a resource is in if the ARN records it.
+    pub fn cluster(&self) -> Option<&'a str> {
+        match self.resource {
+            EcsResource::Cluster { name } => Some(name),
+            EcsResource::Service { cluster, .. }
+            | EcsResource::Task { cluster, .. }
+            | EcsResource::ContainerInstance { cluster, .. } => cluster,
+            EcsResource::TaskDefinition { .. } => None,
+        }
+    }
+
+    /// The task definition revision, if this is a task definition ARN with one.
+    pub fn revision(&self) -> Option<u32> {
+        match self.resource {
+            EcsResource::TaskDefinition { revision, .. } => revision,
+            _ => None,
+        }
+    }
+
+    /// The ARN format of tasks, services and container instances, or `None` for other resources.
+    pub fn format(&self) -> Option<ArnFormat> {
+        match self.resource {
+            EcsResource::Service { cluster, .. }
+            | EcsResource::Task { cluster, .. }
+            | EcsResource::ContainerInstance { cluster, .. } => match cluster {
+                Some(_) => Some(ArnFormat::ClusterQualified),
+                None => Some(ArnFormat::Legacy),
+            },
+            EcsResource::Cluster { .. } | EcsResource::TaskDefinition { .. } => None,
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for EcsArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "ecs" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let (resource_type, path) = arn
+            .resource
+            .split_once('/')
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+
+        let qualified = || match path.split_once('/') {
+            None => Ok((None, non_empty(path)?)),
+            Some((_, id)) if id.contains('/') => Err(ParseServiceArnError::InvalidResource),
+            Some((cluster, id)) => Ok((Some(non_empty(cluster)?), non_empty(id)?)),
+        };
+
+        let resource = match resource_type {
+            "cluster" if !path.contains('/') => EcsResource::Cluster {
+                name: non_empty(path)?,
+            },
+            "service" => {
+                let (cluster, name) = qualified()?;
+                EcsResource::Service { cluster, name }
+            }
+            "task" => {
+                let (cluster, id) = qualified()?;
+                EcsResource::Task { cluster, id }
+            }
+            "container-instance" => {
+                let (cluster, id) = qualified()?;
+                EcsResource::ContainerInstance { cluster, id }
+            }
+            "task-definition" if !path.contains('/') => {
+                let (family, revision) = match path.split_once(':') {
+                    Some((family, revision)) => (
+                        family,
+                        Some(
+                            revision
+                                .parse()
+                                .map_err(|_| ParseServiceArnError::InvalidResource)?,
+                        ),
+                    ),
+                    None => (path, None),
+                };
+
+                EcsResource::TaskDefinition {
+                    family: non_empty(family)?,
+                    revision,
+                }
+            }
+            "cluster" | "task-definition" => return Err(ParseServiceArnError::InvalidResource),
+            _ => return Err(ParseServiceArnError::UnknownResourceType),
+        };
+
+        Ok(EcsArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            resource,
+        })
+    }
+}
+
+impl<'a> fmt::Display for EcsArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:ecs:{}:{}:",
+            self.partition, self.region, self.account_id
+        )?;
+
+        let (resource_type, cluster, id) = match self.resource {
+            EcsResource::Cluster { name } => return write!(f, "cluster/{}", name),
+            EcsResource::TaskDefinition { family, revision } => {
+                write!(f, "task-definition/{}", family)?;
+
+                return match revision {
+                    Some(revision) => write!(f, ":{}", revision),
+                    None => Ok(()),
+                };
+            }
+            EcsResource::Service { cluster, name } => ("service", cluster, name),
+            EcsResource::Task { cluster, id } => ("task", cluster, id),
+            EcsResource::ContainerInstance { cluster, id } => ("container-instance", cluster, id),
+        };
+
+        match cluster {
+            Some(cluster) => write!(f, "{}/{}/{}", resource_type, cluster, id),
+            None => write!(f, "{}/{}", resource_type, id),
+        }
+    }
+}
+
+/// Detect which format an ECS task, service or container instance ARN uses.
+///
+/// Returns `None` for other ARNs, including other ECS resources whose format never changed.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::naive::NaiveArn;
+/// use arn::service::ecs::{format_of, ArnFormat};
+///
+/// let arn = NaiveArn::parse("arn:aws:ecs:us-east-1:123456789012:task/my-cluster/1234567890abcdef0").unwrap();
+/// assert_eq!(format_of(&arn), Some(ArnFormat::ClusterQualified));
+/// ~~~~
+pub fn format_of(arn: &NaiveArn) -> Option<ArnFormat> {
+    let (_, path) = split(arn)?;
+
+    match path.split('/').count() {
+        1 => Some(ArnFormat::Legacy),
+        2 => Some(ArnFormat::ClusterQualified),
+        _ => None,
+    }
+}
+
+/// Convert a legacy ARN to the cluster-qualified format, given the name of the cluster the resource is in.
+///
+/// Cluster-qualified ARNs are returned unchanged.
+pub fn to_cluster_qualified(arn: &NaiveArn, cluster: &str) -> Option<ArnBuf> {
+    let (resource_type, path) = split(arn)?;
+
+    let resource = match format_of(arn)? {
+        ArnFormat::ClusterQualified => return Some(ArnBuf::from(*arn)),
+        ArnFormat::Legacy => format!("{}/{}/{}", resource_type, cluster, path),
+    };
+
+    Some(ArnBuf {
+        resource,
+        ..ArnBuf::from(*arn)
+    })
+}
+
+/// Convert a cluster-qualified ARN to the legacy format, dropping the cluster name.
+///
+/// Legacy ARNs are returned unchanged, so this gives a common key for joining inventories that mix both formats.
+pub fn to_legacy(arn: &NaiveArn) -> Option<ArnBuf> {
+    let (resource_type, path) = split(arn)?;
+
+    let resource = match (format_of(arn)?, path.split_once('/')) {
+        (ArnFormat::ClusterQualified, Some((_, id))) => format!("{}/{}", resource_type, id),
+        _ => return Some(ArnBuf::from(*arn)),
+    };
+
+    Some(ArnBuf {
+        resource,
+        ..ArnBuf::from(*arn)
+    })
+}
+
+/// The cluster name, for cluster-qualified ARNs.
+pub fn cluster_of<'a>(arn: &NaiveArn<'a>) -> Option<&'a str> {
+    match format_of(arn)? {
+        ArnFormat::ClusterQualified => split(arn)?.1.split('/').next(),
+        ArnFormat::Legacy => None,
+    }
+}
+
+fn split<'a>(arn: &NaiveArn<'a>) -> Option<(&'a str, &'a str)> {
+    if arn.service != "ecs" {
+        return None;
+    }
+
+    arn.resource
+        .split_once('/')
+        .filter(|(resource_type, path)| {
+            CLUSTER_QUALIFIED_TYPES.contains(resource_type)
+                && !path.is_empty()
+                && path.split('/').all(|segment| !segment.is_empty())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        cluster_of, format_of, to_cluster_qualified, to_legacy, ArnFormat, EcsArn, EcsResource,
+    };
+    use crate::naive::NaiveArn;
+    use crate::service::ParseServiceArnError;
+
+    const LEGACY: &str = "arn:aws:ecs:us-east-1:123456789012:task/1234567890abcdef0";
+    const QUALIFIED: &str = "arn:aws:ecs:us-east-1:123456789012:task/my-cluster/1234567890abcdef0";
+
+    #[test]
+    fn detection() {
+        let legacy = NaiveArn::parse(LEGACY).unwrap();
+        let qualified = NaiveArn::parse(QUALIFIED).unwrap();
+
+        assert_eq!(format_of(&legacy), Some(ArnFormat::Legacy));
+        assert_eq!(format_of(&qualified), Some(ArnFormat::ClusterQualified));
+        assert_eq!(cluster_of(&legacy), None);
+        assert_eq!(cluster_of(&qualified), Some("my-cluster"));
+    }
+
+    #[test]
+    fn upgrade() {
+        let legacy = NaiveArn::parse(LEGACY).unwrap();
+        let qualified = NaiveArn::parse(QUALIFIED).unwrap();
+
+        assert_eq!(
+            to_cluster_qualified(&legacy, "my-cluster")
+                .unwrap()
+                .to_string(),
+            QUALIFIED
+        );
+        assert_eq!(
+            to_cluster_qualified(&qualified, "other-cluster")
+                .unwrap()
+                .to_string(),
+            QUALIFIED
+        );
+    }
+
+    #[test]
+    fn downgrade() {
+        let legacy = NaiveArn::parse(LEGACY).unwrap();
+        let qualified = NaiveArn::parse(QUALIFIED).unwrap();
+
+        assert_eq!(to_legacy(&qualified).unwrap().to_string(), LEGACY);
+        assert_eq!(to_legacy(&legacy).unwrap().to_string(), LEGACY);
+    }
+
+    #[test]
+    fn services_and_container_instances() {
+        let arn =
+            NaiveArn::parse("arn:aws:ecs:us-east-1:123456789012:service/my-cluster/my-service")
+                .unwrap();
+        assert_eq!(
+            to_legacy(&arn).unwrap().to_string(),
+            "arn:aws:ecs:us-east-1:123456789012:service/my-service"
+        );
+
+        let arn = NaiveArn::parse("arn:aws:ecs:us-east-1:123456789012:container-instance/0123abcd")
+            .unwrap();
+        assert_eq!(format_of(&arn), Some(ArnFormat::Legacy));
+    }
+
+    #[test]
+    fn unchanged_resources() {
+        for arn_str in &[
+            "arn:aws:ecs:us-east-1:123456789012:cluster/my-cluster",
+            "arn:aws:ecs:us-east-1:123456789012:task-definition/my-task:1",
+            "arn:aws:ecs:us-east-1:123456789012:task/",
+            "arn:aws:ec2:us-east-1:123456789012:task/my-cluster/abc",
+        ] {
+            let arn = NaiveArn::parse(arn_str).unwrap();
+
+            assert_eq!(format_of(&arn), None, "{}", arn_str);
+            assert_eq!(to_legacy(&arn), None, "{}", arn_str);
+        }
+    }
+
+    #[test]
+    fn typed_tasks() {
+        let legacy = EcsArn::parse(LEGACY).unwrap();
+        let qualified = EcsArn::parse(QUALIFIED).unwrap();
+
+        assert_eq!(
+            legacy.resource,
+            EcsResource::Task {
+                cluster: None,
+                id: "1234567890abcdef0"
+            }
+        );
+        assert_eq!(legacy.cluster(), None);
+        assert_eq!(legacy.format(), Some(ArnFormat::Legacy));
+        assert_eq!(legacy.to_string(), LEGACY);
+
+        assert_eq!(qualified.cluster(), Some("my-cluster"));
+        assert_eq!(qualified.format(), Some(ArnFormat::ClusterQualified));
+        assert_eq!(qualified.to_string(), QUALIFIED);
+    }
+
+    #[test]
+    fn typed_resources() {
+        for arn_str in &[
+            "arn:aws:ecs:us-east-1:123456789012:cluster/my-cluster",
+            "arn:aws:ecs:us-east-1:123456789012:service/my-cluster/my-service",
+            "arn:aws:ecs:us-east-1:123456789012:service/my-service",
+            "arn:aws:ecs:us-east-1:123456789012:container-instance/my-cluster/0123abcd",
+            "arn:aws:ecs:us-east-1:123456789012:task-definition/my-task:12",
+            "arn:aws:ecs:us-east-1:123456789012:task-definition/my-task",
+        ] {
+            assert_eq!(EcsArn::parse(arn_str).unwrap().to_string(), *arn_str);
+        }
+
+        let cluster =
+            EcsArn::parse("arn:aws:ecs:us-east-1:123456789012:cluster/my-cluster").unwrap();
+        assert_eq!(cluster.cluster(), Some("my-cluster"));
+        assert_eq!(cluster.format(), None);
+
+        let task_definition =
+            EcsArn::parse("arn:aws:ecs:us-east-1:123456789012:task-definition/my-task:12").unwrap();
+        assert_eq!(
+            task_definition.resource,
+            EcsResource::TaskDefinition {
+                family: "my-task",
+                revision: Some(12)
+            }
+        );
+        assert_eq!(task_definition.revision(), Some(12));
+    }
+
+    #[test]
+    fn typed_invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:ecs:us-east-1:123456789012:{}", resource);
+            EcsArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(parse("task/"), Err(ParseServiceArnError::InvalidResource));
+        assert_eq!(
+            parse("task//1234567890abcdef0"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("task/my-cluster/1234567890abcdef0/extra"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("cluster/my-cluster/extra"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("task-definition/my-task:latest"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("capacity-provider/my-provider"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
@@ -0,0 +1,221 @@
+//! Amazon EKS (`arn:partition:eks:region:account-id:resource-type/cluster-name/...`) ARNs
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// The resource an [`EksArn`] refers to.
+///
+/// Every resource other than the cluster itself is nested under a cluster, and carries a UUID that distinguishes it
+/// from earlier resources with the same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EksResource<'a> {
+    /// `cluster/name`
+    Cluster { name: &'a str },
+    /// `nodegroup/cluster/name/uuid`
+    Nodegroup {
+        cluster: &'a str,
+        name: &'a str,
+        uuid: &'a str,
+    },
+    /// `fargateprofile/cluster/name/uuid`
+    FargateProfile {
+        cluster: &'a str,
+        name: &'a str,
+        uuid: &'a str,
+    },
+    /// `addon/cluster/name/uuid`
+    Addon {
+        cluster: &'a str,
+        name: &'a str,
+        uuid: &'a str,
+    },
+}
+
+/// An EKS ARN
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::eks::{EksArn, EksResource};
+///
+/// let arn = EksArn::parse("arn:aws:eks:us-west-2:123456789012:nodegroup/my-cluster/workers/0cbd6f0a-8c9a-9e1f-2c3d-4e5f6a7b8c9d").unwrap();
+/// assert!(matches!(arn.resource, EksResource::Nodegroup { name: "workers", .. }));
+/// assert_eq!(arn.cluster(), "my-cluster");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EksArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub resource: EksResource<'a>,
+}
+
+impl<'a> EksArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        EksArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The cluster's name, or the name of the cluster the resource belongs to.
+    pub fn cluster(&self) -> &'a str {
+        match self.resource {
+            EksResource::Cluster { name } => name,
+            EksResource::Nodegroup { cluster, .. }
+            | EksResource::FargateProfile { cluster, .. }
+            | EksResource::Addon { cluster, .. } => cluster,
+        }
+    }
+
+    /// The ARN of the cluster the resource belongs to.
+    pub fn cluster_arn(&self) -> Self {
+        EksArn {
+            resource: EksResource::Cluster {
+                name: self.cluster(),
+            },
+            ..*self
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for EksArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "eks" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let (resource_type, path) = arn
+            .resource
+            .split_once('/')
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+        let segments = path
+            .split('/')
+            .map(non_empty)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let resource = match (resource_type, segments.as_slice()) {
+            ("cluster", [name]) => EksResource::Cluster { name },
+            ("nodegroup", [cluster, name, uuid]) => EksResource::Nodegroup {
+                cluster,
+                name,
+                uuid,
+            },
+            ("fargateprofile", [cluster, name, uuid]) => EksResource::FargateProfile {
+                cluster,
+                name,
+                uuid,
+            },
+            ("addon", [cluster, name, uuid]) => EksResource::Addon {
+                cluster,
+                name,
+                uuid,
+            },
+            ("cluster", _) | ("nodegroup", _) | ("fargateprofile", _) | ("addon", _) => {
+                return Err(ParseServiceArnError::InvalidResource)
+            }
+            _ => return Err(ParseServiceArnError::UnknownResourceType),
+        };
+
+        Ok(EksArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            resource,
+        })
+    }
+}
+
+impl<'a> fmt::Display for EksArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:eks:{}:{}:",
+            self.partition, self.region, self.account_id
+        )?;
+
+        let (resource_type, cluster, name, uuid) = match self.resource {
+            EksResource::Cluster { name } => return write!(f, "cluster/{}", name),
+            EksResource::Nodegroup {
+                cluster,
+                name,
+                uuid,
+            } => ("nodegroup", cluster, name, uuid),
+            EksResource::FargateProfile {
+                cluster,
+                name,
+                uuid,
+            } => ("fargateprofile", cluster, name, uuid),
+            EksResource::Addon {
+                cluster,
+                name,
+                uuid,
+            } => ("addon", cluster, name, uuid),
+        };
+
+        write!(f, "{}/{}/{}/{}", resource_type, cluster, name, uuid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EksArn, EksResource};
+    use crate::service::ParseServiceArnError;
+
+    const CLUSTER: &str = "arn:aws:eks:us-west-2:123456789012:cluster/my-cluster";
+
+    #[test]
+    fn cluster() {
+        let arn = EksArn::parse(CLUSTER).unwrap();
+
+        assert_eq!(arn.resource, EksResource::Cluster { name: "my-cluster" });
+        assert_eq!(arn.cluster(), "my-cluster");
+        assert_eq!(arn.cluster_arn(), arn);
+        assert_eq!(arn.to_string(), CLUSTER);
+    }
+
+    #[test]
+    fn nested() {
+        for resource in &[
+            "nodegroup/my-cluster/workers/0cbd6f0a-8c9a-9e1f-2c3d-4e5f6a7b8c9d",
+            "fargateprofile/my-cluster/default/1ab2c3d4-5e6f-7a8b-9c0d-1e2f3a4b5c6d",
+            "addon/my-cluster/vpc-cni/8abc1234-5678-90de-f123-456789abcdef",
+        ] {
+            let arn_str = format!("arn:aws:eks:us-west-2:123456789012:{}", resource);
+            let arn = EksArn::parse(&arn_str).unwrap();
+
+            assert_eq!(arn.cluster(), "my-cluster");
+            assert_eq!(arn.cluster_arn().to_string(), CLUSTER);
+            assert_eq!(arn.to_string(), arn_str);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:eks:us-west-2:123456789012:{}", resource);
+            EksArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(
+            parse("cluster/my-cluster/extra"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("nodegroup/my-cluster/workers"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("nodegroup/my-cluster//0cbd6f0a"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("podidentityassociation/my-cluster/a-0123"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
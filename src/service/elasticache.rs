@@ -0,0 +1,387 @@
+//! Amazon ElastiCache (`arn:partition:elasticache:region:account-id:resource-type:name`) ARNs
+
+use alloc::format;
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::buf::ArnBuf;
+use crate::naive::NaiveArn;
+use crate::service::{lookup, non_empty, regional, ParseServiceArnError};
+
+/// The kind of resource an [`ElastiCacheArn`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ElastiCacheResourceKind {
+    Cluster,
+    GlobalReplicationGroup,
+    ParameterGroup,
+    ReplicationGroup,
+    ReservedInstance,
+    ServerlessCache,
+    Snapshot,
+    SubnetGroup,
+    User,
+    UserGroup,
+}
+
+impl ElastiCacheResourceKind {
+    /// `(resource type, kind)`, sorted by resource type.
+    const ALL: &'static [(&'static str, ElastiCacheResourceKind)] = &[
+        ("cluster", ElastiCacheResourceKind::Cluster),
+        (
+            "globalreplicationgroup",
+            ElastiCacheResourceKind::GlobalReplicationGroup,
+        ),
+        ("parametergroup", ElastiCacheResourceKind::ParameterGroup),
+        (
+            "replicationgroup",
+            ElastiCacheResourceKind::ReplicationGroup,
+        ),
+        (
+            "reserved-instance",
+            ElastiCacheResourceKind::ReservedInstance,
+        ),
+        ("serverlesscache", ElastiCacheResourceKind::ServerlessCache),
+        ("snapshot", ElastiCacheResourceKind::Snapshot),
+        ("subnetgroup", ElastiCacheResourceKind::SubnetGroup),
+        ("user", ElastiCacheResourceKind::User),
+        ("usergroup", ElastiCacheResourceKind::UserGroup),
+    ];
+
+    fn from_resource_type(resource_type: &str) -> Option<Self> {
+        lookup(Self::ALL, resource_type)
+    }
+
+    /// The resource type, as it appears in the ARN.
+    pub fn resource_type(self) -> &'static str {
+        match self {
+            ElastiCacheResourceKind::Cluster => "cluster",
+            ElastiCacheResourceKind::GlobalReplicationGroup => "globalreplicationgroup",
+            ElastiCacheResourceKind::ParameterGroup => "parametergroup",
+            ElastiCacheResourceKind::ReplicationGroup => "replicationgroup",
+            ElastiCacheResourceKind::ReservedInstance => "reserved-instance",
+            ElastiCacheResourceKind::ServerlessCache => "serverlesscache",
+            ElastiCacheResourceKind::Snapshot => "snapshot",
+            ElastiCacheResourceKind::SubnetGroup => "subnetgroup",
+            ElastiCacheResourceKind::User => "user",
+            ElastiCacheResourceKind::UserGroup => "usergroup",
+        }
+    }
+}
+
+/// An ElastiCache ARN
+///
+/// The resource type and name are separated by `:`, not `/`, and names can't contain either.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::elasticache::{ElastiCacheArn, ElastiCacheResourceKind};
+///
+/// let arn = ElastiCacheArn::parse("arn:aws:elasticache:us-east-1:123456789012:replicationgroup:sessions").unwrap();
+/// assert_eq!(arn.kind, ElastiCacheResourceKind::ReplicationGroup);
+/// assert_eq!(arn.name, "sessions");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ElastiCacheArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub kind: ElastiCacheResourceKind,
+    pub name: &'a str,
+}
+
+impl<'a> ElastiCacheArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        ElastiCacheArn::try_from(NaiveArn::parse(s)?)
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for ElastiCacheArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "elasticache" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let (resource_type, name) = arn
+            .resource
+            .split_once(':')
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+        let kind = ElastiCacheResourceKind::from_resource_type(resource_type)
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+
+        if name.contains([':', '/']) {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        Ok(ElastiCacheArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            kind,
+            name: non_empty(name)?,
+        })
+    }
+}
+
+impl<'a> fmt::Display for ElastiCacheArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:elasticache:{}:{}:{}:{}",
+            self.partition,
+            self.region,
+            self.account_id,
+            self.kind.resource_type(),
+            self.name
+        )
+    }
+}
+
+/// The generation of an ElastiCache Redis or Valkey ARN.
+///
+/// Redis was first modelled as standalone cache clusters, one `cluster:` ARN per node; replication groups came later,
+/// and newer APIs (and most inventories) identify the cache by its `replicationgroup:` ARN instead. The nodes of a
+/// replication group are named after it, `group-001` or, with cluster mode enabled, `group-0001-001`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArnFormat {
+    /// `cluster:group-001`, a node of a replication group
+    CacheCluster,
+    /// `replicationgroup:group`
+    ReplicationGroup,
+}
+
+/// Detect which format an ElastiCache cache ARN uses.
+///
+/// Returns `None` for other ARNs, including cache clusters whose names don't follow the replication group node
+/// naming, such as Memcached clusters.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::naive::NaiveArn;
+/// use arn::service::elasticache::{format_of, ArnFormat};
+///
+/// let arn = NaiveArn::parse("arn:aws:elasticache:us-east-1:123456789012:cluster:sessions-0001-002").unwrap();
+/// assert_eq!(format_of(&arn), Some(ArnFormat::CacheCluster));
+/// ~~~~
+pub fn format_of(arn: &NaiveArn) -> Option<ArnFormat> {
+    match split(arn)? {
+        ("cluster", name) => split_node(name).map(|_| ArnFormat::CacheCluster),
+        ("replicationgroup", _) => Some(ArnFormat::ReplicationGroup),
+        _ => None,
+    }
+}
+
+/// Convert the ARN of a replication group's node to the replication group's ARN.
+///
+/// Replication group ARNs are returned unchanged, so this gives a common key for joining inventories that mix both
+/// formats.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::naive::NaiveArn;
+/// use arn::service::elasticache::to_replication_group;
+///
+/// let arn = NaiveArn::parse("arn:aws:elasticache:us-east-1:123456789012:cluster:sessions-001").unwrap();
+/// assert_eq!(
+///     to_replication_group(&arn).unwrap().to_string(),
+///     "arn:aws:elasticache:us-east-1:123456789012:replicationgroup:sessions"
+/// );
+/// ~~~~
+pub fn to_replication_group(arn: &NaiveArn) -> Option<ArnBuf> {
+    let resource = match format_of(arn)? {
+        ArnFormat::ReplicationGroup => return Some(ArnBuf::from(*arn)),
+        ArnFormat::CacheCluster => format!("replicationgroup:{}", replication_group_of(arn)?),
+    };
+
+    Some(ArnBuf {
+        resource,
+        ..ArnBuf::from(*arn)
+    })
+}
+
+/// Convert a replication group ARN to the ARN of one of its nodes, given the node's suffix: `001` for the first node,
+/// or `0001-001` for the first node of the first shard with cluster mode enabled.
+///
+/// Node ARNs are returned unchanged.
+pub fn to_cache_cluster(arn: &NaiveArn, node: &str) -> Option<ArnBuf> {
+    let resource = match format_of(arn)? {
+        ArnFormat::CacheCluster => return Some(ArnBuf::from(*arn)),
+        ArnFormat::ReplicationGroup => {
+            let name = format!("{}-{}", split(arn)?.1, node);
+            if split_node(&name)?.1 != node {
+                return None;
+            }
+            format!("cluster:{}", name)
+        }
+    };
+
+    Some(ArnBuf {
+        resource,
+        ..ArnBuf::from(*arn)
+    })
+}
+
+/// The name of the replication group, for node and replication group ARNs.
+pub fn replication_group_of<'a>(arn: &NaiveArn<'a>) -> Option<&'a str> {
+    match split(arn)? {
+        ("cluster", name) => split_node(name).map(|(group, _)| group),
+        ("replicationgroup", name) => Some(name),
+        _ => None,
+    }
+}
+
+fn split<'a>(arn: &NaiveArn<'a>) -> Option<(&'a str, &'a str)> {
+    if arn.service != "elasticache" {
+        return None;
+    }
+
+    arn.resource
+        .split_once(':')
+        .filter(|(_, name)| !name.is_empty() && !name.contains([':', '/']))
+}
+
+/// Split a node name, `group-001` or `group-0001-001`, into the replication group's name and the node's suffix.
+fn split_node(name: &str) -> Option<(&str, &str)> {
+    let is_digits = |s: &str, len: usize| s.len() == len && s.bytes().all(|b| b.is_ascii_digit());
+
+    let (rest, node) = name.rsplit_once('-')?;
+    if !is_digits(node, 3) {
+        return None;
+    }
+
+    let group = match rest.rsplit_once('-') {
+        Some((group, shard)) if is_digits(shard, 4) => group,
+        _ => rest,
+    };
+
+    if group.is_empty() {
+        return None;
+    }
+
+    Some((group, &name[group.len() + 1..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        format_of, replication_group_of, to_cache_cluster, to_replication_group, ArnFormat,
+        ElastiCacheArn, ElastiCacheResourceKind,
+    };
+    use crate::naive::NaiveArn;
+    use crate::service::ParseServiceArnError;
+
+    const NODE: &str = "arn:aws:elasticache:us-east-1:123456789012:cluster:sessions-001";
+    const SHARD_NODE: &str = "arn:aws:elasticache:us-east-1:123456789012:cluster:sessions-0002-001";
+    const GROUP: &str = "arn:aws:elasticache:us-east-1:123456789012:replicationgroup:sessions";
+
+    #[test]
+    fn resource_types_sorted() {
+        assert!(ElastiCacheResourceKind::ALL
+            .windows(2)
+            .all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn kinds() {
+        for (resource_type, kind) in ElastiCacheResourceKind::ALL {
+            let arn_str = format!(
+                "arn:aws:elasticache:us-east-1:123456789012:{}:my-resource",
+                resource_type
+            );
+            let arn = ElastiCacheArn::parse(&arn_str).unwrap();
+
+            assert_eq!(arn.kind, *kind);
+            assert_eq!(arn.name, "my-resource");
+            assert_eq!(arn.to_string(), arn_str);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:elasticache:us-east-1:123456789012:{}", resource);
+            ElastiCacheArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(
+            parse("cluster:"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("cluster:sessions:0001"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("cluster/sessions"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            parse("users:default"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+
+    #[test]
+    fn detection() {
+        for (arn_str, format) in [
+            (NODE, ArnFormat::CacheCluster),
+            (SHARD_NODE, ArnFormat::CacheCluster),
+            (GROUP, ArnFormat::ReplicationGroup),
+        ] {
+            let arn = NaiveArn::parse(arn_str).unwrap();
+
+            assert_eq!(format_of(&arn), Some(format), "{}", arn_str);
+            assert_eq!(replication_group_of(&arn), Some("sessions"), "{}", arn_str);
+        }
+    }
+
+    #[test]
+    fn upgrade() {
+        for arn_str in [NODE, SHARD_NODE, GROUP] {
+            let arn = NaiveArn::parse(arn_str).unwrap();
+            assert_eq!(
+                to_replication_group(&arn).unwrap().to_string(),
+                GROUP,
+                "{}",
+                arn_str
+            );
+        }
+    }
+
+    #[test]
+    fn downgrade() {
+        let group = NaiveArn::parse(GROUP).unwrap();
+        let node = NaiveArn::parse(NODE).unwrap();
+
+        assert_eq!(to_cache_cluster(&group, "001").unwrap().to_string(), NODE);
+        assert_eq!(
+            to_cache_cluster(&group, "0002-001").unwrap().to_string(),
+            SHARD_NODE
+        );
+        assert_eq!(to_cache_cluster(&node, "002").unwrap().to_string(), NODE);
+        assert_eq!(to_cache_cluster(&group, "1"), None);
+        assert_eq!(to_cache_cluster(&group, "primary"), None);
+    }
+
+    #[test]
+    fn unchanged_resources() {
+        for arn_str in &[
+            "arn:aws:elasticache:us-east-1:123456789012:cluster:memcached",
+            "arn:aws:elasticache:us-east-1:123456789012:cluster:-001",
+            "arn:aws:elasticache:us-east-1:123456789012:snapshot:sessions-001",
+            "arn:aws:elasticache:us-east-1:123456789012:serverlesscache:sessions",
+            "arn:aws:ec2:us-east-1:123456789012:cluster:sessions-001",
+        ] {
+            let arn = NaiveArn::parse(arn_str).unwrap();
+
+            assert_eq!(format_of(&arn), None, "{}", arn_str);
+            assert_eq!(to_replication_group(&arn), None, "{}", arn_str);
+        }
+    }
+}
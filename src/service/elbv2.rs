@@ -0,0 +1,278 @@
+//! Elastic Load Balancing v2 (`arn:partition:elasticloadbalancing:region:account-id:loadbalancer/app/...`) ARNs
+
+use alloc::{format, string::String, vec::Vec};
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// The type of an ELBv2 load balancer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoadBalancerType {
+    /// `app`
+    Application,
+    /// `net`
+    Network,
+    /// `gwy`
+    Gateway,
+}
+
+impl LoadBalancerType {
+    /// The type, as it appears in the ARN.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LoadBalancerType::Application => "app",
+            LoadBalancerType::Network => "net",
+            LoadBalancerType::Gateway => "gwy",
+        }
+    }
+}
+
+/// An application, network or gateway load balancer ARN, `loadbalancer/type/name/id`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LoadBalancerArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub load_balancer_type: LoadBalancerType,
+    pub name: &'a str,
+    pub id: &'a str,
+}
+
+impl<'a> LoadBalancerArn<'a> {
+    /// The `type/name/id` label CloudWatch and Application Auto Scaling use to identify the load balancer.
+    pub fn resource_label(&self) -> String {
+        format!(
+            "{}/{}/{}",
+            self.load_balancer_type.as_str(),
+            self.name,
+            self.id
+        )
+    }
+}
+
+impl<'a> fmt::Display for LoadBalancerArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:elasticloadbalancing:{}:{}:loadbalancer/{}",
+            self.partition,
+            self.region,
+            self.account_id,
+            self.resource_label()
+        )
+    }
+}
+
+/// A target group ARN, `targetgroup/name/id`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TargetGroupArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub name: &'a str,
+    pub id: &'a str,
+}
+
+impl<'a> TargetGroupArn<'a> {
+    /// The `app/lb-name/lb-id/targetgroup/tg-name/tg-id` label identifying this target group behind
+    /// `load_balancer`, as required by the `ALBRequestCountPerTarget` scaling metric.
+    pub fn resource_label(&self, load_balancer: &LoadBalancerArn) -> String {
+        format!(
+            "{}/targetgroup/{}/{}",
+            load_balancer.resource_label(),
+            self.name,
+            self.id
+        )
+    }
+}
+
+impl<'a> fmt::Display for TargetGroupArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:elasticloadbalancing:{}:{}:targetgroup/{}/{}",
+            self.partition, self.region, self.account_id, self.name, self.id
+        )
+    }
+}
+
+/// An ELBv2 load balancer or target group ARN
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::elbv2::{ElbV2Arn, LoadBalancerType};
+///
+/// let lb = match ElbV2Arn::parse("arn:aws:elasticloadbalancing:us-west-2:123456789012:loadbalancer/app/my-load-balancer/50dc6c495c0c9188").unwrap() {
+///     ElbV2Arn::LoadBalancer(lb) => lb,
+///     _ => unreachable!(),
+/// };
+/// assert_eq!(lb.load_balancer_type, LoadBalancerType::Application);
+/// assert_eq!(lb.name, "my-load-balancer");
+/// assert_eq!(lb.resource_label(), "app/my-load-balancer/50dc6c495c0c9188");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ElbV2Arn<'a> {
+    LoadBalancer(LoadBalancerArn<'a>),
+    TargetGroup(TargetGroupArn<'a>),
+}
+
+impl<'a> ElbV2Arn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        ElbV2Arn::try_from(NaiveArn::parse(s)?)
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for ElbV2Arn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "elasticloadbalancing" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let (resource_type, path) = arn
+            .resource
+            .split_once('/')
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+        let segments = path
+            .split('/')
+            .map(non_empty)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match (resource_type, segments.as_slice()) {
+            ("loadbalancer", [load_balancer_type, name, id]) => {
+                let load_balancer_type = match *load_balancer_type {
+                    "app" => LoadBalancerType::Application,
+                    "net" => LoadBalancerType::Network,
+                    "gwy" => LoadBalancerType::Gateway,
+                    _ => return Err(ParseServiceArnError::InvalidResource),
+                };
+
+                Ok(ElbV2Arn::LoadBalancer(LoadBalancerArn {
+                    partition: arn.partition,
+                    region,
+                    account_id,
+                    load_balancer_type,
+                    name,
+                    id,
+                }))
+            }
+            // Classic load balancers, `loadbalancer/name`, are a different API
+            ("loadbalancer", [_]) => Err(ParseServiceArnError::UnknownResourceType),
+            ("targetgroup", [name, id]) => Ok(ElbV2Arn::TargetGroup(TargetGroupArn {
+                partition: arn.partition,
+                region,
+                account_id,
+                name,
+                id,
+            })),
+            ("loadbalancer", _) | ("targetgroup", _) => Err(ParseServiceArnError::InvalidResource),
+            _ => Err(ParseServiceArnError::UnknownResourceType),
+        }
+    }
+}
+
+impl<'a> fmt::Display for ElbV2Arn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ElbV2Arn::LoadBalancer(load_balancer) => load_balancer.fmt(f),
+            ElbV2Arn::TargetGroup(target_group) => target_group.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ElbV2Arn, LoadBalancerArn, LoadBalancerType, TargetGroupArn};
+    use crate::service::ParseServiceArnError;
+
+    const LOAD_BALANCER: &str = "arn:aws:elasticloadbalancing:us-west-2:123456789012:loadbalancer/app/my-load-balancer/50dc6c495c0c9188";
+    const TARGET_GROUP: &str = "arn:aws:elasticloadbalancing:us-west-2:123456789012:targetgroup/my-targets/73e2d6bc24d8a067";
+
+    fn load_balancer() -> LoadBalancerArn<'static> {
+        match ElbV2Arn::parse(LOAD_BALANCER).unwrap() {
+            ElbV2Arn::LoadBalancer(load_balancer) => load_balancer,
+            arn => panic!("{:?}", arn),
+        }
+    }
+
+    fn target_group() -> TargetGroupArn<'static> {
+        match ElbV2Arn::parse(TARGET_GROUP).unwrap() {
+            ElbV2Arn::TargetGroup(target_group) => target_group,
+            arn => panic!("{:?}", arn),
+        }
+    }
+
+    #[test]
+    fn load_balancers() {
+        let load_balancer = load_balancer();
+
+        assert_eq!(
+            load_balancer.load_balancer_type,
+            LoadBalancerType::Application
+        );
+        assert_eq!(load_balancer.name, "my-load-balancer");
+        assert_eq!(load_balancer.id, "50dc6c495c0c9188");
+        assert_eq!(load_balancer.to_string(), LOAD_BALANCER);
+
+        let arn_str = "arn:aws:elasticloadbalancing:us-west-2:123456789012:loadbalancer/net/my-nlb/0123456789abcdef";
+        assert_eq!(ElbV2Arn::parse(arn_str).unwrap().to_string(), arn_str);
+    }
+
+    #[test]
+    fn target_groups() {
+        let target_group = target_group();
+
+        assert_eq!(target_group.name, "my-targets");
+        assert_eq!(target_group.id, "73e2d6bc24d8a067");
+        assert_eq!(target_group.to_string(), TARGET_GROUP);
+    }
+
+    #[test]
+    fn resource_labels() {
+        assert_eq!(
+            load_balancer().resource_label(),
+            "app/my-load-balancer/50dc6c495c0c9188"
+        );
+        assert_eq!(
+            target_group().resource_label(&load_balancer()),
+            "app/my-load-balancer/50dc6c495c0c9188/targetgroup/my-targets/73e2d6bc24d8a067"
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!(
+                "arn:aws:elasticloadbalancing:us-west-2:123456789012:{}",
+                resource
+            );
+            ElbV2Arn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(
+            parse("loadbalancer/my-classic-lb"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            parse("loadbalancer/xyz/my-lb/50dc6c495c0c9188"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("targetgroup/my-targets"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("targetgroup//73e2d6bc24d8a067"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("listener/app/my-lb/50dc6c495c0c9188/f2f7dc8efc522ab2"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
@@ -0,0 +1,202 @@
+//! Amazon EventBridge (`arn:partition:events:region:account-id:rule/...`) ARNs
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// The resource an [`EventBridgeArn`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventBridgeResource<'a> {
+    /// `event-bus/name`
+    EventBus { name: &'a str },
+    /// `rule/name` on the default event bus, or `rule/bus-name/name` on any other
+    Rule {
+        event_bus: Option<&'a str>,
+        name: &'a str,
+    },
+    /// `archive/name`
+    Archive { name: &'a str },
+}
+
+/// An EventBridge ARN
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::events::{EventBridgeArn, EventBridgeResource};
+///
+/// let arn = EventBridgeArn::parse("arn:aws:events:us-east-1:123456789012:rule/orders/order-created").unwrap();
+/// assert_eq!(arn.resource, EventBridgeResource::Rule { event_bus: Some("orders"), name: "order-created" });
+/// assert_eq!(arn.event_bus(), Some("orders"));
+///
+/// let arn = EventBridgeArn::parse("arn:aws:events:us-east-1:123456789012:rule/nightly").unwrap();
+/// assert_eq!(arn.event_bus(), Some("default"));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventBridgeArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub resource: EventBridgeResource<'a>,
+}
+
+impl<'a> EventBridgeArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        EventBridgeArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The event bus's name, or the name of the bus a rule is on. Archives don't record their bus in the ARN.
+    pub fn event_bus(&self) -> Option<&'a str> {
+        match self.resource {
+            EventBridgeResource::EventBus { name } => Some(name),
+            EventBridgeResource::Rule { event_bus, .. } => Some(event_bus.unwrap_or("default")),
+            EventBridgeResource::Archive { .. } => None,
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for EventBridgeArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "events" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let (resource_type, path) = arn
+            .resource
+            .split_once('/')
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+        let segments = path
+            .split('/')
+            .map(non_empty)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let resource = match (resource_type, segments.as_slice()) {
+            ("event-bus", [name]) => EventBridgeResource::EventBus { name },
+            ("rule", [name]) => EventBridgeResource::Rule {
+                event_bus: None,
+                name,
+            },
+            ("rule", [event_bus, name]) => EventBridgeResource::Rule {
+                event_bus: Some(event_bus),
+                name,
+            },
+            ("archive", [name]) => EventBridgeResource::Archive { name },
+            ("event-bus", _) | ("rule", _) | ("archive", _) => {
+                return Err(ParseServiceArnError::InvalidResource)
+            }
+            _ => return Err(ParseServiceArnError::UnknownResourceType),
+        };
+
+        Ok(EventBridgeArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            resource,
+        })
+    }
+}
+
+impl<'a> fmt::Display for EventBridgeArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:events:{}:{}:",
+            self.partition, self.region, self.account_id
+        )?;
+
+        match self.resource {
+            EventBridgeResource::EventBus { name } => write!(f, "event-bus/{}", name),
+            EventBridgeResource::Rule {
+                event_bus: Some(event_bus),
+                name,
+            } => write!(f, "rule/{}/{}", event_bus, name),
+            EventBridgeResource::Rule {
+                event_bus: None,
+                name,
+            } => write!(f, "rule/{}", name),
+            EventBridgeResource::Archive { name } => write!(f, "archive/{}", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventBridgeArn, EventBridgeResource};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn resources() {
+        for (arn_str, event_bus) in &[
+            (
+                "arn:aws:events:us-east-1:123456789012:event-bus/orders",
+                Some("orders"),
+            ),
+            (
+                "arn:aws:events:us-east-1:123456789012:rule/nightly",
+                Some("default"),
+            ),
+            (
+                "arn:aws:events:us-east-1:123456789012:rule/orders/order-created",
+                Some("orders"),
+            ),
+            (
+                "arn:aws:events:us-east-1:123456789012:archive/orders-archive",
+                None,
+            ),
+        ] {
+            let arn = EventBridgeArn::parse(arn_str).unwrap();
+
+            assert_eq!(arn.event_bus(), *event_bus, "{}", arn_str);
+            assert_eq!(arn.to_string(), *arn_str);
+        }
+    }
+
+    #[test]
+    fn rule_on_custom_bus() {
+        let arn = EventBridgeArn::parse(
+            "arn:aws:events:us-east-1:123456789012:rule/orders/order-created",
+        )
+        .unwrap();
+
+        assert_eq!(
+            arn,
+            EventBridgeArn {
+                partition: "aws",
+                region: "us-east-1",
+                account_id: "123456789012",
+                resource: EventBridgeResource::Rule {
+                    event_bus: Some("orders"),
+                    name: "order-created",
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:events:us-east-1:123456789012:{}", resource);
+            EventBridgeArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(parse("rule/"), Err(ParseServiceArnError::InvalidResource));
+        assert_eq!(
+            parse("rule/a/b/c"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("event-bus/orders/extra"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("connection/my-connection/0123"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
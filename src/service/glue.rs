@@ -0,0 +1,224 @@
+//! AWS Glue Data Catalog (`arn:partition:glue:region:account-id:table/...`) ARNs
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// The resource a [`GlueArn`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GlueResource<'a> {
+    /// `catalog`, the account's Data Catalog in the region
+    Catalog,
+    /// `database/name`
+    Database { name: &'a str },
+    /// `table/database-name/table-name`
+    Table { database: &'a str, name: &'a str },
+}
+
+/// A Glue Data Catalog ARN
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::glue::{GlueArn, GlueResource};
+///
+/// let arn = GlueArn::parse("arn:aws:glue:us-east-1:123456789012:table/sales/orders").unwrap();
+/// assert_eq!(arn.resource, GlueResource::Table { database: "sales", name: "orders" });
+/// assert_eq!(arn.database(), Some("sales"));
+/// assert_eq!(arn.table(), Some("orders"));
+/// assert_eq!(arn.database_arn().unwrap().to_string(), "arn:aws:glue:us-east-1:123456789012:database/sales");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GlueArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub resource: GlueResource<'a>,
+}
+
+impl<'a> GlueArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        GlueArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The database's name, or the name of the database a table is in.
+    pub fn database(&self) -> Option<&'a str> {
+        match self.resource {
+            GlueResource::Catalog => None,
+            GlueResource::Database { name } | GlueResource::Table { database: name, .. } => {
+                Some(name)
+            }
+        }
+    }
+
+    /// The table's name, if this is a table ARN.
+    pub fn table(&self) -> Option<&'a str> {
+        match self.resource {
+            GlueResource::Table { name, .. } => Some(name),
+            _ => None,
+        }
+    }
+
+    /// The ARN of the database, or of the database a table is in.
+    pub fn database_arn(&self) -> Option<GlueArn<'a>> {
+        Some(GlueArn {
+            resource: GlueResource::Database {
+                name: self.database()?,
+            },
+            ..*self
+        })
+    }
+
+    /// The ARN of the Data Catalog that holds this resource.
+    pub fn catalog_arn(&self) -> GlueArn<'a> {
+        GlueArn {
+            resource: GlueResource::Catalog,
+            ..*self
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for GlueArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "glue" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+
+        let resource = if arn.resource == "catalog" {
+            GlueResource::Catalog
+        } else {
+            let (resource_type, path) = arn
+                .resource
+                .split_once('/')
+                .ok_or(ParseServiceArnError::UnknownResourceType)?;
+            let segments = path
+                .split('/')
+                .map(non_empty)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            match (resource_type, segments.as_slice()) {
+                ("database", [name]) => GlueResource::Database { name },
+                ("table", [database, name]) => GlueResource::Table { database, name },
+                ("database", _) | ("table", _) => {
+                    return Err(ParseServiceArnError::InvalidResource)
+                }
+                _ => return Err(ParseServiceArnError::UnknownResourceType),
+            }
+        };
+
+        Ok(GlueArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            resource,
+        })
+    }
+}
+
+impl<'a> fmt::Display for GlueArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:glue:{}:{}:",
+            self.partition, self.region, self.account_id
+        )?;
+
+        match self.resource {
+            GlueResource::Catalog => f.write_str("catalog"),
+            GlueResource::Database { name } => write!(f, "database/{}", name),
+            GlueResource::Table { database, name } => write!(f, "table/{}/{}", database, name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GlueArn, GlueResource};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn resources() {
+        for (arn_str, resource) in &[
+            (
+                "arn:aws:glue:us-east-1:123456789012:catalog",
+                GlueResource::Catalog,
+            ),
+            (
+                "arn:aws:glue:us-east-1:123456789012:database/sales",
+                GlueResource::Database { name: "sales" },
+            ),
+            (
+                "arn:aws:glue:us-east-1:123456789012:table/sales/orders",
+                GlueResource::Table {
+                    database: "sales",
+                    name: "orders",
+                },
+            ),
+        ] {
+            let arn = GlueArn::parse(arn_str).unwrap();
+
+            assert_eq!(arn.resource, *resource);
+            assert_eq!(arn.to_string(), *arn_str);
+            assert_eq!(
+                arn.catalog_arn().to_string(),
+                "arn:aws:glue:us-east-1:123456789012:catalog"
+            );
+        }
+    }
+
+    #[test]
+    fn accessors() {
+        let catalog = GlueArn::parse("arn:aws:glue:us-east-1:123456789012:catalog").unwrap();
+        assert_eq!(catalog.database(), None);
+        assert_eq!(catalog.database_arn(), None);
+
+        let database =
+            GlueArn::parse("arn:aws:glue:us-east-1:123456789012:database/sales").unwrap();
+        assert_eq!(database.database(), Some("sales"));
+        assert_eq!(database.table(), None);
+        assert_eq!(database.database_arn(), Some(database));
+
+        let table =
+            GlueArn::parse("arn:aws:glue:us-east-1:123456789012:table/sales/orders").unwrap();
+        assert_eq!(table.database_arn(), Some(database));
+    }
+
+    #[test]
+    fn invalid() {
+        for (arn_str, err) in &[
+            (
+                "arn:aws:glue:us-east-1:123456789012:table/sales",
+                ParseServiceArnError::InvalidResource,
+            ),
+            (
+                "arn:aws:glue:us-east-1:123456789012:table/sales/",
+                ParseServiceArnError::InvalidResource,
+            ),
+            (
+                "arn:aws:glue:us-east-1:123456789012:database/sales/orders",
+                ParseServiceArnError::InvalidResource,
+            ),
+            (
+                "arn:aws:glue:us-east-1:123456789012:crawler/nightly",
+                ParseServiceArnError::UnknownResourceType,
+            ),
+            (
+                "arn:aws:glue:us-east-1:123456789012:catalogs",
+                ParseServiceArnError::UnknownResourceType,
+            ),
+            (
+                "arn:aws:glue:us-east-1::catalog",
+                ParseServiceArnError::MissingAccountId,
+            ),
+        ] {
+            assert_eq!(GlueArn::parse(arn_str).as_ref(), Err(err), "{}", arn_str);
+        }
+    }
+}
@@ -0,0 +1,336 @@
+//! AWS IAM (`arn:partition:iam::account-id:type/path/name`) ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{global, non_empty, ParseServiceArnError};
+
+/// The account, path and name shared by IAM resources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IamEntity<'a> {
+    pub partition: &'a str,
+    /// The owning account, or `aws` for AWS managed policies.
+    pub account_id: &'a str,
+    /// The IAM path, starting and ending with `/`.
+    pub path: &'a str,
+    pub name: &'a str,
+}
+
+impl<'a> IamEntity<'a> {
+    /// Parses a `path/name` resource suffix, where `rest` still has its leading `/`.
+    fn parse(
+        partition: &'a str,
+        account_id: &'a str,
+        rest: &'a str,
+    ) -> Result<Self, ParseServiceArnError> {
+        let split = rest
+            .rfind('/')
+            .ok_or(ParseServiceArnError::InvalidResource)?
+            + 1;
+
+        Ok(IamEntity {
+            partition,
+            account_id,
+            path: &rest[..split],
+            name: non_empty(&rest[split..])?,
+        })
+    }
+}
+
+/// The account and name of an IAM identity provider, which have no path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IamProvider<'a> {
+    pub partition: &'a str,
+    pub account_id: &'a str,
+    /// The provider name. For OIDC providers this is the provider URL without its `https://` scheme, which may
+    /// itself contain `/`.
+    pub name: &'a str,
+}
+
+/// An IAM ARN, split into its resource type, path and name
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::iam::IamArn;
+///
+/// let arn = IamArn::parse("arn:aws:iam::123456789012:role/service-role/my-role").unwrap();
+/// assert!(matches!(arn, IamArn::Role(_)));
+/// assert_eq!(arn.entity().unwrap().path, "/service-role/");
+/// assert_eq!(arn.entity().unwrap().name, "my-role");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IamArn<'a> {
+    User(IamEntity<'a>),
+    Role(IamEntity<'a>),
+    Group(IamEntity<'a>),
+    Policy(IamEntity<'a>),
+    InstanceProfile(IamEntity<'a>),
+    ServerCertificate(IamEntity<'a>),
+    SamlProvider(IamProvider<'a>),
+    OidcProvider(IamProvider<'a>),
+}
+
+impl<'a> IamArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        IamArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The path and name of resources that have a path, or `None` for identity providers.
+    pub fn entity(&self) -> Option<&IamEntity<'a>> {
+        match self {
+            IamArn::User(entity)
+            | IamArn::Role(entity)
+            | IamArn::Group(entity)
+            | IamArn::Policy(entity)
+            | IamArn::InstanceProfile(entity)
+            | IamArn::ServerCertificate(entity) => Some(entity),
+            IamArn::SamlProvider(_) | IamArn::OidcProvider(_) => None,
+        }
+    }
+
+    /// The identity provider, or `None` for resources with a path.
+    pub fn provider(&self) -> Option<&IamProvider<'a>> {
+        match self {
+            IamArn::SamlProvider(provider) | IamArn::OidcProvider(provider) => Some(provider),
+            _ => None,
+        }
+    }
+
+    pub fn partition(&self) -> &'a str {
+        self.parts().0
+    }
+
+    pub fn account_id(&self) -> &'a str {
+        self.parts().1
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.parts().2
+    }
+
+    /// The partition, account ID and name of any variant.
+    fn parts(&self) -> (&'a str, &'a str, &'a str) {
+        match self {
+            IamArn::User(entity)
+            | IamArn::Role(entity)
+            | IamArn::Group(entity)
+            | IamArn::Policy(entity)
+            | IamArn::InstanceProfile(entity)
+            | IamArn::ServerCertificate(entity) => {
+                (entity.partition, entity.account_id, entity.name)
+            }
+            IamArn::SamlProvider(provider) | IamArn::OidcProvider(provider) => {
+                (provider.partition, provider.account_id, provider.name)
+            }
+        }
+    }
+
+    /// The resource type, as it appears in the ARN.
+    pub fn resource_type(&self) -> &'static str {
+        match self {
+            IamArn::User(_) => "user",
+            IamArn::Role(_) => "role",
+            IamArn::Group(_) => "group",
+            IamArn::Policy(_) => "policy",
+            IamArn::InstanceProfile(_) => "instance-profile",
+            IamArn::ServerCertificate(_) => "server-certificate",
+            IamArn::SamlProvider(_) => "saml-provider",
+            IamArn::OidcProvider(_) => "oidc-provider",
+        }
+    }
+
+    /// Whether this is an AWS managed policy, owned by the `aws` pseudo-account.
+    pub fn is_aws_managed_policy(&self) -> bool {
+        match self {
+            IamArn::Policy(entity) => entity.account_id == "aws",
+            _ => false,
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for IamArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "iam" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let account_id = global(arn)?;
+        let split = arn
+            .resource
+            .find('/')
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+        let (resource_type, rest) = arn.resource.split_at(split);
+        let entity = || IamEntity::parse(arn.partition, account_id, rest);
+        let provider = || {
+            Ok(IamProvider {
+                partition: arn.partition,
+                account_id,
+                name: non_empty(&rest[1..])?,
+            })
+        };
+
+        match resource_type {
+            "user" => entity().map(IamArn::User),
+            "role" => entity().map(IamArn::Role),
+            "group" => entity().map(IamArn::Group),
+            "policy" => entity().map(IamArn::Policy),
+            "instance-profile" => entity().map(IamArn::InstanceProfile),
+            "server-certificate" => entity().map(IamArn::ServerCertificate),
+            "saml-provider" if !rest[1..].contains('/') => provider().map(IamArn::SamlProvider),
+            "saml-provider" => Err(ParseServiceArnError::InvalidResource),
+            "oidc-provider" => provider().map(IamArn::OidcProvider),
+            _ => Err(ParseServiceArnError::UnknownResourceType),
+        }
+    }
+}
+
+impl<'a> fmt::Display for IamArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:iam::{}:{}",
+            self.partition(),
+            self.account_id(),
+            self.resource_type()
+        )?;
+
+        match self.entity() {
+            Some(entity) => write!(f, "{}{}", entity.path, entity.name),
+            None => write!(f, "/{}", self.name()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IamArn, IamEntity, IamProvider};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn default_path() {
+        let arn_str = "arn:aws:iam::123456789012:user/JohnDoe";
+        let arn = IamArn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn,
+            IamArn::User(IamEntity {
+                partition: "aws",
+                account_id: "123456789012",
+                path: "/",
+                name: "JohnDoe",
+            })
+        );
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn nested_path() {
+        let arn_str = "arn:aws:iam::123456789012:role/aws-service-role/access-analyzer.amazonaws.com/AWSServiceRoleForAccessAnalyzer";
+        let arn = IamArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.resource_type(), "role");
+        assert_eq!(
+            arn.entity().unwrap().path,
+            "/aws-service-role/access-analyzer.amazonaws.com/"
+        );
+        assert_eq!(arn.name(), "AWSServiceRoleForAccessAnalyzer");
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn managed_policy() {
+        let arn = IamArn::parse("arn:aws:iam::aws:policy/job-function/ViewOnlyAccess").unwrap();
+
+        assert!(arn.is_aws_managed_policy());
+        assert_eq!(arn.entity().unwrap().path, "/job-function/");
+
+        let arn =
+            IamArn::parse("arn:aws:iam::123456789012:policy/UsersManageOwnCredentials").unwrap();
+        assert!(!arn.is_aws_managed_policy());
+
+        let arn = IamArn::parse("arn:aws:iam::123456789012:group/Developers").unwrap();
+        assert!(!arn.is_aws_managed_policy());
+    }
+
+    #[test]
+    fn instance_profile_and_server_certificate() {
+        let arn_str = "arn:aws:iam::123456789012:instance-profile/webserver/Webserver";
+        let arn = IamArn::parse(arn_str).unwrap();
+
+        assert!(matches!(arn, IamArn::InstanceProfile(_)));
+        assert_eq!(arn.entity().unwrap().path, "/webserver/");
+        assert_eq!(arn.to_string(), arn_str);
+
+        let arn_str =
+            "arn:aws:iam::123456789012:server-certificate/company/servercerts/ProdServerCert";
+        let arn = IamArn::parse(arn_str).unwrap();
+
+        assert!(matches!(arn, IamArn::ServerCertificate(_)));
+        assert_eq!(arn.entity().unwrap().path, "/company/servercerts/");
+        assert_eq!(arn.name(), "ProdServerCert");
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn providers() {
+        let arn_str = "arn:aws:iam::123456789012:saml-provider/ADFSProvider";
+        let arn = IamArn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn,
+            IamArn::SamlProvider(IamProvider {
+                partition: "aws",
+                account_id: "123456789012",
+                name: "ADFSProvider",
+            })
+        );
+        assert_eq!(arn.entity(), None);
+        assert_eq!(arn.to_string(), arn_str);
+
+        let arn_str = "arn:aws:iam::123456789012:oidc-provider/oidc.eks.us-west-2.amazonaws.com/id/EXAMPLED539D4633E53DE1B71EXAMPLE";
+        let arn = IamArn::parse(arn_str).unwrap();
+
+        assert!(matches!(arn, IamArn::OidcProvider(_)));
+        assert_eq!(
+            arn.name(),
+            "oidc.eks.us-west-2.amazonaws.com/id/EXAMPLED539D4633E53DE1B71EXAMPLE"
+        );
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            IamArn::parse("arn:aws:iam::123456789012:root"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            IamArn::parse("arn:aws:iam::123456789012:mfa/JaneMFA"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            IamArn::parse("arn:aws:iam::123456789012:role/service-role/"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            IamArn::parse("arn:aws:iam::123456789012:saml-provider/"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            IamArn::parse("arn:aws:iam::123456789012:saml-provider/path/ADFSProvider"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            IamArn::parse("arn:aws:iam:us-east-1:123456789012:role/my-role"),
+            Err(ParseServiceArnError::UnexpectedRegion)
+        );
+        assert_eq!(
+            IamArn::parse("arn:aws:iam:::role/my-role"),
+            Err(ParseServiceArnError::MissingAccountId)
+        );
+    }
+}
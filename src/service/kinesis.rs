@@ -0,0 +1,196 @@
+//! Amazon Kinesis Data Streams (`arn:partition:kinesis:region:account-id:stream/name`) ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// A Kinesis data stream ARN
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KinesisStreamArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub name: &'a str,
+}
+
+impl<'a> KinesisStreamArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        KinesisStreamArn::try_from(NaiveArn::parse(s)?)
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for KinesisStreamArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        match KinesisArn::try_from(arn)? {
+            KinesisArn::Stream(stream) => Ok(stream),
+            KinesisArn::Consumer { .. } => Err(ParseServiceArnError::UnknownResourceType),
+        }
+    }
+}
+
+impl<'a> fmt::Display for KinesisStreamArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:kinesis:{}:{}:stream/{}",
+            self.partition, self.region, self.account_id, self.name
+        )
+    }
+}
+
+/// A Kinesis data stream or enhanced fan-out consumer ARN
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::kinesis::KinesisArn;
+///
+/// let arn = KinesisArn::parse("arn:aws:kinesis:us-east-1:123456789012:stream/clicks/consumer/analytics:1573692012").unwrap();
+/// assert!(matches!(arn, KinesisArn::Consumer { name: "analytics", creation_timestamp: "1573692012", .. }));
+/// assert_eq!(arn.stream_name(), "clicks");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KinesisArn<'a> {
+    Stream(KinesisStreamArn<'a>),
+    /// `stream/stream-name/consumer/consumer-name:creation-timestamp`
+    Consumer {
+        stream: KinesisStreamArn<'a>,
+        name: &'a str,
+        creation_timestamp: &'a str,
+    },
+}
+
+impl<'a> KinesisArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        KinesisArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The stream, or the stream a consumer reads from.
+    pub fn stream(&self) -> KinesisStreamArn<'a> {
+        match *self {
+            KinesisArn::Stream(stream) | KinesisArn::Consumer { stream, .. } => stream,
+        }
+    }
+
+    pub fn stream_name(&self) -> &'a str {
+        self.stream().name
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for KinesisArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "kinesis" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let rest = arn
+            .resource
+            .strip_prefix("stream/")
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+        let (name, consumer) = match rest.split_once('/') {
+            Some((name, consumer)) => (name, Some(consumer)),
+            None => (rest, None),
+        };
+        let stream = KinesisStreamArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            name: non_empty(name)?,
+        };
+
+        let consumer = match consumer {
+            Some(consumer) => consumer
+                .strip_prefix("consumer/")
+                .and_then(|consumer| consumer.split_once(':'))
+                .filter(|(name, timestamp)| !name.contains('/') && !timestamp.contains(':'))
+                .ok_or(ParseServiceArnError::InvalidResource)?,
+            None => return Ok(KinesisArn::Stream(stream)),
+        };
+
+        Ok(KinesisArn::Consumer {
+            stream,
+            name: non_empty(consumer.0)?,
+            creation_timestamp: non_empty(consumer.1)?,
+        })
+    }
+}
+
+impl<'a> fmt::Display for KinesisArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KinesisArn::Stream(stream) => stream.fmt(f),
+            KinesisArn::Consumer {
+                stream,
+                name,
+                creation_timestamp,
+            } => write!(f, "{}/consumer/{}:{}", stream, name, creation_timestamp),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KinesisArn, KinesisStreamArn};
+    use crate::service::ParseServiceArnError;
+
+    const STREAM: &str = "arn:aws:kinesis:us-east-1:123456789012:stream/clicks";
+
+    #[test]
+    fn stream() {
+        let arn = KinesisArn::parse(STREAM).unwrap();
+
+        assert_eq!(
+            arn,
+            KinesisArn::Stream(KinesisStreamArn {
+                partition: "aws",
+                region: "us-east-1",
+                account_id: "123456789012",
+                name: "clicks",
+            })
+        );
+        assert_eq!(arn.stream_name(), "clicks");
+        assert_eq!(arn.to_string(), STREAM);
+    }
+
+    #[test]
+    fn consumer() {
+        let arn_str = format!("{}/consumer/analytics:1573692012", STREAM);
+        let arn = KinesisArn::parse(&arn_str).unwrap();
+
+        assert_eq!(arn.stream().to_string(), STREAM);
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:kinesis:us-east-1:123456789012:{}", resource);
+            KinesisArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(parse("stream/"), Err(ParseServiceArnError::InvalidResource));
+        assert_eq!(
+            parse("stream/clicks/consumer/analytics"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("stream/clicks/consumer/:1573692012"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("stream/clicks/shard/0001"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("clicks"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
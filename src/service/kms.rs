@@ -0,0 +1,393 @@
+//! AWS KMS (`arn:partition:kms:region:account-id:key/key-id`) helpers
+
+use alloc::{borrow::ToOwned, format};
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::buf::ArnBuf;
+use crate::context::Context;
+use crate::naive::NaiveArn;
+use crate::service::{is_uuid, regional, ParseServiceArnError};
+
+/// The resource a [`KmsArn`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KmsResource<'a> {
+    /// `key/key-id`
+    Key(&'a str),
+    /// `alias/name`, holding the name without its `alias/` prefix
+    Alias(&'a str),
+}
+
+/// A KMS key or alias ARN
+///
+/// Key IDs are validated as UUIDs or multi-Region `mrk-` IDs, and alias names as 1-250 characters of
+/// `[a-zA-Z0-9/_-]`.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::kms::KmsArn;
+///
+/// let arn = KmsArn::parse("arn:aws:kms:us-east-1:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab").unwrap();
+/// assert!(!arn.is_alias());
+/// assert_eq!(arn.key_id(), Some("1234abcd-12ab-34cd-56ef-1234567890ab"));
+///
+/// let arn = KmsArn::parse("arn:aws:kms:us-east-1:123456789012:alias/aws/s3").unwrap();
+/// assert!(arn.is_alias());
+/// assert_eq!(arn.alias_name(), Some("aws/s3"));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KmsArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub resource: KmsResource<'a>,
+}
+
+impl<'a> KmsArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        KmsArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    pub fn is_alias(&self) -> bool {
+        matches!(self.resource, KmsResource::Alias(_))
+    }
+
+    /// The key ID, for key ARNs.
+    pub fn key_id(&self) -> Option<&'a str> {
+        match self.resource {
+            KmsResource::Key(id) => Some(id),
+            KmsResource::Alias(_) => None,
+        }
+    }
+
+    /// The alias name, without its `alias/` prefix, for alias ARNs.
+    pub fn alias_name(&self) -> Option<&'a str> {
+        match self.resource {
+            KmsResource::Alias(name) => Some(name),
+            KmsResource::Key(_) => None,
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for KmsArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "kms" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let resource = match arn.resource.split_once('/') {
+            Some(("key", id)) if is_key_id(id) => KmsResource::Key(id),
+            Some(("alias", name)) if is_alias_name(name) => KmsResource::Alias(name),
+            Some(("key", _)) | Some(("alias", _)) => {
+                return Err(ParseServiceArnError::InvalidResource)
+            }
+            _ => return Err(ParseServiceArnError::UnknownResourceType),
+        };
+
+        Ok(KmsArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            resource,
+        })
+    }
+}
+
+impl<'a> fmt::Display for KmsArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:kms:{}:{}:",
+            self.partition, self.region, self.account_id
+        )?;
+
+        match self.resource {
+            KmsResource::Key(id) => write!(f, "key/{}", id),
+            KmsResource::Alias(name) => write!(f, "alias/{}", name),
+        }
+    }
+}
+
+/// The forms in which the KMS APIs accept a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyIdentifier<'a> {
+    /// A bare key ID, e.g. `1234abcd-12ab-34cd-56ef-1234567890ab` or a multi-Region `mrk-...` ID
+    KeyId(&'a str),
+    /// An alias name, e.g. `alias/my-key`
+    AliasName(&'a str),
+    /// A key ARN, e.g. `arn:aws:kms:us-east-1:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab`
+    KeyArn(NaiveArn<'a>),
+    /// An alias ARN, e.g. `arn:aws:kms:us-east-1:123456789012:alias/my-key`
+    AliasArn(NaiveArn<'a>),
+}
+
+impl<'a> KeyIdentifier<'a> {
+    /// Detect which form `s` is in.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::service::kms::KeyIdentifier;
+    ///
+    /// assert_eq!(KeyIdentifier::parse("alias/my-key"), Ok(KeyIdentifier::AliasName("alias/my-key")));
+    /// ~~~~
+    pub fn parse(s: &'a str) -> Result<Self, ParseKeyIdentifierError> {
+        if s.starts_with("arn:") {
+            let arn = NaiveArn::parse(s).map_err(|_| ParseKeyIdentifierError::InvalidArn)?;
+
+            return match KmsArn::try_from(arn) {
+                Ok(KmsArn {
+                    resource: KmsResource::Key(_),
+                    ..
+                }) => Ok(KeyIdentifier::KeyArn(arn)),
+                Ok(KmsArn {
+                    resource: KmsResource::Alias(_),
+                    ..
+                }) => Ok(KeyIdentifier::AliasArn(arn)),
+                Err(_) => Err(ParseKeyIdentifierError::InvalidArn),
+            };
+        }
+
+        if let Some(name) = s.strip_prefix("alias/") {
+            return if is_alias_name(name) {
+                Ok(KeyIdentifier::AliasName(s))
+            } else {
+                Err(ParseKeyIdentifierError::InvalidAlias)
+            };
+        }
+
+        if is_key_id(s) {
+            Ok(KeyIdentifier::KeyId(s))
+        } else {
+            Err(ParseKeyIdentifierError::InvalidKeyId)
+        }
+    }
+
+    /// Whether this identifies a key through an alias.
+    pub fn is_alias(&self) -> bool {
+        matches!(
+            self,
+            KeyIdentifier::AliasName(_) | KeyIdentifier::AliasArn(_)
+        )
+    }
+
+    /// The bare key ID or `alias/name`, without any ARN context.
+    pub fn short_form(&self) -> &'a str {
+        match self {
+            KeyIdentifier::KeyId(s) | KeyIdentifier::AliasName(s) => s,
+            KeyIdentifier::KeyArn(arn) => &arn.resource["key/".len()..],
+            KeyIdentifier::AliasArn(arn) => arn.resource,
+        }
+    }
+
+    /// Expand this identifier into a full key or alias ARN, taking the region and account from `context` if needed.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::context::Context;
+    /// use arn::service::kms::KeyIdentifier;
+    ///
+    /// let context = Context::new("aws", "us-east-1", "123456789012");
+    /// let key = KeyIdentifier::parse("1234abcd-12ab-34cd-56ef-1234567890ab").unwrap();
+    ///
+    /// assert_eq!(
+    ///     key.to_arn(&context).to_string(),
+    ///     "arn:aws:kms:us-east-1:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab"
+    /// );
+    /// ~~~~
+    pub fn to_arn(&self, context: &Context) -> ArnBuf {
+        let resource = match self {
+            KeyIdentifier::KeyArn(arn) | KeyIdentifier::AliasArn(arn) => return ArnBuf::from(*arn),
+            KeyIdentifier::KeyId(id) => format!("key/{}", id),
+            KeyIdentifier::AliasName(name) => (*name).to_owned(),
+        };
+
+        ArnBuf {
+            partition: context.partition.to_owned(),
+            service: "kms".to_owned(),
+            region: Some(context.region.to_owned()),
+            account_id: Some(context.account_id.to_owned()),
+            resource,
+        }
+    }
+}
+
+/// A key ID is a UUID, or a multi-Region key ID (`mrk-` followed by 32 hex digits).
+fn is_key_id(s: &str) -> bool {
+    if let Some(hex) = s.strip_prefix("mrk-") {
+        return hex.len() == 32 && hex.bytes().all(|b| b.is_ascii_hexdigit());
+    }
+
+    is_uuid(s)
+}
+
+/// Alias names are 1-250 characters of `[a-zA-Z0-9/_-]`.
+fn is_alias_name(s: &str) -> bool {
+    !s.is_empty()
+        && s.len() <= 250
+        && s.bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"/_-".contains(&b))
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseKeyIdentifierError {
+    InvalidArn,
+    InvalidAlias,
+    InvalidKeyId,
+}
+
+impl fmt::Display for ParseKeyIdentifierError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseKeyIdentifierError::InvalidArn => write!(f, "Not a KMS key or alias ARN"),
+            ParseKeyIdentifierError::InvalidAlias => write!(f, "Invalid alias name"),
+            ParseKeyIdentifierError::InvalidKeyId => write!(f, "Invalid key ID"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseKeyIdentifierError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeyIdentifier, KmsArn, KmsResource, ParseKeyIdentifierError};
+    use crate::context::Context;
+    use crate::service::ParseServiceArnError;
+
+    const KEY_ID: &str = "1234abcd-12ab-34cd-56ef-1234567890ab";
+    const CONTEXT: Context<'static> = Context {
+        partition: "aws",
+        region: "us-east-1",
+        account_id: "123456789012",
+    };
+
+    #[test]
+    fn key_id() {
+        let key = KeyIdentifier::parse(KEY_ID).unwrap();
+
+        assert_eq!(key, KeyIdentifier::KeyId(KEY_ID));
+        assert!(!key.is_alias());
+        assert_eq!(key.short_form(), KEY_ID);
+        assert_eq!(
+            key.to_arn(&CONTEXT).to_string(),
+            "arn:aws:kms:us-east-1:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab"
+        );
+    }
+
+    #[test]
+    fn multi_region_key_id() {
+        let id = "mrk-1234abcd12ab34cd56ef1234567890ab";
+
+        assert_eq!(KeyIdentifier::parse(id), Ok(KeyIdentifier::KeyId(id)));
+    }
+
+    #[test]
+    fn alias_name() {
+        let key = KeyIdentifier::parse("alias/aws/s3").unwrap();
+
+        assert_eq!(key, KeyIdentifier::AliasName("alias/aws/s3"));
+        assert!(key.is_alias());
+        assert_eq!(
+            key.to_arn(&CONTEXT).to_string(),
+            "arn:aws:kms:us-east-1:123456789012:alias/aws/s3"
+        );
+    }
+
+    #[test]
+    fn key_arn() {
+        let arn_str =
+            "arn:aws-cn:kms:cn-north-1:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab";
+        let key = KeyIdentifier::parse(arn_str).unwrap();
+
+        assert!(matches!(key, KeyIdentifier::KeyArn(_)));
+        assert_eq!(key.short_form(), KEY_ID);
+        // ARNs already carry their own context
+        assert_eq!(key.to_arn(&CONTEXT).to_string(), arn_str);
+    }
+
+    #[test]
+    fn alias_arn() {
+        let key = KeyIdentifier::parse("arn:aws:kms:us-east-1:123456789012:alias/my-key").unwrap();
+
+        assert!(matches!(key, KeyIdentifier::AliasArn(_)));
+        assert!(key.is_alias());
+        assert_eq!(key.short_form(), "alias/my-key");
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            KeyIdentifier::parse("1234abcd"),
+            Err(ParseKeyIdentifierError::InvalidKeyId)
+        );
+        assert_eq!(
+            KeyIdentifier::parse("alias/"),
+            Err(ParseKeyIdentifierError::InvalidAlias)
+        );
+        assert_eq!(
+            KeyIdentifier::parse("arn:aws:kms:us-east-1:123456789012:key/not-a-uuid"),
+            Err(ParseKeyIdentifierError::InvalidArn)
+        );
+        assert_eq!(
+            KeyIdentifier::parse("arn:aws:s3:::my_corporate_bucket"),
+            Err(ParseKeyIdentifierError::InvalidArn)
+        );
+    }
+
+    #[test]
+    fn typed_key() {
+        let arn_str = "arn:aws:kms:us-east-1:123456789012:key/mrk-1234abcd12ab34cd56ef1234567890ab";
+        let arn = KmsArn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn,
+            KmsArn {
+                partition: "aws",
+                region: "us-east-1",
+                account_id: "123456789012",
+                resource: KmsResource::Key("mrk-1234abcd12ab34cd56ef1234567890ab"),
+            }
+        );
+        assert_eq!(arn.alias_name(), None);
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn typed_alias() {
+        let arn_str = "arn:aws:kms:us-east-1:123456789012:alias/my-key";
+        let arn = KmsArn::parse(arn_str).unwrap();
+
+        assert!(arn.is_alias());
+        assert_eq!(arn.key_id(), None);
+        assert_eq!(arn.alias_name(), Some("my-key"));
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn typed_invalid() {
+        assert_eq!(
+            KmsArn::parse(
+                "arn:aws:kms:us-east-1:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890"
+            ),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            KmsArn::parse("arn:aws:kms:us-east-1:123456789012:alias/my.key"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            KmsArn::parse("arn:aws:kms:us-east-1:123456789012:grant/0123"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            KmsArn::parse("arn:aws:kms::123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab"),
+            Err(ParseServiceArnError::MissingRegion)
+        );
+    }
+}
@@ -0,0 +1,314 @@
+//! AWS Lambda (`arn:partition:lambda:region:account-id:function:...`) ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// A Lambda function ARN, `function:name[:qualifier]`
+///
+/// The qualifier is a published version, an alias or `$LATEST`.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::lambda::FunctionArn;
+///
+/// let arn = FunctionArn::parse("arn:aws:lambda:us-west-2:123456789012:function:my-function:PROD").unwrap();
+/// assert_eq!(arn.name, "my-function");
+/// assert_eq!(arn.qualifier, Some("PROD"));
+///
+/// assert_eq!(arn.unqualified().to_string(), "arn:aws:lambda:us-west-2:123456789012:function:my-function");
+/// assert_eq!(arn.qualified("3").to_string(), "arn:aws:lambda:us-west-2:123456789012:function:my-function:3");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FunctionArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub name: &'a str,
+    pub qualifier: Option<&'a str>,
+}
+
+impl<'a> FunctionArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        FunctionArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// This function, qualified with a version or alias, replacing any existing qualifier.
+    pub fn qualified(self, qualifier: &'a str) -> Self {
+        FunctionArn {
+            qualifier: Some(qualifier),
+            ..self
+        }
+    }
+
+    /// This function without any qualifier.
+    pub fn unqualified(self) -> Self {
+        FunctionArn {
+            qualifier: None,
+            ..self
+        }
+    }
+
+    pub fn is_qualified(&self) -> bool {
+        self.qualifier.is_some()
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for FunctionArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        match LambdaArn::try_from(arn)? {
+            LambdaArn::Function(function) => Ok(function),
+            _ => Err(ParseServiceArnError::UnknownResourceType),
+        }
+    }
+}
+
+impl<'a> fmt::Display for FunctionArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:lambda:{}:{}:function:{}",
+            self.partition, self.region, self.account_id, self.name
+        )?;
+
+        match self.qualifier {
+            Some(qualifier) => write!(f, ":{}", qualifier),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A Lambda ARN
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::lambda::LambdaArn;
+///
+/// let arn = LambdaArn::parse("arn:aws:lambda:us-west-2:123456789012:layer:my-layer:3").unwrap();
+/// assert!(matches!(arn, LambdaArn::Layer { name: "my-layer", version: Some(3), .. }));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LambdaArn<'a> {
+    Function(FunctionArn<'a>),
+    /// `layer:name[:version]`
+    Layer {
+        partition: &'a str,
+        region: &'a str,
+        account_id: &'a str,
+        name: &'a str,
+        version: Option<u64>,
+    },
+    /// `event-source-mapping:uuid`
+    EventSourceMapping {
+        partition: &'a str,
+        region: &'a str,
+        account_id: &'a str,
+        uuid: &'a str,
+    },
+}
+
+impl<'a> LambdaArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        LambdaArn::try_from(NaiveArn::parse(s)?)
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for LambdaArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "lambda" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let mut parts = arn.resource.split(':');
+        let resource_type = parts.next().unwrap_or_default();
+        let name = non_empty(parts.next().unwrap_or_default())?;
+        let qualifier = parts.next();
+
+        if parts.next().is_some() {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        match resource_type {
+            "function" => Ok(LambdaArn::Function(FunctionArn {
+                partition: arn.partition,
+                region,
+                account_id,
+                name,
+                qualifier: qualifier.map(non_empty).transpose()?,
+            })),
+            "layer" => Ok(LambdaArn::Layer {
+                partition: arn.partition,
+                region,
+                account_id,
+                name,
+                version: qualifier
+                    .map(|version| version.parse())
+                    .transpose()
+                    .map_err(|_| ParseServiceArnError::InvalidResource)?,
+            }),
+            "event-source-mapping" if qualifier.is_none() => Ok(LambdaArn::EventSourceMapping {
+                partition: arn.partition,
+                region,
+                account_id,
+                uuid: name,
+            }),
+            "event-source-mapping" => Err(ParseServiceArnError::InvalidResource),
+            _ => Err(ParseServiceArnError::UnknownResourceType),
+        }
+    }
+}
+
+impl<'a> fmt::Display for LambdaArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LambdaArn::Function(function) => function.fmt(f),
+            LambdaArn::Layer {
+                partition,
+                region,
+                account_id,
+                name,
+                version,
+            } => {
+                write!(
+                    f,
+                    "arn:{}:lambda:{}:{}:layer:{}",
+                    partition, region, account_id, name
+                )?;
+
+                match version {
+                    Some(version) => write!(f, ":{}", version),
+                    None => Ok(()),
+                }
+            }
+            LambdaArn::EventSourceMapping {
+                partition,
+                region,
+                account_id,
+                uuid,
+            } => write!(
+                f,
+                "arn:{}:lambda:{}:{}:event-source-mapping:{}",
+                partition, region, account_id, uuid
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FunctionArn, LambdaArn};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn function() {
+        let arn_str = "arn:aws:lambda:us-west-2:123456789012:function:my-function";
+        let arn = FunctionArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.region, "us-west-2");
+        assert_eq!(arn.account_id, "123456789012");
+        assert_eq!(arn.name, "my-function");
+        assert!(!arn.is_qualified());
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn qualifiers() {
+        let arn = FunctionArn::parse(
+            "arn:aws:lambda:us-west-2:123456789012:function:my-function:$LATEST",
+        )
+        .unwrap();
+
+        assert_eq!(arn.qualifier, Some("$LATEST"));
+        assert_eq!(
+            arn.qualified("PROD").to_string(),
+            "arn:aws:lambda:us-west-2:123456789012:function:my-function:PROD"
+        );
+        assert_eq!(
+            arn.unqualified().to_string(),
+            "arn:aws:lambda:us-west-2:123456789012:function:my-function"
+        );
+        assert_eq!(arn.unqualified().unqualified(), arn.unqualified());
+    }
+
+    #[test]
+    fn layer() {
+        let arn_str = "arn:aws:lambda:us-west-2:123456789012:layer:my-layer:3";
+        let arn = LambdaArn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn,
+            LambdaArn::Layer {
+                partition: "aws",
+                region: "us-west-2",
+                account_id: "123456789012",
+                name: "my-layer",
+                version: Some(3),
+            }
+        );
+        assert_eq!(arn.to_string(), arn_str);
+
+        let arn_str = "arn:aws:lambda:us-west-2:123456789012:layer:my-layer";
+        assert_eq!(LambdaArn::parse(arn_str).unwrap().to_string(), arn_str);
+    }
+
+    #[test]
+    fn event_source_mapping() {
+        let arn_str = "arn:aws:lambda:us-west-2:123456789012:event-source-mapping:fa123456-14a1-4fd2-9fec-83de64ad683de";
+        let arn = LambdaArn::parse(arn_str).unwrap();
+
+        assert!(matches!(
+            arn,
+            LambdaArn::EventSourceMapping {
+                uuid: "fa123456-14a1-4fd2-9fec-83de64ad683de",
+                ..
+            }
+        ));
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:lambda:us-west-2:123456789012:{}", resource);
+            LambdaArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(
+            parse("function:"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("function:my-function:"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("function:my-function:1:2"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("layer:my-layer:latest"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("code-signing-config:csc-0123"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            FunctionArn::parse("arn:aws:lambda:us-west-2:123456789012:layer:my-layer:3"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            FunctionArn::parse("arn:aws:lambda:::function:my-function"),
+            Err(ParseServiceArnError::MissingRegion)
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! Amazon CloudWatch Logs (`arn:partition:logs:region:account-id:log-group:name`) ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// A log group ARN
+///
+/// Log groups are written both bare, `log-group:name`, and with a trailing `:*`, which is how the CloudWatch Logs
+/// APIs return them. The two forms compare (and hash) as equal, and [`Display`](fmt::Display) keeps whichever form
+/// was parsed.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::logs::LogGroupArn;
+///
+/// let bare = LogGroupArn::parse("arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/my-function").unwrap();
+/// let wildcard = LogGroupArn::parse("arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/my-function:*").unwrap();
+///
+/// assert_eq!(bare.name, "/aws/lambda/my-function");
+/// assert_eq!(bare, wildcard);
+/// ~~~~
+#[derive(Debug, Clone, Copy)]
+pub struct LogGroupArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub name: &'a str,
+    /// Whether the ARN ended in `:*`.
+    pub trailing_wildcard: bool,
+}
+
+impl<'a> LogGroupArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        LogGroupArn::try_from(NaiveArn::parse(s)?)
+    }
+}
+
+impl<'a> PartialEq for LogGroupArn<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.partition == other.partition
+            && self.region == other.region
+            && self.account_id == other.account_id
+            && self.name == other.name
+    }
+}
+
+impl<'a> Eq for LogGroupArn<'a> {}
+
+impl<'a> Hash for LogGroupArn<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.partition.hash(state);
+        self.region.hash(state);
+        self.account_id.hash(state);
+        self.name.hash(state);
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for LogGroupArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        match LogsArn::try_from(arn)? {
+            LogsArn::LogGroup(group) => Ok(group),
+            LogsArn::LogStream { .. } => Err(ParseServiceArnError::UnknownResourceType),
+        }
+    }
+}
+
+impl<'a> fmt::Display for LogGroupArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:logs:{}:{}:log-group:{}",
+            self.partition, self.region, self.account_id, self.name
+        )?;
+
+        if self.trailing_wildcard {
+            write!(f, ":*")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A log group or log stream ARN
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::logs::LogsArn;
+///
+/// let arn = LogsArn::parse("arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/my-function:log-stream:2024/01/01/[$LATEST]abc").unwrap();
+/// assert!(matches!(arn, LogsArn::LogStream { name: "2024/01/01/[$LATEST]abc", .. }));
+/// assert_eq!(arn.log_group().name, "/aws/lambda/my-function");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogsArn<'a> {
+    LogGroup(LogGroupArn<'a>),
+    /// `log-group:group-name:log-stream:stream-name`
+    LogStream {
+        group: LogGroupArn<'a>,
+        name: &'a str,
+    },
+}
+
+impl<'a> LogsArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        LogsArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The log group, or the log group a stream is in.
+    pub fn log_group(&self) -> LogGroupArn<'a> {
+        match *self {
+            LogsArn::LogGroup(group) | LogsArn::LogStream { group, .. } => group,
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for LogsArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "logs" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let rest = arn
+            .resource
+            .strip_prefix("log-group:")
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+
+        let (name, stream, trailing_wildcard) = match rest.split_once(':') {
+            None => (rest, None, false),
+            Some((name, "*")) => (name, None, true),
+            Some((name, stream)) => match stream.strip_prefix("log-stream:") {
+                Some(stream) => (name, Some(non_empty(stream)?), false),
+                None => return Err(ParseServiceArnError::InvalidResource),
+            },
+        };
+
+        let group = LogGroupArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            name: non_empty(name)?,
+            trailing_wildcard,
+        };
+
+        Ok(match stream {
+            Some(name) => LogsArn::LogStream { group, name },
+            None => LogsArn::LogGroup(group),
+        })
+    }
+}
+
+impl<'a> fmt::Display for LogsArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LogsArn::LogGroup(group) => group.fmt(f),
+            LogsArn::LogStream { group, name } => write!(f, "{}:log-stream:{}", group, name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{LogGroupArn, LogsArn};
+    use crate::service::ParseServiceArnError;
+
+    const GROUP: &str = "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/my-function";
+
+    #[test]
+    fn log_group() {
+        let arn = LogsArn::parse(GROUP).unwrap();
+
+        assert_eq!(
+            arn,
+            LogsArn::LogGroup(LogGroupArn {
+                partition: "aws",
+                region: "us-east-1",
+                account_id: "123456789012",
+                name: "/aws/lambda/my-function",
+                trailing_wildcard: false,
+            })
+        );
+        assert_eq!(arn.to_string(), GROUP);
+    }
+
+    #[test]
+    fn trailing_wildcard() {
+        let arn_str = format!("{}:*", GROUP);
+        let wildcard = LogGroupArn::parse(&arn_str).unwrap();
+        let bare = LogGroupArn::parse(GROUP).unwrap();
+
+        assert!(wildcard.trailing_wildcard);
+        assert_eq!(wildcard, bare);
+        assert_eq!(wildcard.to_string(), arn_str);
+
+        let set: HashSet<_> = vec![wildcard, bare].into_iter().collect();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn log_stream() {
+        let arn_str = format!("{}:log-stream:2024/01/01/[$LATEST]0123abcd", GROUP);
+        let arn = LogsArn::parse(&arn_str).unwrap();
+
+        assert!(matches!(
+            arn,
+            LogsArn::LogStream {
+                name: "2024/01/01/[$LATEST]0123abcd",
+                ..
+            }
+        ));
+        assert_eq!(arn.log_group().to_string(), GROUP);
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:logs:us-east-1:123456789012:{}", resource);
+            LogsArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(
+            parse("log-group:"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("log-group:my-group:log-stream:"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("log-group:my-group:stream"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("destination:my-destination"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Service-specific helpers built on top of [`NaiveArn`](crate::naive::NaiveArn)
+//!
+//! Typed ARNs are parsed from a [`NaiveArn`](crate::naive::NaiveArn) with `TryFrom`, or straight from a string with
+//! their `parse` function, and all fail with [`ParseServiceArnError`]. [`Service`] names the namespaces themselves.
+
+use core::fmt;
+
+use crate::naive::{NaiveArn, ParseNaiveArnError};
+
+pub mod acm;
+pub mod apigateway;
+pub mod athena;
+pub mod backup;
+pub mod cloudformation;
+pub mod cloudwatch;
+pub mod cognito;
+pub mod devtools;
+pub mod dynamodb;
+pub mod ec2;
+pub mod ecr;
+pub mod ecs;
+pub mod eks;
+pub mod elasticache;
+pub mod elbv2;
+pub mod events;
+pub mod glue;
+pub mod iam;
+pub mod kinesis;
+pub mod kms;
+pub mod lambda;
+pub mod logs;
+mod namespace;
+pub mod organizations;
+pub mod rds;
+pub mod redshift;
+pub mod route53;
+pub mod s3;
+pub mod s3outposts;
+pub mod sagemaker;
+pub mod secretsmanager;
+pub mod sns;
+pub mod sqs;
+pub mod ssm;
+pub mod states;
+pub mod sts;
+
+pub use namespace::Service;
+
+#[derive(Debug, PartialEq)]
+pub enum ParseServiceArnError {
+    Naive(ParseNaiveArnError),
+    WrongService,
+    MissingRegion,
+    UnexpectedRegion,
+    MissingAccountId,
+    UnexpectedAccountId,
+    UnknownResourceType,
+    InvalidResource,
+}
+
+impl From<ParseNaiveArnError> for ParseServiceArnError {
+    fn from(err: ParseNaiveArnError) -> Self {
+        ParseServiceArnError::Naive(err)
+    }
+}
+
+impl ParseServiceArnError {
+    /// A stable identifier for the kind of error, `ARN1xx`, or the code of the underlying
+    /// [`ParseNaiveArnError`](ParseNaiveArnError::code).
+    ///
+    /// | Code     | Variant                                               |
+    /// |----------|-------------------------------------------------------|
+    /// | `ARN101` | [`WrongService`](Self::WrongService)                  |
+    /// | `ARN102` | [`MissingRegion`](Self::MissingRegion)                |
+    /// | `ARN103` | [`UnexpectedRegion`](Self::UnexpectedRegion)          |
+    /// | `ARN104` | [`MissingAccountId`](Self::MissingAccountId)          |
+    /// | `ARN105` | [`UnexpectedAccountId`](Self::UnexpectedAccountId)    |
+    /// | `ARN106` | [`UnknownResourceType`](Self::UnknownResourceType)    |
+    /// | `ARN107` | [`InvalidResource`](Self::InvalidResource)            |
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParseServiceArnError::Naive(err) => err.code(),
+            ParseServiceArnError::WrongService => "ARN101",
+            ParseServiceArnError::MissingRegion => "ARN102",
+            ParseServiceArnError::UnexpectedRegion => "ARN103",
+            ParseServiceArnError::MissingAccountId => "ARN104",
+            ParseServiceArnError::UnexpectedAccountId => "ARN105",
+            ParseServiceArnError::UnknownResourceType => "ARN106",
+            ParseServiceArnError::InvalidResource => "ARN107",
+        }
+    }
+}
+
+impl fmt::Display for ParseServiceArnError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let ParseServiceArnError::Naive(err) = self {
+            return err.fmt(f);
+        }
+
+        write!(f, "{}: ", self.code())?;
+
+        match self {
+            ParseServiceArnError::Naive(_) => Ok(()),
+            ParseServiceArnError::WrongService => write!(f, "ARN is for a different service"),
+            ParseServiceArnError::MissingRegion => write!(f, "Missing region element"),
+            ParseServiceArnError::UnexpectedRegion => {
+                write!(f, "Region element must be empty for this resource")
+            }
+            ParseServiceArnError::MissingAccountId => write!(f, "Missing account-id element"),
+            ParseServiceArnError::UnexpectedAccountId => {
+                write!(f, "Account-id element must be empty for this resource")
+            }
+            ParseServiceArnError::UnknownResourceType => write!(f, "Unknown resource type"),
+            ParseServiceArnError::InvalidResource => write!(f, "Invalid resource element"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseServiceArnError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseServiceArnError::Naive(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// `s`, unless it is empty.
+pub(crate) fn non_empty(s: &str) -> Result<&str, ParseServiceArnError> {
+    if s.is_empty() {
+        Err(ParseServiceArnError::InvalidResource)
+    } else {
+        Ok(s)
+    }
+}
+
+/// The region and account ID of `arn`, which must both be present.
+pub(crate) fn regional<'a>(arn: NaiveArn<'a>) -> Result<(&'a str, &'a str), ParseServiceArnError> {
+    let region = arn.region.ok_or(ParseServiceArnError::MissingRegion)?;
+    let account_id = arn
+        .account_id
+        .ok_or(ParseServiceArnError::MissingAccountId)?;
+
+    Ok((region, account_id))
+}
+
+/// The account ID of a global `arn`, which must have an account ID but no region.
+pub(crate) fn global<'a>(arn: NaiveArn<'a>) -> Result<&'a str, ParseServiceArnError> {
+    if arn.region.is_some() {
+        return Err(ParseServiceArnError::UnexpectedRegion);
+    }
+
+    arn.account_id.ok_or(ParseServiceArnError::MissingAccountId)
+}
+
+/// The kind of `resource_type` in `table`, which must be sorted by resource type.
+pub(crate) fn lookup<K: Copy>(table: &[(&str, K)], resource_type: &str) -> Option<K> {
+    table
+        .binary_search_by_key(&resource_type, |(name, _)| name)
+        .ok()
+        .map(|i| table[i].1)
+}
+
+/// Whether `s` is a UUID in its hyphenated hex form, e.g. `1234abcd-12ab-34cd-56ef-1234567890ab`.
+pub(crate) fn is_uuid(s: &str) -> bool {
+    s.len() == 36
+        && s.bytes().enumerate().all(|(idx, b)| match idx {
+            8 | 13 | 18 | 23 => b == b'-',
+            _ => b.is_ascii_hexdigit(),
+        })
+}
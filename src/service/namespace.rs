@@ -0,0 +1,317 @@
+//! A typed set of service namespaces
+
+use alloc::{borrow::ToOwned, string::String};
+use core::convert::Infallible;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::explain::service_name;
+use crate::naive::NaiveArn;
+
+/// The service namespace of an ARN, such as `s3` in `arn:aws:s3:::my_corporate_bucket`.
+///
+/// Every namespace this crate knows (those with a [`service_name`]) has a variant, and any other namespace is kept
+/// in [`Service::Other`], so parsing never fails and matching on services stays type-safe as AWS launches new ones.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::naive::NaiveArn;
+/// use arn::service::Service;
+///
+/// let arn = NaiveArn::parse("arn:aws:s3:::my_corporate_bucket").unwrap();
+/// assert_eq!(arn.known_service(), Service::S3);
+///
+/// let service: Service = "cognito-idp".parse().unwrap();
+/// assert_eq!(service, Service::CognitoIdp);
+/// assert_eq!(service.as_str(), "cognito-idp");
+///
+/// let service = Service::from("unicorn");
+/// assert_eq!(service, Service::Other("unicorn".to_owned()));
+/// assert!(!service.is_known());
+/// ~~~~
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Service {
+    /// `acm`, AWS Certificate Manager
+    Acm,
+    /// `apigateway`, Amazon API Gateway
+    ApiGateway,
+    /// `athena`, Amazon Athena
+    Athena,
+    /// `backup`, AWS Backup
+    Backup,
+    /// `cloudformation`, AWS CloudFormation
+    CloudFormation,
+    /// `cloudwatch`, Amazon CloudWatch
+    CloudWatch,
+    /// `codebuild`, AWS CodeBuild
+    CodeBuild,
+    /// `codecommit`, AWS CodeCommit
+    CodeCommit,
+    /// `codepipeline`, AWS CodePipeline
+    CodePipeline,
+    /// `cognito-identity`, Amazon Cognito Identity
+    CognitoIdentity,
+    /// `cognito-idp`, Amazon Cognito User Pools
+    CognitoIdp,
+    /// `dynamodb`, Amazon DynamoDB
+    DynamoDb,
+    /// `ec2`, Amazon Elastic Compute Cloud
+    Ec2,
+    /// `ecr`, Amazon Elastic Container Registry
+    Ecr,
+    /// `ecs`, Amazon Elastic Container Service
+    Ecs,
+    /// `eks`, Amazon Elastic Kubernetes Service
+    Eks,
+    /// `elasticache`, Amazon ElastiCache
+    ElastiCache,
+    /// `elasticloadbalancing`, Elastic Load Balancing
+    ElasticLoadBalancing,
+    /// `events`, Amazon EventBridge
+    Events,
+    /// `execute-api`, Amazon API Gateway
+    ExecuteApi,
+    /// `glue`, AWS Glue
+    Glue,
+    /// `iam`, AWS Identity and Access Management
+    Iam,
+    /// `kinesis`, Amazon Kinesis Data Streams
+    Kinesis,
+    /// `kms`, AWS Key Management Service
+    Kms,
+    /// `lambda`, AWS Lambda
+    Lambda,
+    /// `logs`, Amazon CloudWatch Logs
+    Logs,
+    /// `organizations`, AWS Organizations
+    Organizations,
+    /// `rds`, Amazon Relational Database Service
+    Rds,
+    /// `redshift`, Amazon Redshift
+    Redshift,
+    /// `route53`, Amazon Route 53
+    Route53,
+    /// `s3`, Amazon Simple Storage Service
+    S3,
+    /// `s3-object-lambda`, Amazon S3 Object Lambda
+    S3ObjectLambda,
+    /// `s3-outposts`, Amazon S3 on Outposts
+    S3Outposts,
+    /// `sagemaker`, Amazon SageMaker
+    SageMaker,
+    /// `secretsmanager`, AWS Secrets Manager
+    SecretsManager,
+    /// `sns`, Amazon Simple Notification Service
+    Sns,
+    /// `sqs`, Amazon Simple Queue Service
+    Sqs,
+    /// `ssm`, AWS Systems Manager
+    Ssm,
+    /// `states`, AWS Step Functions
+    States,
+    /// `sts`, AWS Security Token Service
+    Sts,
+    /// A namespace without a variant of its own.
+    ///
+    /// This only holds namespaces that are not otherwise known; [`Service::from`] never returns `Other("s3")`.
+    Other(String),
+}
+
+impl Service {
+    /// Every service with a variant of its own, sorted by namespace.
+    pub const ALL: &'static [Service] = &[
+        Service::Acm,
+        Service::ApiGateway,
+        Service::Athena,
+        Service::Backup,
+        Service::CloudFormation,
+        Service::CloudWatch,
+        Service::CodeBuild,
+        Service::CodeCommit,
+        Service::CodePipeline,
+        Service::CognitoIdentity,
+        Service::CognitoIdp,
+        Service::DynamoDb,
+        Service::Ec2,
+        Service::Ecr,
+        Service::Ecs,
+        Service::Eks,
+        Service::ElastiCache,
+        Service::ElasticLoadBalancing,
+        Service::Events,
+        Service::ExecuteApi,
+        Service::Glue,
+        Service::Iam,
+        Service::Kinesis,
+        Service::Kms,
+        Service::Lambda,
+        Service::Logs,
+        Service::Organizations,
+        Service::Rds,
+        Service::Redshift,
+        Service::Route53,
+        Service::S3,
+        Service::S3ObjectLambda,
+        Service::S3Outposts,
+        Service::SageMaker,
+        Service::SecretsManager,
+        Service::Sns,
+        Service::Sqs,
+        Service::Ssm,
+        Service::States,
+        Service::Sts,
+    ];
+
+    /// The service namespace, as it appears in ARNs.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Service::Acm => "acm",
+            Service::ApiGateway => "apigateway",
+            Service::Athena => "athena",
+            Service::Backup => "backup",
+            Service::CloudFormation => "cloudformation",
+            Service::CloudWatch => "cloudwatch",
+            Service::CodeBuild => "codebuild",
+            Service::CodeCommit => "codecommit",
+            Service::CodePipeline => "codepipeline",
+            Service::CognitoIdentity => "cognito-identity",
+            Service::CognitoIdp => "cognito-idp",
+            Service::DynamoDb => "dynamodb",
+            Service::Ec2 => "ec2",
+            Service::Ecr => "ecr",
+            Service::Ecs => "ecs",
+            Service::Eks => "eks",
+            Service::ElastiCache => "elasticache",
+            Service::ElasticLoadBalancing => "elasticloadbalancing",
+            Service::Events => "events",
+            Service::ExecuteApi => "execute-api",
+            Service::Glue => "glue",
+            Service::Iam => "iam",
+            Service::Kinesis => "kinesis",
+            Service::Kms => "kms",
+            Service::Lambda => "lambda",
+            Service::Logs => "logs",
+            Service::Organizations => "organizations",
+            Service::Rds => "rds",
+            Service::Redshift => "redshift",
+            Service::Route53 => "route53",
+            Service::S3 => "s3",
+            Service::S3ObjectLambda => "s3-object-lambda",
+            Service::S3Outposts => "s3-outposts",
+            Service::SageMaker => "sagemaker",
+            Service::SecretsManager => "secretsmanager",
+            Service::Sns => "sns",
+            Service::Sqs => "sqs",
+            Service::Ssm => "ssm",
+            Service::States => "states",
+            Service::Sts => "sts",
+            Service::Other(namespace) => namespace,
+        }
+    }
+
+    /// The full name of the service, for known services.
+    pub fn name(&self) -> Option<&'static str> {
+        service_name(self.as_str())
+    }
+
+    /// Whether this is a service with a variant of its own, rather than [`Service::Other`].
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Service::Other(_))
+    }
+}
+
+impl<'a> NaiveArn<'a> {
+    /// The ARN's service namespace as a [`Service`].
+    pub fn known_service(&self) -> Service {
+        Service::from(self.service)
+    }
+}
+
+impl From<&str> for Service {
+    fn from(namespace: &str) -> Self {
+        Service::ALL
+            .binary_search_by(|service| service.as_str().cmp(namespace))
+            .map(|idx| Service::ALL[idx].clone())
+            .unwrap_or_else(|_| Service::Other(namespace.to_owned()))
+    }
+}
+
+impl FromStr for Service {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Service::from(s))
+    }
+}
+
+impl fmt::Display for Service {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq<str> for Service {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for Service {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Service;
+    use crate::naive::NaiveArn;
+
+    #[test]
+    fn round_trip() {
+        for service in Service::ALL {
+            assert_eq!(&Service::from(service.as_str()), service);
+            assert_eq!(service.to_string().parse::<Service>(), Ok(service.clone()));
+            assert!(service.name().is_some(), "{}", service);
+        }
+    }
+
+    #[test]
+    fn sorted() {
+        assert!(Service::ALL
+            .windows(2)
+            .all(|pair| pair[0].as_str() < pair[1].as_str()));
+    }
+
+    #[test]
+    fn other() {
+        let arn = NaiveArn::parse("arn:aws:bedrock:us-east-1:123456789012:agent/AGENT1").unwrap();
+        let service = arn.known_service();
+
+        assert_eq!(service, Service::Other("bedrock".to_owned()));
+        assert_eq!(service, "bedrock");
+        assert_eq!(service.name(), None);
+        assert!(!service.is_known());
+    }
+
+    #[test]
+    fn matching() {
+        let describe = |arn: &str| match NaiveArn::parse(arn).unwrap().known_service() {
+            Service::S3 | Service::S3Outposts => "bucket",
+            Service::Sqs => "queue",
+            _ => "other",
+        };
+
+        assert_eq!(describe("arn:aws:s3:::my_corporate_bucket"), "bucket");
+        assert_eq!(
+            describe("arn:aws:sqs:us-east-1:123456789012:orders"),
+            "queue"
+        );
+        assert_eq!(
+            describe("arn:aws:sns:us-east-1:123456789012:alerts"),
+            "other"
+        );
+    }
+}
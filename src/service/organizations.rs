@@ -0,0 +1,239 @@
+//! AWS Organizations (`arn:partition:organizations::management-account-id:account/o-orgid/...`) ARNs
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::account::AccountId;
+use crate::naive::NaiveArn;
+use crate::service::{global, ParseServiceArnError};
+
+/// The entity an [`OrganizationsArn`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrganizationsResource<'a> {
+    /// `organization/o-orgid`
+    Organization,
+    /// `account/o-orgid/account-id`
+    Account { account_id: &'a str },
+    /// `ou/o-orgid/ou-rootid-ouid`
+    OrganizationalUnit { id: &'a str },
+    /// `root/o-orgid/r-rootid`
+    Root { id: &'a str },
+}
+
+/// An AWS Organizations ARN
+///
+/// The ARN's account ID is the organization's management account, not the member account an `account/` ARN refers
+/// to.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::organizations::{OrganizationsArn, OrganizationsResource};
+///
+/// let arn = OrganizationsArn::parse("arn:aws:organizations::111111111111:account/o-exampleorgid/222222222222").unwrap();
+/// assert_eq!(arn.management_account_id, "111111111111");
+/// assert_eq!(arn.org_id, "o-exampleorgid");
+/// assert_eq!(arn.resource, OrganizationsResource::Account { account_id: "222222222222" });
+/// assert_eq!(arn.entity_id(), Some("222222222222"));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OrganizationsArn<'a> {
+    pub partition: &'a str,
+    pub management_account_id: &'a str,
+    pub org_id: &'a str,
+    pub resource: OrganizationsResource<'a>,
+}
+
+impl<'a> OrganizationsArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        OrganizationsArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The ID of the account, OU or root, or `None` for the organization itself.
+    pub fn entity_id(&self) -> Option<&'a str> {
+        match self.resource {
+            OrganizationsResource::Organization => None,
+            OrganizationsResource::Account { account_id: id }
+            | OrganizationsResource::OrganizationalUnit { id }
+            | OrganizationsResource::Root { id } => Some(id),
+        }
+    }
+
+    /// The ARN of the organization this entity belongs to.
+    pub fn organization_arn(&self) -> OrganizationsArn<'a> {
+        OrganizationsArn {
+            resource: OrganizationsResource::Organization,
+            ..*self
+        }
+    }
+}
+
+/// Whether `id` is `prefix` followed by lowercase letters and digits.
+fn is_prefixed_id(id: &str, prefix: &str) -> bool {
+    match id.strip_prefix(prefix) {
+        Some(rest) => {
+            !rest.is_empty()
+                && rest
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
+/// Whether `id` is an OU ID, `ou-` followed by the root's ID and the OU's own ID.
+fn is_ou_id(id: &str) -> bool {
+    match id.strip_prefix("ou-").and_then(|rest| rest.split_once('-')) {
+        Some((root, ou)) => is_prefixed_id(root, "") && is_prefixed_id(ou, ""),
+        None => false,
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for OrganizationsArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "organizations" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let management_account_id = global(arn)?;
+        let (resource_type, path) = arn
+            .resource
+            .split_once('/')
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+        let segments: Vec<&str> = path.split('/').collect();
+
+        let (org_id, resource) = match (resource_type, segments.as_slice()) {
+            ("organization", [org_id]) => (*org_id, OrganizationsResource::Organization),
+            ("account", [org_id, account_id]) if AccountId::parse(account_id).is_ok() => {
+                (*org_id, OrganizationsResource::Account { account_id })
+            }
+            ("ou", [org_id, id]) if is_ou_id(id) => {
+                (*org_id, OrganizationsResource::OrganizationalUnit { id })
+            }
+            ("root", [org_id, id]) if is_prefixed_id(id, "r-") => {
+                (*org_id, OrganizationsResource::Root { id })
+            }
+            ("organization", _) | ("account", _) | ("ou", _) | ("root", _) => {
+                return Err(ParseServiceArnError::InvalidResource)
+            }
+            _ => return Err(ParseServiceArnError::UnknownResourceType),
+        };
+
+        if !is_prefixed_id(org_id, "o-") {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        Ok(OrganizationsArn {
+            partition: arn.partition,
+            management_account_id,
+            org_id,
+            resource,
+        })
+    }
+}
+
+impl<'a> fmt::Display for OrganizationsArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:organizations::{}:",
+            self.partition, self.management_account_id
+        )?;
+
+        match self.resource {
+            OrganizationsResource::Organization => write!(f, "organization/{}", self.org_id),
+            OrganizationsResource::Account { account_id } => {
+                write!(f, "account/{}/{}", self.org_id, account_id)
+            }
+            OrganizationsResource::OrganizationalUnit { id } => {
+                write!(f, "ou/{}/{}", self.org_id, id)
+            }
+            OrganizationsResource::Root { id } => write!(f, "root/{}/{}", self.org_id, id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OrganizationsArn, OrganizationsResource};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn resources() {
+        for (arn_str, resource) in &[
+            (
+                "arn:aws:organizations::111111111111:organization/o-exampleorgid",
+                OrganizationsResource::Organization,
+            ),
+            (
+                "arn:aws:organizations::111111111111:account/o-exampleorgid/222222222222",
+                OrganizationsResource::Account {
+                    account_id: "222222222222",
+                },
+            ),
+            (
+                "arn:aws:organizations::111111111111:ou/o-exampleorgid/ou-examplerootid111-exampleouid111",
+                OrganizationsResource::OrganizationalUnit {
+                    id: "ou-examplerootid111-exampleouid111",
+                },
+            ),
+            (
+                "arn:aws:organizations::111111111111:root/o-exampleorgid/r-examplerootid111",
+                OrganizationsResource::Root {
+                    id: "r-examplerootid111",
+                },
+            ),
+        ] {
+            let arn = OrganizationsArn::parse(arn_str).unwrap();
+
+            assert_eq!(arn.org_id, "o-exampleorgid");
+            assert_eq!(arn.resource, *resource);
+            assert_eq!(arn.to_string(), *arn_str);
+            assert_eq!(
+                arn.organization_arn().to_string(),
+                "arn:aws:organizations::111111111111:organization/o-exampleorgid"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:organizations::111111111111:{}", resource);
+            OrganizationsArn::parse(&arn).map(|_| ())
+        };
+
+        for resource in &[
+            "organization/exampleorgid",
+            "organization/o-",
+            "organization/o-exampleorgid/extra",
+            "account/o-exampleorgid/2222",
+            "account/o-exampleorgid",
+            "ou/o-exampleorgid/ou-examplerootid111",
+            "ou/o-exampleorgid/r-examplerootid111",
+            "root/o-exampleorgid/ou-examplerootid111-exampleouid111",
+            "root/o-ExampleOrgId/r-examplerootid111",
+        ] {
+            assert_eq!(
+                parse(resource),
+                Err(ParseServiceArnError::InvalidResource),
+                "{}",
+                resource
+            );
+        }
+
+        assert_eq!(
+            parse("handshake/o-exampleorgid/invite/h-examplehandshakeid111"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            OrganizationsArn::parse(
+                "arn:aws:organizations:us-east-1:111111111111:organization/o-exampleorgid"
+            ),
+            Err(ParseServiceArnError::UnexpectedRegion)
+        );
+    }
+}
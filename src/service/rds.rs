@@ -0,0 +1,198 @@
+//! Amazon RDS (`arn:partition:rds:region:account-id:resource-type:identifier`) ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{lookup, non_empty, regional, ParseServiceArnError};
+
+/// The kind of resource an [`RdsArn`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RdsResourceKind {
+    Cluster,
+    ClusterEndpoint,
+    ClusterParameterGroup,
+    ClusterSnapshot,
+    DbInstance,
+    DbProxy,
+    EventSubscription,
+    OptionGroup,
+    ParameterGroup,
+    ReservedInstance,
+    SecurityGroup,
+    Snapshot,
+    SubnetGroup,
+}
+
+impl RdsResourceKind {
+    /// `(resource type, kind)`, sorted by resource type.
+    const ALL: &'static [(&'static str, RdsResourceKind)] = &[
+        ("cluster", RdsResourceKind::Cluster),
+        ("cluster-endpoint", RdsResourceKind::ClusterEndpoint),
+        ("cluster-pg", RdsResourceKind::ClusterParameterGroup),
+        ("cluster-snapshot", RdsResourceKind::ClusterSnapshot),
+        ("db", RdsResourceKind::DbInstance),
+        ("db-proxy", RdsResourceKind::DbProxy),
+        ("es", RdsResourceKind::EventSubscription),
+        ("og", RdsResourceKind::OptionGroup),
+        ("pg", RdsResourceKind::ParameterGroup),
+        ("ri", RdsResourceKind::ReservedInstance),
+        ("secgrp", RdsResourceKind::SecurityGroup),
+        ("snapshot", RdsResourceKind::Snapshot),
+        ("subgrp", RdsResourceKind::SubnetGroup),
+    ];
+
+    fn from_resource_type(resource_type: &str) -> Option<Self> {
+        lookup(Self::ALL, resource_type)
+    }
+
+    /// The resource type, as it appears in the ARN.
+    pub fn resource_type(self) -> &'static str {
+        match self {
+            RdsResourceKind::Cluster => "cluster",
+            RdsResourceKind::ClusterEndpoint => "cluster-endpoint",
+            RdsResourceKind::ClusterParameterGroup => "cluster-pg",
+            RdsResourceKind::ClusterSnapshot => "cluster-snapshot",
+            RdsResourceKind::DbInstance => "db",
+            RdsResourceKind::DbProxy => "db-proxy",
+            RdsResourceKind::EventSubscription => "es",
+            RdsResourceKind::OptionGroup => "og",
+            RdsResourceKind::ParameterGroup => "pg",
+            RdsResourceKind::ReservedInstance => "ri",
+            RdsResourceKind::SecurityGroup => "secgrp",
+            RdsResourceKind::Snapshot => "snapshot",
+            RdsResourceKind::SubnetGroup => "subgrp",
+        }
+    }
+}
+
+/// An RDS ARN
+///
+/// The identifier is everything after the resource type, so automated snapshot names like `rds:my-db-2024-01-01`
+/// keep their `:`.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::rds::{RdsArn, RdsResourceKind};
+///
+/// let arn = RdsArn::parse("arn:aws:rds:us-east-1:123456789012:snapshot:rds:my-db-2024-01-01-05-00").unwrap();
+/// assert_eq!(arn.kind, RdsResourceKind::Snapshot);
+/// assert_eq!(arn.identifier, "rds:my-db-2024-01-01-05-00");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RdsArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub kind: RdsResourceKind,
+    pub identifier: &'a str,
+}
+
+impl<'a> RdsArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        RdsArn::try_from(NaiveArn::parse(s)?)
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for RdsArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "rds" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let (resource_type, identifier) = arn
+            .resource
+            .split_once(':')
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+        let kind = RdsResourceKind::from_resource_type(resource_type)
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+
+        Ok(RdsArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            kind,
+            identifier: non_empty(identifier)?,
+        })
+    }
+}
+
+impl<'a> fmt::Display for RdsArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:rds:{}:{}:{}:{}",
+            self.partition,
+            self.region,
+            self.account_id,
+            self.kind.resource_type(),
+            self.identifier
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RdsArn, RdsResourceKind};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn resource_types_sorted() {
+        assert!(RdsResourceKind::ALL.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn kinds() {
+        for (resource_type, kind) in RdsResourceKind::ALL {
+            let arn_str = format!(
+                "arn:aws:rds:us-east-1:123456789012:{}:my-resource",
+                resource_type
+            );
+            let arn = RdsArn::parse(&arn_str).unwrap();
+
+            assert_eq!(arn.kind, *kind);
+            assert_eq!(arn.identifier, "my-resource");
+            assert_eq!(arn.to_string(), arn_str);
+        }
+    }
+
+    #[test]
+    fn db_instance() {
+        let arn =
+            RdsArn::parse("arn:aws:rds:us-east-1:123456789012:db:my-mysql-instance-1").unwrap();
+
+        assert_eq!(
+            arn,
+            RdsArn {
+                partition: "aws",
+                region: "us-east-1",
+                account_id: "123456789012",
+                kind: RdsResourceKind::DbInstance,
+                identifier: "my-mysql-instance-1",
+            }
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:rds:us-east-1:123456789012:{}", resource);
+            RdsArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(parse("db:"), Err(ParseServiceArnError::InvalidResource));
+        assert_eq!(
+            parse("db/my-instance"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            parse("global-cluster:my-cluster"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
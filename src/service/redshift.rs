@@ -0,0 +1,213 @@
+//! Amazon Redshift (`arn:partition:redshift:region:account-id:cluster:name`) ARNs
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// The resource a [`RedshiftArn`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RedshiftResource<'a> {
+    /// `cluster:name`
+    Cluster { name: &'a str },
+    /// `snapshot:cluster-name/snapshot-name`
+    Snapshot { cluster: &'a str, name: &'a str },
+    /// `dbname:cluster-name/database-name`
+    DbName { cluster: &'a str, name: &'a str },
+    /// `dbuser:cluster-name/user-name`
+    DbUser { cluster: &'a str, name: &'a str },
+    /// `dbgroup:cluster-name/group-name`
+    DbGroup { cluster: &'a str, name: &'a str },
+}
+
+/// A Redshift ARN
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::redshift::{RedshiftArn, RedshiftResource};
+///
+/// let arn = RedshiftArn::parse("arn:aws:redshift:us-east-1:123456789012:dbuser:analytics/alice").unwrap();
+/// assert_eq!(arn.resource, RedshiftResource::DbUser { cluster: "analytics", name: "alice" });
+/// assert_eq!(arn.cluster(), "analytics");
+/// assert_eq!(arn.cluster_arn().to_string(), "arn:aws:redshift:us-east-1:123456789012:cluster:analytics");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RedshiftArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub resource: RedshiftResource<'a>,
+}
+
+impl<'a> RedshiftArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        RedshiftArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The cluster's name, or the name of the cluster the resource belongs to.
+    pub fn cluster(&self) -> &'a str {
+        match self.resource {
+            RedshiftResource::Cluster { name } => name,
+            RedshiftResource::Snapshot { cluster, .. }
+            | RedshiftResource::DbName { cluster, .. }
+            | RedshiftResource::DbUser { cluster, .. }
+            | RedshiftResource::DbGroup { cluster, .. } => cluster,
+        }
+    }
+
+    /// The ARN of the cluster, or of the cluster the resource belongs to.
+    pub fn cluster_arn(&self) -> RedshiftArn<'a> {
+        RedshiftArn {
+            resource: RedshiftResource::Cluster {
+                name: self.cluster(),
+            },
+            ..*self
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for RedshiftArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "redshift" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let (resource_type, path) = arn
+            .resource
+            .split_once(':')
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+        let segments = path
+            .split('/')
+            .map(non_empty)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let resource = match (resource_type, segments.as_slice()) {
+            ("cluster", [name]) => RedshiftResource::Cluster { name },
+            ("snapshot", [cluster, name]) => RedshiftResource::Snapshot { cluster, name },
+            ("dbname", [cluster, name]) => RedshiftResource::DbName { cluster, name },
+            ("dbuser", [cluster, name]) => RedshiftResource::DbUser { cluster, name },
+            ("dbgroup", [cluster, name]) => RedshiftResource::DbGroup { cluster, name },
+            ("cluster", _) | ("snapshot", _) | ("dbname", _) | ("dbuser", _) | ("dbgroup", _) => {
+                return Err(ParseServiceArnError::InvalidResource)
+            }
+            _ => return Err(ParseServiceArnError::UnknownResourceType),
+        };
+
+        Ok(RedshiftArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            resource,
+        })
+    }
+}
+
+impl<'a> fmt::Display for RedshiftArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:redshift:{}:{}:",
+            self.partition, self.region, self.account_id
+        )?;
+
+        match self.resource {
+            RedshiftResource::Cluster { name } => write!(f, "cluster:{}", name),
+            RedshiftResource::Snapshot { cluster, name } => {
+                write!(f, "snapshot:{}/{}", cluster, name)
+            }
+            RedshiftResource::DbName { cluster, name } => write!(f, "dbname:{}/{}", cluster, name),
+            RedshiftResource::DbUser { cluster, name } => write!(f, "dbuser:{}/{}", cluster, name),
+            RedshiftResource::DbGroup { cluster, name } => {
+                write!(f, "dbgroup:{}/{}", cluster, name)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RedshiftArn, RedshiftResource};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn resources() {
+        for (arn_str, resource) in &[
+            (
+                "arn:aws:redshift:us-east-1:123456789012:cluster:analytics",
+                RedshiftResource::Cluster { name: "analytics" },
+            ),
+            (
+                "arn:aws:redshift:us-east-1:123456789012:snapshot:analytics/nightly-2024-01-01",
+                RedshiftResource::Snapshot {
+                    cluster: "analytics",
+                    name: "nightly-2024-01-01",
+                },
+            ),
+            (
+                "arn:aws:redshift:us-east-1:123456789012:dbname:analytics/sales",
+                RedshiftResource::DbName {
+                    cluster: "analytics",
+                    name: "sales",
+                },
+            ),
+            (
+                "arn:aws:redshift:us-east-1:123456789012:dbuser:analytics/alice",
+                RedshiftResource::DbUser {
+                    cluster: "analytics",
+                    name: "alice",
+                },
+            ),
+            (
+                "arn:aws:redshift:us-east-1:123456789012:dbgroup:analytics/readers",
+                RedshiftResource::DbGroup {
+                    cluster: "analytics",
+                    name: "readers",
+                },
+            ),
+        ] {
+            let arn = RedshiftArn::parse(arn_str).unwrap();
+
+            assert_eq!(arn.resource, *resource);
+            assert_eq!(arn.cluster(), "analytics");
+            assert_eq!(arn.to_string(), *arn_str);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:redshift:us-east-1:123456789012:{}", resource);
+            RedshiftArn::parse(&arn).map(|_| ())
+        };
+
+        for resource in &[
+            "cluster:",
+            "cluster:analytics/extra",
+            "snapshot:analytics",
+            "dbuser:/alice",
+            "dbname:analytics/sales/extra",
+        ] {
+            assert_eq!(
+                parse(resource),
+                Err(ParseServiceArnError::InvalidResource),
+                "{}",
+                resource
+            );
+        }
+
+        assert_eq!(
+            parse("cluster/analytics"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            parse("parametergroup:default"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
@@ -0,0 +1,163 @@
+//! Amazon Route 53 (`arn:partition:route53:::hostedzone/zone-id`) ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, ParseServiceArnError};
+
+/// A Route 53 hosted zone or health check ARN
+///
+/// Route 53 is global and its ARNs have neither a region nor an account ID.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::route53::Route53Arn;
+///
+/// let arn = Route53Arn::parse("arn:aws:route53:::hostedzone/Z1D633PJN98FT9").unwrap();
+/// assert_eq!(arn, Route53Arn::hosted_zone("/hostedzone/Z1D633PJN98FT9"));
+/// assert_eq!(arn.hosted_zone_id(), Some("Z1D633PJN98FT9"));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Route53Arn<'a> {
+    HostedZone { partition: &'a str, id: &'a str },
+    HealthCheck { partition: &'a str, id: &'a str },
+}
+
+impl<'a> Route53Arn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        Route53Arn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The ARN of a hosted zone in the `aws` partition, given its ID as either `Z123` or `/hostedzone/Z123`.
+    pub fn hosted_zone(id: &'a str) -> Self {
+        Route53Arn::HostedZone {
+            partition: "aws",
+            id: hosted_zone_id(id),
+        }
+    }
+
+    /// The hosted zone ID, without any `/hostedzone/` prefix.
+    pub fn hosted_zone_id(&self) -> Option<&'a str> {
+        match self {
+            Route53Arn::HostedZone { id, .. } => Some(id),
+            Route53Arn::HealthCheck { .. } => None,
+        }
+    }
+}
+
+/// A hosted zone ID as returned by the Route 53 APIs, `/hostedzone/Z123`, or as written elsewhere, `Z123`, reduced
+/// to the bare `Z123`.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::route53::hosted_zone_id;
+///
+/// assert_eq!(hosted_zone_id("/hostedzone/Z1D633PJN98FT9"), "Z1D633PJN98FT9");
+/// assert_eq!(hosted_zone_id("Z1D633PJN98FT9"), "Z1D633PJN98FT9");
+/// ~~~~
+pub fn hosted_zone_id(id: &str) -> &str {
+    id.strip_prefix("/hostedzone/").unwrap_or(id)
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for Route53Arn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "route53" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        if arn.region.is_some() {
+            return Err(ParseServiceArnError::UnexpectedRegion);
+        }
+
+        if arn.account_id.is_some() {
+            return Err(ParseServiceArnError::UnexpectedAccountId);
+        }
+
+        let (resource_type, id) = arn
+            .resource
+            .split_once('/')
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+
+        if id.contains('/') {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        let (partition, id) = (arn.partition, non_empty(id)?);
+
+        match resource_type {
+            "hostedzone" => Ok(Route53Arn::HostedZone { partition, id }),
+            "healthcheck" => Ok(Route53Arn::HealthCheck { partition, id }),
+            _ => Err(ParseServiceArnError::UnknownResourceType),
+        }
+    }
+}
+
+impl<'a> fmt::Display for Route53Arn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Route53Arn::HostedZone { partition, id } => {
+                write!(f, "arn:{}:route53:::hostedzone/{}", partition, id)
+            }
+            Route53Arn::HealthCheck { partition, id } => {
+                write!(f, "arn:{}:route53:::healthcheck/{}", partition, id)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Route53Arn;
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn hosted_zone() {
+        let arn_str = "arn:aws:route53:::hostedzone/Z1D633PJN98FT9";
+        let arn = Route53Arn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn,
+            Route53Arn::HostedZone {
+                partition: "aws",
+                id: "Z1D633PJN98FT9"
+            }
+        );
+        assert_eq!(arn, Route53Arn::hosted_zone("Z1D633PJN98FT9"));
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn health_check() {
+        let arn_str = "arn:aws:route53:::healthcheck/abcdef11-2222-3333-4444-555555fedcba";
+        let arn = Route53Arn::parse(arn_str).unwrap();
+
+        assert!(matches!(arn, Route53Arn::HealthCheck { .. }));
+        assert_eq!(arn.hosted_zone_id(), None);
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Route53Arn::parse("arn:aws:route53:us-east-1::hostedzone/Z1D633PJN98FT9"),
+            Err(ParseServiceArnError::UnexpectedRegion)
+        );
+        assert_eq!(
+            Route53Arn::parse("arn:aws:route53::123456789012:hostedzone/Z1D633PJN98FT9"),
+            Err(ParseServiceArnError::UnexpectedAccountId)
+        );
+        assert_eq!(
+            Route53Arn::parse("arn:aws:route53:::hostedzone//hostedzone/Z1D633PJN98FT9"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            Route53Arn::parse("arn:aws:route53:::change/C2682N5HXP0BZ4"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
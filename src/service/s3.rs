@@ -0,0 +1,1041 @@
+//! Amazon S3 (`arn:partition:s3:::bucket[/key]`) helpers
+
+use alloc::{borrow::ToOwned, format, string::String};
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::buf::ArnBuf;
+use crate::naive::NaiveArn;
+use crate::partition::{self, Partition};
+use crate::percent;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+const S3_URI_SCHEME: &str = "s3://";
+
+/// An S3 bucket or object ARN, `arn:partition:s3:::bucket[/key]`
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::s3::S3Arn;
+///
+/// let arn = S3Arn::parse("arn:aws:s3:::my_corporate_bucket/exampleobject.png").unwrap();
+/// assert_eq!(arn.bucket, "my_corporate_bucket");
+/// assert_eq!(arn.key, Some("exampleobject.png"));
+///
+/// assert_eq!(arn.bucket_arn().with_wildcard_objects().to_string(), "arn:aws:s3:::my_corporate_bucket/*");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct S3Arn<'a> {
+    pub partition: &'a str,
+    pub bucket: &'a str,
+    /// The object key, or key pattern, for object ARNs. `None` for bucket ARNs.
+    pub key: Option<&'a str>,
+}
+
+impl<'a> S3Arn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        S3Arn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The ARN of `bucket` in the `aws` partition.
+    pub fn bucket(bucket: &'a str) -> Self {
+        S3Arn {
+            partition: "aws",
+            bucket,
+            key: None,
+        }
+    }
+
+    /// The ARN of the object `key` in `bucket`, in the `aws` partition.
+    pub fn object_in(bucket: &'a str, key: &'a str) -> Self {
+        S3Arn {
+            partition: "aws",
+            bucket,
+            key: Some(key),
+        }
+    }
+
+    /// This ARN, moved to `partition`.
+    pub fn in_partition(self, partition: &'a str) -> Self {
+        S3Arn { partition, ..self }
+    }
+
+    pub fn is_bucket(&self) -> bool {
+        self.key.is_none()
+    }
+
+    pub fn is_object(&self) -> bool {
+        self.key.is_some()
+    }
+
+    /// The ARN of the bucket this object is in, or of this bucket.
+    pub fn bucket_arn(&self) -> Self {
+        S3Arn { key: None, ..*self }
+    }
+
+    /// The `bucket/*` ARN matching every object in the bucket.
+    pub fn with_wildcard_objects(&self) -> Self {
+        S3Arn {
+            key: Some("*"),
+            ..*self
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for S3Arn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "s3" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        if arn.region.is_some() {
+            return Err(ParseServiceArnError::UnexpectedRegion);
+        }
+
+        if arn.account_id.is_some() {
+            return Err(ParseServiceArnError::UnexpectedAccountId);
+        }
+
+        let (bucket, key) = match arn.resource.split_once('/') {
+            Some((bucket, key)) => (bucket, Some(key)),
+            None => (arn.resource, None),
+        };
+
+        Ok(S3Arn {
+            partition: arn.partition,
+            bucket: non_empty(bucket)?,
+            key,
+        })
+    }
+}
+
+impl<'a> fmt::Display for S3Arn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "arn:{}:s3:::{}", self.partition, self.bucket)?;
+
+        match self.key {
+            Some(key) => write!(f, "/{}", key),
+            None => Ok(()),
+        }
+    }
+}
+
+/// An S3 access point ARN, `arn:partition:s3:region:account-id:accesspoint/name[/object/key]`
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::s3::S3AccessPointArn;
+///
+/// let arn = S3AccessPointArn::parse("arn:aws:s3:us-west-2:123456789012:accesspoint/test/object/unit-01").unwrap();
+/// assert_eq!(arn.name, "test");
+/// assert_eq!(arn.key, Some("unit-01"));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct S3AccessPointArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub name: &'a str,
+    /// The object key, or key pattern, for objects accessed through the access point.
+    pub key: Option<&'a str>,
+}
+
+impl<'a> S3AccessPointArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        S3AccessPointArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The ARN of the access point itself, without any object key.
+    pub fn access_point_arn(&self) -> Self {
+        S3AccessPointArn { key: None, ..*self }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for S3AccessPointArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "s3" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let (name, key) = access_point_resource(arn.resource)?;
+
+        Ok(S3AccessPointArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            name,
+            key,
+        })
+    }
+}
+
+impl<'a> fmt::Display for S3AccessPointArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:s3:{}:{}:accesspoint/{}",
+            self.partition, self.region, self.account_id, self.name
+        )?;
+
+        match self.key {
+            Some(key) => write!(f, "/object/{}", key),
+            None => Ok(()),
+        }
+    }
+}
+
+/// An S3 Object Lambda access point ARN, `arn:partition:s3-object-lambda:region:account-id:accesspoint/name`
+///
+/// Unlike [`S3AccessPointArn`], these never address individual objects.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::s3::S3ObjectLambdaAccessPointArn;
+///
+/// let arn = S3ObjectLambdaAccessPointArn::parse("arn:aws:s3-object-lambda:us-west-2:123456789012:accesspoint/redactor").unwrap();
+/// assert_eq!(arn.name, "redactor");
+///
+/// assert!(S3ObjectLambdaAccessPointArn::parse("arn:aws:s3-object-lambda:us-west-2:123456789012:accesspoint/redactor/object/key").is_err());
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct S3ObjectLambdaAccessPointArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub name: &'a str,
+}
+
+impl<'a> S3ObjectLambdaAccessPointArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        S3ObjectLambdaAccessPointArn::try_from(NaiveArn::parse(s)?)
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for S3ObjectLambdaAccessPointArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "s3-object-lambda" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let name = match access_point_resource(arn.resource)? {
+            (name, None) => name,
+            (_, Some(_)) => return Err(ParseServiceArnError::InvalidResource),
+        };
+
+        Ok(S3ObjectLambdaAccessPointArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            name,
+        })
+    }
+}
+
+impl<'a> fmt::Display for S3ObjectLambdaAccessPointArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:s3-object-lambda:{}:{}:accesspoint/{}",
+            self.partition, self.region, self.account_id, self.name
+        )
+    }
+}
+
+/// An S3 Multi-Region Access Point ARN, `arn:partition:s3::account-id:accesspoint/alias.mrap[/object/key]`
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::naive::NaiveArn;
+/// use arn::service::s3::S3MultiRegionAccessPointArn;
+///
+/// let arn_str = "arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap";
+/// assert!(NaiveArn::parse(arn_str).unwrap().is_s3_multi_region_access_point());
+///
+/// let arn = S3MultiRegionAccessPointArn::parse(arn_str).unwrap();
+/// assert_eq!(arn.alias, "mfzwi23gnjvgw.mrap");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct S3MultiRegionAccessPointArn<'a> {
+    pub partition: &'a str,
+    pub account_id: &'a str,
+    /// The access point alias, including its `.mrap` suffix.
+    pub alias: &'a str,
+    /// The object key, or key pattern, for objects accessed through the access point.
+    pub key: Option<&'a str>,
+}
+
+impl<'a> S3MultiRegionAccessPointArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        S3MultiRegionAccessPointArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The ARN of the access point itself, without any object key.
+    pub fn access_point_arn(&self) -> Self {
+        S3MultiRegionAccessPointArn { key: None, ..*self }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for S3MultiRegionAccessPointArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "s3" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        if arn.region.is_some() {
+            return Err(ParseServiceArnError::UnexpectedRegion);
+        }
+
+        let account_id = arn
+            .account_id
+            .ok_or(ParseServiceArnError::MissingAccountId)?;
+        let (alias, key) = access_point_resource(arn.resource)?;
+
+        if !is_mrap_alias(alias) {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        Ok(S3MultiRegionAccessPointArn {
+            partition: arn.partition,
+            account_id,
+            alias,
+            key,
+        })
+    }
+}
+
+impl<'a> fmt::Display for S3MultiRegionAccessPointArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:s3::{}:accesspoint/{}",
+            self.partition, self.account_id, self.alias
+        )?;
+
+        match self.key {
+            Some(key) => write!(f, "/object/{}", key),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> NaiveArn<'a> {
+    /// Whether this is an S3 Multi-Region Access Point ARN.
+    ///
+    /// These share the `accesspoint/` resource type with regional access points, but have no region and an alias
+    /// ending in `.mrap` in place of the access point name.
+    pub fn is_s3_multi_region_access_point(&self) -> bool {
+        S3MultiRegionAccessPointArn::try_from(*self).is_ok()
+    }
+}
+
+fn is_mrap_alias(alias: &str) -> bool {
+    match alias.strip_suffix(".mrap") {
+        Some(id) => !id.is_empty() && id.bytes().all(|b| b.is_ascii_alphanumeric()),
+        None => false,
+    }
+}
+
+/// Splits an `accesspoint/name[/object/key]` resource into its name and key.
+fn access_point_resource(resource: &str) -> Result<(&str, Option<&str>), ParseServiceArnError> {
+    let rest = match resource.strip_prefix("accesspoint/") {
+        Some(rest) => rest,
+        None => return Err(ParseServiceArnError::UnknownResourceType),
+    };
+
+    match rest.split_once('/') {
+        None => Ok((non_empty(rest)?, None)),
+        Some((name, rest)) => match rest.strip_prefix("object/") {
+            Some(key) => Ok((non_empty(name)?, Some(non_empty(key)?))),
+            None => Err(ParseServiceArnError::InvalidResource),
+        },
+    }
+}
+
+impl<'a> NaiveArn<'a> {
+    /// Parse an `s3://bucket[/key]` URI into the equivalent S3 bucket or object ARN.
+    ///
+    /// S3 URIs carry no partition, so the returned ARN is in the `aws` partition. Object keys are taken verbatim: S3
+    /// URIs and ARNs both use the raw key, so characters such as spaces, `:`, `?` or `#` need no escaping.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let arn = NaiveArn::from_s3_uri("s3://my_corporate_bucket/exampleobject.png").unwrap();
+    /// assert_eq!(arn.to_string(), "arn:aws:s3:::my_corporate_bucket/exampleobject.png");
+    /// ~~~~
+    pub fn from_s3_uri(uri: &'a str) -> Result<Self, ParseS3UriError> {
+        let path = match strip_prefix_ignore_ascii_case(uri, S3_URI_SCHEME) {
+            Some(path) => path,
+            None => return Err(ParseS3UriError::MissingScheme),
+        };
+
+        let resource = match path.find('/') {
+            Some(0) => return Err(ParseS3UriError::MissingBucket),
+            // `s3://bucket/` refers to the bucket itself
+            Some(idx) if idx + 1 == path.len() => &path[..idx],
+            Some(_) => path,
+            None if path.is_empty() => return Err(ParseS3UriError::MissingBucket),
+            None => path,
+        };
+
+        Ok(NaiveArn {
+            partition: "aws",
+            service: "s3",
+            region: None,
+            account_id: None,
+            resource,
+        })
+    }
+
+    /// Render an S3 bucket or object ARN as an `s3://bucket[/key]` URI.
+    ///
+    /// Returns `None` if this is not an S3 bucket or object ARN (for example, an access point ARN, which carries a
+    /// region and account).
+    pub fn to_s3_uri(&self) -> Option<String> {
+        if self.service != "s3" || self.region.is_some() || self.account_id.is_some() {
+            return None;
+        }
+
+        if self.resource.starts_with('/') {
+            return None;
+        }
+
+        Some(format!("{}{}", S3_URI_SCHEME, self.resource))
+    }
+}
+
+/// Addressing style of an S3 HTTPS URL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum S3UrlStyle {
+    /// `https://bucket.s3.region.amazonaws.com/key`
+    VirtualHosted,
+    /// `https://s3.region.amazonaws.com/bucket/key`
+    Path,
+}
+
+impl<'a> NaiveArn<'a> {
+    /// Render an S3 bucket or object ARN as an HTTPS URL for the bucket's `region`.
+    ///
+    /// The DNS suffix follows the ARN's partition, and the object key is percent-encoded. Returns `None` if this is not
+    /// an S3 bucket or object ARN, or if the partition is not known.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    /// use arn::service::s3::S3UrlStyle;
+    ///
+    /// let arn = NaiveArn::parse("arn:aws-cn:s3:::my_corporate_bucket/my object").unwrap();
+    /// assert_eq!(
+    ///     arn.to_s3_url("cn-north-1", S3UrlStyle::VirtualHosted).unwrap(),
+    ///     "https://my_corporate_bucket.s3.cn-north-1.amazonaws.com.cn/my%20object"
+    /// );
+    /// ~~~~
+    pub fn to_s3_url(&self, region: &str, style: S3UrlStyle) -> Option<String> {
+        self.to_s3_uri()?;
+        let dns_suffix = self.known_partition()?.dns_suffix();
+
+        let (bucket, key) = match self.resource.find('/') {
+            Some(idx) => (&self.resource[..idx], &self.resource[idx + 1..]),
+            None => (self.resource, ""),
+        };
+        let key = percent::encode_path(key);
+
+        Some(match style {
+            S3UrlStyle::VirtualHosted => {
+                format!("https://{}.s3.{}.{}/{}", bucket, region, dns_suffix, key)
+            }
+            S3UrlStyle::Path => format!("https://s3.{}.{}/{}/{}", region, dns_suffix, bucket, key),
+        })
+    }
+}
+
+impl ArnBuf {
+    /// Parse a virtual-hosted or path-style S3 HTTPS URL into the equivalent S3 bucket or object ARN.
+    ///
+    /// The partition is inferred from the host's DNS suffix. Legacy global (`s3.amazonaws.com`), dash-region
+    /// (`s3-us-west-2`), dualstack and FIPS endpoints are accepted. The query string and fragment are ignored.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::buf::ArnBuf;
+    ///
+    /// let arn = ArnBuf::from_s3_url("https://s3.us-gov-west-1.amazonaws.com/my_corporate_bucket/my%20object").unwrap();
+    /// assert_eq!(arn.to_string(), "arn:aws-us-gov:s3:::my_corporate_bucket/my object");
+    /// ~~~~
+    pub fn from_s3_url(url: &str) -> Result<Self, ParseS3UrlError> {
+        let rest = strip_prefix_ignore_ascii_case(url, "https://")
+            .or_else(|| strip_prefix_ignore_ascii_case(url, "http://"))
+            .ok_or(ParseS3UrlError::UnsupportedScheme)?;
+
+        let rest = match rest.find(['?', '#']) {
+            Some(idx) => &rest[..idx],
+            None => rest,
+        };
+
+        let (authority, path) = match rest.find('/') {
+            Some(idx) => (&rest[..idx], &rest[idx + 1..]),
+            None => (rest, ""),
+        };
+
+        let host = match authority.rfind(':') {
+            Some(idx) => &authority[..idx],
+            None => authority,
+        }
+        .to_ascii_lowercase();
+
+        let (prefix, dns_suffix) =
+            partition::split_host(&host).ok_or(ParseS3UrlError::UnknownHost)?;
+
+        // The S3 endpoint starts at the right-most `s3` / `s3-*` label; anything before it is the bucket
+        let endpoint_start = prefix
+            .match_indices('.')
+            .map(|(idx, _)| idx + 1)
+            .chain(core::iter::once(0))
+            .filter(|&idx| {
+                is_s3_endpoint_label(prefix[idx..].split('.').next().unwrap_or_default())
+            })
+            .max()
+            .ok_or(ParseS3UrlError::UnknownHost)?;
+        let endpoint = &prefix[endpoint_start..];
+
+        let region = endpoint.split('.').rev().find_map(|label| {
+            let label = label.strip_prefix("s3").unwrap_or(label);
+            let label = label.strip_prefix('-').unwrap_or(label);
+            let label = label.strip_prefix("website-").unwrap_or(label);
+            let label = label.strip_prefix("fips-").unwrap_or(label);
+            Some(label).filter(|label| label.contains('-'))
+        });
+        let partition =
+            Partition::from_dns_suffix(dns_suffix, region).ok_or(ParseS3UrlError::UnknownHost)?;
+
+        let path = percent::decode(path).ok_or(ParseS3UrlError::InvalidEncoding)?;
+        let resource = if endpoint_start == 0 {
+            path.trim_end_matches('/').to_owned()
+        } else {
+            let bucket = &prefix[..endpoint_start - 1];
+            match path.as_str() {
+                "" => bucket.to_owned(),
+                key => format!("{}/{}", bucket, key),
+            }
+        };
+
+        if resource.is_empty() || resource.starts_with('/') {
+            return Err(ParseS3UrlError::MissingBucket);
+        }
+
+        Ok(ArnBuf {
+            partition: partition.id().to_owned(),
+            service: "s3".to_owned(),
+            region: None,
+            account_id: None,
+            resource,
+        })
+    }
+}
+
+fn is_s3_endpoint_label(label: &str) -> bool {
+    match label.strip_prefix("s3") {
+        Some("") => true,
+        Some(rest) => {
+            rest.starts_with('-')
+                && !rest.starts_with("-accesspoint")
+                && !rest.starts_with("-control")
+        }
+        None => false,
+    }
+}
+
+fn strip_prefix_ignore_ascii_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    match s.get(..prefix.len()) {
+        Some(head) if head.eq_ignore_ascii_case(prefix) => Some(&s[prefix.len()..]),
+        _ => None,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseS3UriError {
+    MissingScheme,
+    MissingBucket,
+}
+
+impl fmt::Display for ParseS3UriError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseS3UriError::MissingScheme => write!(f, "Missing 's3://' scheme"),
+            ParseS3UriError::MissingBucket => write!(f, "Missing bucket name"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseS3UriError {}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseS3UrlError {
+    UnsupportedScheme,
+    UnknownHost,
+    MissingBucket,
+    InvalidEncoding,
+}
+
+impl fmt::Display for ParseS3UrlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseS3UrlError::UnsupportedScheme => {
+                write!(f, "Expected an 'https://' or 'http://' URL")
+            }
+            ParseS3UrlError::UnknownHost => write!(f, "Host is not an S3 endpoint"),
+            ParseS3UrlError::MissingBucket => write!(f, "Missing bucket name"),
+            ParseS3UrlError::InvalidEncoding => write!(f, "Invalid percent-encoding in object key"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseS3UrlError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ParseS3UriError, ParseS3UrlError, S3AccessPointArn, S3Arn, S3MultiRegionAccessPointArn,
+        S3ObjectLambdaAccessPointArn, S3UrlStyle,
+    };
+    use crate::buf::ArnBuf;
+    use crate::naive::{NaiveArn, ParseNaiveArnError};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn bucket_uri() {
+        let arn = NaiveArn::from_s3_uri("s3://my_corporate_bucket").unwrap();
+
+        assert_eq!(arn.partition, "aws");
+        assert_eq!(arn.service, "s3");
+        assert_eq!(arn.region, None);
+        assert_eq!(arn.account_id, None);
+        assert_eq!(arn.resource, "my_corporate_bucket");
+
+        assert_eq!(arn.to_s3_uri().unwrap(), "s3://my_corporate_bucket");
+    }
+
+    #[test]
+    fn bucket_uri_trailing_slash() {
+        let arn = NaiveArn::from_s3_uri("s3://my_corporate_bucket/").unwrap();
+
+        assert_eq!(arn.resource, "my_corporate_bucket");
+    }
+
+    #[test]
+    fn object_uri() {
+        let uri = "s3://my_corporate_bucket/Development/exampleobject.png";
+        let arn = NaiveArn::from_s3_uri(uri).unwrap();
+
+        assert_eq!(
+            arn.resource,
+            "my_corporate_bucket/Development/exampleobject.png"
+        );
+        assert_eq!(
+            arn.to_string(),
+            "arn:aws:s3:::my_corporate_bucket/Development/exampleobject.png"
+        );
+
+        assert_eq!(arn.to_s3_uri().unwrap(), uri);
+    }
+
+    #[test]
+    fn object_uri_special_characters() {
+        let uri = "s3://my_corporate_bucket/reports/Q1 2020: final?#%20+.csv";
+        let arn = NaiveArn::from_s3_uri(uri).unwrap();
+
+        assert_eq!(
+            arn.resource,
+            "my_corporate_bucket/reports/Q1 2020: final?#%20+.csv"
+        );
+
+        let reparsed = NaiveArn::parse(&arn.to_string()).unwrap().to_s3_uri();
+        assert_eq!(reparsed.unwrap(), uri);
+    }
+
+    #[test]
+    fn uppercase_scheme() {
+        let arn = NaiveArn::from_s3_uri("S3://my_corporate_bucket/key").unwrap();
+
+        assert_eq!(arn.resource, "my_corporate_bucket/key");
+    }
+
+    #[test]
+    fn other_partition() {
+        let arn = NaiveArn::parse("arn:aws-cn:s3:::my_corporate_bucket/key").unwrap();
+
+        assert_eq!(arn.to_s3_uri().unwrap(), "s3://my_corporate_bucket/key");
+    }
+
+    #[test]
+    fn not_s3() {
+        let arn = NaiveArn::parse("arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98").unwrap();
+
+        assert_eq!(arn.to_s3_uri(), None);
+    }
+
+    #[test]
+    fn access_point() {
+        let arn = NaiveArn::parse("arn:aws:s3:us-west-2:123456789012:accesspoint/test").unwrap();
+
+        assert_eq!(arn.to_s3_uri(), None);
+    }
+
+    #[test]
+    fn malformed_uri_no_scheme() {
+        let uri = "https://my_corporate_bucket/key";
+
+        assert_eq!(
+            NaiveArn::from_s3_uri(uri),
+            Err(ParseS3UriError::MissingScheme)
+        );
+    }
+
+    #[test]
+    fn malformed_uri_no_bucket() {
+        assert_eq!(
+            NaiveArn::from_s3_uri("s3://"),
+            Err(ParseS3UriError::MissingBucket)
+        );
+        assert_eq!(
+            NaiveArn::from_s3_uri("s3:///key"),
+            Err(ParseS3UriError::MissingBucket)
+        );
+    }
+
+    #[test]
+    fn virtual_hosted_url() {
+        let arn = NaiveArn::parse("arn:aws:s3:::my-bucket/photos/2020/a b+c.png").unwrap();
+        let url = arn
+            .to_s3_url("us-east-1", S3UrlStyle::VirtualHosted)
+            .unwrap();
+
+        assert_eq!(
+            url,
+            "https://my-bucket.s3.us-east-1.amazonaws.com/photos/2020/a%20b%2Bc.png"
+        );
+        assert_eq!(ArnBuf::from_s3_url(&url).unwrap().as_naive(), arn);
+    }
+
+    #[test]
+    fn path_style_url() {
+        let arn = NaiveArn::parse("arn:aws:s3:::my-bucket/photos/2020/a b+c.png").unwrap();
+        let url = arn.to_s3_url("eu-west-1", S3UrlStyle::Path).unwrap();
+
+        assert_eq!(
+            url,
+            "https://s3.eu-west-1.amazonaws.com/my-bucket/photos/2020/a%20b%2Bc.png"
+        );
+        assert_eq!(ArnBuf::from_s3_url(&url).unwrap().as_naive(), arn);
+    }
+
+    #[test]
+    fn bucket_url() {
+        let arn = NaiveArn::parse("arn:aws:s3:::my-bucket").unwrap();
+        let url = arn.to_s3_url("us-east-1", S3UrlStyle::Path).unwrap();
+
+        assert_eq!(url, "https://s3.us-east-1.amazonaws.com/my-bucket/");
+        assert_eq!(ArnBuf::from_s3_url(&url).unwrap().as_naive(), arn);
+        assert_eq!(
+            ArnBuf::from_s3_url("https://my-bucket.s3.amazonaws.com")
+                .unwrap()
+                .as_naive(),
+            arn
+        );
+    }
+
+    #[test]
+    fn china_url() {
+        let arn = NaiveArn::parse("arn:aws-cn:s3:::my-bucket/key").unwrap();
+        let url = arn
+            .to_s3_url("cn-north-1", S3UrlStyle::VirtualHosted)
+            .unwrap();
+
+        assert_eq!(url, "https://my-bucket.s3.cn-north-1.amazonaws.com.cn/key");
+        assert_eq!(ArnBuf::from_s3_url(&url).unwrap().as_naive(), arn);
+    }
+
+    #[test]
+    fn govcloud_url() {
+        let arn = ArnBuf::from_s3_url("https://my-bucket.s3-fips.us-gov-west-1.amazonaws.com/key")
+            .unwrap();
+
+        assert_eq!(arn.to_string(), "arn:aws-us-gov:s3:::my-bucket/key");
+    }
+
+    #[test]
+    fn legacy_urls() {
+        let expected = "arn:aws:s3:::my-bucket/key";
+
+        for url in &[
+            "https://s3.amazonaws.com/my-bucket/key",
+            "http://my-bucket.s3.amazonaws.com/key?versionId=1#top",
+            "https://my-bucket.s3-us-west-2.amazonaws.com/key",
+            "https://s3-us-west-2.amazonaws.com/my-bucket/key",
+            "https://my-bucket.s3.dualstack.us-west-2.amazonaws.com:443/key",
+            "HTTPS://MY-BUCKET.S3.US-WEST-2.AMAZONAWS.COM/key",
+        ] {
+            assert_eq!(
+                ArnBuf::from_s3_url(url).unwrap().to_string(),
+                expected,
+                "{}",
+                url
+            );
+        }
+    }
+
+    #[test]
+    fn dotted_bucket_url() {
+        let arn =
+            ArnBuf::from_s3_url("https://logs.s3.example.s3.us-east-1.amazonaws.com/key").unwrap();
+
+        assert_eq!(arn.resource, "logs.s3.example/key");
+    }
+
+    #[test]
+    fn url_not_s3() {
+        let arn = NaiveArn::parse("arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98").unwrap();
+
+        assert_eq!(arn.to_s3_url("us-east-1", S3UrlStyle::Path), None);
+    }
+
+    #[test]
+    fn malformed_urls() {
+        assert_eq!(
+            ArnBuf::from_s3_url("s3://my-bucket/key"),
+            Err(ParseS3UrlError::UnsupportedScheme)
+        );
+        assert_eq!(
+            ArnBuf::from_s3_url("https://example.com/my-bucket/key"),
+            Err(ParseS3UrlError::UnknownHost)
+        );
+        assert_eq!(
+            ArnBuf::from_s3_url("https://sqs.us-east-1.amazonaws.com/123456789012/queue"),
+            Err(ParseS3UrlError::UnknownHost)
+        );
+        assert_eq!(
+            ArnBuf::from_s3_url("https://s3.us-east-1.amazonaws.com/"),
+            Err(ParseS3UrlError::MissingBucket)
+        );
+        assert_eq!(
+            ArnBuf::from_s3_url("https://my-bucket.s3.amazonaws.com/%zz"),
+            Err(ParseS3UrlError::InvalidEncoding)
+        );
+        assert_eq!(
+            ArnBuf::from_s3_url("https://my-bucket.s3.amazonaws.com/key%+1.txt"),
+            Err(ParseS3UrlError::InvalidEncoding)
+        );
+    }
+
+    #[test]
+    fn typed_bucket() {
+        let arn = S3Arn::parse("arn:aws:s3:::my_corporate_bucket").unwrap();
+
+        assert_eq!(arn, S3Arn::bucket("my_corporate_bucket"));
+        assert!(arn.is_bucket());
+        assert_eq!(arn.to_string(), "arn:aws:s3:::my_corporate_bucket");
+    }
+
+    #[test]
+    fn typed_object() {
+        let arn_str = "arn:aws-cn:s3:::my_corporate_bucket/Development/*";
+        let arn = S3Arn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn,
+            S3Arn::object_in("my_corporate_bucket", "Development/*").in_partition("aws-cn")
+        );
+        assert!(arn.is_object());
+        assert_eq!(arn.to_string(), arn_str);
+        assert_eq!(
+            arn.bucket_arn().to_string(),
+            "arn:aws-cn:s3:::my_corporate_bucket"
+        );
+    }
+
+    #[test]
+    fn typed_wildcard_objects() {
+        assert_eq!(
+            S3Arn::bucket("my_corporate_bucket")
+                .with_wildcard_objects()
+                .to_string(),
+            "arn:aws:s3:::my_corporate_bucket/*"
+        );
+    }
+
+    #[test]
+    fn typed_invalid() {
+        assert_eq!(
+            S3Arn::parse("arn:aws:s3:us-east-1::my_corporate_bucket"),
+            Err(ParseServiceArnError::UnexpectedRegion)
+        );
+        assert_eq!(
+            S3Arn::parse("arn:aws:s3::123456789012:my_corporate_bucket"),
+            Err(ParseServiceArnError::UnexpectedAccountId)
+        );
+        assert_eq!(
+            S3Arn::parse("arn:aws:s3:::/key"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            S3Arn::parse("arn:aws:sqs:::my_corporate_bucket"),
+            Err(ParseServiceArnError::WrongService)
+        );
+        assert_eq!(
+            S3Arn::parse("arn:aws:s3:::"),
+            Err(ParseServiceArnError::Naive(
+                ParseNaiveArnError::MissingResource { position: 13 }
+            ))
+        );
+    }
+
+    #[test]
+    fn typed_access_point() {
+        let arn_str = "arn:aws:s3:us-west-2:123456789012:accesspoint/test";
+        let arn = S3AccessPointArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.region, "us-west-2");
+        assert_eq!(arn.account_id, "123456789012");
+        assert_eq!(arn.name, "test");
+        assert_eq!(arn.key, None);
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn typed_access_point_object() {
+        let arn_str = "arn:aws:s3:us-west-2:123456789012:accesspoint/test/object/unit-01/*";
+        let arn = S3AccessPointArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.name, "test");
+        assert_eq!(arn.key, Some("unit-01/*"));
+        assert_eq!(arn.to_string(), arn_str);
+        assert_eq!(
+            arn.access_point_arn().to_string(),
+            "arn:aws:s3:us-west-2:123456789012:accesspoint/test"
+        );
+    }
+
+    #[test]
+    fn typed_access_point_invalid() {
+        assert_eq!(
+            S3AccessPointArn::parse("arn:aws:s3:::my_corporate_bucket"),
+            Err(ParseServiceArnError::MissingRegion)
+        );
+        assert_eq!(
+            S3AccessPointArn::parse("arn:aws:s3:us-west-2:123456789012:bucket/test"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            S3AccessPointArn::parse("arn:aws:s3:us-west-2:123456789012:accesspoint/test/unit-01"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            S3AccessPointArn::parse("arn:aws:s3:us-west-2:123456789012:accesspoint/"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+    }
+
+    #[test]
+    fn typed_object_lambda_access_point() {
+        let arn_str = "arn:aws:s3-object-lambda:us-west-2:123456789012:accesspoint/redactor";
+        let arn = S3ObjectLambdaAccessPointArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.region, "us-west-2");
+        assert_eq!(arn.account_id, "123456789012");
+        assert_eq!(arn.name, "redactor");
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn typed_object_lambda_access_point_invalid() {
+        assert_eq!(
+            S3ObjectLambdaAccessPointArn::parse(
+                "arn:aws:s3-object-lambda:us-west-2:123456789012:accesspoint/redactor/object/key"
+            ),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            S3ObjectLambdaAccessPointArn::parse(
+                "arn:aws:s3:us-west-2:123456789012:accesspoint/redactor"
+            ),
+            Err(ParseServiceArnError::WrongService)
+        );
+        assert_eq!(
+            S3AccessPointArn::parse(
+                "arn:aws:s3-object-lambda:us-west-2:123456789012:accesspoint/redactor"
+            ),
+            Err(ParseServiceArnError::WrongService)
+        );
+    }
+
+    #[test]
+    fn typed_multi_region_access_point() {
+        let arn_str = "arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap/object/unit-01";
+        let arn = S3MultiRegionAccessPointArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.account_id, "123456789012");
+        assert_eq!(arn.alias, "mfzwi23gnjvgw.mrap");
+        assert_eq!(arn.key, Some("unit-01"));
+        assert_eq!(arn.to_string(), arn_str);
+        assert_eq!(
+            arn.access_point_arn().to_string(),
+            "arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap"
+        );
+    }
+
+    #[test]
+    fn detect_multi_region_access_point() {
+        let is_mrap = |s| {
+            NaiveArn::parse(s)
+                .unwrap()
+                .is_s3_multi_region_access_point()
+        };
+
+        assert!(is_mrap(
+            "arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap"
+        ));
+        assert!(!is_mrap(
+            "arn:aws:s3:us-west-2:123456789012:accesspoint/mfzwi23gnjvgw.mrap"
+        ));
+        assert!(!is_mrap("arn:aws:s3::123456789012:accesspoint/test"));
+        assert!(!is_mrap("arn:aws:s3:::my_corporate_bucket"));
+    }
+
+    #[test]
+    fn typed_multi_region_access_point_invalid() {
+        assert_eq!(
+            S3MultiRegionAccessPointArn::parse(
+                "arn:aws:s3:us-west-2:123456789012:accesspoint/mfzwi23gnjvgw.mrap"
+            ),
+            Err(ParseServiceArnError::UnexpectedRegion)
+        );
+        assert_eq!(
+            S3MultiRegionAccessPointArn::parse("arn:aws:s3:::accesspoint/mfzwi23gnjvgw.mrap"),
+            Err(ParseServiceArnError::MissingAccountId)
+        );
+        assert_eq!(
+            S3MultiRegionAccessPointArn::parse("arn:aws:s3::123456789012:accesspoint/.mrap"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+    }
+}
@@ -0,0 +1,187 @@
+//! S3 on Outposts ARNs, which nest the bucket or access point under the outpost in the resource element
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// The bucket or access point an [`S3OutpostsArn`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutpostsResource<'a> {
+    Bucket(&'a str),
+    AccessPoint(&'a str),
+}
+
+impl<'a> OutpostsResource<'a> {
+    /// The bucket or access point name.
+    pub fn name(&self) -> &'a str {
+        match self {
+            OutpostsResource::Bucket(name) | OutpostsResource::AccessPoint(name) => name,
+        }
+    }
+}
+
+/// An S3 on Outposts ARN, `arn:partition:s3-outposts:region:account-id:outpost/outpost-id/{bucket,accesspoint}/name[/object/key]`
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::s3outposts::{OutpostsResource, S3OutpostsArn};
+///
+/// let arn = S3OutpostsArn::parse("arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-01ac5d28a6a232904/bucket/example-outpost-bucket").unwrap();
+/// assert_eq!(arn.outpost_id, "op-01ac5d28a6a232904");
+/// assert_eq!(arn.resource, OutpostsResource::Bucket("example-outpost-bucket"));
+/// assert_eq!(arn.key, None);
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct S3OutpostsArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub outpost_id: &'a str,
+    pub resource: OutpostsResource<'a>,
+    /// The object key, or key pattern, for object ARNs.
+    pub key: Option<&'a str>,
+}
+
+impl<'a> S3OutpostsArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        S3OutpostsArn::try_from(NaiveArn::parse(s)?)
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for S3OutpostsArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "s3-outposts" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+
+        let mut parts = arn.resource.splitn(4, '/');
+        if parts.next() != Some("outpost") {
+            return Err(ParseServiceArnError::UnknownResourceType);
+        }
+
+        let outpost_id = non_empty(parts.next().unwrap_or_default())?;
+        let resource_type = parts.next().ok_or(ParseServiceArnError::InvalidResource)?;
+        let (name, key) = match parts.next() {
+            Some(rest) => match rest.split_once('/') {
+                None => (rest, None),
+                Some((name, rest)) => match rest.strip_prefix("object/") {
+                    Some(key) => (name, Some(non_empty(key)?)),
+                    None => return Err(ParseServiceArnError::InvalidResource),
+                },
+            },
+            None => return Err(ParseServiceArnError::InvalidResource),
+        };
+
+        let resource = match resource_type {
+            "bucket" => OutpostsResource::Bucket(non_empty(name)?),
+            "accesspoint" => OutpostsResource::AccessPoint(non_empty(name)?),
+            _ => return Err(ParseServiceArnError::UnknownResourceType),
+        };
+
+        Ok(S3OutpostsArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            outpost_id,
+            resource,
+            key,
+        })
+    }
+}
+
+impl<'a> fmt::Display for S3OutpostsArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let resource_type = match self.resource {
+            OutpostsResource::Bucket(_) => "bucket",
+            OutpostsResource::AccessPoint(_) => "accesspoint",
+        };
+
+        write!(
+            f,
+            "arn:{}:s3-outposts:{}:{}:outpost/{}/{}/{}",
+            self.partition,
+            self.region,
+            self.account_id,
+            self.outpost_id,
+            resource_type,
+            self.resource.name()
+        )?;
+
+        match self.key {
+            Some(key) => write!(f, "/object/{}", key),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OutpostsResource, S3OutpostsArn};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn bucket() {
+        let arn_str = "arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-01ac5d28a6a232904/bucket/example-outpost-bucket";
+        let arn = S3OutpostsArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.region, "us-west-2");
+        assert_eq!(arn.account_id, "123456789012");
+        assert_eq!(arn.outpost_id, "op-01ac5d28a6a232904");
+        assert_eq!(
+            arn.resource,
+            OutpostsResource::Bucket("example-outpost-bucket")
+        );
+        assert_eq!(arn.key, None);
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn access_point_object() {
+        let arn_str = "arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-01ac5d28a6a232904/accesspoint/example-access-point/object/reports/*";
+        let arn = S3OutpostsArn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn.resource,
+            OutpostsResource::AccessPoint("example-access-point")
+        );
+        assert_eq!(arn.resource.name(), "example-access-point");
+        assert_eq!(arn.key, Some("reports/*"));
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:s3-outposts:us-west-2:123456789012:{}", resource);
+            S3OutpostsArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(
+            parse("bucket/example-outpost-bucket"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            parse("outpost/op-01ac5d28a6a232904"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("outpost/op-01ac5d28a6a232904/endpoint/ec2"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            parse("outpost//bucket/example-outpost-bucket"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("outpost/op-01ac5d28a6a232904/bucket/example-outpost-bucket/reports"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+    }
+}
@@ -0,0 +1,182 @@
+//! Amazon SageMaker (`arn:partition:sagemaker:region:account-id:resource-type/name`) ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{lookup, non_empty, regional, ParseServiceArnError};
+
+/// The kind of resource a [`SageMakerArn`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SageMakerResourceKind {
+    Endpoint,
+    EndpointConfig,
+    HyperParameterTuningJob,
+    Model,
+    NotebookInstance,
+    ProcessingJob,
+    TrainingJob,
+    TransformJob,
+}
+
+impl SageMakerResourceKind {
+    /// `(resource type, kind)`, sorted by resource type.
+    const ALL: &'static [(&'static str, SageMakerResourceKind)] = &[
+        ("endpoint", SageMakerResourceKind::Endpoint),
+        ("endpoint-config", SageMakerResourceKind::EndpointConfig),
+        (
+            "hyper-parameter-tuning-job",
+            SageMakerResourceKind::HyperParameterTuningJob,
+        ),
+        ("model", SageMakerResourceKind::Model),
+        ("notebook-instance", SageMakerResourceKind::NotebookInstance),
+        ("processing-job", SageMakerResourceKind::ProcessingJob),
+        ("training-job", SageMakerResourceKind::TrainingJob),
+        ("transform-job", SageMakerResourceKind::TransformJob),
+    ];
+
+    fn from_resource_type(resource_type: &str) -> Option<Self> {
+        lookup(Self::ALL, resource_type)
+    }
+
+    /// The resource type, as it appears in the ARN.
+    pub fn resource_type(self) -> &'static str {
+        match self {
+            SageMakerResourceKind::Endpoint => "endpoint",
+            SageMakerResourceKind::EndpointConfig => "endpoint-config",
+            SageMakerResourceKind::HyperParameterTuningJob => "hyper-parameter-tuning-job",
+            SageMakerResourceKind::Model => "model",
+            SageMakerResourceKind::NotebookInstance => "notebook-instance",
+            SageMakerResourceKind::ProcessingJob => "processing-job",
+            SageMakerResourceKind::TrainingJob => "training-job",
+            SageMakerResourceKind::TransformJob => "transform-job",
+        }
+    }
+}
+
+/// A SageMaker ARN
+///
+/// SageMaker lowercases resource names in ARNs, so `name` may differ in case from the name the resource was created
+/// with.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::sagemaker::{SageMakerArn, SageMakerResourceKind};
+///
+/// let arn = SageMakerArn::parse("arn:aws:sagemaker:us-east-1:123456789012:training-job/xgboost-2024-01-01").unwrap();
+/// assert_eq!(arn.kind, SageMakerResourceKind::TrainingJob);
+/// assert_eq!(arn.name, "xgboost-2024-01-01");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SageMakerArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub kind: SageMakerResourceKind,
+    pub name: &'a str,
+}
+
+impl<'a> SageMakerArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        SageMakerArn::try_from(NaiveArn::parse(s)?)
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for SageMakerArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "sagemaker" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let (resource_type, name) = arn
+            .resource
+            .split_once('/')
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+        let kind = SageMakerResourceKind::from_resource_type(resource_type)
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+
+        if name.contains('/') {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        Ok(SageMakerArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            kind,
+            name: non_empty(name)?,
+        })
+    }
+}
+
+impl<'a> fmt::Display for SageMakerArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:sagemaker:{}:{}:{}/{}",
+            self.partition,
+            self.region,
+            self.account_id,
+            self.kind.resource_type(),
+            self.name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SageMakerArn, SageMakerResourceKind};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn resource_types_sorted() {
+        assert!(SageMakerResourceKind::ALL
+            .windows(2)
+            .all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn kinds() {
+        for (resource_type, kind) in SageMakerResourceKind::ALL {
+            let arn_str = format!(
+                "arn:aws:sagemaker:us-east-1:123456789012:{}/my-resource",
+                resource_type
+            );
+            let arn = SageMakerArn::parse(&arn_str).unwrap();
+
+            assert_eq!(arn.kind, *kind);
+            assert_eq!(arn.name, "my-resource");
+            assert_eq!(arn.to_string(), arn_str);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:sagemaker:us-east-1:123456789012:{}", resource);
+            SageMakerArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(
+            parse("endpoint/"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("model-package/my-package/1"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            parse("endpoint/my-endpoint/variant"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("endpoint:my-endpoint"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
@@ -0,0 +1,171 @@
+//! AWS Secrets Manager (`arn:partition:secretsmanager:region:account-id:secret:name-suffix`) ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// A Secrets Manager secret ARN
+///
+/// Secrets Manager appends a hyphen and six random characters to the secret name in the ARN, so that a new secret
+/// with the name of a deleted one gets a different ARN. Partial ARNs, without the suffix, are also accepted by the
+/// APIs and in IAM policies.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::secretsmanager::SecretArn;
+///
+/// let arn = SecretArn::parse("arn:aws:secretsmanager:us-east-1:123456789012:secret:prod/db-password-a1B2c3").unwrap();
+/// assert_eq!(arn.secret_id, "prod/db-password-a1B2c3");
+/// assert_eq!(arn.name(), "prod/db-password");
+/// assert_eq!(arn.suffix(), Some("a1B2c3"));
+///
+/// let rotated = SecretArn::parse("arn:aws:secretsmanager:us-east-1:123456789012:secret:prod/db-password-d4E5f6").unwrap();
+/// assert!(arn.is_same_secret(&rotated));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SecretArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    /// The resource name after `secret:`, including any random suffix.
+    pub secret_id: &'a str,
+}
+
+impl<'a> SecretArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        SecretArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The secret's name, without its random suffix.
+    ///
+    /// Secret names can't be told apart from partial ARNs in general: a secret named `my-secret-abcdef` looks like
+    /// `my-secret` with a suffix. This treats any trailing `-` and six alphanumeric characters as the suffix.
+    pub fn name(&self) -> &'a str {
+        self.split().0
+    }
+
+    /// The six random characters Secrets Manager appended to the name, if present.
+    pub fn suffix(&self) -> Option<&'a str> {
+        self.split().1
+    }
+
+    /// Whether `other` refers to a secret with the same name in the same account and region, ignoring suffixes.
+    pub fn is_same_secret(&self, other: &SecretArn) -> bool {
+        self.partition == other.partition
+            && self.region == other.region
+            && self.account_id == other.account_id
+            && self.name() == other.name()
+    }
+
+    fn split(&self) -> (&'a str, Option<&'a str>) {
+        match self.secret_id.rsplit_once('-') {
+            Some((name, suffix))
+                if !name.is_empty()
+                    && suffix.len() == 6
+                    && suffix.bytes().all(|b| b.is_ascii_alphanumeric()) =>
+            {
+                (name, Some(suffix))
+            }
+            _ => (self.secret_id, None),
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for SecretArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "secretsmanager" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let secret_id = arn
+            .resource
+            .strip_prefix("secret:")
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+
+        Ok(SecretArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            secret_id: non_empty(secret_id)?,
+        })
+    }
+}
+
+impl<'a> fmt::Display for SecretArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:secretsmanager:{}:{}:secret:{}",
+            self.partition, self.region, self.account_id, self.secret_id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SecretArn;
+    use crate::service::ParseServiceArnError;
+
+    fn secret(secret_id: &str) -> SecretArn<'_> {
+        SecretArn {
+            partition: "aws",
+            region: "us-east-1",
+            account_id: "123456789012",
+            secret_id,
+        }
+    }
+
+    #[test]
+    fn full_arn() {
+        let arn_str =
+            "arn:aws:secretsmanager:us-east-1:123456789012:secret:MyTestDatabaseSecret-a1b2c3";
+        let arn = SecretArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn, secret("MyTestDatabaseSecret-a1b2c3"));
+        assert_eq!(arn.name(), "MyTestDatabaseSecret");
+        assert_eq!(arn.suffix(), Some("a1b2c3"));
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn partial_arn() {
+        let arn = secret("MyTestDatabaseSecret");
+        assert_eq!(arn.name(), "MyTestDatabaseSecret");
+        assert_eq!(arn.suffix(), None);
+
+        let arn = secret("my-db-password");
+        assert_eq!(arn.name(), "my-db-password");
+        assert_eq!(arn.suffix(), None);
+    }
+
+    #[test]
+    fn same_secret() {
+        let full = secret("prod/db-a1b2c3");
+
+        assert!(full.is_same_secret(&secret("prod/db")));
+        assert!(full.is_same_secret(&secret("prod/db-Z9y8X7")));
+        assert!(!full.is_same_secret(&secret("prod/db2-a1b2c3")));
+        assert!(!full.is_same_secret(&SecretArn {
+            region: "us-west-2",
+            ..full
+        }));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            SecretArn::parse("arn:aws:secretsmanager:us-east-1:123456789012:secret:"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            SecretArn::parse("arn:aws:secretsmanager:us-east-1:123456789012:MyTestDatabaseSecret"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
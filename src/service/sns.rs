@@ -0,0 +1,202 @@
+//! Amazon SNS (`arn:partition:sns:region:account-id:topic-name[:subscription-id]`) ARNs
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// An SNS topic ARN
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SnsTopicArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub name: &'a str,
+}
+
+impl<'a> SnsTopicArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        SnsTopicArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// Whether this is a FIFO topic, named with a `.fifo` suffix.
+    pub fn is_fifo(&self) -> bool {
+        self.name.ends_with(".fifo")
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for SnsTopicArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        match SnsArn::try_from(arn)? {
+            SnsArn::Topic(topic) => Ok(topic),
+            SnsArn::Subscription { .. } => Err(ParseServiceArnError::UnknownResourceType),
+        }
+    }
+}
+
+impl<'a> fmt::Display for SnsTopicArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:sns:{}:{}:{}",
+            self.partition, self.region, self.account_id, self.name
+        )
+    }
+}
+
+/// An SNS topic or subscription ARN
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::sns::SnsArn;
+///
+/// let arn = SnsArn::parse("arn:aws:sns:us-east-1:123456789012:my_topic:8a21d249-4329-4871-acc6-7be709c6ea7f").unwrap();
+/// assert!(matches!(arn, SnsArn::Subscription { id: "8a21d249-4329-4871-acc6-7be709c6ea7f", .. }));
+/// assert_eq!(arn.topic_of().to_string(), "arn:aws:sns:us-east-1:123456789012:my_topic");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnsArn<'a> {
+    Topic(SnsTopicArn<'a>),
+    Subscription { topic: SnsTopicArn<'a>, id: &'a str },
+}
+
+impl<'a> SnsArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        SnsArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The topic, or the topic a subscription is to.
+    pub fn topic_of(&self) -> SnsTopicArn<'a> {
+        match *self {
+            SnsArn::Topic(topic) | SnsArn::Subscription { topic, .. } => topic,
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for SnsArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "sns" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+
+        // Platform applications and endpoints (`app/...`, `endpoint/...`) are the only SNS resources with a `/`
+        if arn.resource.contains('/') {
+            return Err(ParseServiceArnError::UnknownResourceType);
+        }
+
+        let (name, id) = match arn.resource.split_once(':') {
+            Some((name, id)) => (name, Some(id)),
+            None => (arn.resource, None),
+        };
+        let topic = SnsTopicArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            name: non_empty(name)?,
+        };
+
+        match id {
+            Some(id) if !id.contains(':') => Ok(SnsArn::Subscription {
+                topic,
+                id: non_empty(id)?,
+            }),
+            Some(_) => Err(ParseServiceArnError::InvalidResource),
+            None => Ok(SnsArn::Topic(topic)),
+        }
+    }
+}
+
+impl<'a> fmt::Display for SnsArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SnsArn::Topic(topic) => topic.fmt(f),
+            SnsArn::Subscription { topic, id } => write!(f, "{}:{}", topic, id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SnsArn, SnsTopicArn};
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn topic() {
+        let arn_str = "arn:aws:sns:us-east-1:123456789012:my_corporate_topic";
+        let arn = SnsArn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn,
+            SnsArn::Topic(SnsTopicArn {
+                partition: "aws",
+                region: "us-east-1",
+                account_id: "123456789012",
+                name: "my_corporate_topic",
+            })
+        );
+        assert_eq!(arn.topic_of(), SnsTopicArn::parse(arn_str).unwrap());
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn fifo_topic() {
+        let arn = SnsTopicArn::parse("arn:aws:sns:us-east-1:123456789012:orders.fifo").unwrap();
+        assert!(arn.is_fifo());
+
+        let arn = SnsTopicArn::parse("arn:aws:sns:us-east-1:123456789012:orders").unwrap();
+        assert!(!arn.is_fifo());
+    }
+
+    #[test]
+    fn subscription() {
+        let arn_str = "arn:aws:sns:us-east-1:123456789012:my_corporate_topic:02034b43-fefa-4e07-a5eb-3be56f8c54ce";
+        let arn = SnsArn::parse(arn_str).unwrap();
+
+        assert!(matches!(
+            arn,
+            SnsArn::Subscription {
+                id: "02034b43-fefa-4e07-a5eb-3be56f8c54ce",
+                ..
+            }
+        ));
+        assert_eq!(
+            arn.topic_of().to_string(),
+            "arn:aws:sns:us-east-1:123456789012:my_corporate_topic"
+        );
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            SnsTopicArn::parse(
+                "arn:aws:sns:us-east-1:123456789012:my_corporate_topic:02034b43-fefa-4e07-a5eb-3be56f8c54ce"
+            ),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            SnsArn::parse("arn:aws:sns:us-east-1:123456789012:app/GCM/MyApplication"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            SnsArn::parse("arn:aws:sns:us-east-1:123456789012:my_corporate_topic:"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            SnsArn::parse("arn:aws:sns:us-east-1:123456789012:my_corporate_topic:a:b"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            SnsArn::parse("arn:aws:sns:us-east-1::my_corporate_topic"),
+            Err(ParseServiceArnError::MissingAccountId)
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! Amazon SQS (`arn:partition:sqs:region:account-id:queue-name`) ARNs
+
+use alloc::{format, string::String};
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::account::AccountId;
+use crate::naive::NaiveArn;
+use crate::partition::{split_host, Partition};
+use crate::service::{regional, ParseServiceArnError};
+
+/// An SQS queue ARN
+///
+/// Queue names are validated: up to 80 alphanumeric characters, hyphens and underscores, where FIFO queues also
+/// end in `.fifo`.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::sqs::SqsArn;
+///
+/// let arn = SqsArn::parse("arn:aws:sqs:us-east-1:123456789012:orders.fifo").unwrap();
+/// assert_eq!(arn.name, "orders.fifo");
+/// assert!(arn.is_fifo());
+/// assert_eq!(arn.queue_url().unwrap(), "https://sqs.us-east-1.amazonaws.com/123456789012/orders.fifo");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SqsArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub name: &'a str,
+}
+
+impl<'a> SqsArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        SqsArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// Whether this is a FIFO queue, named with a `.fifo` suffix.
+    pub fn is_fifo(&self) -> bool {
+        self.name.ends_with(".fifo")
+    }
+
+    /// The queue URL, e.g. `https://sqs.us-east-1.amazonaws.com/123456789012/my-queue`.
+    ///
+    /// Returns `None` if the partition isn't known, since its DNS suffix can't be determined.
+    pub fn queue_url(&self) -> Option<String> {
+        let partition: Partition = self.partition.parse().ok()?;
+
+        Some(format!(
+            "https://sqs.{}.{}/{}/{}",
+            self.region,
+            partition.dns_suffix(),
+            self.account_id,
+            self.name
+        ))
+    }
+
+    /// The queue with the URL `url`, e.g. `https://sqs.us-east-1.amazonaws.com/123456789012/my-queue`.
+    ///
+    /// Returns `None` if `url` isn't the queue URL of a known partition.
+    pub fn from_queue_url(url: &'a str) -> Option<Self> {
+        let (host, path) = url.strip_prefix("https://")?.split_once('/')?;
+        let (prefix, dns_suffix) = split_host(host)?;
+        let region = prefix.strip_prefix("sqs.")?;
+        let (account_id, name) = path.trim_end_matches('/').split_once('/')?;
+        let partition = Partition::of_region(region)
+            .filter(|partition| partition.dns_suffix() == dns_suffix)?;
+
+        if AccountId::parse(account_id).is_err() || !is_valid_queue_name(name) {
+            return None;
+        }
+
+        Some(SqsArn {
+            partition: partition.id(),
+            region,
+            account_id,
+            name,
+        })
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for SqsArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "sqs" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+
+        if !is_valid_queue_name(arn.resource) {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        Ok(SqsArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            name: arn.resource,
+        })
+    }
+}
+
+impl<'a> fmt::Display for SqsArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:sqs:{}:{}:{}",
+            self.partition, self.region, self.account_id, self.name
+        )
+    }
+}
+
+fn is_valid_queue_name(name: &str) -> bool {
+    let base = name.strip_suffix(".fifo").unwrap_or(name);
+
+    !base.is_empty()
+        && name.len() <= 80
+        && base
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SqsArn;
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn standard_queue() {
+        let arn_str = "arn:aws:sqs:us-east-1:123456789012:my-queue_1";
+        let arn = SqsArn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn,
+            SqsArn {
+                partition: "aws",
+                region: "us-east-1",
+                account_id: "123456789012",
+                name: "my-queue_1",
+            }
+        );
+        assert!(!arn.is_fifo());
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn queue_url() {
+        let arn = SqsArn::parse("arn:aws-cn:sqs:cn-north-1:123456789012:my-queue").unwrap();
+        assert_eq!(
+            arn.queue_url().unwrap(),
+            "https://sqs.cn-north-1.amazonaws.com.cn/123456789012/my-queue"
+        );
+
+        let arn = SqsArn::parse("arn:aws-moon:sqs:moon-1:123456789012:my-queue").unwrap();
+        assert_eq!(arn.queue_url(), None);
+    }
+
+    #[test]
+    fn from_queue_url() {
+        let url = "https://sqs.us-gov-west-1.amazonaws.com/123456789012/orders.fifo";
+        let arn = SqsArn::from_queue_url(url).unwrap();
+
+        assert_eq!(
+            arn.to_string(),
+            "arn:aws-us-gov:sqs:us-gov-west-1:123456789012:orders.fifo"
+        );
+        assert_eq!(arn.queue_url().unwrap(), url);
+
+        for url in [
+            "http://sqs.us-east-1.amazonaws.com/123456789012/orders",
+            "https://sqs.us-east-1.example.com/123456789012/orders",
+            "https://sns.us-east-1.amazonaws.com/123456789012/orders",
+            "https://sqs.us-east-1.amazonaws.com/123456789012",
+            "https://sqs.cn-north-1.amazonaws.com/123456789012/orders",
+            "https://sqs.us-east-1.amazonaws.com/123456789012/my.queue",
+        ] {
+            assert_eq!(SqsArn::from_queue_url(url), None, "{}", url);
+        }
+    }
+
+    #[test]
+    fn name_validation() {
+        let parse = |name: &str| {
+            let arn = format!("arn:aws:sqs:us-east-1:123456789012:{}", name);
+            SqsArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(parse(&"q".repeat(80)), Ok(()));
+        assert_eq!(parse(&format!("{}.fifo", "q".repeat(75))), Ok(()));
+        assert_eq!(
+            parse(&"q".repeat(81)),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse(&format!("{}.fifo", "q".repeat(76))),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(parse(".fifo"), Err(ParseServiceArnError::InvalidResource));
+        assert_eq!(
+            parse("my.queue"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("my-queue:extra"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+    }
+}
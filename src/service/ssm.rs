@@ -0,0 +1,178 @@
+//! AWS Systems Manager (`arn:partition:ssm:region:account-id:parameter/...`) ARNs
+
+use alloc::{borrow::ToOwned, format, string::String};
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// An SSM Parameter Store parameter ARN
+///
+/// Hierarchical parameter names like `/prod/db/password` appear in the ARN without their leading `/`, as
+/// `parameter/prod/db/password`.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::ssm::SsmParameterArn;
+///
+/// let arn = SsmParameterArn::parse("arn:aws:ssm:us-east-1:123456789012:parameter/prod/db/password").unwrap();
+/// assert_eq!(arn.path_segments().collect::<Vec<_>>(), vec!["prod", "db"]);
+/// assert_eq!(arn.base_name(), "password");
+/// assert_eq!(arn.parameter_name(), "/prod/db/password");
+/// assert!(arn.is_under("/prod/"));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SsmParameterArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    /// The resource name after `parameter/`, e.g. `prod/db/password`.
+    pub name: &'a str,
+}
+
+impl<'a> SsmParameterArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        SsmParameterArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// Whether the parameter is in a hierarchy, i.e. its name has more than one segment.
+    pub fn is_hierarchical(&self) -> bool {
+        self.name.contains('/')
+    }
+
+    /// The hierarchy segments containing the parameter, excluding its base name.
+    pub fn path_segments(&self) -> impl Iterator<Item = &'a str> + 'a {
+        let path = self.name.rsplit_once('/').map(|(path, _)| path);
+
+        path.into_iter().flat_map(|path| path.split('/'))
+    }
+
+    /// The last segment of the parameter name.
+    pub fn base_name(&self) -> &'a str {
+        self.name.rsplit('/').next().unwrap_or(self.name)
+    }
+
+    /// The parameter name as used by the SSM APIs, with a leading `/` for hierarchical parameters.
+    pub fn parameter_name(&self) -> String {
+        if self.is_hierarchical() {
+            format!("/{}", self.name)
+        } else {
+            self.name.to_owned()
+        }
+    }
+
+    /// Whether the parameter is somewhere below the hierarchy `path`, e.g. `/prod` or `/prod/db/`.
+    pub fn is_under(&self, path: &str) -> bool {
+        let path = path.trim_matches('/');
+
+        if path.is_empty() {
+            return true;
+        }
+
+        match self.name.strip_prefix(path) {
+            Some(rest) => rest.starts_with('/'),
+            None => false,
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for SsmParameterArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "ssm" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let name = arn
+            .resource
+            .strip_prefix("parameter/")
+            .ok_or(ParseServiceArnError::UnknownResourceType)?;
+
+        if name.split('/').any(str::is_empty) {
+            return Err(ParseServiceArnError::InvalidResource);
+        }
+
+        Ok(SsmParameterArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            name: non_empty(name)?,
+        })
+    }
+}
+
+impl<'a> fmt::Display for SsmParameterArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:ssm:{}:{}:parameter/{}",
+            self.partition, self.region, self.account_id, self.name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SsmParameterArn;
+    use crate::service::ParseServiceArnError;
+
+    #[test]
+    fn hierarchical() {
+        let arn_str = "arn:aws:ssm:us-east-1:123456789012:parameter/prod/db/password";
+        let arn = SsmParameterArn::parse(arn_str).unwrap();
+
+        assert_eq!(arn.name, "prod/db/password");
+        assert!(arn.is_hierarchical());
+        assert_eq!(arn.path_segments().collect::<Vec<_>>(), vec!["prod", "db"]);
+        assert_eq!(arn.base_name(), "password");
+        assert_eq!(arn.parameter_name(), "/prod/db/password");
+        assert_eq!(arn.to_string(), arn_str);
+    }
+
+    #[test]
+    fn flat() {
+        let arn =
+            SsmParameterArn::parse("arn:aws:ssm:us-east-1:123456789012:parameter/MyParameter")
+                .unwrap();
+
+        assert!(!arn.is_hierarchical());
+        assert_eq!(arn.path_segments().count(), 0);
+        assert_eq!(arn.base_name(), "MyParameter");
+        assert_eq!(arn.parameter_name(), "MyParameter");
+    }
+
+    #[test]
+    fn is_under() {
+        let arn =
+            SsmParameterArn::parse("arn:aws:ssm:us-east-1:123456789012:parameter/prod/db/password")
+                .unwrap();
+
+        assert!(arn.is_under("/"));
+        assert!(arn.is_under("/prod"));
+        assert!(arn.is_under("/prod/"));
+        assert!(arn.is_under("prod/db"));
+        assert!(!arn.is_under("/prod/db/password"));
+        assert!(!arn.is_under("/pro"));
+        assert!(!arn.is_under("/dev"));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            SsmParameterArn::parse("arn:aws:ssm:us-east-1:123456789012:parameter/"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            SsmParameterArn::parse("arn:aws:ssm:us-east-1:123456789012:parameter/prod//password"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            SsmParameterArn::parse("arn:aws:ssm:us-east-1:123456789012:document/MyDocument"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
@@ -0,0 +1,222 @@
+//! AWS Step Functions (`arn:partition:states:region:account-id:stateMachine:name`) ARNs
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::naive::NaiveArn;
+use crate::service::{non_empty, regional, ParseServiceArnError};
+
+/// A Step Functions state machine ARN
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StateMachineArn<'a> {
+    pub partition: &'a str,
+    pub region: &'a str,
+    pub account_id: &'a str,
+    pub name: &'a str,
+}
+
+impl<'a> StateMachineArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        StateMachineArn::try_from(NaiveArn::parse(s)?)
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for StateMachineArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        match StatesArn::try_from(arn)? {
+            StatesArn::StateMachine(state_machine) => Ok(state_machine),
+            _ => Err(ParseServiceArnError::UnknownResourceType),
+        }
+    }
+}
+
+impl<'a> fmt::Display for StateMachineArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:states:{}:{}:stateMachine:{}",
+            self.partition, self.region, self.account_id, self.name
+        )
+    }
+}
+
+/// A Step Functions state machine or execution ARN
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::states::StatesArn;
+///
+/// let arn = StatesArn::parse("arn:aws:states:us-east-1:123456789012:execution:HelloWorld:run-42").unwrap();
+/// assert!(matches!(arn, StatesArn::Execution { name: "run-42", .. }));
+/// assert_eq!(arn.state_machine_of().to_string(), "arn:aws:states:us-east-1:123456789012:stateMachine:HelloWorld");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatesArn<'a> {
+    StateMachine(StateMachineArn<'a>),
+    /// `execution:state-machine-name:execution-name`, for standard workflows
+    Execution {
+        state_machine: StateMachineArn<'a>,
+        name: &'a str,
+    },
+    /// `express:state-machine-name:execution-name:execution-id`, for express workflows
+    ExpressExecution {
+        state_machine: StateMachineArn<'a>,
+        name: &'a str,
+        id: &'a str,
+    },
+}
+
+impl<'a> StatesArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        StatesArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    /// The state machine, or the state machine an execution is of.
+    pub fn state_machine_of(&self) -> StateMachineArn<'a> {
+        match *self {
+            StatesArn::StateMachine(state_machine)
+            | StatesArn::Execution { state_machine, .. }
+            | StatesArn::ExpressExecution { state_machine, .. } => state_machine,
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for StatesArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "states" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let (region, account_id) = regional(arn)?;
+        let mut parts = arn.resource.split(':');
+        let resource_type = parts.next().unwrap_or_default();
+        let state_machine = StateMachineArn {
+            partition: arn.partition,
+            region,
+            account_id,
+            name: non_empty(parts.next().unwrap_or_default())?,
+        };
+        let rest = parts.map(non_empty).collect::<Result<Vec<_>, _>>()?;
+
+        match (resource_type, rest.as_slice()) {
+            ("stateMachine", []) => Ok(StatesArn::StateMachine(state_machine)),
+            ("execution", [name]) => Ok(StatesArn::Execution {
+                state_machine,
+                name,
+            }),
+            ("express", [name, id]) => Ok(StatesArn::ExpressExecution {
+                state_machine,
+                name,
+                id,
+            }),
+            ("stateMachine", _) | ("execution", _) | ("express", _) => {
+                Err(ParseServiceArnError::InvalidResource)
+            }
+            _ => Err(ParseServiceArnError::UnknownResourceType),
+        }
+    }
+}
+
+impl<'a> fmt::Display for StatesArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (state_machine, resource_type) = match self {
+            StatesArn::StateMachine(state_machine) => return state_machine.fmt(f),
+            StatesArn::Execution { state_machine, .. } => (state_machine, "execution"),
+            StatesArn::ExpressExecution { state_machine, .. } => (state_machine, "express"),
+        };
+
+        write!(
+            f,
+            "arn:{}:states:{}:{}:{}:{}",
+            state_machine.partition,
+            state_machine.region,
+            state_machine.account_id,
+            resource_type,
+            state_machine.name
+        )?;
+
+        match self {
+            StatesArn::Execution { name, .. } => write!(f, ":{}", name),
+            StatesArn::ExpressExecution { name, id, .. } => write!(f, ":{}:{}", name, id),
+            StatesArn::StateMachine(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StateMachineArn, StatesArn};
+    use crate::service::ParseServiceArnError;
+
+    const STATE_MACHINE: &str = "arn:aws:states:us-east-1:123456789012:stateMachine:HelloWorld";
+
+    #[test]
+    fn state_machine() {
+        let arn = StatesArn::parse(STATE_MACHINE).unwrap();
+
+        assert_eq!(
+            arn,
+            StatesArn::StateMachine(StateMachineArn {
+                partition: "aws",
+                region: "us-east-1",
+                account_id: "123456789012",
+                name: "HelloWorld",
+            })
+        );
+        assert_eq!(arn.to_string(), STATE_MACHINE);
+    }
+
+    #[test]
+    fn executions() {
+        for arn_str in &[
+            "arn:aws:states:us-east-1:123456789012:execution:HelloWorld:d7a0a0b2-5b8e-4f0e-9d3b-1f5e2c3a4b5c",
+            "arn:aws:states:us-east-1:123456789012:express:HelloWorld:run-42:8f2e1c3b-0a4d-4e5f-9b6a-7c8d9e0f1a2b",
+        ] {
+            let arn = StatesArn::parse(arn_str).unwrap();
+
+            assert_eq!(arn.state_machine_of().to_string(), STATE_MACHINE);
+            assert_eq!(arn.to_string(), *arn_str);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let parse = |resource: &str| {
+            let arn = format!("arn:aws:states:us-east-1:123456789012:{}", resource);
+            StatesArn::parse(&arn).map(|_| ())
+        };
+
+        assert_eq!(
+            parse("stateMachine:"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("execution:HelloWorld"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("execution:HelloWorld:"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("express:HelloWorld:run-42"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            parse("activity:MyActivity"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            StateMachineArn::parse(
+                "arn:aws:states:us-east-1:123456789012:execution:HelloWorld:run-42"
+            ),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+    }
+}
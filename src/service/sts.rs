@@ -0,0 +1,298 @@
+//! AWS STS (`arn:partition:sts::account-id:assumed-role/...`) helpers
+
+use alloc::{borrow::ToOwned, format};
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::buf::ArnBuf;
+use crate::naive::NaiveArn;
+use crate::service::iam::{IamArn, IamEntity};
+use crate::service::{global, non_empty, ParseServiceArnError};
+
+/// An STS caller identity ARN, as found in CloudTrail `userIdentity` records
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::service::sts::StsArn;
+///
+/// let arn = StsArn::parse("arn:aws:sts::123456789012:assumed-role/Admin/alice").unwrap();
+/// assert_eq!(arn, StsArn::AssumedRole {
+///     partition: "aws",
+///     account_id: "123456789012",
+///     role_name: "Admin",
+///     session_name: "alice",
+/// });
+/// assert_eq!(arn.role_arn().unwrap().to_string(), "arn:aws:iam::123456789012:role/Admin");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StsArn<'a> {
+    /// `assumed-role/RoleName/SessionName`
+    AssumedRole {
+        partition: &'a str,
+        account_id: &'a str,
+        role_name: &'a str,
+        session_name: &'a str,
+    },
+    /// `federated-user/Name`
+    FederatedUser {
+        partition: &'a str,
+        account_id: &'a str,
+        name: &'a str,
+    },
+}
+
+impl<'a> StsArn<'a> {
+    pub fn parse(s: &'a str) -> Result<Self, ParseServiceArnError> {
+        StsArn::try_from(NaiveArn::parse(s)?)
+    }
+
+    pub fn partition(&self) -> &'a str {
+        match self {
+            StsArn::AssumedRole { partition, .. } | StsArn::FederatedUser { partition, .. } => {
+                partition
+            }
+        }
+    }
+
+    pub fn account_id(&self) -> &'a str {
+        match self {
+            StsArn::AssumedRole { account_id, .. } | StsArn::FederatedUser { account_id, .. } => {
+                account_id
+            }
+        }
+    }
+
+    /// The ARN of the role behind an assumed-role session, or `None` for federated users.
+    ///
+    /// Assumed-role ARNs don't record the role's path, so this is only exact for roles with the default `/` path.
+    pub fn role_arn(&self) -> Option<IamArn<'a>> {
+        match *self {
+            StsArn::AssumedRole {
+                partition,
+                account_id,
+                role_name,
+                ..
+            } => Some(IamArn::Role(IamEntity {
+                partition,
+                account_id,
+                path: "/",
+                name: role_name,
+            })),
+            StsArn::FederatedUser { .. } => None,
+        }
+    }
+}
+
+impl<'a> TryFrom<NaiveArn<'a>> for StsArn<'a> {
+    type Error = ParseServiceArnError;
+
+    fn try_from(arn: NaiveArn<'a>) -> Result<Self, Self::Error> {
+        if arn.service != "sts" {
+            return Err(ParseServiceArnError::WrongService);
+        }
+
+        let account_id = global(arn)?;
+
+        match arn.resource.split_once('/') {
+            Some(("assumed-role", rest)) => match rest.split_once('/') {
+                Some((role_name, session_name)) if !session_name.contains('/') => {
+                    Ok(StsArn::AssumedRole {
+                        partition: arn.partition,
+                        account_id,
+                        role_name: non_empty(role_name)?,
+                        session_name: non_empty(session_name)?,
+                    })
+                }
+                _ => Err(ParseServiceArnError::InvalidResource),
+            },
+            Some(("federated-user", name)) if !name.contains('/') => Ok(StsArn::FederatedUser {
+                partition: arn.partition,
+                account_id,
+                name: non_empty(name)?,
+            }),
+            Some(("federated-user", _)) => Err(ParseServiceArnError::InvalidResource),
+            _ => Err(ParseServiceArnError::UnknownResourceType),
+        }
+    }
+}
+
+impl<'a> fmt::Display for StsArn<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "arn:{}:sts::{}:", self.partition(), self.account_id())?;
+
+        match self {
+            StsArn::AssumedRole {
+                role_name,
+                session_name,
+                ..
+            } => write!(f, "assumed-role/{}/{}", role_name, session_name),
+            StsArn::FederatedUser { name, .. } => write!(f, "federated-user/{}", name),
+        }
+    }
+}
+
+impl<'a> NaiveArn<'a> {
+    /// The IAM principal behind a caller identity, as returned by `sts:GetCallerIdentity` or found in CloudTrail.
+    ///
+    /// - `sts::account-id:assumed-role/RoleName/SessionName` maps to `iam::account-id:role/RoleName`. Assumed-role
+    ///   ARNs don't record the role's path, so the role ARN is only exact for roles with the default `/` path.
+    /// - `sts::account-id:federated-user/Name` maps to the account root, `iam::account-id:root`: the IAM user that
+    ///   requested the federation token isn't recorded in the ARN.
+    /// - IAM user and role ARNs are returned unchanged.
+    ///
+    /// Returns `None` for anything else.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let arn = NaiveArn::parse("arn:aws:sts::123456789012:assumed-role/Admin/alice").unwrap();
+    /// assert_eq!(arn.iam_principal().unwrap().to_string(), "arn:aws:iam::123456789012:role/Admin");
+    /// ~~~~
+    pub fn iam_principal(&self) -> Option<ArnBuf> {
+        let account_id = self.account_id?;
+
+        let resource = match (self.service, self.resource.split_once('/')) {
+            ("sts", _) => match StsArn::try_from(*self).ok()? {
+                StsArn::AssumedRole { role_name, .. } => format!("role/{}", role_name),
+                StsArn::FederatedUser { .. } => "root".to_owned(),
+            },
+            ("iam", Some(("role", _))) | ("iam", Some(("user", _))) => {
+                return Some(ArnBuf::from(*self))
+            }
+            _ => return None,
+        };
+
+        Some(ArnBuf {
+            partition: self.partition.to_owned(),
+            service: "iam".to_owned(),
+            region: None,
+            account_id: Some(account_id.to_owned()),
+            resource,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StsArn;
+    use crate::naive::NaiveArn;
+    use crate::service::ParseServiceArnError;
+
+    fn iam_principal(arn_str: &str) -> Option<String> {
+        NaiveArn::parse(arn_str)
+            .unwrap()
+            .iam_principal()
+            .map(|arn| arn.to_string())
+    }
+
+    #[test]
+    fn assumed_role() {
+        assert_eq!(
+            iam_principal("arn:aws:sts::123456789012:assumed-role/Admin/alice@example.com")
+                .unwrap(),
+            "arn:aws:iam::123456789012:role/Admin"
+        );
+        assert_eq!(
+            iam_principal(
+                "arn:aws-cn:sts::123456789012:assumed-role/AWSReservedSSO_Admin_0123/alice"
+            )
+            .unwrap(),
+            "arn:aws-cn:iam::123456789012:role/AWSReservedSSO_Admin_0123"
+        );
+    }
+
+    #[test]
+    fn federated_user() {
+        assert_eq!(
+            iam_principal("arn:aws:sts::123456789012:federated-user/bob").unwrap(),
+            "arn:aws:iam::123456789012:root"
+        );
+    }
+
+    #[test]
+    fn iam_principals_unchanged() {
+        for arn_str in &[
+            "arn:aws:iam::123456789012:role/service-role/my-role",
+            "arn:aws:iam::123456789012:user/alice",
+        ] {
+            assert_eq!(iam_principal(arn_str).unwrap(), *arn_str);
+        }
+    }
+
+    #[test]
+    fn not_principals() {
+        for arn_str in &[
+            "arn:aws:sts::123456789012:assumed-role/Admin",
+            "arn:aws:sts::123456789012:assumed-role//alice",
+            "arn:aws:iam::123456789012:policy/my-policy",
+            "arn:aws:s3:::my_corporate_bucket",
+        ] {
+            assert_eq!(iam_principal(arn_str), None, "{}", arn_str);
+        }
+    }
+
+    #[test]
+    fn typed_assumed_role() {
+        let arn_str = "arn:aws:sts::123456789012:assumed-role/Admin/alice@example.com";
+        let arn = StsArn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn,
+            StsArn::AssumedRole {
+                partition: "aws",
+                account_id: "123456789012",
+                role_name: "Admin",
+                session_name: "alice@example.com",
+            }
+        );
+        assert_eq!(arn.to_string(), arn_str);
+        assert_eq!(
+            arn.role_arn().unwrap().to_string(),
+            "arn:aws:iam::123456789012:role/Admin"
+        );
+    }
+
+    #[test]
+    fn typed_federated_user() {
+        let arn_str = "arn:aws-us-gov:sts::123456789012:federated-user/bob";
+        let arn = StsArn::parse(arn_str).unwrap();
+
+        assert_eq!(
+            arn,
+            StsArn::FederatedUser {
+                partition: "aws-us-gov",
+                account_id: "123456789012",
+                name: "bob",
+            }
+        );
+        assert_eq!(arn.to_string(), arn_str);
+        assert_eq!(arn.role_arn(), None);
+    }
+
+    #[test]
+    fn typed_invalid() {
+        assert_eq!(
+            StsArn::parse("arn:aws:sts::123456789012:assumed-role/Admin"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            StsArn::parse("arn:aws:sts::123456789012:assumed-role/Admin/alice/extra"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            StsArn::parse("arn:aws:sts::123456789012:federated-user/"),
+            Err(ParseServiceArnError::InvalidResource)
+        );
+        assert_eq!(
+            StsArn::parse("arn:aws:sts::123456789012:self"),
+            Err(ParseServiceArnError::UnknownResourceType)
+        );
+        assert_eq!(
+            StsArn::parse("arn:aws:iam::123456789012:role/Admin"),
+            Err(ParseServiceArnError::WrongService)
+        );
+    }
+}
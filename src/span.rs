@@ -0,0 +1,94 @@
+//! Locating the components of an ARN within the text it was parsed from
+
+use core::ops::Range;
+
+use crate::naive::NaiveArn;
+
+/// The byte range of each component of an ARN, relative to the start of the ARN.
+///
+/// The region and account ID are empty ranges, positioned between their surrounding colons, when the ARN doesn't
+/// have them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArnSpans {
+    pub partition: Range<usize>,
+    pub service: Range<usize>,
+    pub region: Range<usize>,
+    pub account_id: Range<usize>,
+    pub resource: Range<usize>,
+}
+
+impl ArnSpans {
+    /// Every span shifted by `offset`, for an ARN that starts `offset` bytes into a larger text, like one found by
+    /// [`ArnFinder`](crate::find::ArnFinder).
+    pub fn offset(&self, offset: usize) -> ArnSpans {
+        let shift = |range: &Range<usize>| range.start + offset..range.end + offset;
+
+        ArnSpans {
+            partition: shift(&self.partition),
+            service: shift(&self.service),
+            region: shift(&self.region),
+            account_id: shift(&self.account_id),
+            resource: shift(&self.resource),
+        }
+    }
+}
+
+impl<'a> NaiveArn<'a> {
+    /// The byte range of each component within the string this ARN was parsed from.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let arn_str = "arn:aws:sqs:us-east-1:123456789012:orders";
+    /// let spans = NaiveArn::parse(arn_str).unwrap().spans();
+    ///
+    /// assert_eq!(&arn_str[spans.region.clone()], "us-east-1");
+    /// assert_eq!(spans.account_id, 22..34);
+    /// ~~~~
+    pub fn spans(&self) -> ArnSpans {
+        let mut pos = "arn:".len();
+        let mut next = |len: usize| {
+            let range = pos..pos + len;
+            pos = range.end + 1;
+            range
+        };
+
+        ArnSpans {
+            partition: next(self.partition.len()),
+            service: next(self.service.len()),
+            region: next(self.region.map_or(0, str::len)),
+            account_id: next(self.account_id.map_or(0, str::len)),
+            resource: next(self.resource.len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::find::ArnFinder;
+    use crate::naive::NaiveArn;
+
+    #[test]
+    fn components() {
+        let arn_str = "arn:aws:s3:::my_corporate_bucket/a:b";
+        let spans = NaiveArn::parse(arn_str).unwrap().spans();
+
+        assert_eq!(&arn_str[spans.partition], "aws");
+        assert_eq!(&arn_str[spans.service], "s3");
+        assert_eq!(spans.region, 11..11);
+        assert_eq!(spans.account_id, 12..12);
+        assert_eq!(&arn_str[spans.resource], "my_corporate_bucket/a:b");
+    }
+
+    #[test]
+    fn offset() {
+        let text = "denied: arn:aws:sqs:us-east:123456789012:orders";
+        let (range, arn) = ArnFinder::default().find_iter(text).next().unwrap();
+        let spans = arn.unwrap().spans().offset(range.start);
+
+        assert_eq!(&text[spans.region], "us-east");
+        assert_eq!(&text[spans.resource], "orders");
+    }
+}
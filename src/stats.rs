@@ -0,0 +1,210 @@
+//! Statistics over a collection of ARNs, for inventory reports
+
+use alloc::collections::BTreeMap;
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+use core::iter::FromIterator;
+
+use crate::naive::{split_resource, NaiveArn};
+
+/// How many ARNs there are of each partition, service, region, account and resource type.
+///
+/// Regions and accounts are only counted for ARNs that have them, and resource types only for resources with a type
+/// before a `/` or `:`; resource types are keyed by service, as in `ec2:vpc`. With the `serde` feature, a summary
+/// serializes as a map of these counts.
+///
+/// # Example
+///
+/// ~~~~
+/// use std::iter::FromIterator;
+///
+/// use arn::naive::NaiveArn;
+/// use arn::stats::Summary;
+///
+/// let arns = [
+///     "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98",
+///     "arn:aws:ec2:us-east-1:123456789012:instance/i-0123456789abcdef0",
+///     "arn:aws:ec2:eu-west-1:210987654321:vpc/vpc-0e9801d129EXAMPLE",
+///     "arn:aws:s3:::my_corporate_bucket",
+/// ];
+/// let summary = Summary::from_iter(arns.iter().map(|arn| NaiveArn::parse(arn).unwrap()));
+///
+/// assert_eq!(summary.total, 4);
+/// assert_eq!(summary.services.top(1), [("ec2", 3)]);
+/// assert_eq!(summary.resource_types.get("ec2:vpc"), 2);
+/// assert_eq!(summary.regions.get("us-east-1"), 2);
+/// ~~~~
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub total: usize,
+    pub partitions: Counts,
+    pub services: Counts,
+    pub regions: Counts,
+    pub accounts: Counts,
+    pub resource_types: Counts,
+}
+
+impl Summary {
+    pub fn new() -> Self {
+        Summary::default()
+    }
+
+    /// Count `arn`.
+    pub fn add(&mut self, arn: &NaiveArn) {
+        self.total += 1;
+        self.partitions.add(arn.partition);
+        self.services.add(arn.service);
+
+        if let Some(region) = arn.region {
+            self.regions.add(region);
+        }
+        if let Some(account_id) = arn.account_id {
+            self.accounts.add(account_id);
+        }
+        if let (resource_type, Some(_)) = split_resource(arn.resource) {
+            self.resource_types
+                .add(&format!("{}:{}", arn.service, resource_type));
+        }
+    }
+}
+
+impl<'a> FromIterator<NaiveArn<'a>> for Summary {
+    fn from_iter<I: IntoIterator<Item = NaiveArn<'a>>>(arns: I) -> Self {
+        let mut summary = Summary::new();
+        summary.extend(arns);
+        summary
+    }
+}
+
+impl<'a> Extend<NaiveArn<'a>> for Summary {
+    fn extend<I: IntoIterator<Item = NaiveArn<'a>>>(&mut self, arns: I) {
+        for arn in arns {
+            self.add(&arn);
+        }
+    }
+}
+
+/// The number of ARNs with each value of a component.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Counts(BTreeMap<String, usize>);
+
+impl Counts {
+    /// How many ARNs had `key`.
+    pub fn get(&self, key: &str) -> usize {
+        self.0.get(key).copied().unwrap_or_default()
+    }
+
+    /// The `n` most common values with their counts, most common first, and in order of value for equal counts.
+    pub fn top(&self, n: usize) -> Vec<(&str, usize)> {
+        let mut counts: Vec<_> = self.iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        counts.truncate(n);
+        counts
+    }
+
+    /// Every value with its count, in order of value.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.0.iter().map(|(key, &count)| (key.as_str(), count))
+    }
+
+    /// The number of distinct values.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn add(&mut self, key: &str) {
+        match self.0.get_mut(key) {
+            Some(count) => *count += 1,
+            None => {
+                self.0.insert(key.to_owned(), 1);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Summary {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Summary", 6)?;
+        state.serialize_field("total", &self.total)?;
+        state.serialize_field("partitions", &self.partitions)?;
+        state.serialize_field("services", &self.services)?;
+        state.serialize_field("regions", &self.regions)?;
+        state.serialize_field("accounts", &self.accounts)?;
+        state.serialize_field("resource_types", &self.resource_types)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Counts {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Summary;
+    use crate::naive::NaiveArn;
+
+    fn summary() -> Summary {
+        [
+            "arn:aws:sqs:us-east-1:111111111111:orders",
+            "arn:aws:sqs:us-east-1:222222222222:payments",
+            "arn:aws:sns:us-west-2:111111111111:alerts",
+            "arn:aws:iam::111111111111:role/Admin",
+            "arn:aws-cn:iam::333333333333:user/ops",
+        ]
+        .iter()
+        .map(|arn| NaiveArn::parse(arn).unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn counts() {
+        let summary = summary();
+
+        assert_eq!(summary.total, 5);
+        assert_eq!(summary.partitions.get("aws"), 4);
+        assert_eq!(summary.partitions.get("aws-gov"), 0);
+        assert_eq!(
+            summary
+                .regions
+                .iter()
+                .map(|(_, count)| count)
+                .sum::<usize>(),
+            3
+        );
+        assert_eq!(summary.accounts.len(), 3);
+        assert_eq!(
+            summary.resource_types.iter().collect::<Vec<_>>(),
+            [("iam:role", 1), ("iam:user", 1)]
+        );
+    }
+
+    #[test]
+    fn top() {
+        let summary = summary();
+
+        assert_eq!(summary.services.top(2), [("iam", 2), ("sqs", 2)]);
+        assert_eq!(summary.accounts.top(1), [("111111111111", 3)]);
+        assert_eq!(summary.regions.top(10).len(), 2);
+        assert!(Summary::new().services.top(3).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize() {
+        let value = serde_json::to_value(summary()).unwrap();
+
+        assert_eq!(value["total"], 5);
+        assert_eq!(value["services"]["sqs"], 2);
+        assert_eq!(value["resource_types"]["iam:role"], 1);
+    }
+}
@@ -0,0 +1,382 @@
+//! ARNs built with CloudFormation `Fn::Sub`, such as `arn:${AWS::Partition}:s3:::${AWS::AccountId}-logs`
+
+use alloc::{borrow::ToOwned, string::String};
+use core::fmt;
+
+use crate::buf::ArnBuf;
+use crate::context::Context;
+use crate::naive::{from_elements, ParseNaiveArnError};
+use crate::partition::Partition;
+
+/// A CloudFormation pseudo parameter that can appear in an ARN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PseudoParameter {
+    /// `${AWS::AccountId}`
+    AccountId,
+    /// `${AWS::Partition}`
+    Partition,
+    /// `${AWS::Region}`
+    Region,
+    /// `${AWS::URLSuffix}`, the partition's DNS suffix
+    UrlSuffix,
+}
+
+impl PseudoParameter {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "AWS::AccountId" => Some(PseudoParameter::AccountId),
+            "AWS::Partition" => Some(PseudoParameter::Partition),
+            "AWS::Region" => Some(PseudoParameter::Region),
+            "AWS::URLSuffix" => Some(PseudoParameter::UrlSuffix),
+            _ => None,
+        }
+    }
+
+    /// The parameter's value in `context`, if it can be derived from it.
+    pub fn resolve<'c>(&self, context: &Context<'c>) -> Option<&'c str> {
+        match self {
+            PseudoParameter::AccountId => Some(context.account_id),
+            PseudoParameter::Partition => Some(context.partition),
+            PseudoParameter::Region => Some(context.region),
+            PseudoParameter::UrlSuffix => context
+                .partition
+                .parse::<Partition>()
+                .ok()
+                .map(Partition::dns_suffix),
+        }
+    }
+}
+
+/// A piece of a template ARN component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Segment<'a> {
+    /// Literal text
+    Literal(&'a str),
+    /// A `${AWS::...}` pseudo parameter
+    Pseudo(PseudoParameter),
+    /// Any other `${...}` substitution, such as a template parameter or resource attribute
+    Variable(&'a str),
+}
+
+/// An ARN that may contain `${...}` substitutions, as written in a CloudFormation template.
+///
+/// Components are kept as written; use [`segments`] to break one down, or [`TemplateArn::resolve`] to substitute the
+/// pseudo parameters.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::context::Context;
+/// use arn::template::{PseudoParameter, Segment, TemplateArn};
+///
+/// let arn = TemplateArn::parse("arn:${AWS::Partition}:sqs:${AWS::Region}:${AWS::AccountId}:my-queue").unwrap();
+/// assert_eq!(arn.region_parameter(), Some(PseudoParameter::Region));
+///
+/// let context = Context::new("aws-cn", "cn-north-1", "123456789012");
+/// assert_eq!(arn.resolve(&context).unwrap().to_string(), "arn:aws-cn:sqs:cn-north-1:123456789012:my-queue");
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TemplateArn<'a> {
+    pub partition: &'a str,
+    pub service: &'a str,
+    pub region: Option<&'a str>,
+    pub account_id: Option<&'a str>,
+    pub resource: &'a str,
+}
+
+impl<'a> TemplateArn<'a> {
+    /// Parse an ARN in which `:` inside `${...}` substitutions does not separate components.
+    pub fn parse(s: &'a str) -> Result<Self, ParseNaiveArnError> {
+        let arn = from_elements(s, split_components(s))?;
+
+        Ok(TemplateArn {
+            partition: arn.partition,
+            service: arn.service,
+            region: arn.region,
+            account_id: arn.account_id,
+            resource: arn.resource,
+        })
+    }
+
+    /// The pseudo parameter making up the whole partition component, if any.
+    pub fn partition_parameter(&self) -> Option<PseudoParameter> {
+        sole_parameter(self.partition)
+    }
+
+    /// The pseudo parameter making up the whole region component, if any.
+    pub fn region_parameter(&self) -> Option<PseudoParameter> {
+        self.region.and_then(sole_parameter)
+    }
+
+    /// The pseudo parameter making up the whole account ID component, if any.
+    pub fn account_id_parameter(&self) -> Option<PseudoParameter> {
+        self.account_id.and_then(sole_parameter)
+    }
+
+    /// Whether any component contains a `${...}` substitution.
+    pub fn has_substitutions(&self) -> bool {
+        [
+            Some(self.partition),
+            Some(self.service),
+            self.region,
+            self.account_id,
+            Some(self.resource),
+        ]
+        .iter()
+        .flatten()
+        .any(|component| segments(component).any(|s| !matches!(s, Segment::Literal(_))))
+    }
+
+    /// Substitute the pseudo parameters from `context`, producing a concrete ARN.
+    ///
+    /// `${!Literal}` escapes become `${Literal}`, as in `Fn::Sub`. Any other substitution is an error, since its
+    /// value can only be known by CloudFormation.
+    pub fn resolve(&self, context: &Context) -> Result<ArnBuf, ResolveError> {
+        let resolve = |component: &str| resolve_component(component, context);
+
+        Ok(ArnBuf {
+            partition: resolve(self.partition)?,
+            service: resolve(self.service)?,
+            region: self.region.map(resolve).transpose()?,
+            account_id: self.account_id.map(resolve).transpose()?,
+            resource: resolve(self.resource)?,
+        })
+    }
+}
+
+fn sole_parameter(component: &str) -> Option<PseudoParameter> {
+    let mut segments = segments(component);
+
+    match (segments.next(), segments.next()) {
+        (Some(Segment::Pseudo(parameter)), None) => Some(parameter),
+        _ => None,
+    }
+}
+
+fn resolve_component(component: &str, context: &Context) -> Result<String, ResolveError> {
+    let mut resolved = String::with_capacity(component.len());
+
+    for segment in segments(component) {
+        match segment {
+            Segment::Literal(literal) => resolved.push_str(literal),
+            Segment::Pseudo(parameter) => resolved.push_str(
+                parameter
+                    .resolve(context)
+                    .ok_or(ResolveError::UnknownPartition)?,
+            ),
+            Segment::Variable(name) => match name.strip_prefix('!') {
+                Some(literal) => {
+                    resolved.push_str("${");
+                    resolved.push_str(literal);
+                    resolved.push('}');
+                }
+                None => return Err(ResolveError::UnresolvedVariable(name.to_owned())),
+            },
+        }
+    }
+
+    Ok(resolved)
+}
+
+/// Break a template ARN component into literal text and `${...}` substitutions.
+///
+/// An unterminated `${` is treated as literal text.
+pub fn segments(component: &str) -> impl Iterator<Item = Segment<'_>> {
+    let mut rest = component;
+
+    core::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let substitution = rest
+            .find("${")
+            .and_then(|start| Some((start, start + rest[start..].find('}')?)));
+
+        match substitution {
+            Some((0, end)) => {
+                let name = &rest[2..end];
+                rest = &rest[end + 1..];
+                Some(match PseudoParameter::from_name(name) {
+                    Some(parameter) => Segment::Pseudo(parameter),
+                    None => Segment::Variable(name),
+                })
+            }
+            Some((start, _)) => {
+                let literal = &rest[..start];
+                rest = &rest[start..];
+                Some(Segment::Literal(literal))
+            }
+            None => {
+                let literal = rest;
+                rest = "";
+                Some(Segment::Literal(literal))
+            }
+        }
+    })
+}
+
+/// Split on `:`, except inside `${...}`, into at most six components.
+fn split_components(s: &str) -> impl Iterator<Item = &str> {
+    let mut rest = Some(s);
+    let mut count = 0;
+
+    core::iter::from_fn(move || {
+        let current = rest?;
+        count += 1;
+
+        if count == 6 {
+            rest = None;
+            return Some(current);
+        }
+
+        let mut in_substitution = false;
+        let mut prev = '\0';
+        for (idx, c) in current.char_indices() {
+            match c {
+                '{' if prev == '$' => in_substitution = true,
+                '}' if in_substitution => in_substitution = false,
+                ':' if !in_substitution => {
+                    rest = Some(&current[idx + 1..]);
+                    return Some(&current[..idx]);
+                }
+                _ => {}
+            }
+            prev = c;
+        }
+
+        rest = None;
+        Some(current)
+    })
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ResolveError {
+    UnresolvedVariable(String),
+    UnknownPartition,
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ResolveError::UnresolvedVariable(name) => {
+                write!(f, "Cannot resolve substitution '${{{}}}'", name)
+            }
+            ResolveError::UnknownPartition => {
+                write!(f, "Cannot resolve AWS::URLSuffix for an unknown partition")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ResolveError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{segments, PseudoParameter, ResolveError, Segment, TemplateArn};
+    use crate::context::Context;
+    use crate::naive::{Component, ParseNaiveArnError};
+
+    const CONTEXT: Context<'static> = Context {
+        partition: "aws",
+        region: "us-east-1",
+        account_id: "123456789012",
+    };
+
+    #[test]
+    fn pseudo_parameters() {
+        let arn = TemplateArn::parse("arn:${AWS::Partition}:logs:${AWS::Region}:${AWS::AccountId}:log-group:/aws/lambda/fn:*").unwrap();
+
+        assert_eq!(arn.partition, "${AWS::Partition}");
+        assert_eq!(arn.service, "logs");
+        assert_eq!(arn.region, Some("${AWS::Region}"));
+        assert_eq!(arn.account_id, Some("${AWS::AccountId}"));
+        assert_eq!(arn.resource, "log-group:/aws/lambda/fn:*");
+
+        assert_eq!(arn.partition_parameter(), Some(PseudoParameter::Partition));
+        assert_eq!(arn.region_parameter(), Some(PseudoParameter::Region));
+        assert_eq!(arn.account_id_parameter(), Some(PseudoParameter::AccountId));
+        assert!(arn.has_substitutions());
+
+        assert_eq!(
+            arn.resolve(&CONTEXT).unwrap().to_string(),
+            "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/fn:*"
+        );
+    }
+
+    #[test]
+    fn embedded_substitutions() {
+        let arn =
+            TemplateArn::parse("arn:aws:s3:::${AWS::AccountId}-${AWS::Region}-logs/*").unwrap();
+
+        assert_eq!(
+            segments(arn.resource).collect::<Vec<_>>(),
+            vec![
+                Segment::Pseudo(PseudoParameter::AccountId),
+                Segment::Literal("-"),
+                Segment::Pseudo(PseudoParameter::Region),
+                Segment::Literal("-logs/*"),
+            ]
+        );
+        assert_eq!(
+            arn.resolve(&CONTEXT).unwrap().to_string(),
+            "arn:aws:s3:::123456789012-us-east-1-logs/*"
+        );
+    }
+
+    #[test]
+    fn url_suffix() {
+        let arn =
+            TemplateArn::parse("arn:aws:iam::${AWS::AccountId}:role/${AWS::URLSuffix}").unwrap();
+        let context = Context::new("aws-cn", "cn-north-1", "123456789012");
+
+        assert_eq!(
+            arn.resolve(&context).unwrap().resource,
+            "role/amazonaws.com.cn"
+        );
+    }
+
+    #[test]
+    fn plain_arn() {
+        let arn_str = "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98";
+        let arn = TemplateArn::parse(arn_str).unwrap();
+
+        assert!(!arn.has_substitutions());
+        assert_eq!(arn.resolve(&CONTEXT).unwrap().to_string(), arn_str);
+    }
+
+    #[test]
+    fn other_variables() {
+        let arn = TemplateArn::parse("arn:aws:s3:::${BucketName}/${!Literal}").unwrap();
+
+        assert_eq!(
+            segments(arn.resource).next(),
+            Some(Segment::Variable("BucketName"))
+        );
+        assert_eq!(
+            arn.resolve(&CONTEXT),
+            Err(ResolveError::UnresolvedVariable("BucketName".to_owned()))
+        );
+
+        let arn = TemplateArn::parse("arn:aws:s3:::bucket/${!Literal}").unwrap();
+        assert_eq!(arn.resolve(&CONTEXT).unwrap().resource, "bucket/${Literal}");
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            TemplateArn::parse("arn:${AWS::Partition}:sqs:${AWS::Region}"),
+            Err(ParseNaiveArnError::NotEnoughElements {
+                component: Component::AccountId,
+                position: 40
+            })
+        );
+        assert_eq!(
+            TemplateArn::parse("${AWS::Partition}:sqs:::q"),
+            Err(ParseNaiveArnError::MissingPrefix {
+                found: "${AWS::Partition}:".to_owned()
+            })
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! Terraform `import` identifiers for ARNs
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use crate::naive::{split_resource, NaiveArn};
+
+/// The arguments `terraform import` expects for a resource: the Terraform resource type and its import ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerraformImport<'a> {
+    /// The Terraform resource type, e.g. `aws_sqs_queue`.
+    pub resource_type: &'static str,
+
+    /// The identifier the provider's importer expects. Depending on the resource this is the full ARN, a name, an ID,
+    /// or another identifier (such as a queue URL) derived from the ARN.
+    pub id: Cow<'a, str>,
+}
+
+impl<'a> NaiveArn<'a> {
+    /// Derive the Terraform AWS provider resource type and import ID for this ARN, if the resource is supported.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    ///
+    /// let arn = NaiveArn::parse("arn:aws:iam::123456789012:role/service-role/my-role").unwrap();
+    /// let import = arn.terraform_import().unwrap();
+    ///
+    /// assert_eq!(import.resource_type, "aws_iam_role");
+    /// assert_eq!(import.id, "my-role");
+    /// ~~~~
+    pub fn terraform_import(&self) -> Option<TerraformImport<'a>> {
+        let (resource_type, rest) = split_resource(self.resource);
+
+        let (tf_type, id) = match (self.service, resource_type, rest) {
+            ("s3", bucket, None) => ("aws_s3_bucket", Cow::Borrowed(bucket)),
+            ("sqs", name, None) => ("aws_sqs_queue", Cow::Owned(self.sqs_queue_url(name)?)),
+            ("sns", _, None) => ("aws_sns_topic", Cow::Owned(self.to_string())),
+            ("codecommit", name, None) => ("aws_codecommit_repository", Cow::Borrowed(name)),
+
+            ("iam", "role", Some(path)) => ("aws_iam_role", last_segment(path)?),
+            ("iam", "user", Some(path)) => ("aws_iam_user", last_segment(path)?),
+            ("iam", "group", Some(path)) => ("aws_iam_group", last_segment(path)?),
+            ("iam", "instance-profile", Some(path)) => {
+                ("aws_iam_instance_profile", last_segment(path)?)
+            }
+            ("iam", "policy", Some(_)) => ("aws_iam_policy", Cow::Owned(self.to_string())),
+
+            ("ec2", "instance", Some(id)) => ("aws_instance", Cow::Borrowed(id)),
+            ("ec2", "vpc", Some(id)) => ("aws_vpc", Cow::Borrowed(id)),
+            ("ec2", "subnet", Some(id)) => ("aws_subnet", Cow::Borrowed(id)),
+            ("ec2", "security-group", Some(id)) => ("aws_security_group", Cow::Borrowed(id)),
+            ("ec2", "volume", Some(id)) => ("aws_ebs_volume", Cow::Borrowed(id)),
+            ("ec2", "internet-gateway", Some(id)) => ("aws_internet_gateway", Cow::Borrowed(id)),
+            ("ec2", "natgateway", Some(id)) => ("aws_nat_gateway", Cow::Borrowed(id)),
+            ("ec2", "route-table", Some(id)) => ("aws_route_table", Cow::Borrowed(id)),
+
+            ("lambda", "function", Some(name)) => {
+                let name = name.split(':').next().unwrap_or(name);
+                ("aws_lambda_function", Cow::Borrowed(name))
+            }
+            ("dynamodb", "table", Some(name)) if !name.contains('/') => {
+                ("aws_dynamodb_table", Cow::Borrowed(name))
+            }
+            // Log streams are `log-group:group-name:log-stream:stream-name`, and have no importer of their own
+            ("logs", "log-group", Some(name)) if !name.contains(":log-stream:") => {
+                let name = name.strip_suffix(":*").unwrap_or(name);
+                ("aws_cloudwatch_log_group", Cow::Borrowed(name))
+            }
+            ("cloudwatch", "alarm", Some(name)) => {
+                ("aws_cloudwatch_metric_alarm", Cow::Borrowed(name))
+            }
+            ("kms", "key", Some(id)) => ("aws_kms_key", Cow::Borrowed(id)),
+            ("kms", "alias", Some(_)) => ("aws_kms_alias", Cow::Borrowed(self.resource)),
+            ("ecr", "repository", Some(name)) => ("aws_ecr_repository", Cow::Borrowed(name)),
+            ("ecs", "cluster", Some(name)) => ("aws_ecs_cluster", Cow::Borrowed(name)),
+            // New-format service ARNs are `service/cluster/name`, and the importer wants `cluster/name`
+            ("ecs", "service", Some(name)) if name.contains('/') => {
+                ("aws_ecs_service", Cow::Borrowed(name))
+            }
+            ("eks", "cluster", Some(name)) => ("aws_eks_cluster", Cow::Borrowed(name)),
+            ("kinesis", "stream", Some(name)) => ("aws_kinesis_stream", Cow::Borrowed(name)),
+            ("ssm", "parameter", Some(name)) => {
+                // Hierarchical parameter names keep their leading slash
+                let name = if name.contains('/') {
+                    Cow::Owned(format!("/{}", name))
+                } else {
+                    Cow::Borrowed(name)
+                };
+                ("aws_ssm_parameter", name)
+            }
+            ("cloudformation", "stack", Some(name)) => {
+                let name = name.split('/').next().unwrap_or(name);
+                ("aws_cloudformation_stack", Cow::Borrowed(name))
+            }
+            ("route53", "hostedzone", Some(id)) => ("aws_route53_zone", Cow::Borrowed(id)),
+            ("rds", "db", Some(id)) => ("aws_db_instance", Cow::Borrowed(id)),
+            ("rds", "cluster", Some(id)) => ("aws_rds_cluster", Cow::Borrowed(id)),
+            ("sagemaker", "endpoint", Some(name)) => {
+                ("aws_sagemaker_endpoint", Cow::Borrowed(name))
+            }
+            ("glue", "job", Some(name)) => ("aws_glue_job", Cow::Borrowed(name)),
+
+            ("secretsmanager", "secret", Some(_)) => {
+                ("aws_secretsmanager_secret", Cow::Owned(self.to_string()))
+            }
+            ("states", "stateMachine", Some(_)) => {
+                ("aws_sfn_state_machine", Cow::Owned(self.to_string()))
+            }
+            ("acm", "certificate", Some(_)) => {
+                ("aws_acm_certificate", Cow::Owned(self.to_string()))
+            }
+            ("cloudtrail", "trail", Some(_)) => ("aws_cloudtrail", Cow::Owned(self.to_string())),
+            ("elasticloadbalancing", "loadbalancer", Some(rest)) if rest.contains('/') => {
+                ("aws_lb", Cow::Owned(self.to_string()))
+            }
+            ("elasticloadbalancing", "targetgroup", Some(_)) => {
+                ("aws_lb_target_group", Cow::Owned(self.to_string()))
+            }
+            ("elasticloadbalancing", "listener", Some(_)) => {
+                ("aws_lb_listener", Cow::Owned(self.to_string()))
+            }
+
+            _ => return None,
+        };
+
+        Some(TerraformImport {
+            resource_type: tf_type,
+            id,
+        })
+    }
+
+    fn sqs_queue_url(&self, name: &str) -> Option<String> {
+        Some(format!(
+            "https://sqs.{}.{}/{}/{}",
+            self.region?,
+            self.known_partition()?.dns_suffix(),
+            self.account_id?,
+            name
+        ))
+    }
+}
+
+/// The name of a path-qualified IAM entity, e.g. `my-role` for `service-role/my-role`.
+fn last_segment(path: &str) -> Option<Cow<'_, str>> {
+    path.rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty())
+        .map(Cow::Borrowed)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive::NaiveArn;
+
+    fn terraform_import(arn_str: &str) -> Option<(&'static str, String)> {
+        NaiveArn::parse(arn_str)
+            .unwrap()
+            .terraform_import()
+            .map(|import| (import.resource_type, import.id.into_owned()))
+    }
+
+    #[test]
+    fn name_ids() {
+        assert_eq!(
+            terraform_import("arn:aws:s3:::my_corporate_bucket"),
+            Some(("aws_s3_bucket", "my_corporate_bucket".to_owned()))
+        );
+        assert_eq!(
+            terraform_import("arn:aws:lambda:us-east-1:123456789012:function:my-function:PROD"),
+            Some(("aws_lambda_function", "my-function".to_owned()))
+        );
+        assert_eq!(
+            terraform_import(
+                "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/my-function:*"
+            ),
+            Some((
+                "aws_cloudwatch_log_group",
+                "/aws/lambda/my-function".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn resource_ids() {
+        assert_eq!(
+            terraform_import("arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98"),
+            Some(("aws_vpc", "vpc-fd580e98".to_owned()))
+        );
+        assert_eq!(
+            terraform_import("arn:aws:kms:us-east-1:123456789012:alias/my-key"),
+            Some(("aws_kms_alias", "alias/my-key".to_owned()))
+        );
+    }
+
+    #[test]
+    fn arn_ids() {
+        let arn_str = "arn:aws:sns:us-east-1:123456789012:my_corporate_topic";
+        assert_eq!(
+            terraform_import(arn_str),
+            Some(("aws_sns_topic", arn_str.to_owned()))
+        );
+
+        let arn_str = "arn:aws:iam::123456789012:policy/my-policy";
+        assert_eq!(
+            terraform_import(arn_str),
+            Some(("aws_iam_policy", arn_str.to_owned()))
+        );
+    }
+
+    #[test]
+    fn derived_ids() {
+        assert_eq!(
+            terraform_import("arn:aws:sqs:us-east-1:123456789012:my-queue"),
+            Some((
+                "aws_sqs_queue",
+                "https://sqs.us-east-1.amazonaws.com/123456789012/my-queue".to_owned()
+            ))
+        );
+        assert_eq!(
+            terraform_import("arn:aws:ssm:us-east-1:123456789012:parameter/app/db/password"),
+            Some(("aws_ssm_parameter", "/app/db/password".to_owned()))
+        );
+        assert_eq!(
+            terraform_import("arn:aws:ssm:us-east-1:123456789012:parameter/password"),
+            Some(("aws_ssm_parameter", "password".to_owned()))
+        );
+        assert_eq!(
+            terraform_import("arn:aws:ecs:us-east-1:123456789012:service/my-cluster/my-service"),
+            Some(("aws_ecs_service", "my-cluster/my-service".to_owned()))
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        assert_eq!(
+            terraform_import("arn:aws:s3:::my_corporate_bucket/exampleobject.png"),
+            None
+        );
+        assert_eq!(
+            terraform_import("arn:aws:ecs:us-east-1:123456789012:service/my-service"),
+            None
+        );
+        assert_eq!(
+            terraform_import(
+                "arn:aws:logs:us-east-1:123456789012:log-group:my-log-group:log-stream:my-log-stream"
+            ),
+            None
+        );
+    }
+}
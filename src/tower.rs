@@ -0,0 +1,422 @@
+//! `tower` middleware that rejects HTTP requests carrying invalid ARNs, before they reach the service
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use http::{HeaderName, Request, Response, StatusCode};
+use pin_project_lite::pin_project;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::naive::NaiveArn;
+use crate::pattern::ArnPattern;
+use crate::percent;
+use crate::validate::ValidationLevel;
+
+/// A [`Layer`] checking that configured headers and query parameters hold ARNs that are valid at a
+/// [`ValidationLevel`], and optionally that they match one of a list of allowed patterns.
+///
+/// A rejected request gets an empty response (the body's [`Default`]) with status `400 Bad Request`, or
+/// `403 Forbidden` for ARNs that aren't allowed, and the [`ArnRejection`] in its extensions for outer layers to render.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::pattern::ArnPattern;
+/// use arn::tower::ArnValidationLayer;
+/// use arn::validate::ValidationLevel;
+/// use http::HeaderName;
+///
+/// let layer = ArnValidationLayer::new(ValidationLevel::Standard)
+///     .header(HeaderName::from_static("x-role-arn"))
+///     .allow(ArnPattern::parse("arn:aws:iam::123456789012:role/*").unwrap());
+///
+/// // let service = tower::ServiceBuilder::new().layer(layer).service(service);
+/// ~~~~
+#[derive(Debug, Clone)]
+pub struct ArnValidationLayer {
+    config: Config,
+}
+
+#[derive(Debug, Clone)]
+struct Config {
+    level: ValidationLevel,
+    fields: Vec<Field>,
+    allowed: Vec<ArnPattern>,
+    optional: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Field {
+    Header(HeaderName),
+    Query(String),
+}
+
+impl ArnValidationLayer {
+    /// A layer validating at `level`, which checks nothing until fields are added.
+    pub fn new(level: ValidationLevel) -> Self {
+        ArnValidationLayer {
+            config: Config {
+                level,
+                fields: Vec::new(),
+                allowed: Vec::new(),
+                optional: false,
+            },
+        }
+    }
+
+    /// Check every value of the header `name`.
+    pub fn header(mut self, name: HeaderName) -> Self {
+        self.config.fields.push(Field::Header(name));
+        self
+    }
+
+    /// Check every value of the query parameter `name`.
+    pub fn query(mut self, name: impl Into<String>) -> Self {
+        self.config.fields.push(Field::Query(name.into()));
+        self
+    }
+
+    /// Only accept ARNs matching `pattern`, or one of the other allowed patterns.
+    pub fn allow(mut self, pattern: ArnPattern) -> Self {
+        self.config.allowed.push(pattern);
+        self
+    }
+
+    /// Accept requests without the fields, checking them only when present.
+    pub fn optional(mut self) -> Self {
+        self.config.optional = true;
+        self
+    }
+}
+
+impl<S> Layer<S> for ArnValidationLayer {
+    type Service = ArnValidation<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ArnValidation {
+            inner,
+            config: Arc::new(self.config.clone()),
+        }
+    }
+}
+
+/// The service created by [`ArnValidationLayer`].
+#[derive(Debug, Clone)]
+pub struct ArnValidation<S> {
+    inner: S,
+    config: Arc<Config>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for ArnValidation<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    ResBody: Default,
+{
+    type Response = Response<ResBody>;
+    type Error = S::Error;
+    type Future = ResponseFuture<S::Future, ResBody>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let kind = match self.config.check(&request) {
+            Ok(()) => Kind::Inner {
+                future: self.inner.call(request),
+            },
+            Err(rejection) => {
+                let mut response = Response::new(ResBody::default());
+                *response.status_mut() = rejection.status();
+                response.extensions_mut().insert(rejection);
+
+                Kind::Rejected {
+                    response: Some(response),
+                }
+            }
+        };
+
+        ResponseFuture { kind }
+    }
+}
+
+impl Config {
+    fn check<B>(&self, request: &Request<B>) -> Result<(), ArnRejection> {
+        for field in &self.fields {
+            let reject = |reason| ArnRejection {
+                field: field.to_string(),
+                reason,
+            };
+
+            let values: Vec<Option<String>> = match field {
+                Field::Header(name) => request
+                    .headers()
+                    .get_all(name)
+                    .iter()
+                    .map(|value| value.to_str().ok().map(str::to_owned))
+                    .collect(),
+                Field::Query(name) => request
+                    .uri()
+                    .query()
+                    .unwrap_or_default()
+                    .split('&')
+                    .filter_map(|pair| {
+                        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                        (percent::decode(key).as_deref() == Some(name.as_str()))
+                            .then(|| percent::decode(value))
+                    })
+                    .collect(),
+            };
+
+            if values.is_empty() && !self.optional {
+                return Err(reject(RejectionReason::Missing));
+            }
+
+            for value in values {
+                let value = value.ok_or_else(|| reject(RejectionReason::Unreadable))?;
+                let invalid = |code: &'static str, message: String| {
+                    reject(RejectionReason::Invalid { code, message })
+                };
+
+                let arn =
+                    NaiveArn::parse(&value).map_err(|err| invalid(err.code(), err.to_string()))?;
+                if let Some(issue) = arn.first_issue(self.level) {
+                    return Err(invalid(issue.code(), issue.to_string()));
+                }
+
+                if !self.allowed.is_empty()
+                    && !self.allowed.iter().any(|pattern| pattern.matches(&arn))
+                {
+                    return Err(reject(RejectionReason::NotAllowed));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Field::Header(name) => write!(f, "header {}", name),
+            Field::Query(name) => write!(f, "query parameter {}", name),
+        }
+    }
+}
+
+pin_project! {
+    /// The future returned by [`ArnValidation`].
+    pub struct ResponseFuture<F, B> {
+        #[pin]
+        kind: Kind<F, B>,
+    }
+}
+
+pin_project! {
+    #[project = KindProj]
+    enum Kind<F, B> {
+        Inner {
+            #[pin]
+            future: F,
+        },
+        Rejected {
+            response: Option<Response<B>>,
+        },
+    }
+}
+
+impl<F, B, E> Future for ResponseFuture<F, B>
+where
+    F: Future<Output = Result<Response<B>, E>>,
+{
+    type Output = Result<Response<B>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.project().kind.project() {
+            KindProj::Inner { future } => future.poll(cx),
+            KindProj::Rejected { response } => {
+                Poll::Ready(Ok(response.take().expect("polled after completion")))
+            }
+        }
+    }
+}
+
+/// Why [`ArnValidation`] rejected a request, found in the extensions of the response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArnRejection {
+    /// The field with the problem, such as `header x-role-arn` or `query parameter resource`.
+    pub field: String,
+    pub reason: RejectionReason,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RejectionReason {
+    /// The request doesn't have the field.
+    Missing,
+    /// The field isn't UTF-8, or has invalid percent-encoding.
+    Unreadable,
+    /// The field isn't an ARN, or isn't valid at the configured level. `code` is the code of the parse error or
+    /// validation issue, such as `ARN007`.
+    Invalid { code: &'static str, message: String },
+    /// The ARN doesn't match any allowed pattern.
+    NotAllowed,
+}
+
+impl ArnRejection {
+    /// The status of the response to the rejected request.
+    pub fn status(&self) -> StatusCode {
+        match self.reason {
+            RejectionReason::NotAllowed => StatusCode::FORBIDDEN,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl fmt::Display for ArnRejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: ", self.field)?;
+
+        match &self.reason {
+            RejectionReason::Missing => write!(f, "Missing"),
+            RejectionReason::Unreadable => write!(f, "Not readable as text"),
+            RejectionReason::Invalid { message, .. } => f.write_str(message),
+            RejectionReason::NotAllowed => write!(f, "ARN is not allowed"),
+        }
+    }
+}
+
+impl std::error::Error for ArnRejection {}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+    use std::future::{ready, Future, Ready};
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    use http::{HeaderName, Request, Response, StatusCode};
+    use tower_layer::Layer;
+    use tower_service::Service;
+
+    use super::{ArnRejection, ArnValidationLayer, RejectionReason};
+    use crate::pattern::ArnPattern;
+    use crate::validate::ValidationLevel;
+
+    struct Echo;
+
+    impl Service<Request<()>> for Echo {
+        type Response = Response<String>;
+        type Error = Infallible;
+        type Future = Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _: Request<()>) -> Self::Future {
+            ready(Ok(Response::new("ok".to_owned())))
+        }
+    }
+
+    fn layer() -> ArnValidationLayer {
+        ArnValidationLayer::new(ValidationLevel::Standard)
+            .header(HeaderName::from_static("x-role-arn"))
+            .query("resource")
+            .allow(ArnPattern::parse("arn:aws:*:*:123456789012:*").unwrap())
+    }
+
+    fn call(layer: &ArnValidationLayer, request: Request<()>) -> Response<String> {
+        let mut service = layer.layer(Echo);
+        let future = pin!(service.call(request));
+
+        match future.poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(Ok(response)) => response,
+            _ => unreachable!(),
+        }
+    }
+
+    fn request(role_arn: Option<&str>, query: &str) -> Request<()> {
+        let mut request = Request::builder().uri(format!("https://example.com/{}", query));
+        if let Some(role_arn) = role_arn {
+            request = request.header("x-role-arn", role_arn);
+        }
+        request.body(()).unwrap()
+    }
+
+    fn rejection(response: &Response<String>) -> &ArnRejection {
+        response.extensions().get().unwrap()
+    }
+
+    #[test]
+    fn accepts() {
+        let response = call(
+            &layer(),
+            request(
+                Some("arn:aws:iam::123456789012:role/Admin"),
+                "?resource=arn%3Aaws%3Asqs%3Aus-east-1%3A123456789012%3Aorders",
+            ),
+        );
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body(), "ok");
+    }
+
+    #[test]
+    fn missing() {
+        let response = call(&layer(), request(None, "?resource=x"));
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response.body(), "");
+        assert_eq!(
+            rejection(&response).to_string(),
+            "header x-role-arn: Missing"
+        );
+
+        let optional = ArnValidationLayer::new(ValidationLevel::Standard)
+            .query("resource")
+            .optional();
+        assert_eq!(call(&optional, request(None, "")).status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn invalid() {
+        let response = call(
+            &layer(),
+            request(
+                Some("arn:aws:iam::123456789012:role/Admin"),
+                "?resource=arn:aws:sqs:us-east:123456789012:orders",
+            ),
+        );
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            rejection(&response),
+            &ArnRejection {
+                field: "query parameter resource".to_owned(),
+                reason: RejectionReason::Invalid {
+                    code: "ARN202",
+                    message: "ARN202: Invalid region".to_owned()
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn not_allowed() {
+        let response = call(
+            &layer(),
+            request(
+                Some("arn:aws:iam::210987654321:role/Admin"),
+                "?resource=arn:aws:sqs:us-east-1:123456789012:orders",
+            ),
+        );
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert_eq!(rejection(&response).reason, RejectionReason::NotAllowed);
+    }
+}
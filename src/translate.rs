@@ -0,0 +1,274 @@
+//! Translating ARNs between partitions, e.g. for disaster recovery or China deployments
+
+use alloc::collections::BTreeMap;
+use alloc::{borrow::ToOwned, string::String};
+use core::fmt;
+
+use crate::buf::ArnBuf;
+use crate::naive::NaiveArn;
+use crate::partition::Partition;
+
+/// Built-in `(from, to)` region pairs between partitions.
+const REGION_MAPPINGS: &[(&str, &str)] = &[
+    ("us-east-1", "cn-north-1"),
+    ("us-west-2", "cn-northwest-1"),
+    ("cn-north-1", "us-east-1"),
+    ("cn-northwest-1", "us-west-2"),
+    ("us-east-1", "us-gov-east-1"),
+    ("us-east-2", "us-gov-east-1"),
+    ("us-west-1", "us-gov-west-1"),
+    ("us-west-2", "us-gov-west-1"),
+    ("us-gov-east-1", "us-east-1"),
+    ("us-gov-west-1", "us-west-2"),
+];
+
+/// Services known to be unavailable in a partition. This is a best-effort list, not an authoritative one.
+const UNAVAILABLE_SERVICES: &[(Partition, &[&str])] = &[
+    (
+        Partition::AwsCn,
+        &[
+            "chime",
+            "connect",
+            "devicefarm",
+            "gamelift",
+            "lightsail",
+            "macie2",
+            "mturk",
+            "route53domains",
+            "shield",
+        ],
+    ),
+    (
+        Partition::AwsUsGov,
+        &[
+            "chime",
+            "devicefarm",
+            "gamelift",
+            "lightsail",
+            "mturk",
+            "route53domains",
+        ],
+    ),
+];
+
+/// Translates ARNs into a target partition, mapping regions along the way.
+///
+/// Regions are mapped using the caller-supplied mappings first, then the built-in ones (for example `us-east-1` to
+/// `cn-north-1`). Regions already in the target partition, wildcard regions and regionless ARNs are left alone.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::naive::NaiveArn;
+/// use arn::partition::Partition;
+/// use arn::translate::PartitionTranslator;
+///
+/// let translator = PartitionTranslator::new(Partition::AwsCn).map_region("eu-west-1", "cn-northwest-1");
+///
+/// let arn = NaiveArn::parse("arn:aws:sqs:eu-west-1:123456789012:my-queue").unwrap();
+/// let translated = translator.translate(&arn).unwrap();
+///
+/// assert_eq!(translated.arn.to_string(), "arn:aws-cn:sqs:cn-northwest-1:123456789012:my-queue");
+/// assert!(translated.service_available);
+/// ~~~~
+#[derive(Debug, Clone)]
+pub struct PartitionTranslator {
+    target: Partition,
+    regions: BTreeMap<String, String>,
+    builtin_regions: bool,
+}
+
+/// The result of translating an ARN into another partition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Translation {
+    /// The ARN in the target partition.
+    pub arn: ArnBuf,
+
+    /// `false` if the ARN's service is known not to exist in the target partition, in which case the translated ARN
+    /// does not refer to anything that could exist.
+    pub service_available: bool,
+}
+
+impl PartitionTranslator {
+    pub fn new(target: Partition) -> Self {
+        PartitionTranslator {
+            target,
+            regions: BTreeMap::new(),
+            builtin_regions: true,
+        }
+    }
+
+    /// Map ARNs in region `from` to region `to`, overriding any built-in mapping for `from`.
+    pub fn map_region(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.regions.insert(from.into(), to.into());
+        self
+    }
+
+    /// Only use the caller-supplied region mappings.
+    pub fn without_builtin_regions(mut self) -> Self {
+        self.builtin_regions = false;
+        self
+    }
+
+    pub fn translate(&self, arn: &NaiveArn) -> Result<Translation, TranslateError> {
+        let region = match arn.region {
+            None => None,
+            Some(region) => Some(self.map(region).ok_or(TranslateError::UnmappedRegion)?),
+        };
+
+        let service_available = !UNAVAILABLE_SERVICES.iter().any(|(partition, services)| {
+            *partition == self.target && services.contains(&arn.service)
+        });
+
+        Ok(Translation {
+            arn: ArnBuf {
+                partition: self.target.id().to_owned(),
+                service: arn.service.to_owned(),
+                region,
+                account_id: arn.account_id.map(str::to_owned),
+                resource: arn.resource.to_owned(),
+            },
+            service_available,
+        })
+    }
+
+    fn map(&self, region: &str) -> Option<String> {
+        if let Some(mapped) = self.regions.get(region) {
+            return Some(mapped.clone());
+        }
+
+        if region.contains(['*', '?']) || Partition::of_region(region) == Some(self.target) {
+            return Some(region.to_owned());
+        }
+
+        if !self.builtin_regions {
+            return None;
+        }
+
+        REGION_MAPPINGS
+            .iter()
+            .find(|(from, to)| *from == region && Partition::of_region(to) == Some(self.target))
+            .map(|(_, to)| (*to).to_owned())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TranslateError {
+    UnmappedRegion,
+}
+
+impl fmt::Display for TranslateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TranslateError::UnmappedRegion => {
+                write!(f, "No region mapping into the target partition")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TranslateError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{PartitionTranslator, TranslateError};
+    use crate::naive::NaiveArn;
+    use crate::partition::Partition;
+
+    fn translate(
+        translator: &PartitionTranslator,
+        arn_str: &str,
+    ) -> Result<String, TranslateError> {
+        translator
+            .translate(&NaiveArn::parse(arn_str).unwrap())
+            .map(|translation| translation.arn.to_string())
+    }
+
+    #[test]
+    fn builtin_regions() {
+        let translator = PartitionTranslator::new(Partition::AwsCn);
+
+        assert_eq!(
+            translate(&translator, "arn:aws:sqs:us-east-1:123456789012:my-queue"),
+            Ok("arn:aws-cn:sqs:cn-north-1:123456789012:my-queue".to_owned())
+        );
+
+        let translator = PartitionTranslator::new(Partition::Aws);
+
+        assert_eq!(
+            translate(
+                &translator,
+                "arn:aws-us-gov:sqs:us-gov-west-1:123456789012:my-queue"
+            ),
+            Ok("arn:aws:sqs:us-west-2:123456789012:my-queue".to_owned())
+        );
+    }
+
+    #[test]
+    fn custom_regions_override_builtin() {
+        let translator =
+            PartitionTranslator::new(Partition::AwsUsGov).map_region("us-east-1", "us-gov-west-1");
+
+        assert_eq!(
+            translate(&translator, "arn:aws:sqs:us-east-1:123456789012:my-queue"),
+            Ok("arn:aws-us-gov:sqs:us-gov-west-1:123456789012:my-queue".to_owned())
+        );
+    }
+
+    #[test]
+    fn regionless() {
+        let translator = PartitionTranslator::new(Partition::AwsCn);
+
+        assert_eq!(
+            translate(&translator, "arn:aws:iam::123456789012:role/my-role"),
+            Ok("arn:aws-cn:iam::123456789012:role/my-role".to_owned())
+        );
+        assert_eq!(
+            translate(&translator, "arn:aws:s3:::my_corporate_bucket"),
+            Ok("arn:aws-cn:s3:::my_corporate_bucket".to_owned())
+        );
+    }
+
+    #[test]
+    fn wildcard_and_target_regions() {
+        let translator = PartitionTranslator::new(Partition::AwsCn);
+
+        assert_eq!(
+            translate(&translator, "arn:aws:sns:*:123456789012:my_corporate_topic"),
+            Ok("arn:aws-cn:sns:*:123456789012:my_corporate_topic".to_owned())
+        );
+        assert_eq!(
+            translate(
+                &translator,
+                "arn:aws:sns:cn-north-1:123456789012:my_corporate_topic"
+            ),
+            Ok("arn:aws-cn:sns:cn-north-1:123456789012:my_corporate_topic".to_owned())
+        );
+    }
+
+    #[test]
+    fn unmapped_region() {
+        let translator = PartitionTranslator::new(Partition::AwsCn);
+        assert_eq!(
+            translate(&translator, "arn:aws:sqs:ap-south-1:123456789012:my-queue"),
+            Err(TranslateError::UnmappedRegion)
+        );
+
+        let translator = PartitionTranslator::new(Partition::AwsCn).without_builtin_regions();
+        assert_eq!(
+            translate(&translator, "arn:aws:sqs:us-east-1:123456789012:my-queue"),
+            Err(TranslateError::UnmappedRegion)
+        );
+    }
+
+    #[test]
+    fn unavailable_service() {
+        let translator = PartitionTranslator::new(Partition::AwsCn);
+        let arn = NaiveArn::parse("arn:aws:lightsail:us-east-1:123456789012:Instance/abc").unwrap();
+        assert!(!translator.translate(&arn).unwrap().service_available);
+
+        let arn = NaiveArn::parse("arn:aws:sqs:us-east-1:123456789012:my-queue").unwrap();
+        assert!(translator.translate(&arn).unwrap().service_available);
+    }
+}
@@ -0,0 +1,370 @@
+//! Validating ARNs beyond their syntax
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::ControlFlow;
+use core::str::FromStr;
+
+use crate::account::AccountId;
+#[cfg(feature = "alloc")]
+use crate::interpret::try_interpret;
+use crate::naive::NaiveArn;
+use crate::partition::Partition;
+#[cfg(feature = "alloc")]
+use crate::service::ParseServiceArnError;
+
+/// How thoroughly to validate an ARN.
+///
+/// Each level includes the checks of the levels before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ValidationLevel {
+    /// Only the `arn:partition:service:region:account-id:resource` structure, as checked by [`NaiveArn::parse`].
+    Syntax,
+    /// The partition is known, the region is a plausible region in that partition, the account ID is 12 digits and no
+    /// component contains whitespace.
+    Standard,
+    /// The resource is also valid for its service, for services this crate has typed ARNs for.
+    Strict,
+}
+
+impl FromStr for ValidationLevel {
+    type Err = ParseValidationLevelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "syntax" => Ok(ValidationLevel::Syntax),
+            "standard" => Ok(ValidationLevel::Standard),
+            "strict" => Ok(ValidationLevel::Strict),
+            _ => Err(ParseValidationLevelError),
+        }
+    }
+}
+
+impl fmt::Display for ValidationLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ValidationLevel::Syntax => "syntax",
+            ValidationLevel::Standard => "standard",
+            ValidationLevel::Strict => "strict",
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseValidationLevelError;
+
+impl fmt::Display for ParseValidationLevelError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Unknown validation level, expected syntax, standard or strict"
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseValidationLevelError {}
+
+/// A problem found while validating a syntactically valid ARN.
+#[derive(Debug, PartialEq)]
+pub enum ValidationIssue {
+    UnknownPartition,
+    /// The region doesn't follow any partition's region naming scheme.
+    InvalidRegion,
+    /// The region belongs to a different partition than the ARN's.
+    RegionNotInPartition,
+    /// The account ID isn't 12 digits, or `aws` for AWS-managed resources.
+    InvalidAccountId,
+    /// A component contains whitespace, usually a sign of bad copy and paste.
+    Whitespace,
+    /// The resource isn't valid for the service.
+    #[cfg(feature = "alloc")]
+    Service(ParseServiceArnError),
+}
+
+impl ValidationIssue {
+    /// A stable identifier for the kind of issue, `ARN2xx`, or the code of the underlying
+    /// [`ParseServiceArnError`](ParseServiceArnError::code).
+    ///
+    /// | Code     | Variant                                                 |
+    /// |----------|---------------------------------------------------------|
+    /// | `ARN201` | [`UnknownPartition`](Self::UnknownPartition)            |
+    /// | `ARN202` | [`InvalidRegion`](Self::InvalidRegion)                  |
+    /// | `ARN203` | [`RegionNotInPartition`](Self::RegionNotInPartition)    |
+    /// | `ARN204` | [`InvalidAccountId`](Self::InvalidAccountId)            |
+    /// | `ARN205` | [`Whitespace`](Self::Whitespace)                        |
+    pub fn code(&self) -> &'static str {
+        match self {
+            ValidationIssue::UnknownPartition => "ARN201",
+            ValidationIssue::InvalidRegion => "ARN202",
+            ValidationIssue::RegionNotInPartition => "ARN203",
+            ValidationIssue::InvalidAccountId => "ARN204",
+            ValidationIssue::Whitespace => "ARN205",
+            #[cfg(feature = "alloc")]
+            ValidationIssue::Service(err) => err.code(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "alloc")]
+        if let ValidationIssue::Service(err) = self {
+            return err.fmt(f);
+        }
+
+        write!(f, "{}: ", self.code())?;
+
+        match self {
+            ValidationIssue::UnknownPartition => write!(f, "Unknown partition"),
+            ValidationIssue::InvalidRegion => write!(f, "Invalid region"),
+            ValidationIssue::RegionNotInPartition => {
+                write!(f, "Region is not in the ARN's partition")
+            }
+            ValidationIssue::InvalidAccountId => write!(f, "Account ID must be 12 digits"),
+            ValidationIssue::Whitespace => write!(f, "ARN contains whitespace"),
+            #[cfg(feature = "alloc")]
+            ValidationIssue::Service(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationIssue {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidationIssue::Service(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> NaiveArn<'a> {
+    /// Validate this ARN at `level`, returning every issue found.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    /// use arn::validate::{ValidationIssue, ValidationLevel};
+    ///
+    /// let arn = NaiveArn::parse("arn:aws:sqs:cn-north-1:123456789012:orders/extra").unwrap();
+    /// assert_eq!(arn.validate(ValidationLevel::Syntax), Ok(()));
+    ///
+    /// let issues = arn.validate(ValidationLevel::Strict).unwrap_err();
+    /// assert_eq!(issues[0], ValidationIssue::RegionNotInPartition);
+    /// assert!(matches!(issues[1], ValidationIssue::Service(_)));
+    /// ~~~~
+    #[cfg(feature = "alloc")]
+    pub fn validate(&self, level: ValidationLevel) -> Result<(), Vec<ValidationIssue>> {
+        let mut issues = Vec::new();
+        self.for_each_issue(level, &mut |issue| {
+            issues.push(issue);
+            ControlFlow::Continue(())
+        });
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// The first issue [`validate`](NaiveArn::validate) would find at `level`, without allocating.
+    ///
+    /// Without the `alloc` feature, [`ValidationLevel::Strict`] checks nothing beyond
+    /// [`ValidationLevel::Standard`], as typed ARNs need `alloc`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use arn::naive::NaiveArn;
+    /// use arn::validate::{ValidationIssue, ValidationLevel};
+    ///
+    /// let arn = NaiveArn::parse("arn:aws:sqs:us-east:123456789012:orders").unwrap();
+    /// assert_eq!(arn.first_issue(ValidationLevel::Standard), Some(ValidationIssue::InvalidRegion));
+    /// ~~~~
+    pub fn first_issue(&self, level: ValidationLevel) -> Option<ValidationIssue> {
+        let mut first = None;
+        self.for_each_issue(level, &mut |issue| {
+            first = Some(issue);
+            ControlFlow::Break(())
+        });
+        first
+    }
+
+    /// Call `f` with each issue found at `level`, in order, until it breaks.
+    fn for_each_issue(
+        &self,
+        level: ValidationLevel,
+        f: &mut dyn FnMut(ValidationIssue) -> ControlFlow<()>,
+    ) {
+        // True once `f` has had enough.
+        let mut report = |issue| f(issue).is_break();
+
+        if level >= ValidationLevel::Standard {
+            let partition = self.known_partition();
+            if partition.is_none() && report(ValidationIssue::UnknownPartition) {
+                return;
+            }
+
+            if let Some(region) = self.region {
+                let issue = match Partition::of_region(region) {
+                    None => Some(ValidationIssue::InvalidRegion),
+                    Some(of_region) if partition.is_some() && partition != Some(of_region) => {
+                        Some(ValidationIssue::RegionNotInPartition)
+                    }
+                    Some(_) => None,
+                };
+                if issue.is_some_and(&mut report) {
+                    return;
+                }
+            }
+
+            if let Some(account_id) = self.account_id {
+                if account_id != "aws"
+                    && AccountId::parse(account_id).is_err()
+                    && report(ValidationIssue::InvalidAccountId)
+                {
+                    return;
+                }
+            }
+
+            let whitespace = [self.partition, self.service, self.resource]
+                .iter()
+                .chain(self.region.iter())
+                .chain(self.account_id.iter())
+                .any(|component| component.contains(char::is_whitespace));
+            if whitespace && report(ValidationIssue::Whitespace) {
+                return;
+            }
+        }
+
+        if level >= ValidationLevel::Strict {
+            #[cfg(feature = "alloc")]
+            if let Some(Err(err)) = try_interpret(*self) {
+                report(ValidationIssue::Service(err));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ValidationIssue, ValidationLevel};
+    use crate::naive::NaiveArn;
+    #[cfg(feature = "alloc")]
+    use crate::service::ParseServiceArnError;
+
+    fn first(arn_str: &str, level: ValidationLevel) -> Option<ValidationIssue> {
+        NaiveArn::parse(arn_str).unwrap().first_issue(level)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn validate(arn_str: &str, level: ValidationLevel) -> Result<(), Vec<ValidationIssue>> {
+        NaiveArn::parse(arn_str).unwrap().validate(level)
+    }
+
+    #[test]
+    fn levels() {
+        assert!(ValidationLevel::Syntax < ValidationLevel::Standard);
+        assert!(ValidationLevel::Standard < ValidationLevel::Strict);
+
+        for level in &["syntax", "standard", "strict"] {
+            assert_eq!(
+                level.parse::<ValidationLevel>().unwrap().to_string(),
+                *level
+            );
+        }
+        assert!("lenient".parse::<ValidationLevel>().is_err());
+    }
+
+    #[test]
+    fn valid() {
+        for arn_str in &[
+            "arn:aws:ec2:us-east-1:123456789012:vpc/vpc-fd580e98",
+            "arn:aws:s3:::my_corporate_bucket",
+            "arn:aws:iam::aws:policy/AdministratorAccess",
+            "arn:aws-cn:sqs:cn-north-1:123456789012:orders",
+            "arn:aws:gamelift:us-east-1:123456789012:fleet/fleet-1234",
+        ] {
+            assert_eq!(first(arn_str, ValidationLevel::Strict), None, "{}", arn_str);
+            #[cfg(feature = "alloc")]
+            assert_eq!(
+                validate(arn_str, ValidationLevel::Strict),
+                Ok(()),
+                "{}",
+                arn_str
+            );
+        }
+    }
+
+    #[test]
+    fn standard() {
+        for (arn_str, issue) in [
+            (
+                "arn:aws-moon:sqs:us-east-1:123456789012:orders",
+                ValidationIssue::UnknownPartition,
+            ),
+            (
+                "arn:aws:sqs:us-east:123456789012:orders",
+                ValidationIssue::InvalidRegion,
+            ),
+            (
+                "arn:aws:sqs:us-gov-west-1:123456789012:orders",
+                ValidationIssue::RegionNotInPartition,
+            ),
+            (
+                "arn:aws:sqs:us-east-1:1234-5678-9012:orders",
+                ValidationIssue::InvalidAccountId,
+            ),
+            (
+                "arn:aws:sqs:us-east-1:123456789012:orders ",
+                ValidationIssue::Whitespace,
+            ),
+        ] {
+            assert_eq!(first(arn_str, ValidationLevel::Syntax), None);
+            #[cfg(feature = "alloc")]
+            assert_eq!(
+                validate(arn_str, ValidationLevel::Standard).map_err(|issues| issues.len()),
+                Err(1),
+                "{}",
+                arn_str
+            );
+            assert_eq!(
+                first(arn_str, ValidationLevel::Standard),
+                Some(issue),
+                "{}",
+                arn_str
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn strict() {
+        let arn_str = "arn:aws:sqs:us-east-1:123456789012:orders/extra";
+
+        assert_eq!(validate(arn_str, ValidationLevel::Standard), Ok(()));
+        assert_eq!(
+            validate(arn_str, ValidationLevel::Strict),
+            Err(vec![ValidationIssue::Service(
+                ParseServiceArnError::InvalidResource
+            )])
+        );
+    }
+
+    #[test]
+    fn first_issue() {
+        let arn = NaiveArn::parse("arn:aws:sqs:us-east:1234:orders/extra").unwrap();
+
+        assert_eq!(arn.first_issue(ValidationLevel::Syntax), None);
+        assert_eq!(
+            arn.first_issue(ValidationLevel::Strict),
+            Some(ValidationIssue::InvalidRegion)
+        );
+        #[cfg(feature = "alloc")]
+        assert_eq!(arn.validate(ValidationLevel::Strict).unwrap_err().len(), 3);
+    }
+}
@@ -0,0 +1,153 @@
+//! Validating ARNs as they are deserialized
+//!
+//! Deserializing an [`ArnBuf`] only checks its syntax. Wrapping it in [`Standard`] or [`Strict`] also validates it
+//! at that [`ValidationLevel`], so malformed ARNs are rejected at the boundary of an API rather than deep inside it.
+//!
+//! # Example
+//!
+//! ~~~~
+//! use arn::buf::ArnBuf;
+//! use arn::validated::Strict;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Request {
+//!     queue: Strict<ArnBuf>,
+//! }
+//!
+//! let request: Request = serde_json::from_str(r#"{"queue": "arn:aws:sqs:us-east-1:123456789012:orders"}"#).unwrap();
+//! assert_eq!(request.queue.service, "sqs");
+//!
+//! let err = serde_json::from_str::<Request>(r#"{"queue": "arn:aws:sqs:us-east:123456789012:orders"}"#).err().unwrap();
+//! assert!(err.to_string().starts_with("ARN202: Invalid region"));
+//! ~~~~
+
+use core::ops::Deref;
+
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::buf::ArnBuf;
+use crate::naive::NaiveArn;
+use crate::validate::ValidationLevel;
+
+/// Deserialize a `T` and check it has no issues at `level`.
+fn deserialize_at<'de, D, T>(deserializer: D, level: ValidationLevel) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+    for<'a> &'a T: Into<NaiveArn<'a>>,
+{
+    let value = T::deserialize(deserializer)?;
+
+    match (&value).into().first_issue(level) {
+        Some(issue) => Err(de::Error::custom(issue)),
+        None => Ok(value),
+    }
+}
+
+/// An ARN that deserializes only if it is valid at [`ValidationLevel::Standard`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Standard<T = ArnBuf>(pub T);
+
+impl<T> Standard<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Standard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Serialize> Serialize for Standard<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Standard<T>
+where
+    T: Deserialize<'de>,
+    for<'a> &'a T: Into<NaiveArn<'a>>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_at(deserializer, ValidationLevel::Standard).map(Standard)
+    }
+}
+
+/// An ARN that deserializes only if it is valid at [`ValidationLevel::Strict`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Strict<T = ArnBuf>(pub T);
+
+impl<T> Strict<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Strict<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Serialize> Serialize for Strict<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Strict<T>
+where
+    T: Deserialize<'de>,
+    for<'a> &'a T: Into<NaiveArn<'a>>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_at(deserializer, ValidationLevel::Strict).map(Strict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::value::{BorrowedStrDeserializer, Error};
+    use serde::Deserialize;
+
+    use super::{Standard, Strict};
+    use crate::buf::ArnBuf;
+
+    fn deserializer(s: &str) -> BorrowedStrDeserializer<'_, Error> {
+        BorrowedStrDeserializer::new(s)
+    }
+
+    #[test]
+    fn levels() {
+        let arn_str = "arn:aws:sqs:us-east-1:123456789012:orders/extra";
+
+        assert!(ArnBuf::deserialize(deserializer(arn_str)).is_ok());
+        assert_eq!(
+            Standard::<ArnBuf>::deserialize(deserializer(arn_str))
+                .unwrap()
+                .into_inner(),
+            ArnBuf::parse(arn_str).unwrap()
+        );
+        assert_eq!(
+            Strict::<ArnBuf>::deserialize(deserializer(arn_str))
+                .unwrap_err()
+                .to_string(),
+            "ARN107: Invalid resource element"
+        );
+    }
+
+    #[test]
+    fn syntax_errors() {
+        let err = Standard::<ArnBuf>::deserialize(deserializer("arn:aws:s3")).unwrap_err();
+        assert!(err.to_string().starts_with("ARN001"));
+    }
+}
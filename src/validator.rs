@@ -0,0 +1,316 @@
+//! Validation with custom checks, for enforcing in-house conventions alongside the built-in validation
+
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use core::fmt;
+
+use crate::buf::ArnBuf;
+use crate::naive::{Component, NaiveArn, ParseNaiveArnError};
+use crate::region::Region;
+use crate::validate::{ValidationIssue, ValidationLevel};
+
+/// A custom check run by a [`Validator`], returning a message describing the problem if `arn` fails it.
+///
+/// Closures taking a `&NaiveArn` implement this, though they need the type of their argument spelled out.
+pub trait Check: Send + Sync {
+    fn check(&self, arn: &NaiveArn) -> Result<(), String>;
+}
+
+impl<F> Check for F
+where
+    F: Fn(&NaiveArn) -> Result<(), String> + Send + Sync,
+{
+    fn check(&self, arn: &NaiveArn) -> Result<(), String> {
+        self(arn)
+    }
+}
+
+/// Built-in validation at a [`ValidationLevel`], followed by named custom [`Check`]s in the order they were added.
+///
+/// # Example
+///
+/// ~~~~
+/// use arn::naive::{Component, NaiveArn};
+/// use arn::validate::ValidationLevel;
+/// use arn::validator::{Validator, ValidatorError};
+///
+/// let validator = Validator::new(ValidationLevel::Strict)
+///     .component("allowed-accounts", Component::AccountId, |account_id| {
+///         match account_id {
+///             "111111111111" | "222222222222" => Ok(()),
+///             _ => Err(format!("{} is not one of our accounts", account_id)),
+///         }
+///     })
+///     .check("team-prefix", |arn: &NaiveArn| {
+///         if arn.service != "sqs" || arn.resource.starts_with("payments-") {
+///             Ok(())
+///         } else {
+///             Err("queue names must start with the team name".to_owned())
+///         }
+///     });
+///
+/// assert!(validator.parse("arn:aws:sqs:us-east-1:111111111111:payments-orders").is_ok());
+///
+/// let err = validator.parse("arn:aws:sqs:us-east-1:333333333333:payments-orders").unwrap_err();
+/// assert!(matches!(err, ValidatorError::Check { ref check, .. } if check == "allowed-accounts"));
+/// ~~~~
+pub struct Validator {
+    level: ValidationLevel,
+    checks: Vec<(String, Box<dyn Check>)>,
+}
+
+impl Validator {
+    /// A validator running the built-in validation at `level`, and no custom checks until they are added.
+    pub fn new(level: ValidationLevel) -> Self {
+        Validator {
+            level,
+            checks: Vec::new(),
+        }
+    }
+
+    /// Add a check of the whole ARN, identified by `name` in errors.
+    pub fn check(mut self, name: impl Into<String>, check: impl Check + 'static) -> Self {
+        self.checks.push((name.into(), Box::new(check)));
+        self
+    }
+
+    /// Add a check of a single component, identified by `name` in errors.
+    ///
+    /// The check isn't run for an ARN without the component, such as the region of an IAM ARN.
+    pub fn component<F>(self, name: impl Into<String>, component: Component, check: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.check(name, move |arn: &NaiveArn| {
+            let value = match component {
+                Component::Prefix => Some("arn"),
+                Component::Partition => Some(arn.partition),
+                Component::Service => Some(arn.service),
+                Component::Region => arn.region,
+                Component::AccountId => arn.account_id,
+                Component::Resource => Some(arn.resource),
+            };
+
+            value.map_or(Ok(()), &check)
+        })
+    }
+
+    /// Only allow ARNs in one of `regions`, identified as `regions` in errors.
+    ///
+    /// ARNs without a region, such as IAM ARNs, pass.
+    pub fn regions<I>(self, regions: I) -> Self
+    where
+        I: IntoIterator<Item = Region>,
+    {
+        let regions: Vec<Region> = regions.into_iter().collect();
+
+        self.component("regions", Component::Region, move |region| {
+            if regions.iter().any(|allowed| allowed == region) {
+                Ok(())
+            } else {
+                Err(format!("{} is not an allowed region", region))
+            }
+        })
+    }
+
+    /// Validate `arn`, returning the first built-in issue or failed check.
+    pub fn validate(&self, arn: &NaiveArn) -> Result<(), ValidatorError> {
+        if let Some(issue) = arn.first_issue(self.level) {
+            return Err(ValidatorError::Issue(issue));
+        }
+
+        for (name, check) in &self.checks {
+            check.check(arn).map_err(|message| ValidatorError::Check {
+                check: name.clone(),
+                message,
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Parse and validate `s`.
+    pub fn parse<'a>(&self, s: &'a str) -> Result<NaiveArn<'a>, ValidatorError> {
+        let arn = NaiveArn::parse(s)?;
+        self.validate(&arn)?;
+        Ok(arn)
+    }
+
+    /// Validate an ARN built from its components, returning it if it passes.
+    pub fn build(&self, arn: ArnBuf) -> Result<ArnBuf, ValidatorError> {
+        self.validate(&arn.as_naive())?;
+        Ok(arn)
+    }
+}
+
+impl fmt::Debug for Validator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Validator")
+            .field("level", &self.level)
+            .field(
+                "checks",
+                &self.checks.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// Why a [`Validator`] rejected an ARN.
+#[derive(Debug, PartialEq)]
+pub enum ValidatorError {
+    Parse(ParseNaiveArnError),
+    Issue(ValidationIssue),
+    /// A custom check failed.
+    Check {
+        check: String,
+        message: String,
+    },
+}
+
+impl ValidatorError {
+    /// The code of the parse error or validation issue, or `ARN206` for a failed custom check.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ValidatorError::Parse(err) => err.code(),
+            ValidatorError::Issue(issue) => issue.code(),
+            ValidatorError::Check { .. } => "ARN206",
+        }
+    }
+}
+
+impl From<ParseNaiveArnError> for ValidatorError {
+    fn from(err: ParseNaiveArnError) -> Self {
+        ValidatorError::Parse(err)
+    }
+}
+
+impl From<ValidationIssue> for ValidatorError {
+    fn from(issue: ValidationIssue) -> Self {
+        ValidatorError::Issue(issue)
+    }
+}
+
+impl fmt::Display for ValidatorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidatorError::Parse(err) => err.fmt(f),
+            ValidatorError::Issue(issue) => issue.fmt(f),
+            ValidatorError::Check { check, message } => {
+                write!(f, "{}: Failed check {}: {}", self.code(), check, message)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidatorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidatorError::Parse(err) => Some(err),
+            ValidatorError::Issue(issue) => Some(issue),
+            ValidatorError::Check { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Validator, ValidatorError};
+    use crate::buf::ArnBuf;
+    use crate::naive::Component;
+    use crate::region::Region;
+    use crate::validate::{ValidationIssue, ValidationLevel};
+
+    fn validator() -> Validator {
+        Validator::new(ValidationLevel::Standard)
+            .component("lowercase", Component::Resource, |resource| {
+                if resource.chars().any(char::is_uppercase) {
+                    Err("resource must be lowercase".to_owned())
+                } else {
+                    Ok(())
+                }
+            })
+            .component("us-only", Component::Region, |region| {
+                if region.starts_with("us-") {
+                    Ok(())
+                } else {
+                    Err("region must be in the US".to_owned())
+                }
+            })
+    }
+
+    #[test]
+    fn passes() {
+        let validator = validator();
+
+        assert!(validator
+            .parse("arn:aws:sqs:us-east-1:123456789012:orders")
+            .is_ok());
+        assert!(validator
+            .parse("arn:aws:iam::123456789012:role/admin")
+            .is_ok());
+    }
+
+    #[test]
+    fn built_in_first() {
+        assert_eq!(
+            validator().parse("arn:aws:sqs:eu-west:123456789012:Orders"),
+            Err(ValidatorError::Issue(ValidationIssue::InvalidRegion))
+        );
+        assert!(matches!(
+            validator().parse("arn:aws:sqs"),
+            Err(ValidatorError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn checks_in_order() {
+        let err = validator()
+            .parse("arn:aws:sqs:eu-west-1:123456789012:Orders")
+            .unwrap_err();
+
+        assert_eq!(err.code(), "ARN206");
+        assert_eq!(
+            err.to_string(),
+            "ARN206: Failed check lowercase: resource must be lowercase"
+        );
+    }
+
+    #[test]
+    fn build() {
+        let arn = ArnBuf {
+            partition: "aws".to_owned(),
+            service: "sqs".to_owned(),
+            region: Some("eu-west-1".to_owned()),
+            account_id: Some("123456789012".to_owned()),
+            resource: "orders".to_owned(),
+        };
+
+        assert!(matches!(
+            validator().build(arn),
+            Err(ValidatorError::Check { check, .. }) if check == "us-only"
+        ));
+    }
+
+    #[test]
+    fn regions() {
+        let validator = Validator::new(ValidationLevel::Standard)
+            .regions([Region::EuWest1, Region::Other("eu-west-9".to_owned())]);
+
+        assert!(validator
+            .parse("arn:aws:sqs:eu-west-1:123456789012:orders")
+            .is_ok());
+        assert!(validator
+            .parse("arn:aws:sqs:eu-west-9:123456789012:orders")
+            .is_ok());
+        assert!(validator
+            .parse("arn:aws:iam::123456789012:role/admin")
+            .is_ok());
+        assert_eq!(
+            validator
+                .parse("arn:aws:sqs:us-east-1:123456789012:orders")
+                .unwrap_err()
+                .to_string(),
+            "ARN206: Failed check regions: us-east-1 is not an allowed region"
+        );
+    }
+}
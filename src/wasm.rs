@@ -0,0 +1,246 @@
+//! JavaScript bindings, for running the same ARN logic in browsers and Node.js
+//!
+//! Build the library as a `cdylib` for `wasm32-unknown-unknown` and generate the JavaScript glue with the
+//! `wasm-bindgen` CLI:
+//!
+//! ~~~~text
+//! cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/arn.wasm
+//! ~~~~
+//!
+//! Errors are thrown as `ArnError` objects, carrying the same stable codes as [`ParseNaiveArnError::code`].
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::buf::ArnBuf;
+use crate::naive::{NaiveArn, ParseNaiveArnError};
+use crate::pattern::ArnPattern;
+use crate::validate::{ValidationIssue, ValidationLevel};
+
+/// A parse error or validation issue.
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArnError {
+    code: &'static str,
+    message: String,
+    position: Option<usize>,
+}
+
+#[wasm_bindgen]
+impl ArnError {
+    /// The stable code of the error, such as `ARN004`.
+    #[wasm_bindgen(getter)]
+    pub fn code(&self) -> String {
+        self.code.to_owned()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    /// The byte offset into the input where parsing failed; undefined for validation issues.
+    #[wasm_bindgen(getter)]
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+}
+
+impl From<ParseNaiveArnError> for ArnError {
+    fn from(err: ParseNaiveArnError) -> Self {
+        ArnError {
+            code: err.code(),
+            message: err.to_string(),
+            position: Some(err.position()),
+        }
+    }
+}
+
+impl From<ValidationIssue> for ArnError {
+    fn from(issue: ValidationIssue) -> Self {
+        ArnError {
+            code: issue.code(),
+            message: issue.to_string(),
+            position: None,
+        }
+    }
+}
+
+/// How thoroughly to validate; see [`ValidationLevel`].
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Syntax,
+    Standard,
+    Strict,
+}
+
+impl From<Level> for ValidationLevel {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::Syntax => ValidationLevel::Syntax,
+            Level::Standard => ValidationLevel::Standard,
+            Level::Strict => ValidationLevel::Strict,
+        }
+    }
+}
+
+/// A parsed ARN.
+#[wasm_bindgen(js_name = Arn)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsArn(ArnBuf);
+
+#[wasm_bindgen(js_class = Arn)]
+impl JsArn {
+    /// An ARN from its components, throwing an `ArnError` if they don't form a valid one.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        partition: &str,
+        service: &str,
+        region: Option<String>,
+        account_id: Option<String>,
+        resource: &str,
+    ) -> Result<JsArn, ArnError> {
+        parse(&format(partition, service, region, account_id, resource))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn partition(&self) -> String {
+        self.0.partition.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn service(&self) -> String {
+        self.0.service.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn region(&self) -> Option<String> {
+        self.0.region.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = accountId)]
+    pub fn account_id(&self) -> Option<String> {
+        self.0.account_id.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn resource(&self) -> String {
+        self.0.resource.clone()
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// Parse `s`, throwing an `ArnError` if it isn't an ARN.
+#[wasm_bindgen]
+pub fn parse(s: &str) -> Result<JsArn, ArnError> {
+    ArnBuf::parse(s).map(JsArn).map_err(ArnError::from)
+}
+
+/// Format the components of an ARN, without checking them.
+#[wasm_bindgen]
+pub fn format(
+    partition: &str,
+    service: &str,
+    region: Option<String>,
+    account_id: Option<String>,
+    resource: &str,
+) -> String {
+    NaiveArn {
+        partition,
+        service,
+        region: region.as_deref(),
+        account_id: account_id.as_deref(),
+        resource,
+    }
+    .to_string()
+}
+
+/// Every issue with `s` at `level`, or an empty array if it is valid. Throws an `ArnError` if `s` isn't an ARN.
+#[wasm_bindgen]
+pub fn validate(s: &str, level: Level) -> Result<Vec<ArnError>, ArnError> {
+    let issues = NaiveArn::parse(s)?
+        .validate(level.into())
+        .err()
+        .unwrap_or_default();
+
+    Ok(issues.into_iter().map(ArnError::from).collect())
+}
+
+/// An IAM-style ARN pattern; see [`ArnPattern`].
+#[wasm_bindgen(js_name = ArnPattern)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsArnPattern(ArnPattern);
+
+#[wasm_bindgen(js_class = ArnPattern)]
+impl JsArnPattern {
+    /// Parse `pattern`, throwing an `ArnError` if it isn't one.
+    #[wasm_bindgen(constructor)]
+    pub fn new(pattern: &str) -> Result<JsArnPattern, ArnError> {
+        ArnPattern::parse(pattern)
+            .map(JsArnPattern)
+            .map_err(ArnError::from)
+    }
+
+    /// Whether `arn` is an ARN matching this pattern.
+    pub fn matches(&self, arn: &str) -> bool {
+        NaiveArn::parse(arn).is_ok_and(|arn| self.0.matches(&arn))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format, parse, validate, JsArn, JsArnPattern, Level};
+
+    #[test]
+    fn round_trip() {
+        let arn = parse("arn:aws:s3:::my_corporate_bucket").unwrap();
+
+        assert_eq!(arn.region(), None);
+        assert_eq!(
+            format(
+                &arn.partition(),
+                &arn.service(),
+                arn.region(),
+                arn.account_id(),
+                &arn.resource()
+            ),
+            arn.to_string()
+        );
+        assert_eq!(
+            JsArn::new("aws", "s3", None, None, "my_corporate_bucket"),
+            Ok(arn)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let err = parse("arn:aws:sqs:us east 1:123456789012:orders").unwrap_err();
+        assert_eq!((err.code().as_str(), err.position()), ("ARN007", Some(14)));
+
+        let issues = validate("arn:aws:sqs:us-east:123456789012:orders", Level::Standard).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            (issues[0].code().as_str(), issues[0].position()),
+            ("ARN202", None)
+        );
+        assert_eq!(
+            validate("arn:aws:sqs:us-east:123456789012:orders", Level::Syntax),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn pattern() {
+        let pattern = JsArnPattern::new("arn:aws:s3:::prod-*").unwrap();
+
+        assert!(pattern.matches("arn:aws:s3:::prod-logs"));
+        assert!(!pattern.matches("arn:aws:s3:::dev-logs"));
+        assert!(!pattern.matches("prod-logs"));
+    }
+}